@@ -261,7 +261,7 @@ type SessionGrid struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
 	// Session identifier (UUIDv4)
 	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
-	// Grid cell data. Maximum number of entities per message is 10000 (server-side limit)
+	// Grid cell data. Maximum number of entities per message is 10000 (server-side limit, configurable)
 	Data          []*Cell `protobuf:"bytes,2,rep,name=data,proto3" json:"data,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
@@ -120,6 +120,119 @@ func (ConflictZoneType) EnumDescriptor() ([]byte, []int) {
 	return file_conflict_zones_proto_rawDescGZIP(), []int{1}
 }
 
+// Priority rule preset expanding into the ConflictWinnerType of every conflict zone
+type ConflictPriorityPreset int32
+
+const (
+	ConflictPriorityPreset_CONFLICT_PRIORITY_PRESET_NONE          ConflictPriorityPreset = 0 // Keep the winner given for every zone
+	ConflictPriorityPreset_CONFLICT_PRIORITY_PRESET_PRIORITY_ROAD ConflictPriorityPreset = 1 // Movements leaving the priority road cells win, otherwise right-hand rule
+	ConflictPriorityPreset_CONFLICT_PRIORITY_PRESET_RIGHT_HAND    ConflictPriorityPreset = 2 // Movement coming from the right wins
+	ConflictPriorityPreset_CONFLICT_PRIORITY_PRESET_ALL_WAY_STOP  ConflictPriorityPreset = 3 // Nobody has priority (CONFLICT_WINNER_EQUAL)
+)
+
+// Enum value maps for ConflictPriorityPreset.
+var (
+	ConflictPriorityPreset_name = map[int32]string{
+		0: "CONFLICT_PRIORITY_PRESET_NONE",
+		1: "CONFLICT_PRIORITY_PRESET_PRIORITY_ROAD",
+		2: "CONFLICT_PRIORITY_PRESET_RIGHT_HAND",
+		3: "CONFLICT_PRIORITY_PRESET_ALL_WAY_STOP",
+	}
+	ConflictPriorityPreset_value = map[string]int32{
+		"CONFLICT_PRIORITY_PRESET_NONE":          0,
+		"CONFLICT_PRIORITY_PRESET_PRIORITY_ROAD": 1,
+		"CONFLICT_PRIORITY_PRESET_RIGHT_HAND":    2,
+		"CONFLICT_PRIORITY_PRESET_ALL_WAY_STOP":  3,
+	}
+)
+
+func (x ConflictPriorityPreset) Enum() *ConflictPriorityPreset {
+	p := new(ConflictPriorityPreset)
+	*p = x
+	return p
+}
+
+func (x ConflictPriorityPreset) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ConflictPriorityPreset) Descriptor() protoreflect.EnumDescriptor {
+	return file_conflict_zones_proto_enumTypes[2].Descriptor()
+}
+
+func (ConflictPriorityPreset) Type() protoreflect.EnumType {
+	return &file_conflict_zones_proto_enumTypes[2]
+}
+
+func (x ConflictPriorityPreset) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ConflictPriorityPreset.Descriptor instead.
+func (ConflictPriorityPreset) EnumDescriptor() ([]byte, []int) {
+	return file_conflict_zones_proto_rawDescGZIP(), []int{2}
+}
+
+// Priority rule applied to a batch of conflict zones.
+//
+// Headings are taken from the movements themselves (source point -> target point) with
+// X growing eastwards and Y northwards. A movement comes from the right of another one
+// when its heading is turned counterclockwise from the other heading; parallel and
+// opposite headings get CONFLICT_WINNER_EQUAL.
+type ConflictPriority struct {
+	state  protoimpl.MessageState `protogen:"open.v1"`
+	Preset ConflictPriorityPreset `protobuf:"varint,1,opt,name=preset,proto3,enum=micro_traffic_sim.ConflictPriorityPreset" json:"preset,omitempty"`
+	// Cells of the priority road (CONFLICT_PRIORITY_PRESET_PRIORITY_ROAD only, required).
+	// A movement belongs to the priority road when its source cell is listed
+	PriorityCells []int64 `protobuf:"varint,2,rep,packed,name=priority_cells,json=priorityCells,proto3" json:"priority_cells,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ConflictPriority) Reset() {
+	*x = ConflictPriority{}
+	mi := &file_conflict_zones_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ConflictPriority) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ConflictPriority) ProtoMessage() {}
+
+func (x *ConflictPriority) ProtoReflect() protoreflect.Message {
+	mi := &file_conflict_zones_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ConflictPriority.ProtoReflect.Descriptor instead.
+func (*ConflictPriority) Descriptor() ([]byte, []int) {
+	return file_conflict_zones_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *ConflictPriority) GetPreset() ConflictPriorityPreset {
+	if x != nil {
+		return x.Preset
+	}
+	return ConflictPriorityPreset_CONFLICT_PRIORITY_PRESET_NONE
+}
+
+func (x *ConflictPriority) GetPriorityCells() []int64 {
+	if x != nil {
+		return x.PriorityCells
+	}
+	return nil
+}
+
 // Conflict zone structure
 type ConflictZone struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
@@ -135,7 +248,7 @@ type ConflictZone struct {
 	TargetY int64 `protobuf:"varint,5,opt,name=target_y,json=targetY,proto3" json:"target_y,omitempty"`
 	// Conflict resolution priority
 	ConflictWinner ConflictWinnerType `protobuf:"varint,6,opt,name=conflict_winner,json=conflictWinner,proto3,enum=micro_traffic_sim.ConflictWinnerType" json:"conflict_winner,omitempty"`
-	// Conflict type. Currently unused - **FUTURE WORKS**
+	// Conflict type. Only CONFLICT_ZONE_TYPE_UNDEFINED is supported - **FUTURE WORKS**
 	ConflictType  ConflictZoneType `protobuf:"varint,7,opt,name=conflict_type,json=conflictType,proto3,enum=micro_traffic_sim.ConflictZoneType" json:"conflict_type,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
@@ -143,7 +256,7 @@ type ConflictZone struct {
 
 func (x *ConflictZone) Reset() {
 	*x = ConflictZone{}
-	mi := &file_conflict_zones_proto_msgTypes[0]
+	mi := &file_conflict_zones_proto_msgTypes[1]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -155,7 +268,7 @@ func (x *ConflictZone) String() string {
 func (*ConflictZone) ProtoMessage() {}
 
 func (x *ConflictZone) ProtoReflect() protoreflect.Message {
-	mi := &file_conflict_zones_proto_msgTypes[0]
+	mi := &file_conflict_zones_proto_msgTypes[1]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -168,7 +281,7 @@ func (x *ConflictZone) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ConflictZone.ProtoReflect.Descriptor instead.
 func (*ConflictZone) Descriptor() ([]byte, []int) {
-	return file_conflict_zones_proto_rawDescGZIP(), []int{0}
+	return file_conflict_zones_proto_rawDescGZIP(), []int{1}
 }
 
 func (x *ConflictZone) GetId() int64 {
@@ -225,15 +338,20 @@ type SessionConflictZones struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
 	// Session identifier (UUIDv4)
 	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
-	// Conflict zone data. Maximum number of entities per message is 10000 (server-side limit)
-	Data          []*ConflictZone `protobuf:"bytes,2,rep,name=data,proto3" json:"data,omitempty"`
+	// Conflict zone data. Maximum number of entities per message is 10000 (server-side limit, configurable).
+	// Both edges of a zone must be connections (forward / left / right) of the session grid,
+	// a pair of edges may only be used by one zone and a target cell may only belong to one
+	// zone (the core maps every target cell to a single conflict zone)
+	Data []*ConflictZone `protobuf:"bytes,2,rep,name=data,proto3" json:"data,omitempty"`
+	// Optional priority rule overriding `conflict_winner` of every zone of the message
+	Priority      *ConflictPriority `protobuf:"bytes,3,opt,name=priority,proto3" json:"priority,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *SessionConflictZones) Reset() {
 	*x = SessionConflictZones{}
-	mi := &file_conflict_zones_proto_msgTypes[1]
+	mi := &file_conflict_zones_proto_msgTypes[2]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -245,7 +363,7 @@ func (x *SessionConflictZones) String() string {
 func (*SessionConflictZones) ProtoMessage() {}
 
 func (x *SessionConflictZones) ProtoReflect() protoreflect.Message {
-	mi := &file_conflict_zones_proto_msgTypes[1]
+	mi := &file_conflict_zones_proto_msgTypes[2]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -258,7 +376,7 @@ func (x *SessionConflictZones) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SessionConflictZones.ProtoReflect.Descriptor instead.
 func (*SessionConflictZones) Descriptor() ([]byte, []int) {
-	return file_conflict_zones_proto_rawDescGZIP(), []int{1}
+	return file_conflict_zones_proto_rawDescGZIP(), []int{2}
 }
 
 func (x *SessionConflictZones) GetSessionId() *UUIDv4 {
@@ -275,6 +393,13 @@ func (x *SessionConflictZones) GetData() []*ConflictZone {
 	return nil
 }
 
+func (x *SessionConflictZones) GetPriority() *ConflictPriority {
+	if x != nil {
+		return x.Priority
+	}
+	return nil
+}
+
 // Server response for session conflict zones ingest
 type SessionConflictZonesResponse struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
@@ -288,7 +413,7 @@ type SessionConflictZonesResponse struct {
 
 func (x *SessionConflictZonesResponse) Reset() {
 	*x = SessionConflictZonesResponse{}
-	mi := &file_conflict_zones_proto_msgTypes[2]
+	mi := &file_conflict_zones_proto_msgTypes[3]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -300,7 +425,7 @@ func (x *SessionConflictZonesResponse) String() string {
 func (*SessionConflictZonesResponse) ProtoMessage() {}
 
 func (x *SessionConflictZonesResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_conflict_zones_proto_msgTypes[2]
+	mi := &file_conflict_zones_proto_msgTypes[3]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -313,7 +438,7 @@ func (x *SessionConflictZonesResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SessionConflictZonesResponse.ProtoReflect.Descriptor instead.
 func (*SessionConflictZonesResponse) Descriptor() ([]byte, []int) {
-	return file_conflict_zones_proto_rawDescGZIP(), []int{2}
+	return file_conflict_zones_proto_rawDescGZIP(), []int{3}
 }
 
 func (x *SessionConflictZonesResponse) GetCode() uint32 {
@@ -330,12 +455,181 @@ func (x *SessionConflictZonesResponse) GetText() string {
 	return ""
 }
 
+// Request to detect conflicting movements within coordination zones and build the
+// conflict zones for them.
+//
+// Movements are the forward / left / right connections of the grid with at least one
+// end in a ZONE_TYPE_COORDINATION cell. Two movements from different source cells
+// conflict when they merge into the same target cell or when their segments (source
+// point -> target point) cross. The core maps every target cell to a single conflict
+// zone, so a conflict whose target cells are already claimed (by a conflict zone of
+// the session or by an earlier generated one) is skipped.
+type GenerateConflictZonesRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Conflict resolution priority of every generated zone.
+	// CONFLICT_WINNER_UNDEFINED = CONFLICT_WINNER_EQUAL
+	DefaultWinner ConflictWinnerType `protobuf:"varint,2,opt,name=default_winner,json=defaultWinner,proto3,enum=micro_traffic_sim.ConflictWinnerType" json:"default_winner,omitempty"`
+	// Only return the zones for review, do not add them to the session
+	DryRun bool `protobuf:"varint,3,opt,name=dry_run,json=dryRun,proto3" json:"dry_run,omitempty"`
+	// Identifier of the first generated zone, the next ones being consecutive.
+	// 0 = right after the largest conflict zone identifier of the session.
+	// ALREADY_EXISTS when a generated identifier is taken by a zone of the session.
+	FirstId int64 `protobuf:"varint,4,opt,name=first_id,json=firstId,proto3" json:"first_id,omitempty"`
+	// Optional priority rule overriding `default_winner`
+	Priority      *ConflictPriority `protobuf:"bytes,5,opt,name=priority,proto3" json:"priority,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GenerateConflictZonesRequest) Reset() {
+	*x = GenerateConflictZonesRequest{}
+	mi := &file_conflict_zones_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GenerateConflictZonesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GenerateConflictZonesRequest) ProtoMessage() {}
+
+func (x *GenerateConflictZonesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_conflict_zones_proto_msgTypes[4]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GenerateConflictZonesRequest.ProtoReflect.Descriptor instead.
+func (*GenerateConflictZonesRequest) Descriptor() ([]byte, []int) {
+	return file_conflict_zones_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *GenerateConflictZonesRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *GenerateConflictZonesRequest) GetDefaultWinner() ConflictWinnerType {
+	if x != nil {
+		return x.DefaultWinner
+	}
+	return ConflictWinnerType_CONFLICT_WINNER_UNDEFINED
+}
+
+func (x *GenerateConflictZonesRequest) GetDryRun() bool {
+	if x != nil {
+		return x.DryRun
+	}
+	return false
+}
+
+func (x *GenerateConflictZonesRequest) GetFirstId() int64 {
+	if x != nil {
+		return x.FirstId
+	}
+	return 0
+}
+
+func (x *GenerateConflictZonesRequest) GetPriority() *ConflictPriority {
+	if x != nil {
+		return x.Priority
+	}
+	return nil
+}
+
+// Server response for conflict zones generation
+type GenerateConflictZonesResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Generated conflict zones
+	Data []*ConflictZone `protobuf:"bytes,3,rep,name=data,proto3" json:"data,omitempty"`
+	// Conflicts skipped because their target cells were already claimed
+	Skipped       uint32 `protobuf:"varint,4,opt,name=skipped,proto3" json:"skipped,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GenerateConflictZonesResponse) Reset() {
+	*x = GenerateConflictZonesResponse{}
+	mi := &file_conflict_zones_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GenerateConflictZonesResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GenerateConflictZonesResponse) ProtoMessage() {}
+
+func (x *GenerateConflictZonesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_conflict_zones_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GenerateConflictZonesResponse.ProtoReflect.Descriptor instead.
+func (*GenerateConflictZonesResponse) Descriptor() ([]byte, []int) {
+	return file_conflict_zones_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *GenerateConflictZonesResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *GenerateConflictZonesResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *GenerateConflictZonesResponse) GetData() []*ConflictZone {
+	if x != nil {
+		return x.Data
+	}
+	return nil
+}
+
+func (x *GenerateConflictZonesResponse) GetSkipped() uint32 {
+	if x != nil {
+		return x.Skipped
+	}
+	return 0
+}
+
 var File_conflict_zones_proto protoreflect.FileDescriptor
 
 const file_conflict_zones_proto_rawDesc = "" +
 	"\n" +
 	"\x14conflict_zones.proto\x12\x11micro_traffic_sim\x1a\n" +
-	"uuid.proto\"\xa4\x02\n" +
+	"uuid.proto\"|\n" +
+	"\x10ConflictPriority\x12A\n" +
+	"\x06preset\x18\x01 \x01(\x0e2).micro_traffic_sim.ConflictPriorityPresetR\x06preset\x12%\n" +
+	"\x0epriority_cells\x18\x02 \x03(\x03R\rpriorityCells\"\xa4\x02\n" +
 	"\fConflictZone\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\x03R\x02id\x12\x19\n" +
 	"\bsource_x\x18\x02 \x01(\x03R\asourceX\x12\x19\n" +
@@ -343,21 +637,39 @@ const file_conflict_zones_proto_rawDesc = "" +
 	"\btarget_x\x18\x04 \x01(\x03R\atargetX\x12\x19\n" +
 	"\btarget_y\x18\x05 \x01(\x03R\atargetY\x12N\n" +
 	"\x0fconflict_winner\x18\x06 \x01(\x0e2%.micro_traffic_sim.ConflictWinnerTypeR\x0econflictWinner\x12H\n" +
-	"\rconflict_type\x18\a \x01(\x0e2#.micro_traffic_sim.ConflictZoneTypeR\fconflictType\"\x85\x01\n" +
+	"\rconflict_type\x18\a \x01(\x0e2#.micro_traffic_sim.ConflictZoneTypeR\fconflictType\"\xc6\x01\n" +
 	"\x14SessionConflictZones\x128\n" +
 	"\n" +
 	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x123\n" +
-	"\x04data\x18\x02 \x03(\v2\x1f.micro_traffic_sim.ConflictZoneR\x04data\"F\n" +
+	"\x04data\x18\x02 \x03(\v2\x1f.micro_traffic_sim.ConflictZoneR\x04data\x12?\n" +
+	"\bpriority\x18\x03 \x01(\v2#.micro_traffic_sim.ConflictPriorityR\bpriority\"F\n" +
 	"\x1cSessionConflictZonesResponse\x12\x12\n" +
 	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
-	"\x04text\x18\x02 \x01(\tR\x04text*\x85\x01\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\"\x9b\x02\n" +
+	"\x1cGenerateConflictZonesRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12L\n" +
+	"\x0edefault_winner\x18\x02 \x01(\x0e2%.micro_traffic_sim.ConflictWinnerTypeR\rdefaultWinner\x12\x17\n" +
+	"\adry_run\x18\x03 \x01(\bR\x06dryRun\x12\x19\n" +
+	"\bfirst_id\x18\x04 \x01(\x03R\afirstId\x12?\n" +
+	"\bpriority\x18\x05 \x01(\v2#.micro_traffic_sim.ConflictPriorityR\bpriority\"\x96\x01\n" +
+	"\x1dGenerateConflictZonesResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x123\n" +
+	"\x04data\x18\x03 \x03(\v2\x1f.micro_traffic_sim.ConflictZoneR\x04data\x12\x18\n" +
+	"\askipped\x18\x04 \x01(\rR\askipped*\x85\x01\n" +
 	"\x12ConflictWinnerType\x12\x1d\n" +
 	"\x19CONFLICT_WINNER_UNDEFINED\x10\x00\x12\x19\n" +
 	"\x15CONFLICT_WINNER_EQUAL\x10\x01\x12\x19\n" +
 	"\x15CONFLICT_WINNER_FIRST\x10\x02\x12\x1a\n" +
 	"\x16CONFLICT_WINNER_SECOND\x10\x03*4\n" +
 	"\x10ConflictZoneType\x12 \n" +
-	"\x1cCONFLICT_ZONE_TYPE_UNDEFINED\x10\x00B@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+	"\x1cCONFLICT_ZONE_TYPE_UNDEFINED\x10\x00*\xbb\x01\n" +
+	"\x16ConflictPriorityPreset\x12!\n" +
+	"\x1dCONFLICT_PRIORITY_PRESET_NONE\x10\x00\x12*\n" +
+	"&CONFLICT_PRIORITY_PRESET_PRIORITY_ROAD\x10\x01\x12'\n" +
+	"#CONFLICT_PRIORITY_PRESET_RIGHT_HAND\x10\x02\x12)\n" +
+	"%CONFLICT_PRIORITY_PRESET_ALL_WAY_STOP\x10\x03B@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
 
 var (
 	file_conflict_zones_proto_rawDescOnce sync.Once
@@ -371,26 +683,36 @@ func file_conflict_zones_proto_rawDescGZIP() []byte {
 	return file_conflict_zones_proto_rawDescData
 }
 
-var file_conflict_zones_proto_enumTypes = make([]protoimpl.EnumInfo, 2)
-var file_conflict_zones_proto_msgTypes = make([]protoimpl.MessageInfo, 3)
+var file_conflict_zones_proto_enumTypes = make([]protoimpl.EnumInfo, 3)
+var file_conflict_zones_proto_msgTypes = make([]protoimpl.MessageInfo, 6)
 var file_conflict_zones_proto_goTypes = []any{
-	(ConflictWinnerType)(0),              // 0: micro_traffic_sim.ConflictWinnerType
-	(ConflictZoneType)(0),                // 1: micro_traffic_sim.ConflictZoneType
-	(*ConflictZone)(nil),                 // 2: micro_traffic_sim.ConflictZone
-	(*SessionConflictZones)(nil),         // 3: micro_traffic_sim.SessionConflictZones
-	(*SessionConflictZonesResponse)(nil), // 4: micro_traffic_sim.SessionConflictZonesResponse
-	(*UUIDv4)(nil),                       // 5: micro_traffic_sim.UUIDv4
+	(ConflictWinnerType)(0),               // 0: micro_traffic_sim.ConflictWinnerType
+	(ConflictZoneType)(0),                 // 1: micro_traffic_sim.ConflictZoneType
+	(ConflictPriorityPreset)(0),           // 2: micro_traffic_sim.ConflictPriorityPreset
+	(*ConflictPriority)(nil),              // 3: micro_traffic_sim.ConflictPriority
+	(*ConflictZone)(nil),                  // 4: micro_traffic_sim.ConflictZone
+	(*SessionConflictZones)(nil),          // 5: micro_traffic_sim.SessionConflictZones
+	(*SessionConflictZonesResponse)(nil),  // 6: micro_traffic_sim.SessionConflictZonesResponse
+	(*GenerateConflictZonesRequest)(nil),  // 7: micro_traffic_sim.GenerateConflictZonesRequest
+	(*GenerateConflictZonesResponse)(nil), // 8: micro_traffic_sim.GenerateConflictZonesResponse
+	(*UUIDv4)(nil),                        // 9: micro_traffic_sim.UUIDv4
 }
 var file_conflict_zones_proto_depIdxs = []int32{
-	0, // 0: micro_traffic_sim.ConflictZone.conflict_winner:type_name -> micro_traffic_sim.ConflictWinnerType
-	1, // 1: micro_traffic_sim.ConflictZone.conflict_type:type_name -> micro_traffic_sim.ConflictZoneType
-	5, // 2: micro_traffic_sim.SessionConflictZones.session_id:type_name -> micro_traffic_sim.UUIDv4
-	2, // 3: micro_traffic_sim.SessionConflictZones.data:type_name -> micro_traffic_sim.ConflictZone
-	4, // [4:4] is the sub-list for method output_type
-	4, // [4:4] is the sub-list for method input_type
-	4, // [4:4] is the sub-list for extension type_name
-	4, // [4:4] is the sub-list for extension extendee
-	0, // [0:4] is the sub-list for field type_name
+	2,  // 0: micro_traffic_sim.ConflictPriority.preset:type_name -> micro_traffic_sim.ConflictPriorityPreset
+	0,  // 1: micro_traffic_sim.ConflictZone.conflict_winner:type_name -> micro_traffic_sim.ConflictWinnerType
+	1,  // 2: micro_traffic_sim.ConflictZone.conflict_type:type_name -> micro_traffic_sim.ConflictZoneType
+	9,  // 3: micro_traffic_sim.SessionConflictZones.session_id:type_name -> micro_traffic_sim.UUIDv4
+	4,  // 4: micro_traffic_sim.SessionConflictZones.data:type_name -> micro_traffic_sim.ConflictZone
+	3,  // 5: micro_traffic_sim.SessionConflictZones.priority:type_name -> micro_traffic_sim.ConflictPriority
+	9,  // 6: micro_traffic_sim.GenerateConflictZonesRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	0,  // 7: micro_traffic_sim.GenerateConflictZonesRequest.default_winner:type_name -> micro_traffic_sim.ConflictWinnerType
+	3,  // 8: micro_traffic_sim.GenerateConflictZonesRequest.priority:type_name -> micro_traffic_sim.ConflictPriority
+	4,  // 9: micro_traffic_sim.GenerateConflictZonesResponse.data:type_name -> micro_traffic_sim.ConflictZone
+	10, // [10:10] is the sub-list for method output_type
+	10, // [10:10] is the sub-list for method input_type
+	10, // [10:10] is the sub-list for extension type_name
+	10, // [10:10] is the sub-list for extension extendee
+	0,  // [0:10] is the sub-list for field type_name
 }
 
 func init() { file_conflict_zones_proto_init() }
@@ -404,8 +726,8 @@ func file_conflict_zones_proto_init() {
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_conflict_zones_proto_rawDesc), len(file_conflict_zones_proto_rawDesc)),
-			NumEnums:      2,
-			NumMessages:   3,
+			NumEnums:      3,
+			NumMessages:   6,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
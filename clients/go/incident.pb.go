@@ -0,0 +1,438 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v6.33.1
+// source: incident.proto
+
+package microtraffic
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Kind of incident
+type IncidentType int32
+
+const (
+	IncidentType_INCIDENT_TYPE_UNDEFINED IncidentType = 0
+	// Cells are closed: vehicles can not enter them
+	IncidentType_INCIDENT_TYPE_BLOCK IncidentType = 1
+	// Speed limit of the cells is reduced to IncidentRequest.speed_limit
+	IncidentType_INCIDENT_TYPE_SPEED_LIMIT IncidentType = 2
+)
+
+// Enum value maps for IncidentType.
+var (
+	IncidentType_name = map[int32]string{
+		0: "INCIDENT_TYPE_UNDEFINED",
+		1: "INCIDENT_TYPE_BLOCK",
+		2: "INCIDENT_TYPE_SPEED_LIMIT",
+	}
+	IncidentType_value = map[string]int32{
+		"INCIDENT_TYPE_UNDEFINED":   0,
+		"INCIDENT_TYPE_BLOCK":       1,
+		"INCIDENT_TYPE_SPEED_LIMIT": 2,
+	}
+)
+
+func (x IncidentType) Enum() *IncidentType {
+	p := new(IncidentType)
+	*p = x
+	return p
+}
+
+func (x IncidentType) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (IncidentType) Descriptor() protoreflect.EnumDescriptor {
+	return file_incident_proto_enumTypes[0].Descriptor()
+}
+
+func (IncidentType) Type() protoreflect.EnumType {
+	return &file_incident_proto_enumTypes[0]
+}
+
+func (x IncidentType) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use IncidentType.Descriptor instead.
+func (IncidentType) EnumDescriptor() ([]byte, []int) {
+	return file_incident_proto_rawDescGZIP(), []int{0}
+}
+
+// Incident (lane closure, slowdown) applied to a set of cells for a time window.
+// Ticks are the simulation step timestamps (see SessionStepResponse.timestamp): the
+// incident is in effect for steps with start_tick <= timestamp < start_tick + duration_ticks.
+// A start_tick in the past means "from the next step".
+// Works on interactive sessions and on sessions currently owned by RunAndRecord.
+// Note: a blocked cell that is also controlled by a traffic light group gets its state
+// overwritten by the signal on every step, use INCIDENT_TYPE_SPEED_LIMIT for such cells.
+type InjectIncidentRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Kind of incident
+	Type IncidentType `protobuf:"varint,2,opt,name=type,proto3,enum=micro_traffic_sim.IncidentType" json:"type,omitempty"`
+	// Affected cells
+	Cells []int64 `protobuf:"varint,3,rep,packed,name=cells,proto3" json:"cells,omitempty"`
+	// Reduced speed limit (INCIDENT_TYPE_SPEED_LIMIT only). Never raises a speed limit
+	SpeedLimit int32 `protobuf:"varint,4,opt,name=speed_limit,json=speedLimit,proto3" json:"speed_limit,omitempty"`
+	// First tick the incident is in effect
+	StartTick uint64 `protobuf:"varint,5,opt,name=start_tick,json=startTick,proto3" json:"start_tick,omitempty"`
+	// Duration in ticks. 0 means until cleared by ClearIncident
+	DurationTicks uint64 `protobuf:"varint,6,opt,name=duration_ticks,json=durationTicks,proto3" json:"duration_ticks,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *InjectIncidentRequest) Reset() {
+	*x = InjectIncidentRequest{}
+	mi := &file_incident_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *InjectIncidentRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*InjectIncidentRequest) ProtoMessage() {}
+
+func (x *InjectIncidentRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_incident_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use InjectIncidentRequest.ProtoReflect.Descriptor instead.
+func (*InjectIncidentRequest) Descriptor() ([]byte, []int) {
+	return file_incident_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *InjectIncidentRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *InjectIncidentRequest) GetType() IncidentType {
+	if x != nil {
+		return x.Type
+	}
+	return IncidentType_INCIDENT_TYPE_UNDEFINED
+}
+
+func (x *InjectIncidentRequest) GetCells() []int64 {
+	if x != nil {
+		return x.Cells
+	}
+	return nil
+}
+
+func (x *InjectIncidentRequest) GetSpeedLimit() int32 {
+	if x != nil {
+		return x.SpeedLimit
+	}
+	return 0
+}
+
+func (x *InjectIncidentRequest) GetStartTick() uint64 {
+	if x != nil {
+		return x.StartTick
+	}
+	return 0
+}
+
+func (x *InjectIncidentRequest) GetDurationTicks() uint64 {
+	if x != nil {
+		return x.DurationTicks
+	}
+	return 0
+}
+
+// Server response for incident injection
+type InjectIncidentResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Server-assigned incident identifier, to be used with ClearIncident
+	IncidentId    int64 `protobuf:"varint,3,opt,name=incident_id,json=incidentId,proto3" json:"incident_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *InjectIncidentResponse) Reset() {
+	*x = InjectIncidentResponse{}
+	mi := &file_incident_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *InjectIncidentResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*InjectIncidentResponse) ProtoMessage() {}
+
+func (x *InjectIncidentResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_incident_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use InjectIncidentResponse.ProtoReflect.Descriptor instead.
+func (*InjectIncidentResponse) Descriptor() ([]byte, []int) {
+	return file_incident_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *InjectIncidentResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *InjectIncidentResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *InjectIncidentResponse) GetIncidentId() int64 {
+	if x != nil {
+		return x.IncidentId
+	}
+	return 0
+}
+
+// Request to lift an incident before its duration has passed
+type ClearIncidentRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Incident identifier returned by InjectIncident
+	IncidentId    int64 `protobuf:"varint,2,opt,name=incident_id,json=incidentId,proto3" json:"incident_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ClearIncidentRequest) Reset() {
+	*x = ClearIncidentRequest{}
+	mi := &file_incident_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ClearIncidentRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ClearIncidentRequest) ProtoMessage() {}
+
+func (x *ClearIncidentRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_incident_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ClearIncidentRequest.ProtoReflect.Descriptor instead.
+func (*ClearIncidentRequest) Descriptor() ([]byte, []int) {
+	return file_incident_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *ClearIncidentRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *ClearIncidentRequest) GetIncidentId() int64 {
+	if x != nil {
+		return x.IncidentId
+	}
+	return 0
+}
+
+// Server response for incident clearing. The cells are restored on the next step
+type ClearIncidentResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text          string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ClearIncidentResponse) Reset() {
+	*x = ClearIncidentResponse{}
+	mi := &file_incident_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ClearIncidentResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ClearIncidentResponse) ProtoMessage() {}
+
+func (x *ClearIncidentResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_incident_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ClearIncidentResponse.ProtoReflect.Descriptor instead.
+func (*ClearIncidentResponse) Descriptor() ([]byte, []int) {
+	return file_incident_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *ClearIncidentResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *ClearIncidentResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+var File_incident_proto protoreflect.FileDescriptor
+
+const file_incident_proto_rawDesc = "" +
+	"\n" +
+	"\x0eincident.proto\x12\x11micro_traffic_sim\x1a\n" +
+	"uuid.proto\"\x83\x02\n" +
+	"\x15InjectIncidentRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x123\n" +
+	"\x04type\x18\x02 \x01(\x0e2\x1f.micro_traffic_sim.IncidentTypeR\x04type\x12\x14\n" +
+	"\x05cells\x18\x03 \x03(\x03R\x05cells\x12\x1f\n" +
+	"\vspeed_limit\x18\x04 \x01(\x05R\n" +
+	"speedLimit\x12\x1d\n" +
+	"\n" +
+	"start_tick\x18\x05 \x01(\x04R\tstartTick\x12%\n" +
+	"\x0eduration_ticks\x18\x06 \x01(\x04R\rdurationTicks\"a\n" +
+	"\x16InjectIncidentResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x12\x1f\n" +
+	"\vincident_id\x18\x03 \x01(\x03R\n" +
+	"incidentId\"q\n" +
+	"\x14ClearIncidentRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x1f\n" +
+	"\vincident_id\x18\x02 \x01(\x03R\n" +
+	"incidentId\"?\n" +
+	"\x15ClearIncidentResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text*c\n" +
+	"\fIncidentType\x12\x1b\n" +
+	"\x17INCIDENT_TYPE_UNDEFINED\x10\x00\x12\x17\n" +
+	"\x13INCIDENT_TYPE_BLOCK\x10\x01\x12\x1d\n" +
+	"\x19INCIDENT_TYPE_SPEED_LIMIT\x10\x02B@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+
+var (
+	file_incident_proto_rawDescOnce sync.Once
+	file_incident_proto_rawDescData []byte
+)
+
+func file_incident_proto_rawDescGZIP() []byte {
+	file_incident_proto_rawDescOnce.Do(func() {
+		file_incident_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_incident_proto_rawDesc), len(file_incident_proto_rawDesc)))
+	})
+	return file_incident_proto_rawDescData
+}
+
+var file_incident_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
+var file_incident_proto_msgTypes = make([]protoimpl.MessageInfo, 4)
+var file_incident_proto_goTypes = []any{
+	(IncidentType)(0),              // 0: micro_traffic_sim.IncidentType
+	(*InjectIncidentRequest)(nil),  // 1: micro_traffic_sim.InjectIncidentRequest
+	(*InjectIncidentResponse)(nil), // 2: micro_traffic_sim.InjectIncidentResponse
+	(*ClearIncidentRequest)(nil),   // 3: micro_traffic_sim.ClearIncidentRequest
+	(*ClearIncidentResponse)(nil),  // 4: micro_traffic_sim.ClearIncidentResponse
+	(*UUIDv4)(nil),                 // 5: micro_traffic_sim.UUIDv4
+}
+var file_incident_proto_depIdxs = []int32{
+	5, // 0: micro_traffic_sim.InjectIncidentRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	0, // 1: micro_traffic_sim.InjectIncidentRequest.type:type_name -> micro_traffic_sim.IncidentType
+	5, // 2: micro_traffic_sim.ClearIncidentRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	3, // [3:3] is the sub-list for method output_type
+	3, // [3:3] is the sub-list for method input_type
+	3, // [3:3] is the sub-list for extension type_name
+	3, // [3:3] is the sub-list for extension extendee
+	0, // [0:3] is the sub-list for field type_name
+}
+
+func init() { file_incident_proto_init() }
+func file_incident_proto_init() {
+	if File_incident_proto != nil {
+		return
+	}
+	file_uuid_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_incident_proto_rawDesc), len(file_incident_proto_rawDesc)),
+			NumEnums:      1,
+			NumMessages:   4,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_incident_proto_goTypes,
+		DependencyIndexes: file_incident_proto_depIdxs,
+		EnumInfos:         file_incident_proto_enumTypes,
+		MessageInfos:      file_incident_proto_msgTypes,
+	}.Build()
+	File_incident_proto = out.File
+	file_incident_proto_goTypes = nil
+	file_incident_proto_depIdxs = nil
+}
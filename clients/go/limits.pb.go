@@ -0,0 +1,408 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v6.33.1
+// source: limits.proto
+
+package microtraffic
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Admission limits. 0 = unlimited
+type ResourceLimits struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Live sessions (recorded ones included)
+	MaxSessions uint64 `protobuf:"varint,1,opt,name=max_sessions,json=maxSessions,proto3" json:"max_sessions,omitempty"`
+	// Grid cells of a single session
+	MaxCellsPerSession uint64 `protobuf:"varint,2,opt,name=max_cells_per_session,json=maxCellsPerSession,proto3" json:"max_cells_per_session,omitempty"`
+	// Trips of a single session
+	MaxTripsPerSession uint64 `protobuf:"varint,3,opt,name=max_trips_per_session,json=maxTripsPerSession,proto3" json:"max_trips_per_session,omitempty"`
+	// RunAndRecord runs at the same time
+	MaxConcurrentRecordings uint64 `protobuf:"varint,4,opt,name=max_concurrent_recordings,json=maxConcurrentRecordings,proto3" json:"max_concurrent_recordings,omitempty"`
+	// RunAndRecord horizon (also caps runs with horizon_ticks = 0)
+	MaxHorizonTicks uint64 `protobuf:"varint,5,opt,name=max_horizon_ticks,json=maxHorizonTicks,proto3" json:"max_horizon_ticks,omitempty"`
+	unknownFields   protoimpl.UnknownFields
+	sizeCache       protoimpl.SizeCache
+}
+
+func (x *ResourceLimits) Reset() {
+	*x = ResourceLimits{}
+	mi := &file_limits_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ResourceLimits) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ResourceLimits) ProtoMessage() {}
+
+func (x *ResourceLimits) ProtoReflect() protoreflect.Message {
+	mi := &file_limits_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ResourceLimits.ProtoReflect.Descriptor instead.
+func (*ResourceLimits) Descriptor() ([]byte, []int) {
+	return file_limits_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *ResourceLimits) GetMaxSessions() uint64 {
+	if x != nil {
+		return x.MaxSessions
+	}
+	return 0
+}
+
+func (x *ResourceLimits) GetMaxCellsPerSession() uint64 {
+	if x != nil {
+		return x.MaxCellsPerSession
+	}
+	return 0
+}
+
+func (x *ResourceLimits) GetMaxTripsPerSession() uint64 {
+	if x != nil {
+		return x.MaxTripsPerSession
+	}
+	return 0
+}
+
+func (x *ResourceLimits) GetMaxConcurrentRecordings() uint64 {
+	if x != nil {
+		return x.MaxConcurrentRecordings
+	}
+	return 0
+}
+
+func (x *ResourceLimits) GetMaxHorizonTicks() uint64 {
+	if x != nil {
+		return x.MaxHorizonTicks
+	}
+	return 0
+}
+
+// Resources in use
+type ResourceUsage struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Live sessions (recorded ones included; expired sessions until they are purged)
+	Sessions uint64 `protobuf:"varint,1,opt,name=sessions,proto3" json:"sessions,omitempty"`
+	// Running RunAndRecord runs
+	RunningRecordings uint64 `protobuf:"varint,2,opt,name=running_recordings,json=runningRecordings,proto3" json:"running_recordings,omitempty"`
+	unknownFields     protoimpl.UnknownFields
+	sizeCache         protoimpl.SizeCache
+}
+
+func (x *ResourceUsage) Reset() {
+	*x = ResourceUsage{}
+	mi := &file_limits_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ResourceUsage) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ResourceUsage) ProtoMessage() {}
+
+func (x *ResourceUsage) ProtoReflect() protoreflect.Message {
+	mi := &file_limits_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ResourceUsage.ProtoReflect.Descriptor instead.
+func (*ResourceUsage) Descriptor() ([]byte, []int) {
+	return file_limits_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *ResourceUsage) GetSessions() uint64 {
+	if x != nil {
+		return x.Sessions
+	}
+	return 0
+}
+
+func (x *ResourceUsage) GetRunningRecordings() uint64 {
+	if x != nil {
+		return x.RunningRecordings
+	}
+	return 0
+}
+
+// Request for the limits applying to the caller
+type GetServerLimitsRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetServerLimitsRequest) Reset() {
+	*x = GetServerLimitsRequest{}
+	mi := &file_limits_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetServerLimitsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetServerLimitsRequest) ProtoMessage() {}
+
+func (x *GetServerLimitsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_limits_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetServerLimitsRequest.ProtoReflect.Descriptor instead.
+func (*GetServerLimitsRequest) Descriptor() ([]byte, []int) {
+	return file_limits_proto_rawDescGZIP(), []int{2}
+}
+
+// Limits applying to the caller and current usage. Exceeding a limit fails the call
+// with RESOURCE_EXHAUSTED.
+type GetServerLimitsResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Server-wide limits
+	Server *ResourceLimits `protobuf:"bytes,3,opt,name=server,proto3" json:"server,omitempty"`
+	// Server-wide usage
+	ServerUsage *ResourceUsage `protobuf:"bytes,4,opt,name=server_usage,json=serverUsage,proto3" json:"server_usage,omitempty"`
+	// Tenant of the caller (empty when authentication is disabled)
+	Tenant string `protobuf:"bytes,5,opt,name=tenant,proto3" json:"tenant,omitempty"`
+	// Limits of the caller's tenant (unset when authentication is disabled). Per-session
+	// limits are the tightest of the server and tenant ones
+	TenantLimits *ResourceLimits `protobuf:"bytes,6,opt,name=tenant_limits,json=tenantLimits,proto3" json:"tenant_limits,omitempty"`
+	// Usage of the caller's tenant (unset when authentication is disabled)
+	TenantUsage *ResourceUsage `protobuf:"bytes,7,opt,name=tenant_usage,json=tenantUsage,proto3" json:"tenant_usage,omitempty"`
+	// Maximum number of data entities in a single message
+	MaxEntitiesPerMessage uint64 `protobuf:"varint,8,opt,name=max_entities_per_message,json=maxEntitiesPerMessage,proto3" json:"max_entities_per_message,omitempty"`
+	// RunAndRecord ticks cap when the request sets no horizon
+	HorizonHardCap uint64 `protobuf:"varint,9,opt,name=horizon_hard_cap,json=horizonHardCap,proto3" json:"horizon_hard_cap,omitempty"`
+	// RunAndRecord ticks per batch when the request leaves batch_ticks = 0
+	DefaultBatchTicks uint32 `protobuf:"varint,10,opt,name=default_batch_ticks,json=defaultBatchTicks,proto3" json:"default_batch_ticks,omitempty"`
+	unknownFields     protoimpl.UnknownFields
+	sizeCache         protoimpl.SizeCache
+}
+
+func (x *GetServerLimitsResponse) Reset() {
+	*x = GetServerLimitsResponse{}
+	mi := &file_limits_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetServerLimitsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetServerLimitsResponse) ProtoMessage() {}
+
+func (x *GetServerLimitsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_limits_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetServerLimitsResponse.ProtoReflect.Descriptor instead.
+func (*GetServerLimitsResponse) Descriptor() ([]byte, []int) {
+	return file_limits_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *GetServerLimitsResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *GetServerLimitsResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *GetServerLimitsResponse) GetServer() *ResourceLimits {
+	if x != nil {
+		return x.Server
+	}
+	return nil
+}
+
+func (x *GetServerLimitsResponse) GetServerUsage() *ResourceUsage {
+	if x != nil {
+		return x.ServerUsage
+	}
+	return nil
+}
+
+func (x *GetServerLimitsResponse) GetTenant() string {
+	if x != nil {
+		return x.Tenant
+	}
+	return ""
+}
+
+func (x *GetServerLimitsResponse) GetTenantLimits() *ResourceLimits {
+	if x != nil {
+		return x.TenantLimits
+	}
+	return nil
+}
+
+func (x *GetServerLimitsResponse) GetTenantUsage() *ResourceUsage {
+	if x != nil {
+		return x.TenantUsage
+	}
+	return nil
+}
+
+func (x *GetServerLimitsResponse) GetMaxEntitiesPerMessage() uint64 {
+	if x != nil {
+		return x.MaxEntitiesPerMessage
+	}
+	return 0
+}
+
+func (x *GetServerLimitsResponse) GetHorizonHardCap() uint64 {
+	if x != nil {
+		return x.HorizonHardCap
+	}
+	return 0
+}
+
+func (x *GetServerLimitsResponse) GetDefaultBatchTicks() uint32 {
+	if x != nil {
+		return x.DefaultBatchTicks
+	}
+	return 0
+}
+
+var File_limits_proto protoreflect.FileDescriptor
+
+const file_limits_proto_rawDesc = "" +
+	"\n" +
+	"\flimits.proto\x12\x11micro_traffic_sim\"\x81\x02\n" +
+	"\x0eResourceLimits\x12!\n" +
+	"\fmax_sessions\x18\x01 \x01(\x04R\vmaxSessions\x121\n" +
+	"\x15max_cells_per_session\x18\x02 \x01(\x04R\x12maxCellsPerSession\x121\n" +
+	"\x15max_trips_per_session\x18\x03 \x01(\x04R\x12maxTripsPerSession\x12:\n" +
+	"\x19max_concurrent_recordings\x18\x04 \x01(\x04R\x17maxConcurrentRecordings\x12*\n" +
+	"\x11max_horizon_ticks\x18\x05 \x01(\x04R\x0fmaxHorizonTicks\"Z\n" +
+	"\rResourceUsage\x12\x1a\n" +
+	"\bsessions\x18\x01 \x01(\x04R\bsessions\x12-\n" +
+	"\x12running_recordings\x18\x02 \x01(\x04R\x11runningRecordings\"\x18\n" +
+	"\x16GetServerLimitsRequest\"\xf9\x03\n" +
+	"\x17GetServerLimitsResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x129\n" +
+	"\x06server\x18\x03 \x01(\v2!.micro_traffic_sim.ResourceLimitsR\x06server\x12C\n" +
+	"\fserver_usage\x18\x04 \x01(\v2 .micro_traffic_sim.ResourceUsageR\vserverUsage\x12\x16\n" +
+	"\x06tenant\x18\x05 \x01(\tR\x06tenant\x12F\n" +
+	"\rtenant_limits\x18\x06 \x01(\v2!.micro_traffic_sim.ResourceLimitsR\ftenantLimits\x12C\n" +
+	"\ftenant_usage\x18\a \x01(\v2 .micro_traffic_sim.ResourceUsageR\vtenantUsage\x127\n" +
+	"\x18max_entities_per_message\x18\b \x01(\x04R\x15maxEntitiesPerMessage\x12(\n" +
+	"\x10horizon_hard_cap\x18\t \x01(\x04R\x0ehorizonHardCap\x12.\n" +
+	"\x13default_batch_ticks\x18\n" +
+	" \x01(\rR\x11defaultBatchTicksB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+
+var (
+	file_limits_proto_rawDescOnce sync.Once
+	file_limits_proto_rawDescData []byte
+)
+
+func file_limits_proto_rawDescGZIP() []byte {
+	file_limits_proto_rawDescOnce.Do(func() {
+		file_limits_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_limits_proto_rawDesc), len(file_limits_proto_rawDesc)))
+	})
+	return file_limits_proto_rawDescData
+}
+
+var file_limits_proto_msgTypes = make([]protoimpl.MessageInfo, 4)
+var file_limits_proto_goTypes = []any{
+	(*ResourceLimits)(nil),          // 0: micro_traffic_sim.ResourceLimits
+	(*ResourceUsage)(nil),           // 1: micro_traffic_sim.ResourceUsage
+	(*GetServerLimitsRequest)(nil),  // 2: micro_traffic_sim.GetServerLimitsRequest
+	(*GetServerLimitsResponse)(nil), // 3: micro_traffic_sim.GetServerLimitsResponse
+}
+var file_limits_proto_depIdxs = []int32{
+	0, // 0: micro_traffic_sim.GetServerLimitsResponse.server:type_name -> micro_traffic_sim.ResourceLimits
+	1, // 1: micro_traffic_sim.GetServerLimitsResponse.server_usage:type_name -> micro_traffic_sim.ResourceUsage
+	0, // 2: micro_traffic_sim.GetServerLimitsResponse.tenant_limits:type_name -> micro_traffic_sim.ResourceLimits
+	1, // 3: micro_traffic_sim.GetServerLimitsResponse.tenant_usage:type_name -> micro_traffic_sim.ResourceUsage
+	4, // [4:4] is the sub-list for method output_type
+	4, // [4:4] is the sub-list for method input_type
+	4, // [4:4] is the sub-list for extension type_name
+	4, // [4:4] is the sub-list for extension extendee
+	0, // [0:4] is the sub-list for field type_name
+}
+
+func init() { file_limits_proto_init() }
+func file_limits_proto_init() {
+	if File_limits_proto != nil {
+		return
+	}
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_limits_proto_rawDesc), len(file_limits_proto_rawDesc)),
+			NumEnums:      0,
+			NumMessages:   4,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_limits_proto_goTypes,
+		DependencyIndexes: file_limits_proto_depIdxs,
+		MessageInfos:      file_limits_proto_msgTypes,
+	}.Build()
+	File_limits_proto = out.File
+	file_limits_proto_goTypes = nil
+	file_limits_proto_depIdxs = nil
+}
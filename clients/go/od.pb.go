@@ -0,0 +1,648 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v6.33.1
+// source: od.proto
+
+package microtraffic
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Traffic analysis zone: cells where its vehicles enter and leave the grid
+type OdZone struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Numeric zone identifier
+	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
+	// Cells where vehicles originating in the zone are generated
+	BirthCells []int64 `protobuf:"varint,2,rep,packed,name=birth_cells,json=birthCells,proto3" json:"birth_cells,omitempty"`
+	// Cells where vehicles destined to the zone finish their trips
+	DeathCells    []int64 `protobuf:"varint,3,rep,packed,name=death_cells,json=deathCells,proto3" json:"death_cells,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *OdZone) Reset() {
+	*x = OdZone{}
+	mi := &file_od_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *OdZone) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*OdZone) ProtoMessage() {}
+
+func (x *OdZone) ProtoReflect() protoreflect.Message {
+	mi := &file_od_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use OdZone.ProtoReflect.Descriptor instead.
+func (*OdZone) Descriptor() ([]byte, []int) {
+	return file_od_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *OdZone) GetId() int64 {
+	if x != nil {
+		return x.Id
+	}
+	return 0
+}
+
+func (x *OdZone) GetBirthCells() []int64 {
+	if x != nil {
+		return x.BirthCells
+	}
+	return nil
+}
+
+func (x *OdZone) GetDeathCells() []int64 {
+	if x != nil {
+		return x.DeathCells
+	}
+	return nil
+}
+
+// Demand between two zones
+type OdFlow struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Origin zone identifier
+	OriginZone int64 `protobuf:"varint,1,opt,name=origin_zone,json=originZone,proto3" json:"origin_zone,omitempty"`
+	// Destination zone identifier
+	DestinationZone int64 `protobuf:"varint,2,opt,name=destination_zone,json=destinationZone,proto3" json:"destination_zone,omitempty"`
+	// Demand, vehicles per hour
+	VehiclesPerHour float64 `protobuf:"fixed64,3,opt,name=vehicles_per_hour,json=vehiclesPerHour,proto3" json:"vehicles_per_hour,omitempty"`
+	unknownFields   protoimpl.UnknownFields
+	sizeCache       protoimpl.SizeCache
+}
+
+func (x *OdFlow) Reset() {
+	*x = OdFlow{}
+	mi := &file_od_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *OdFlow) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*OdFlow) ProtoMessage() {}
+
+func (x *OdFlow) ProtoReflect() protoreflect.Message {
+	mi := &file_od_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use OdFlow.ProtoReflect.Descriptor instead.
+func (*OdFlow) Descriptor() ([]byte, []int) {
+	return file_od_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *OdFlow) GetOriginZone() int64 {
+	if x != nil {
+		return x.OriginZone
+	}
+	return 0
+}
+
+func (x *OdFlow) GetDestinationZone() int64 {
+	if x != nil {
+		return x.DestinationZone
+	}
+	return 0
+}
+
+func (x *OdFlow) GetVehiclesPerHour() float64 {
+	if x != nil {
+		return x.VehiclesPerHour
+	}
+	return 0
+}
+
+// OD matrix valid for a time window (in simulation steps)
+type OdTimeSlice struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// First step of the slice
+	StartTime int64 `protobuf:"varint,1,opt,name=start_time,json=startTime,proto3" json:"start_time,omitempty"`
+	// Step the slice ends at (exclusive). 0 = until the end of the simulation
+	EndTime int64 `protobuf:"varint,2,opt,name=end_time,json=endTime,proto3" json:"end_time,omitempty"`
+	// Non-zero cells of the OD matrix
+	Flows         []*OdFlow `protobuf:"bytes,3,rep,name=flows,proto3" json:"flows,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *OdTimeSlice) Reset() {
+	*x = OdTimeSlice{}
+	mi := &file_od_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *OdTimeSlice) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*OdTimeSlice) ProtoMessage() {}
+
+func (x *OdTimeSlice) ProtoReflect() protoreflect.Message {
+	mi := &file_od_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use OdTimeSlice.ProtoReflect.Descriptor instead.
+func (*OdTimeSlice) Descriptor() ([]byte, []int) {
+	return file_od_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *OdTimeSlice) GetStartTime() int64 {
+	if x != nil {
+		return x.StartTime
+	}
+	return 0
+}
+
+func (x *OdTimeSlice) GetEndTime() int64 {
+	if x != nil {
+		return x.EndTime
+	}
+	return 0
+}
+
+func (x *OdTimeSlice) GetFlows() []*OdFlow {
+	if x != nil {
+		return x.Flows
+	}
+	return nil
+}
+
+// Share of the demand assigned to an agent type / behaviour type pair
+type OdAgentShare struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Agent type
+	AgentType AgentType `protobuf:"varint,1,opt,name=agent_type,json=agentType,proto3,enum=micro_traffic_sim.AgentType" json:"agent_type,omitempty"`
+	// Behaviour type
+	BehaviourType BehaviourType `protobuf:"varint,2,opt,name=behaviour_type,json=behaviourType,proto3,enum=micro_traffic_sim.BehaviourType" json:"behaviour_type,omitempty"`
+	// Weight of the pair. Weights are normalized by their sum
+	Share         float64 `protobuf:"fixed64,3,opt,name=share,proto3" json:"share,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *OdAgentShare) Reset() {
+	*x = OdAgentShare{}
+	mi := &file_od_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *OdAgentShare) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*OdAgentShare) ProtoMessage() {}
+
+func (x *OdAgentShare) ProtoReflect() protoreflect.Message {
+	mi := &file_od_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use OdAgentShare.ProtoReflect.Descriptor instead.
+func (*OdAgentShare) Descriptor() ([]byte, []int) {
+	return file_od_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *OdAgentShare) GetAgentType() AgentType {
+	if x != nil {
+		return x.AgentType
+	}
+	return AgentType_AGENT_TYPE_UNDEFINED
+}
+
+func (x *OdAgentShare) GetBehaviourType() BehaviourType {
+	if x != nil {
+		return x.BehaviourType
+	}
+	return BehaviourType_BEHAVIOUR_TYPE_UNDEFINED
+}
+
+func (x *OdAgentShare) GetShare() float64 {
+	if x != nil {
+		return x.Share
+	}
+	return 0
+}
+
+// OD demand, expanded into trips server-side (or with `micro_traffic_sim::od` on the
+// client side).
+//
+// Every flow is split evenly across the birth cells of its origin zone and the death
+// cells of its destination zone, then across the agent mix: one trip per (slice, flow,
+// agent share, birth cell, death cell). A birth cell generates at most one vehicle per
+// step (and none while it is occupied), so slices asking more than 3600 vehicles per
+// hour from a birth cell, summed over all the flows leaving it, are rejected.
+type OdMatrix struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Zones referenced by the flows
+	Zones []*OdZone `protobuf:"bytes,1,rep,name=zones,proto3" json:"zones,omitempty"`
+	// Time slices
+	Slices []*OdTimeSlice `protobuf:"bytes,2,rep,name=slices,proto3" json:"slices,omitempty"`
+	// Agent mix. Empty = AGENT_TYPE_CAR with BEHAVIOUR_TYPE_COOPERATIVE only
+	AgentMix []*OdAgentShare `protobuf:"bytes,3,rep,name=agent_mix,json=agentMix,proto3" json:"agent_mix,omitempty"`
+	// Generation pattern of the trips: TRIP_TYPE_RANDOM (default, Bernoulli arrivals)
+	// or TRIP_TYPE_CONSTANT (evenly spaced arrivals)
+	TripType TripType `protobuf:"varint,4,opt,name=trip_type,json=tripType,proto3,enum=micro_traffic_sim.TripType" json:"trip_type,omitempty"`
+	// Initial speed of generated vehicles
+	InitialSpeed  int64 `protobuf:"varint,5,opt,name=initial_speed,json=initialSpeed,proto3" json:"initial_speed,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *OdMatrix) Reset() {
+	*x = OdMatrix{}
+	mi := &file_od_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *OdMatrix) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*OdMatrix) ProtoMessage() {}
+
+func (x *OdMatrix) ProtoReflect() protoreflect.Message {
+	mi := &file_od_proto_msgTypes[4]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use OdMatrix.ProtoReflect.Descriptor instead.
+func (*OdMatrix) Descriptor() ([]byte, []int) {
+	return file_od_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *OdMatrix) GetZones() []*OdZone {
+	if x != nil {
+		return x.Zones
+	}
+	return nil
+}
+
+func (x *OdMatrix) GetSlices() []*OdTimeSlice {
+	if x != nil {
+		return x.Slices
+	}
+	return nil
+}
+
+func (x *OdMatrix) GetAgentMix() []*OdAgentShare {
+	if x != nil {
+		return x.AgentMix
+	}
+	return nil
+}
+
+func (x *OdMatrix) GetTripType() TripType {
+	if x != nil {
+		return x.TripType
+	}
+	return TripType_TRIP_TYPE_UNDEFINED
+}
+
+func (x *OdMatrix) GetInitialSpeed() int64 {
+	if x != nil {
+		return x.InitialSpeed
+	}
+	return 0
+}
+
+// OD matrix bound to a session
+type PushSessionOdMatrixRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// OD demand. Expands into at most 10000 trips (server-side limit, configurable)
+	Matrix *OdMatrix `protobuf:"bytes,2,opt,name=matrix,proto3" json:"matrix,omitempty"`
+	// Identifier of the first generated trip, the next ones being consecutive.
+	// 0 = right after the largest trip identifier of the session, removed trips included.
+	// Identifiers already taken by trips of the session are rejected (ALREADY_EXISTS)
+	FirstTripId int64 `protobuf:"varint,3,opt,name=first_trip_id,json=firstTripId,proto3" json:"first_trip_id,omitempty"`
+	// Reject the whole matrix (FAILED_PRECONDITION, no trip added) if any generated trip
+	// is unreachable over the grid pushed so far. Otherwise unreachable trips are added
+	// and only reported in PushSessionOdMatrixResponse.reachability
+	RejectUnreachable bool `protobuf:"varint,4,opt,name=reject_unreachable,json=rejectUnreachable,proto3" json:"reject_unreachable,omitempty"`
+	unknownFields     protoimpl.UnknownFields
+	sizeCache         protoimpl.SizeCache
+}
+
+func (x *PushSessionOdMatrixRequest) Reset() {
+	*x = PushSessionOdMatrixRequest{}
+	mi := &file_od_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PushSessionOdMatrixRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PushSessionOdMatrixRequest) ProtoMessage() {}
+
+func (x *PushSessionOdMatrixRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_od_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PushSessionOdMatrixRequest.ProtoReflect.Descriptor instead.
+func (*PushSessionOdMatrixRequest) Descriptor() ([]byte, []int) {
+	return file_od_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *PushSessionOdMatrixRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *PushSessionOdMatrixRequest) GetMatrix() *OdMatrix {
+	if x != nil {
+		return x.Matrix
+	}
+	return nil
+}
+
+func (x *PushSessionOdMatrixRequest) GetFirstTripId() int64 {
+	if x != nil {
+		return x.FirstTripId
+	}
+	return 0
+}
+
+func (x *PushSessionOdMatrixRequest) GetRejectUnreachable() bool {
+	if x != nil {
+		return x.RejectUnreachable
+	}
+	return false
+}
+
+// Server response for OD matrix ingest
+type PushSessionOdMatrixResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Identifier of the first generated trip
+	FirstTripId int64 `protobuf:"varint,3,opt,name=first_trip_id,json=firstTripId,proto3" json:"first_trip_id,omitempty"`
+	// Number of generated trips (identifiers first_trip_id .. first_trip_id + trips_count - 1)
+	TripsCount uint32 `protobuf:"varint,4,opt,name=trips_count,json=tripsCount,proto3" json:"trips_count,omitempty"`
+	// Route check of every generated trip, in trip identifier order
+	Reachability  []*TripReachability `protobuf:"bytes,5,rep,name=reachability,proto3" json:"reachability,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *PushSessionOdMatrixResponse) Reset() {
+	*x = PushSessionOdMatrixResponse{}
+	mi := &file_od_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PushSessionOdMatrixResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PushSessionOdMatrixResponse) ProtoMessage() {}
+
+func (x *PushSessionOdMatrixResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_od_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PushSessionOdMatrixResponse.ProtoReflect.Descriptor instead.
+func (*PushSessionOdMatrixResponse) Descriptor() ([]byte, []int) {
+	return file_od_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *PushSessionOdMatrixResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *PushSessionOdMatrixResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *PushSessionOdMatrixResponse) GetFirstTripId() int64 {
+	if x != nil {
+		return x.FirstTripId
+	}
+	return 0
+}
+
+func (x *PushSessionOdMatrixResponse) GetTripsCount() uint32 {
+	if x != nil {
+		return x.TripsCount
+	}
+	return 0
+}
+
+func (x *PushSessionOdMatrixResponse) GetReachability() []*TripReachability {
+	if x != nil {
+		return x.Reachability
+	}
+	return nil
+}
+
+var File_od_proto protoreflect.FileDescriptor
+
+const file_od_proto_rawDesc = "" +
+	"\n" +
+	"\bod.proto\x12\x11micro_traffic_sim\x1a\n" +
+	"uuid.proto\x1a\n" +
+	"step.proto\x1a\n" +
+	"trip.proto\"Z\n" +
+	"\x06OdZone\x12\x0e\n" +
+	"\x02id\x18\x01 \x01(\x03R\x02id\x12\x1f\n" +
+	"\vbirth_cells\x18\x02 \x03(\x03R\n" +
+	"birthCells\x12\x1f\n" +
+	"\vdeath_cells\x18\x03 \x03(\x03R\n" +
+	"deathCells\"\x80\x01\n" +
+	"\x06OdFlow\x12\x1f\n" +
+	"\vorigin_zone\x18\x01 \x01(\x03R\n" +
+	"originZone\x12)\n" +
+	"\x10destination_zone\x18\x02 \x01(\x03R\x0fdestinationZone\x12*\n" +
+	"\x11vehicles_per_hour\x18\x03 \x01(\x01R\x0fvehiclesPerHour\"x\n" +
+	"\vOdTimeSlice\x12\x1d\n" +
+	"\n" +
+	"start_time\x18\x01 \x01(\x03R\tstartTime\x12\x19\n" +
+	"\bend_time\x18\x02 \x01(\x03R\aendTime\x12/\n" +
+	"\x05flows\x18\x03 \x03(\v2\x19.micro_traffic_sim.OdFlowR\x05flows\"\xaa\x01\n" +
+	"\fOdAgentShare\x12;\n" +
+	"\n" +
+	"agent_type\x18\x01 \x01(\x0e2\x1c.micro_traffic_sim.AgentTypeR\tagentType\x12G\n" +
+	"\x0ebehaviour_type\x18\x02 \x01(\x0e2 .micro_traffic_sim.BehaviourTypeR\rbehaviourType\x12\x14\n" +
+	"\x05share\x18\x03 \x01(\x01R\x05share\"\x90\x02\n" +
+	"\bOdMatrix\x12/\n" +
+	"\x05zones\x18\x01 \x03(\v2\x19.micro_traffic_sim.OdZoneR\x05zones\x126\n" +
+	"\x06slices\x18\x02 \x03(\v2\x1e.micro_traffic_sim.OdTimeSliceR\x06slices\x12<\n" +
+	"\tagent_mix\x18\x03 \x03(\v2\x1f.micro_traffic_sim.OdAgentShareR\bagentMix\x128\n" +
+	"\ttrip_type\x18\x04 \x01(\x0e2\x1b.micro_traffic_sim.TripTypeR\btripType\x12#\n" +
+	"\rinitial_speed\x18\x05 \x01(\x03R\finitialSpeed\"\xde\x01\n" +
+	"\x1aPushSessionOdMatrixRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x123\n" +
+	"\x06matrix\x18\x02 \x01(\v2\x1b.micro_traffic_sim.OdMatrixR\x06matrix\x12\"\n" +
+	"\rfirst_trip_id\x18\x03 \x01(\x03R\vfirstTripId\x12-\n" +
+	"\x12reject_unreachable\x18\x04 \x01(\bR\x11rejectUnreachable\"\xd3\x01\n" +
+	"\x1bPushSessionOdMatrixResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x12\"\n" +
+	"\rfirst_trip_id\x18\x03 \x01(\x03R\vfirstTripId\x12\x1f\n" +
+	"\vtrips_count\x18\x04 \x01(\rR\n" +
+	"tripsCount\x12G\n" +
+	"\freachability\x18\x05 \x03(\v2#.micro_traffic_sim.TripReachabilityR\freachabilityB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+
+var (
+	file_od_proto_rawDescOnce sync.Once
+	file_od_proto_rawDescData []byte
+)
+
+func file_od_proto_rawDescGZIP() []byte {
+	file_od_proto_rawDescOnce.Do(func() {
+		file_od_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_od_proto_rawDesc), len(file_od_proto_rawDesc)))
+	})
+	return file_od_proto_rawDescData
+}
+
+var file_od_proto_msgTypes = make([]protoimpl.MessageInfo, 7)
+var file_od_proto_goTypes = []any{
+	(*OdZone)(nil),                      // 0: micro_traffic_sim.OdZone
+	(*OdFlow)(nil),                      // 1: micro_traffic_sim.OdFlow
+	(*OdTimeSlice)(nil),                 // 2: micro_traffic_sim.OdTimeSlice
+	(*OdAgentShare)(nil),                // 3: micro_traffic_sim.OdAgentShare
+	(*OdMatrix)(nil),                    // 4: micro_traffic_sim.OdMatrix
+	(*PushSessionOdMatrixRequest)(nil),  // 5: micro_traffic_sim.PushSessionOdMatrixRequest
+	(*PushSessionOdMatrixResponse)(nil), // 6: micro_traffic_sim.PushSessionOdMatrixResponse
+	(AgentType)(0),                      // 7: micro_traffic_sim.AgentType
+	(BehaviourType)(0),                  // 8: micro_traffic_sim.BehaviourType
+	(TripType)(0),                       // 9: micro_traffic_sim.TripType
+	(*UUIDv4)(nil),                      // 10: micro_traffic_sim.UUIDv4
+	(*TripReachability)(nil),            // 11: micro_traffic_sim.TripReachability
+}
+var file_od_proto_depIdxs = []int32{
+	1,  // 0: micro_traffic_sim.OdTimeSlice.flows:type_name -> micro_traffic_sim.OdFlow
+	7,  // 1: micro_traffic_sim.OdAgentShare.agent_type:type_name -> micro_traffic_sim.AgentType
+	8,  // 2: micro_traffic_sim.OdAgentShare.behaviour_type:type_name -> micro_traffic_sim.BehaviourType
+	0,  // 3: micro_traffic_sim.OdMatrix.zones:type_name -> micro_traffic_sim.OdZone
+	2,  // 4: micro_traffic_sim.OdMatrix.slices:type_name -> micro_traffic_sim.OdTimeSlice
+	3,  // 5: micro_traffic_sim.OdMatrix.agent_mix:type_name -> micro_traffic_sim.OdAgentShare
+	9,  // 6: micro_traffic_sim.OdMatrix.trip_type:type_name -> micro_traffic_sim.TripType
+	10, // 7: micro_traffic_sim.PushSessionOdMatrixRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	4,  // 8: micro_traffic_sim.PushSessionOdMatrixRequest.matrix:type_name -> micro_traffic_sim.OdMatrix
+	11, // 9: micro_traffic_sim.PushSessionOdMatrixResponse.reachability:type_name -> micro_traffic_sim.TripReachability
+	10, // [10:10] is the sub-list for method output_type
+	10, // [10:10] is the sub-list for method input_type
+	10, // [10:10] is the sub-list for extension type_name
+	10, // [10:10] is the sub-list for extension extendee
+	0,  // [0:10] is the sub-list for field type_name
+}
+
+func init() { file_od_proto_init() }
+func file_od_proto_init() {
+	if File_od_proto != nil {
+		return
+	}
+	file_uuid_proto_init()
+	file_step_proto_init()
+	file_trip_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_od_proto_rawDesc), len(file_od_proto_rawDesc)),
+			NumEnums:      0,
+			NumMessages:   7,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_od_proto_goTypes,
+		DependencyIndexes: file_od_proto_depIdxs,
+		MessageInfos:      file_od_proto_msgTypes,
+	}.Build()
+	File_od_proto = out.File
+	file_od_proto_goTypes = nil
+	file_od_proto_depIdxs = nil
+}
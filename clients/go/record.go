@@ -30,7 +30,15 @@ func signalChar(c byte) string {
 	}
 }
 
-// DecodeRecordBatch decodes a version-1 RecordBatch columns blob (as packed
+// idOrNone maps a stored u32 id back to int64, restoring the -1 sentinel.
+func idOrNone(v uint32) int64 {
+	if v == 0xFFFFFFFF {
+		return -1
+	}
+	return int64(v)
+}
+
+// DecodeRecordBatch decodes a RecordBatch columns blob (as packed
 // by the server's BatchAcc::to_blob and documented in protos/record.proto)
 // into a per-tick list of *SessionStepResponse, one element per tick in the
 // batch. Replaying a recording therefore yields the SAME proto types that the
@@ -43,8 +51,11 @@ func signalChar(c byte) string {
 // the on-wire centidegrees divided by 100; TravelTime is -1 because it is not
 // recorded), and TlsData built by grouping the tick's traffic-light signals by
 // tl_id into one TLSState per tl_id (each holding a TLGroup per group_id).
+// Version-2 blobs also carry the vehicle events section (see
+// RunMetadata.events_schema), decoded into Events; version-1 blobs yield no
+// events.
 //
-// It returns an error if the version byte is not 1 or if the input is
+// It returns an error if the version byte is not 1 or 2 or if the input is
 // truncated; it never panics. Empty batches (no ticks, no vehicle rows and/or
 // no traffic-light groups) decode without error.
 func DecodeRecordBatch(columns []byte) ([]*SessionStepResponse, error) {
@@ -79,8 +90,8 @@ func DecodeRecordBatch(columns []byte) ([]*SessionStepResponse, error) {
 	}
 	version := columns[o]
 	o++
-	if version != 1 {
-		return nil, fmt.Errorf("microtraffic: unsupported record blob version %d (expected 1)", version)
+	if version != 1 && version != 2 {
+		return nil, fmt.Errorf("microtraffic: unsupported record blob version %d (expected 1 or 2)", version)
 	}
 
 	tickStart, err := rdU32()
@@ -226,6 +237,56 @@ func DecodeRecordBatch(columns []byte) ([]*SessionStepResponse, error) {
 	tlSignals := columns[o : o+tickCount*gCount]
 	o += tickCount * gCount
 
+	// Vehicle events section (version 2 only).
+	eventsPerTick := make([]int, tickCount)
+	var events []*VehicleEvent
+	if version == 2 {
+		totalEvents := 0
+		for i := range eventsPerTick {
+			v, err := rdU32()
+			if err != nil {
+				return nil, err
+			}
+			eventsPerTick[i] = int(v)
+			totalEvents += int(v)
+		}
+		evVehicleID := make([]uint32, totalEvents)
+		for i := range evVehicleID {
+			v, err := rdU32()
+			if err != nil {
+				return nil, err
+			}
+			evVehicleID[i] = v
+		}
+		if err := need(totalEvents); err != nil {
+			return nil, err
+		}
+		evType := columns[o : o+totalEvents]
+		o += totalEvents
+		// trip, cell and from_cell columns, in this order
+		var evIDs [3][]uint32
+		for c := range evIDs {
+			evIDs[c] = make([]uint32, totalEvents)
+			for i := range evIDs[c] {
+				v, err := rdU32()
+				if err != nil {
+					return nil, err
+				}
+				evIDs[c][i] = v
+			}
+		}
+		events = make([]*VehicleEvent, totalEvents)
+		for i := range events {
+			events[i] = &VehicleEvent{
+				VehicleId: int64(evVehicleID[i]),
+				EventType: VehicleEventType(evType[i]),
+				TripId:    idOrNone(evIDs[0][i]),
+				Cell:      idOrNone(evIDs[1][i]),
+				FromCell:  idOrNone(evIDs[2][i]),
+			}
+		}
+	}
+
 	// slice returns the variable-length int64 values for the given row out of
 	// a cumulative-END-offset array (row i covers off[i-1]..off[i], off[-1]:=0).
 	slice := func(off []int, vals []uint32, row int) []int64 {
@@ -244,6 +305,7 @@ func DecodeRecordBatch(columns []byte) ([]*SessionStepResponse, error) {
 	responses := make([]*SessionStepResponse, 0, tickCount)
 
 	row := 0
+	event := 0
 	for t := 0; t < tickCount; t++ {
 		resp := &SessionStepResponse{
 			Code:      0,
@@ -285,6 +347,11 @@ func DecodeRecordBatch(columns []byte) ([]*SessionStepResponse, error) {
 			})
 		}
 
+		if n := eventsPerTick[t]; n > 0 {
+			resp.Events = events[event : event+n]
+			event += n
+		}
+
 		responses = append(responses, resp)
 	}
 
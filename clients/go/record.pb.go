@@ -91,9 +91,17 @@ type RunAndRecordRequest struct {
 	// Optional write-time reduction. LEAVE EMPTY FOR VALIDATION RUNS - any
 	// sampling/subsetting silently degrades a micro recording toward a sparse
 	// aggregate (the macro one-shot model we explicitly do not want here).
-	Filter        *RecordFilter `protobuf:"bytes,4,opt,name=filter,proto3" json:"filter,omitempty"`
-	unknownFields protoimpl.UnknownFields
-	sizeCache     protoimpl.SizeCache
+	Filter *RecordFilter `protobuf:"bytes,4,opt,name=filter,proto3" json:"filter,omitempty"`
+	// Append the per-tick vehicle lifecycle events section to every batch blob
+	// (layout version 2, see RECORD BLOB LAYOUT). false = version 1 blobs, byte
+	// identical to recordings made without this option.
+	IncludeEvents bool `protobuf:"varint,5,opt,name=include_events,json=includeEvents,proto3" json:"include_events,omitempty"`
+	// Compute macroscopic fundamental diagram samples every N ticks and return each
+	// with the RecordBatch during which its window closed (the trailing partial
+	// window in RunSummary.mfd). 0 = disabled.
+	MfdIntervalTicks uint32 `protobuf:"varint,6,opt,name=mfd_interval_ticks,json=mfdIntervalTicks,proto3" json:"mfd_interval_ticks,omitempty"`
+	unknownFields    protoimpl.UnknownFields
+	sizeCache        protoimpl.SizeCache
 }
 
 func (x *RunAndRecordRequest) Reset() {
@@ -154,6 +162,20 @@ func (x *RunAndRecordRequest) GetFilter() *RecordFilter {
 	return nil
 }
 
+func (x *RunAndRecordRequest) GetIncludeEvents() bool {
+	if x != nil {
+		return x.IncludeEvents
+	}
+	return false
+}
+
+func (x *RunAndRecordRequest) GetMfdIntervalTicks() uint32 {
+	if x != nil {
+		return x.MfdIntervalTicks
+	}
+	return 0
+}
+
 // Optional, lossy, write-time data reduction. Empty = full fidelity (every tick,
 // all vehicles).
 type RecordFilter struct {
@@ -348,7 +370,10 @@ type RunMetadata struct {
 	// Self-describing layout of the per-tick traffic-light signal section that
 	// follows the vehicle columns in the blob (see RECORD BLOB LAYOUT). Column
 	// types carry the element shape, e.g. "u8[tick_count*tl_group_count]".
-	TlSchema      *ColumnSchema `protobuf:"bytes,9,opt,name=tl_schema,json=tlSchema,proto3" json:"tl_schema,omitempty"`
+	TlSchema *ColumnSchema `protobuf:"bytes,9,opt,name=tl_schema,json=tlSchema,proto3" json:"tl_schema,omitempty"`
+	// Self-describing layout of the optional vehicle events section that follows
+	// the traffic-light section (present only when include_events was requested).
+	EventsSchema  *ColumnSchema `protobuf:"bytes,10,opt,name=events_schema,json=eventsSchema,proto3" json:"events_schema,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -446,6 +471,13 @@ func (x *RunMetadata) GetTlSchema() *ColumnSchema {
 	return nil
 }
 
+func (x *RunMetadata) GetEventsSchema() *ColumnSchema {
+	if x != nil {
+		return x.EventsSchema
+	}
+	return nil
+}
+
 // Declares the column order and element types of the columnar blob.
 type ColumnSchema struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
@@ -599,6 +631,19 @@ func (x *ColumnDef) GetType() string {
 //	u8   signal[tick_count * G]  // tick-major: tick k's G codes at [k*G .. k*G+G)
 //	                             //   0=undefined 1=r 2=y 3=g 4=G 5=s 6=u 7=o 8=O
 //
+// Vehicle events (version 2 only, i.e. RunAndRecordRequest.include_events). E =
+// total events in the batch. Same semantics as SessionStepResponse.events; -1 ids
+// are stored as 0xFFFFFFFF:
+//
+//	u32  events_per_tick[tick_count] // prefix-sum = event offsets
+//	u32  event_vehicle_id[E]
+//	u8   event_type[E]           // VehicleEventType: 1=spawned 2=arrived 3=lost
+//	                             //   4=lane_changed 5=red_light_stop 6=conflict_yield
+//	                             //   7=removed
+//	u32  event_trip_id[E]
+//	u32  event_cell[E]
+//	u32  event_from_cell[E]
+//
 // Notes:
 //   - Column-major + cell-id (no geometry) is what makes the blob compress several-fold
 //     (measured, gzip; Parquet dictionary+RLE+zstd is comparable). Compression is
@@ -624,7 +669,11 @@ type RecordBatch struct {
 	// Sum of vehicle rows across the batch (mirror, for buffer sizing).
 	TotalRows uint32 `protobuf:"varint,3,opt,name=total_rows,json=totalRows,proto3" json:"total_rows,omitempty"`
 	// The columnar blob (see RECORD BLOB LAYOUT above).
-	Columns       []byte `protobuf:"bytes,4,opt,name=columns,proto3" json:"columns,omitempty"`
+	Columns []byte `protobuf:"bytes,4,opt,name=columns,proto3" json:"columns,omitempty"`
+	// Speed limit changes (SetSpeedLimit) that took effect during the batch, in tick order.
+	SpeedLimitChanges []*SpeedLimitChange `protobuf:"bytes,5,rep,name=speed_limit_changes,json=speedLimitChanges,proto3" json:"speed_limit_changes,omitempty"`
+	// MFD samples whose window closed during the batch, in tick order.
+	Mfd           []*MfdSample `protobuf:"bytes,6,rep,name=mfd,proto3" json:"mfd,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -687,6 +736,20 @@ func (x *RecordBatch) GetColumns() []byte {
 	return nil
 }
 
+func (x *RecordBatch) GetSpeedLimitChanges() []*SpeedLimitChange {
+	if x != nil {
+		return x.SpeedLimitChanges
+	}
+	return nil
+}
+
+func (x *RecordBatch) GetMfd() []*MfdSample {
+	if x != nil {
+		return x.Mfd
+	}
+	return nil
+}
+
 // Final tallies, sent once after the last batch.
 type RunSummary struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
@@ -699,7 +762,10 @@ type RunSummary struct {
 	// Cumulative vehicles that reached their destination.
 	VehiclesCompleted int32 `protobuf:"varint,4,opt,name=vehicles_completed,json=vehiclesCompleted,proto3" json:"vehicles_completed,omitempty"`
 	// Cumulative vehicles lost (despawned in a death zone without arriving).
-	VehiclesLost  int32 `protobuf:"varint,5,opt,name=vehicles_lost,json=vehiclesLost,proto3" json:"vehicles_lost,omitempty"`
+	VehiclesLost int32 `protobuf:"varint,5,opt,name=vehicles_lost,json=vehiclesLost,proto3" json:"vehicles_lost,omitempty"`
+	// MFD sample of the trailing partial window, if any (complete windows come with
+	// the batches).
+	Mfd           []*MfdSample `protobuf:"bytes,6,rep,name=mfd,proto3" json:"mfd,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -769,6 +835,13 @@ func (x *RunSummary) GetVehiclesLost() int32 {
 	return 0
 }
 
+func (x *RunSummary) GetMfd() []*MfdSample {
+	if x != nil {
+		return x.Mfd
+	}
+	return nil
+}
+
 type RecordingStatusRequest struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
 	// Session whose recording to query.
@@ -983,14 +1056,16 @@ var File_record_proto protoreflect.FileDescriptor
 const file_record_proto_rawDesc = "" +
 	"\n" +
 	"\frecord.proto\x12\x11micro_traffic_sim\x1a\n" +
-	"uuid.proto\"\xce\x01\n" +
+	"uuid.proto\x1a\vstats.proto\x1a\x11speed_limit.proto\"\xa3\x02\n" +
 	"\x13RunAndRecordRequest\x128\n" +
 	"\n" +
 	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12#\n" +
 	"\rhorizon_ticks\x18\x02 \x01(\x04R\fhorizonTicks\x12\x1f\n" +
 	"\vbatch_ticks\x18\x03 \x01(\rR\n" +
 	"batchTicks\x127\n" +
-	"\x06filter\x18\x04 \x01(\v2\x1f.micro_traffic_sim.RecordFilterR\x06filter\"W\n" +
+	"\x06filter\x18\x04 \x01(\v2\x1f.micro_traffic_sim.RecordFilterR\x06filter\x12%\n" +
+	"\x0einclude_events\x18\x05 \x01(\bR\rincludeEvents\x12,\n" +
+	"\x12mfd_interval_ticks\x18\x06 \x01(\rR\x10mfdIntervalTicks\"W\n" +
 	"\fRecordFilter\x12#\n" +
 	"\rsample_period\x18\x01 \x01(\rR\fsamplePeriod\x12\"\n" +
 	"\rmeso_link_ids\x18\x02 \x03(\x03R\vmesoLinkIds\"\xd2\x01\n" +
@@ -998,7 +1073,7 @@ const file_record_proto_rawDesc = "" +
 	"\bmetadata\x18\x01 \x01(\v2\x1e.micro_traffic_sim.RunMetadataH\x00R\bmetadata\x126\n" +
 	"\x05batch\x18\x02 \x01(\v2\x1e.micro_traffic_sim.RecordBatchH\x00R\x05batch\x129\n" +
 	"\asummary\x18\x03 \x01(\v2\x1d.micro_traffic_sim.RunSummaryH\x00R\asummaryB\t\n" +
-	"\apayload\"\xfd\x02\n" +
+	"\apayload\"\xc3\x03\n" +
 	"\vRunMetadata\x12%\n" +
 	"\x0eformat_version\x18\x01 \x01(\rR\rformatVersion\x12!\n" +
 	"\ftick_seconds\x18\x02 \x01(\x01R\vtickSeconds\x12\x1d\n" +
@@ -1010,12 +1085,14 @@ const file_record_proto_rawDesc = "" +
 	"\vconfig_hash\x18\a \x01(\tR\n" +
 	"configHash\x127\n" +
 	"\x06schema\x18\b \x01(\v2\x1f.micro_traffic_sim.ColumnSchemaR\x06schema\x12<\n" +
-	"\ttl_schema\x18\t \x01(\v2\x1f.micro_traffic_sim.ColumnSchemaR\btlSchema\"F\n" +
+	"\ttl_schema\x18\t \x01(\v2\x1f.micro_traffic_sim.ColumnSchemaR\btlSchema\x12D\n" +
+	"\revents_schema\x18\n" +
+	" \x01(\v2\x1f.micro_traffic_sim.ColumnSchemaR\feventsSchema\"F\n" +
 	"\fColumnSchema\x126\n" +
 	"\acolumns\x18\x01 \x03(\v2\x1c.micro_traffic_sim.ColumnDefR\acolumns\"3\n" +
 	"\tColumnDef\x12\x12\n" +
 	"\x04name\x18\x01 \x01(\tR\x04name\x12\x12\n" +
-	"\x04type\x18\x02 \x01(\tR\x04type\"\x84\x01\n" +
+	"\x04type\x18\x02 \x01(\tR\x04type\"\x89\x02\n" +
 	"\vRecordBatch\x12\x1d\n" +
 	"\n" +
 	"tick_start\x18\x01 \x01(\x04R\ttickStart\x12\x1d\n" +
@@ -1023,7 +1100,9 @@ const file_record_proto_rawDesc = "" +
 	"tick_count\x18\x02 \x01(\rR\ttickCount\x12\x1d\n" +
 	"\n" +
 	"total_rows\x18\x03 \x01(\rR\ttotalRows\x12\x18\n" +
-	"\acolumns\x18\x04 \x01(\fR\acolumns\"\xc1\x01\n" +
+	"\acolumns\x18\x04 \x01(\fR\acolumns\x12S\n" +
+	"\x13speed_limit_changes\x18\x05 \x03(\v2#.micro_traffic_sim.SpeedLimitChangeR\x11speedLimitChanges\x12.\n" +
+	"\x03mfd\x18\x06 \x03(\v2\x1c.micro_traffic_sim.MfdSampleR\x03mfd\"\xf1\x01\n" +
 	"\n" +
 	"RunSummary\x12\x1f\n" +
 	"\vtotal_ticks\x18\x01 \x01(\x04R\n" +
@@ -1033,7 +1112,8 @@ const file_record_proto_rawDesc = "" +
 	"\vtotal_bytes\x18\x03 \x01(\x04R\n" +
 	"totalBytes\x12-\n" +
 	"\x12vehicles_completed\x18\x04 \x01(\x05R\x11vehiclesCompleted\x12#\n" +
-	"\rvehicles_lost\x18\x05 \x01(\x05R\fvehiclesLost\"R\n" +
+	"\rvehicles_lost\x18\x05 \x01(\x05R\fvehiclesLost\x12.\n" +
+	"\x03mfd\x18\x06 \x03(\v2\x1c.micro_traffic_sim.MfdSampleR\x03mfd\"R\n" +
 	"\x16RecordingStatusRequest\x128\n" +
 	"\n" +
 	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\"\xb4\x01\n" +
@@ -1081,6 +1161,8 @@ var file_record_proto_goTypes = []any{
 	(*StopRecordingRequest)(nil),    // 11: micro_traffic_sim.StopRecordingRequest
 	(*StopRecordingResponse)(nil),   // 12: micro_traffic_sim.StopRecordingResponse
 	(*UUIDv4)(nil),                  // 13: micro_traffic_sim.UUIDv4
+	(*SpeedLimitChange)(nil),        // 14: micro_traffic_sim.SpeedLimitChange
+	(*MfdSample)(nil),               // 15: micro_traffic_sim.MfdSample
 }
 var file_record_proto_depIdxs = []int32{
 	13, // 0: micro_traffic_sim.RunAndRecordRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
@@ -1090,15 +1172,19 @@ var file_record_proto_depIdxs = []int32{
 	8,  // 4: micro_traffic_sim.RunAndRecordResponse.summary:type_name -> micro_traffic_sim.RunSummary
 	5,  // 5: micro_traffic_sim.RunMetadata.schema:type_name -> micro_traffic_sim.ColumnSchema
 	5,  // 6: micro_traffic_sim.RunMetadata.tl_schema:type_name -> micro_traffic_sim.ColumnSchema
-	6,  // 7: micro_traffic_sim.ColumnSchema.columns:type_name -> micro_traffic_sim.ColumnDef
-	13, // 8: micro_traffic_sim.RecordingStatusRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
-	0,  // 9: micro_traffic_sim.RecordingStatusResponse.state:type_name -> micro_traffic_sim.RecordingState
-	13, // 10: micro_traffic_sim.StopRecordingRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
-	11, // [11:11] is the sub-list for method output_type
-	11, // [11:11] is the sub-list for method input_type
-	11, // [11:11] is the sub-list for extension type_name
-	11, // [11:11] is the sub-list for extension extendee
-	0,  // [0:11] is the sub-list for field type_name
+	5,  // 7: micro_traffic_sim.RunMetadata.events_schema:type_name -> micro_traffic_sim.ColumnSchema
+	6,  // 8: micro_traffic_sim.ColumnSchema.columns:type_name -> micro_traffic_sim.ColumnDef
+	14, // 9: micro_traffic_sim.RecordBatch.speed_limit_changes:type_name -> micro_traffic_sim.SpeedLimitChange
+	15, // 10: micro_traffic_sim.RecordBatch.mfd:type_name -> micro_traffic_sim.MfdSample
+	15, // 11: micro_traffic_sim.RunSummary.mfd:type_name -> micro_traffic_sim.MfdSample
+	13, // 12: micro_traffic_sim.RecordingStatusRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	0,  // 13: micro_traffic_sim.RecordingStatusResponse.state:type_name -> micro_traffic_sim.RecordingState
+	13, // 14: micro_traffic_sim.StopRecordingRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	15, // [15:15] is the sub-list for method output_type
+	15, // [15:15] is the sub-list for method input_type
+	15, // [15:15] is the sub-list for extension type_name
+	15, // [15:15] is the sub-list for extension extendee
+	0,  // [0:15] is the sub-list for field type_name
 }
 
 func init() { file_record_proto_init() }
@@ -1107,6 +1193,8 @@ func file_record_proto_init() {
 		return
 	}
 	file_uuid_proto_init()
+	file_stats_proto_init()
+	file_speed_limit_proto_init()
 	file_record_proto_msgTypes[2].OneofWrappers = []any{
 		(*RunAndRecordResponse_Metadata)(nil),
 		(*RunAndRecordResponse_Batch)(nil),
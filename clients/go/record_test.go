@@ -0,0 +1,127 @@
+package microtraffic
+
+import (
+	"encoding/binary"
+	"testing"
+)
+
+// blob is a little-endian writer for hand-built record blobs.
+type blob []byte
+
+func (b *blob) u8(vs ...uint8) { *b = append(*b, vs...) }
+
+func (b *blob) u16(vs ...uint16) {
+	for _, v := range vs {
+		*b = binary.LittleEndian.AppendUint16(*b, v)
+	}
+}
+
+func (b *blob) u32(vs ...uint32) {
+	for _, v := range vs {
+		*b = binary.LittleEndian.AppendUint32(*b, v)
+	}
+}
+
+// buildBlob packs a version-1 batch of two ticks (100 and 101) with three
+// vehicle rows and one traffic light of two groups, as the server does.
+func buildBlob() blob {
+	var b blob
+	b.u8(1)
+	// tick_start, tick_count K, total_rows R, rows_per_tick[K]
+	b.u32(100, 2, 3, 2, 1)
+	// vehicle_id[R], cell[R]
+	b.u32(10, 11, 12)
+	b.u32(5, 6, 7)
+	// agent_type[R]
+	b.u8(1, 2, 5)
+	// angle_cdeg[R], speed[R] (-1 as two's complement)
+	b.u16(0, 9000, 35999)
+	b.u16(3, 0, 0xFFFF)
+	// trip_id[R]
+	b.u32(1, 1, 2)
+	// ic_off[R] cumulative END offsets: row0 [100,101], row1 [], row2 [102]
+	b.u32(2, 2, 3)
+	b.u32(100, 101, 102)
+	// tail_off[R]: row0 [], row1 [200], row2 [201,202]
+	b.u32(0, 1, 3)
+	b.u32(200, 201, 202)
+	// G = 2 keys (tl_id, group_id), then signal[K*G] tick-major
+	b.u32(2, 1, 100, 1, 200)
+	b.u8(3, 1, 1, 3)
+	return b
+}
+
+func TestDecodeRecordBatch(t *testing.T) {
+	responses, err := DecodeRecordBatch(buildBlob())
+	if err != nil {
+		t.Fatalf("decode: %v", err)
+	}
+	if len(responses) != 2 {
+		t.Fatalf("got %d responses, want 2", len(responses))
+	}
+	r0 := responses[0]
+	if r0.Timestamp != 100 || len(r0.VehicleData) != 2 || len(r0.Events) != 0 {
+		t.Fatalf("unexpected tick 100: %v", r0)
+	}
+	v0 := r0.VehicleData[0]
+	if v0.VehicleId != 10 || v0.Cell != 5 || v0.VehicleType != AgentType_AGENT_TYPE_CAR || v0.Speed != 3 || v0.TravelTime != -1 {
+		t.Fatalf("unexpected vehicle row: %v", v0)
+	}
+	if len(v0.IntermediateCells) != 2 || v0.IntermediateCells[1] != 101 {
+		t.Fatalf("unexpected intermediate cells: %v", v0.IntermediateCells)
+	}
+	r1 := responses[1]
+	if r1.VehicleData[0].Speed != -1 || r1.VehicleData[0].Bearing != 359.99 || len(r1.VehicleData[0].TailCells) != 2 {
+		t.Fatalf("unexpected vehicle row: %v", r1.VehicleData[0])
+	}
+	if len(r1.TlsData) != 1 || len(r1.TlsData[0].Groups) != 2 || r1.TlsData[0].Groups[1].Signal != "g" {
+		t.Fatalf("unexpected traffic lights: %v", r1.TlsData)
+	}
+}
+
+func TestDecodeRecordBatchWithEvents(t *testing.T) {
+	b := buildBlob()
+	b[0] = 2
+	// events_per_tick[K]: 2 events at tick 100, 1 at tick 101
+	b.u32(2, 1)
+	// event_vehicle_id[E]
+	b.u32(10, 11, 12)
+	// event_type[E]: spawned, lane_changed, lost
+	b.u8(1, 4, 3)
+	// event_trip_id[E], event_cell[E] (lost vehicle has no known cell), event_from_cell[E]
+	b.u32(1, 1, 2)
+	b.u32(5, 6, 0xFFFFFFFF)
+	b.u32(0xFFFFFFFF, 9, 0xFFFFFFFF)
+
+	responses, err := DecodeRecordBatch(b)
+	if err != nil {
+		t.Fatalf("decode: %v", err)
+	}
+	e0 := responses[0].Events
+	if len(e0) != 2 {
+		t.Fatalf("got %d events at tick 100, want 2", len(e0))
+	}
+	if e0[0].VehicleId != 10 || e0[0].EventType != VehicleEventType_VEHICLE_EVENT_TYPE_SPAWNED || e0[0].Cell != 5 || e0[0].FromCell != -1 {
+		t.Fatalf("unexpected event: %v", e0[0])
+	}
+	if e0[1].EventType != VehicleEventType_VEHICLE_EVENT_TYPE_LANE_CHANGED || e0[1].FromCell != 9 {
+		t.Fatalf("unexpected event: %v", e0[1])
+	}
+	e1 := responses[1].Events
+	if len(e1) != 1 || e1[0].VehicleId != 12 || e1[0].EventType != VehicleEventType_VEHICLE_EVENT_TYPE_LOST || e1[0].TripId != 2 || e1[0].Cell != -1 {
+		t.Fatalf("unexpected events at tick 101: %v", e1)
+	}
+
+	// The events section is part of the blob: cutting it short is an error
+	if _, err := DecodeRecordBatch(b[:len(b)-1]); err == nil {
+		t.Fatal("expected an error for a truncated events section")
+	}
+}
+
+func TestDecodeRecordBatchRejectsUnknownVersion(t *testing.T) {
+	b := buildBlob()
+	b[0] = 3
+	if _, err := DecodeRecordBatch(b); err == nil {
+		t.Fatal("expected an error for version 3")
+	}
+}
@@ -0,0 +1,272 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v6.33.1
+// source: route.proto
+
+package microtraffic
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Request for the shortest route over the session grid (forward / left / right
+// connections, lane changes allowed), as the computation core routes vehicles
+type ComputeRouteRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Origin cell
+	FromCell int64 `protobuf:"varint,2,opt,name=from_cell,json=fromCell,proto3" json:"from_cell,omitempty"`
+	// Destination cell
+	ToCell int64 `protobuf:"varint,3,opt,name=to_cell,json=toCell,proto3" json:"to_cell,omitempty"`
+	// Optional cells to pass on the way, in order
+	Transits      []int64 `protobuf:"varint,4,rep,packed,name=transits,proto3" json:"transits,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ComputeRouteRequest) Reset() {
+	*x = ComputeRouteRequest{}
+	mi := &file_route_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ComputeRouteRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ComputeRouteRequest) ProtoMessage() {}
+
+func (x *ComputeRouteRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_route_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ComputeRouteRequest.ProtoReflect.Descriptor instead.
+func (*ComputeRouteRequest) Descriptor() ([]byte, []int) {
+	return file_route_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *ComputeRouteRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *ComputeRouteRequest) GetFromCell() int64 {
+	if x != nil {
+		return x.FromCell
+	}
+	return 0
+}
+
+func (x *ComputeRouteRequest) GetToCell() int64 {
+	if x != nil {
+		return x.ToCell
+	}
+	return 0
+}
+
+func (x *ComputeRouteRequest) GetTransits() []int64 {
+	if x != nil {
+		return x.Transits
+	}
+	return nil
+}
+
+// Server response for route query
+type ComputeRouteResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Cells from the origin to the destination (both included)
+	Cells []int64 `protobuf:"varint,3,rep,packed,name=cells,proto3" json:"cells,omitempty"`
+	// Route length in cells (number of moves)
+	LengthCells uint32 `protobuf:"varint,4,opt,name=length_cells,json=lengthCells,proto3" json:"length_cells,omitempty"`
+	// Geometric length: sum of distances between consecutive cells
+	Length float64 `protobuf:"fixed64,5,opt,name=length,proto3" json:"length,omitempty"`
+	// Free-flow travel time in simulation steps: sum of 1 / speed_limit over the
+	// traversed cells (the origin excluded)
+	FreeFlowTime float64 `protobuf:"fixed64,6,opt,name=free_flow_time,json=freeFlowTime,proto3" json:"free_flow_time,omitempty"`
+	// Meso links traversed, in order (consecutive repeats collapsed, cells without a
+	// meso link skipped)
+	MesoLinks     []int64 `protobuf:"varint,7,rep,packed,name=meso_links,json=mesoLinks,proto3" json:"meso_links,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ComputeRouteResponse) Reset() {
+	*x = ComputeRouteResponse{}
+	mi := &file_route_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ComputeRouteResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ComputeRouteResponse) ProtoMessage() {}
+
+func (x *ComputeRouteResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_route_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ComputeRouteResponse.ProtoReflect.Descriptor instead.
+func (*ComputeRouteResponse) Descriptor() ([]byte, []int) {
+	return file_route_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *ComputeRouteResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *ComputeRouteResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *ComputeRouteResponse) GetCells() []int64 {
+	if x != nil {
+		return x.Cells
+	}
+	return nil
+}
+
+func (x *ComputeRouteResponse) GetLengthCells() uint32 {
+	if x != nil {
+		return x.LengthCells
+	}
+	return 0
+}
+
+func (x *ComputeRouteResponse) GetLength() float64 {
+	if x != nil {
+		return x.Length
+	}
+	return 0
+}
+
+func (x *ComputeRouteResponse) GetFreeFlowTime() float64 {
+	if x != nil {
+		return x.FreeFlowTime
+	}
+	return 0
+}
+
+func (x *ComputeRouteResponse) GetMesoLinks() []int64 {
+	if x != nil {
+		return x.MesoLinks
+	}
+	return nil
+}
+
+var File_route_proto protoreflect.FileDescriptor
+
+const file_route_proto_rawDesc = "" +
+	"\n" +
+	"\vroute.proto\x12\x11micro_traffic_sim\x1a\n" +
+	"uuid.proto\"\xa1\x01\n" +
+	"\x13ComputeRouteRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x1b\n" +
+	"\tfrom_cell\x18\x02 \x01(\x03R\bfromCell\x12\x17\n" +
+	"\ato_cell\x18\x03 \x01(\x03R\x06toCell\x12\x1a\n" +
+	"\btransits\x18\x04 \x03(\x03R\btransits\"\xd4\x01\n" +
+	"\x14ComputeRouteResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x12\x14\n" +
+	"\x05cells\x18\x03 \x03(\x03R\x05cells\x12!\n" +
+	"\flength_cells\x18\x04 \x01(\rR\vlengthCells\x12\x16\n" +
+	"\x06length\x18\x05 \x01(\x01R\x06length\x12$\n" +
+	"\x0efree_flow_time\x18\x06 \x01(\x01R\ffreeFlowTime\x12\x1d\n" +
+	"\n" +
+	"meso_links\x18\a \x03(\x03R\tmesoLinksB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+
+var (
+	file_route_proto_rawDescOnce sync.Once
+	file_route_proto_rawDescData []byte
+)
+
+func file_route_proto_rawDescGZIP() []byte {
+	file_route_proto_rawDescOnce.Do(func() {
+		file_route_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_route_proto_rawDesc), len(file_route_proto_rawDesc)))
+	})
+	return file_route_proto_rawDescData
+}
+
+var file_route_proto_msgTypes = make([]protoimpl.MessageInfo, 2)
+var file_route_proto_goTypes = []any{
+	(*ComputeRouteRequest)(nil),  // 0: micro_traffic_sim.ComputeRouteRequest
+	(*ComputeRouteResponse)(nil), // 1: micro_traffic_sim.ComputeRouteResponse
+	(*UUIDv4)(nil),               // 2: micro_traffic_sim.UUIDv4
+}
+var file_route_proto_depIdxs = []int32{
+	2, // 0: micro_traffic_sim.ComputeRouteRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	1, // [1:1] is the sub-list for method output_type
+	1, // [1:1] is the sub-list for method input_type
+	1, // [1:1] is the sub-list for extension type_name
+	1, // [1:1] is the sub-list for extension extendee
+	0, // [0:1] is the sub-list for field type_name
+}
+
+func init() { file_route_proto_init() }
+func file_route_proto_init() {
+	if File_route_proto != nil {
+		return
+	}
+	file_uuid_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_route_proto_rawDesc), len(file_route_proto_rawDesc)),
+			NumEnums:      0,
+			NumMessages:   2,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_route_proto_goTypes,
+		DependencyIndexes: file_route_proto_depIdxs,
+		MessageInfos:      file_route_proto_msgTypes,
+	}.Build()
+	File_route_proto = out.File
+	file_route_proto_goTypes = nil
+	file_route_proto_depIdxs = nil
+}
@@ -28,7 +28,7 @@ const file_service_proto_rawDesc = "" +
 	"uuid.proto\x1a\rsession.proto\x1a\n" +
 	"cell.proto\x1a\n" +
 	"trip.proto\x1a\n" +
-	"step.proto\x1a\ttls.proto\x1a\x14conflict_zones.proto\x1a\frecord.proto2\xed\a\n" +
+	"step.proto\x1a\ttls.proto\x1a\x14conflict_zones.proto\x1a\frecord.proto\x1a\vstats.proto\x1a\flimits.proto\x1a\vroute.proto\x1a\bod.proto\x1a\rvehicle.proto\x1a\x11speed_limit.proto\x1a\x0eincident.proto2\xbe\x14\n" +
 	"\aService\x12T\n" +
 	"\n" +
 	"NewSession\x12\x1d.micro_traffic_sim.SessionReq\x1a%.micro_traffic_sim.NewSessionResponse\"\x00\x12R\n" +
@@ -40,29 +40,75 @@ const file_service_proto_rawDesc = "" +
 	"\x18PushSessionConflictZones\x12'.micro_traffic_sim.SessionConflictZones\x1a/.micro_traffic_sim.SessionConflictZonesResponse\"\x00(\x010\x01\x12c\n" +
 	"\fRunAndRecord\x12&.micro_traffic_sim.RunAndRecordRequest\x1a'.micro_traffic_sim.RunAndRecordResponse\"\x000\x01\x12j\n" +
 	"\x0fRecordingStatus\x12).micro_traffic_sim.RecordingStatusRequest\x1a*.micro_traffic_sim.RecordingStatusResponse\"\x00\x12d\n" +
-	"\rStopRecording\x12'.micro_traffic_sim.StopRecordingRequest\x1a(.micro_traffic_sim.StopRecordingResponse\"\x00B@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+	"\rStopRecording\x12'.micro_traffic_sim.StopRecordingRequest\x1a(.micro_traffic_sim.StopRecordingResponse\"\x00\x12[\n" +
+	"\fGetTripStats\x12#.micro_traffic_sim.TripStatsRequest\x1a$.micro_traffic_sim.TripStatsResponse\"\x00\x12s\n" +
+	"\x14GetTrafficLightStats\x12+.micro_traffic_sim.TrafficLightStatsRequest\x1a,.micro_traffic_sim.TrafficLightStatsResponse\"\x00\x12g\n" +
+	"\x0eInjectIncident\x12(.micro_traffic_sim.InjectIncidentRequest\x1a).micro_traffic_sim.InjectIncidentResponse\"\x00\x12d\n" +
+	"\rClearIncident\x12'.micro_traffic_sim.ClearIncidentRequest\x1a(.micro_traffic_sim.ClearIncidentResponse\"\x00\x12d\n" +
+	"\rSetSpeedLimit\x12'.micro_traffic_sim.SetSpeedLimitRequest\x1a(.micro_traffic_sim.SetSpeedLimitResponse\"\x00\x12a\n" +
+	"\fSpawnVehicle\x12&.micro_traffic_sim.SpawnVehicleRequest\x1a'.micro_traffic_sim.SpawnVehicleResponse\"\x00\x12d\n" +
+	"\rRemoveVehicle\x12'.micro_traffic_sim.RemoveVehicleRequest\x1a(.micro_traffic_sim.RemoveVehicleResponse\"\x00\x12[\n" +
+	"\n" +
+	"GetVehicle\x12$.micro_traffic_sim.GetVehicleRequest\x1a%.micro_traffic_sim.GetVehicleResponse\"\x00\x12p\n" +
+	"\x11UpdateSessionTrip\x12+.micro_traffic_sim.UpdateSessionTripRequest\x1a,.micro_traffic_sim.UpdateSessionTripResponse\"\x00\x12p\n" +
+	"\x11RemoveSessionTrip\x12+.micro_traffic_sim.RemoveSessionTripRequest\x1a,.micro_traffic_sim.RemoveSessionTripResponse\"\x00\x12y\n" +
+	"\x14SetSessionTripPaused\x12..micro_traffic_sim.SetSessionTripPausedRequest\x1a/.micro_traffic_sim.SetSessionTripPausedResponse\"\x00\x12v\n" +
+	"\x13PushSessionOdMatrix\x12-.micro_traffic_sim.PushSessionOdMatrixRequest\x1a..micro_traffic_sim.PushSessionOdMatrixResponse\"\x00\x12a\n" +
+	"\fComputeRoute\x12&.micro_traffic_sim.ComputeRouteRequest\x1a'.micro_traffic_sim.ComputeRouteResponse\"\x00\x12|\n" +
+	"\x15GenerateConflictZones\x12/.micro_traffic_sim.GenerateConflictZonesRequest\x1a0.micro_traffic_sim.GenerateConflictZonesResponse\"\x00\x12j\n" +
+	"\x0fGetServerLimits\x12).micro_traffic_sim.GetServerLimitsRequest\x1a*.micro_traffic_sim.GetServerLimitsResponse\"\x00B@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
 
 var file_service_proto_goTypes = []any{
-	(*SessionReq)(nil),                   // 0: micro_traffic_sim.SessionReq
-	(*UUIDv4)(nil),                       // 1: micro_traffic_sim.UUIDv4
-	(*SessionGrid)(nil),                  // 2: micro_traffic_sim.SessionGrid
-	(*SessionTrip)(nil),                  // 3: micro_traffic_sim.SessionTrip
-	(*SessionStep)(nil),                  // 4: micro_traffic_sim.SessionStep
-	(*SessionTLS)(nil),                   // 5: micro_traffic_sim.SessionTLS
-	(*SessionConflictZones)(nil),         // 6: micro_traffic_sim.SessionConflictZones
-	(*RunAndRecordRequest)(nil),          // 7: micro_traffic_sim.RunAndRecordRequest
-	(*RecordingStatusRequest)(nil),       // 8: micro_traffic_sim.RecordingStatusRequest
-	(*StopRecordingRequest)(nil),         // 9: micro_traffic_sim.StopRecordingRequest
-	(*NewSessionResponse)(nil),           // 10: micro_traffic_sim.NewSessionResponse
-	(*InfoSessionResponse)(nil),          // 11: micro_traffic_sim.InfoSessionResponse
-	(*SessionGridResponse)(nil),          // 12: micro_traffic_sim.SessionGridResponse
-	(*SessionTripResponse)(nil),          // 13: micro_traffic_sim.SessionTripResponse
-	(*SessionStepResponse)(nil),          // 14: micro_traffic_sim.SessionStepResponse
-	(*SessionTLSResponse)(nil),           // 15: micro_traffic_sim.SessionTLSResponse
-	(*SessionConflictZonesResponse)(nil), // 16: micro_traffic_sim.SessionConflictZonesResponse
-	(*RunAndRecordResponse)(nil),         // 17: micro_traffic_sim.RunAndRecordResponse
-	(*RecordingStatusResponse)(nil),      // 18: micro_traffic_sim.RecordingStatusResponse
-	(*StopRecordingResponse)(nil),        // 19: micro_traffic_sim.StopRecordingResponse
+	(*SessionReq)(nil),                    // 0: micro_traffic_sim.SessionReq
+	(*UUIDv4)(nil),                        // 1: micro_traffic_sim.UUIDv4
+	(*SessionGrid)(nil),                   // 2: micro_traffic_sim.SessionGrid
+	(*SessionTrip)(nil),                   // 3: micro_traffic_sim.SessionTrip
+	(*SessionStep)(nil),                   // 4: micro_traffic_sim.SessionStep
+	(*SessionTLS)(nil),                    // 5: micro_traffic_sim.SessionTLS
+	(*SessionConflictZones)(nil),          // 6: micro_traffic_sim.SessionConflictZones
+	(*RunAndRecordRequest)(nil),           // 7: micro_traffic_sim.RunAndRecordRequest
+	(*RecordingStatusRequest)(nil),        // 8: micro_traffic_sim.RecordingStatusRequest
+	(*StopRecordingRequest)(nil),          // 9: micro_traffic_sim.StopRecordingRequest
+	(*TripStatsRequest)(nil),              // 10: micro_traffic_sim.TripStatsRequest
+	(*TrafficLightStatsRequest)(nil),      // 11: micro_traffic_sim.TrafficLightStatsRequest
+	(*InjectIncidentRequest)(nil),         // 12: micro_traffic_sim.InjectIncidentRequest
+	(*ClearIncidentRequest)(nil),          // 13: micro_traffic_sim.ClearIncidentRequest
+	(*SetSpeedLimitRequest)(nil),          // 14: micro_traffic_sim.SetSpeedLimitRequest
+	(*SpawnVehicleRequest)(nil),           // 15: micro_traffic_sim.SpawnVehicleRequest
+	(*RemoveVehicleRequest)(nil),          // 16: micro_traffic_sim.RemoveVehicleRequest
+	(*GetVehicleRequest)(nil),             // 17: micro_traffic_sim.GetVehicleRequest
+	(*UpdateSessionTripRequest)(nil),      // 18: micro_traffic_sim.UpdateSessionTripRequest
+	(*RemoveSessionTripRequest)(nil),      // 19: micro_traffic_sim.RemoveSessionTripRequest
+	(*SetSessionTripPausedRequest)(nil),   // 20: micro_traffic_sim.SetSessionTripPausedRequest
+	(*PushSessionOdMatrixRequest)(nil),    // 21: micro_traffic_sim.PushSessionOdMatrixRequest
+	(*ComputeRouteRequest)(nil),           // 22: micro_traffic_sim.ComputeRouteRequest
+	(*GenerateConflictZonesRequest)(nil),  // 23: micro_traffic_sim.GenerateConflictZonesRequest
+	(*GetServerLimitsRequest)(nil),        // 24: micro_traffic_sim.GetServerLimitsRequest
+	(*NewSessionResponse)(nil),            // 25: micro_traffic_sim.NewSessionResponse
+	(*InfoSessionResponse)(nil),           // 26: micro_traffic_sim.InfoSessionResponse
+	(*SessionGridResponse)(nil),           // 27: micro_traffic_sim.SessionGridResponse
+	(*SessionTripResponse)(nil),           // 28: micro_traffic_sim.SessionTripResponse
+	(*SessionStepResponse)(nil),           // 29: micro_traffic_sim.SessionStepResponse
+	(*SessionTLSResponse)(nil),            // 30: micro_traffic_sim.SessionTLSResponse
+	(*SessionConflictZonesResponse)(nil),  // 31: micro_traffic_sim.SessionConflictZonesResponse
+	(*RunAndRecordResponse)(nil),          // 32: micro_traffic_sim.RunAndRecordResponse
+	(*RecordingStatusResponse)(nil),       // 33: micro_traffic_sim.RecordingStatusResponse
+	(*StopRecordingResponse)(nil),         // 34: micro_traffic_sim.StopRecordingResponse
+	(*TripStatsResponse)(nil),             // 35: micro_traffic_sim.TripStatsResponse
+	(*TrafficLightStatsResponse)(nil),     // 36: micro_traffic_sim.TrafficLightStatsResponse
+	(*InjectIncidentResponse)(nil),        // 37: micro_traffic_sim.InjectIncidentResponse
+	(*ClearIncidentResponse)(nil),         // 38: micro_traffic_sim.ClearIncidentResponse
+	(*SetSpeedLimitResponse)(nil),         // 39: micro_traffic_sim.SetSpeedLimitResponse
+	(*SpawnVehicleResponse)(nil),          // 40: micro_traffic_sim.SpawnVehicleResponse
+	(*RemoveVehicleResponse)(nil),         // 41: micro_traffic_sim.RemoveVehicleResponse
+	(*GetVehicleResponse)(nil),            // 42: micro_traffic_sim.GetVehicleResponse
+	(*UpdateSessionTripResponse)(nil),     // 43: micro_traffic_sim.UpdateSessionTripResponse
+	(*RemoveSessionTripResponse)(nil),     // 44: micro_traffic_sim.RemoveSessionTripResponse
+	(*SetSessionTripPausedResponse)(nil),  // 45: micro_traffic_sim.SetSessionTripPausedResponse
+	(*PushSessionOdMatrixResponse)(nil),   // 46: micro_traffic_sim.PushSessionOdMatrixResponse
+	(*ComputeRouteResponse)(nil),          // 47: micro_traffic_sim.ComputeRouteResponse
+	(*GenerateConflictZonesResponse)(nil), // 48: micro_traffic_sim.GenerateConflictZonesResponse
+	(*GetServerLimitsResponse)(nil),       // 49: micro_traffic_sim.GetServerLimitsResponse
 }
 var file_service_proto_depIdxs = []int32{
 	0,  // 0: micro_traffic_sim.Service.NewSession:input_type -> micro_traffic_sim.SessionReq
@@ -75,18 +121,48 @@ var file_service_proto_depIdxs = []int32{
 	7,  // 7: micro_traffic_sim.Service.RunAndRecord:input_type -> micro_traffic_sim.RunAndRecordRequest
 	8,  // 8: micro_traffic_sim.Service.RecordingStatus:input_type -> micro_traffic_sim.RecordingStatusRequest
 	9,  // 9: micro_traffic_sim.Service.StopRecording:input_type -> micro_traffic_sim.StopRecordingRequest
-	10, // 10: micro_traffic_sim.Service.NewSession:output_type -> micro_traffic_sim.NewSessionResponse
-	11, // 11: micro_traffic_sim.Service.InfoSession:output_type -> micro_traffic_sim.InfoSessionResponse
-	12, // 12: micro_traffic_sim.Service.PushSessionGrid:output_type -> micro_traffic_sim.SessionGridResponse
-	13, // 13: micro_traffic_sim.Service.PushSessionTrip:output_type -> micro_traffic_sim.SessionTripResponse
-	14, // 14: micro_traffic_sim.Service.SimulationStepSession:output_type -> micro_traffic_sim.SessionStepResponse
-	15, // 15: micro_traffic_sim.Service.PushSessionTLS:output_type -> micro_traffic_sim.SessionTLSResponse
-	16, // 16: micro_traffic_sim.Service.PushSessionConflictZones:output_type -> micro_traffic_sim.SessionConflictZonesResponse
-	17, // 17: micro_traffic_sim.Service.RunAndRecord:output_type -> micro_traffic_sim.RunAndRecordResponse
-	18, // 18: micro_traffic_sim.Service.RecordingStatus:output_type -> micro_traffic_sim.RecordingStatusResponse
-	19, // 19: micro_traffic_sim.Service.StopRecording:output_type -> micro_traffic_sim.StopRecordingResponse
-	10, // [10:20] is the sub-list for method output_type
-	0,  // [0:10] is the sub-list for method input_type
+	10, // 10: micro_traffic_sim.Service.GetTripStats:input_type -> micro_traffic_sim.TripStatsRequest
+	11, // 11: micro_traffic_sim.Service.GetTrafficLightStats:input_type -> micro_traffic_sim.TrafficLightStatsRequest
+	12, // 12: micro_traffic_sim.Service.InjectIncident:input_type -> micro_traffic_sim.InjectIncidentRequest
+	13, // 13: micro_traffic_sim.Service.ClearIncident:input_type -> micro_traffic_sim.ClearIncidentRequest
+	14, // 14: micro_traffic_sim.Service.SetSpeedLimit:input_type -> micro_traffic_sim.SetSpeedLimitRequest
+	15, // 15: micro_traffic_sim.Service.SpawnVehicle:input_type -> micro_traffic_sim.SpawnVehicleRequest
+	16, // 16: micro_traffic_sim.Service.RemoveVehicle:input_type -> micro_traffic_sim.RemoveVehicleRequest
+	17, // 17: micro_traffic_sim.Service.GetVehicle:input_type -> micro_traffic_sim.GetVehicleRequest
+	18, // 18: micro_traffic_sim.Service.UpdateSessionTrip:input_type -> micro_traffic_sim.UpdateSessionTripRequest
+	19, // 19: micro_traffic_sim.Service.RemoveSessionTrip:input_type -> micro_traffic_sim.RemoveSessionTripRequest
+	20, // 20: micro_traffic_sim.Service.SetSessionTripPaused:input_type -> micro_traffic_sim.SetSessionTripPausedRequest
+	21, // 21: micro_traffic_sim.Service.PushSessionOdMatrix:input_type -> micro_traffic_sim.PushSessionOdMatrixRequest
+	22, // 22: micro_traffic_sim.Service.ComputeRoute:input_type -> micro_traffic_sim.ComputeRouteRequest
+	23, // 23: micro_traffic_sim.Service.GenerateConflictZones:input_type -> micro_traffic_sim.GenerateConflictZonesRequest
+	24, // 24: micro_traffic_sim.Service.GetServerLimits:input_type -> micro_traffic_sim.GetServerLimitsRequest
+	25, // 25: micro_traffic_sim.Service.NewSession:output_type -> micro_traffic_sim.NewSessionResponse
+	26, // 26: micro_traffic_sim.Service.InfoSession:output_type -> micro_traffic_sim.InfoSessionResponse
+	27, // 27: micro_traffic_sim.Service.PushSessionGrid:output_type -> micro_traffic_sim.SessionGridResponse
+	28, // 28: micro_traffic_sim.Service.PushSessionTrip:output_type -> micro_traffic_sim.SessionTripResponse
+	29, // 29: micro_traffic_sim.Service.SimulationStepSession:output_type -> micro_traffic_sim.SessionStepResponse
+	30, // 30: micro_traffic_sim.Service.PushSessionTLS:output_type -> micro_traffic_sim.SessionTLSResponse
+	31, // 31: micro_traffic_sim.Service.PushSessionConflictZones:output_type -> micro_traffic_sim.SessionConflictZonesResponse
+	32, // 32: micro_traffic_sim.Service.RunAndRecord:output_type -> micro_traffic_sim.RunAndRecordResponse
+	33, // 33: micro_traffic_sim.Service.RecordingStatus:output_type -> micro_traffic_sim.RecordingStatusResponse
+	34, // 34: micro_traffic_sim.Service.StopRecording:output_type -> micro_traffic_sim.StopRecordingResponse
+	35, // 35: micro_traffic_sim.Service.GetTripStats:output_type -> micro_traffic_sim.TripStatsResponse
+	36, // 36: micro_traffic_sim.Service.GetTrafficLightStats:output_type -> micro_traffic_sim.TrafficLightStatsResponse
+	37, // 37: micro_traffic_sim.Service.InjectIncident:output_type -> micro_traffic_sim.InjectIncidentResponse
+	38, // 38: micro_traffic_sim.Service.ClearIncident:output_type -> micro_traffic_sim.ClearIncidentResponse
+	39, // 39: micro_traffic_sim.Service.SetSpeedLimit:output_type -> micro_traffic_sim.SetSpeedLimitResponse
+	40, // 40: micro_traffic_sim.Service.SpawnVehicle:output_type -> micro_traffic_sim.SpawnVehicleResponse
+	41, // 41: micro_traffic_sim.Service.RemoveVehicle:output_type -> micro_traffic_sim.RemoveVehicleResponse
+	42, // 42: micro_traffic_sim.Service.GetVehicle:output_type -> micro_traffic_sim.GetVehicleResponse
+	43, // 43: micro_traffic_sim.Service.UpdateSessionTrip:output_type -> micro_traffic_sim.UpdateSessionTripResponse
+	44, // 44: micro_traffic_sim.Service.RemoveSessionTrip:output_type -> micro_traffic_sim.RemoveSessionTripResponse
+	45, // 45: micro_traffic_sim.Service.SetSessionTripPaused:output_type -> micro_traffic_sim.SetSessionTripPausedResponse
+	46, // 46: micro_traffic_sim.Service.PushSessionOdMatrix:output_type -> micro_traffic_sim.PushSessionOdMatrixResponse
+	47, // 47: micro_traffic_sim.Service.ComputeRoute:output_type -> micro_traffic_sim.ComputeRouteResponse
+	48, // 48: micro_traffic_sim.Service.GenerateConflictZones:output_type -> micro_traffic_sim.GenerateConflictZonesResponse
+	49, // 49: micro_traffic_sim.Service.GetServerLimits:output_type -> micro_traffic_sim.GetServerLimitsResponse
+	25, // [25:50] is the sub-list for method output_type
+	0,  // [0:25] is the sub-list for method input_type
 	0,  // [0:0] is the sub-list for extension type_name
 	0,  // [0:0] is the sub-list for extension extendee
 	0,  // [0:0] is the sub-list for field type_name
@@ -105,6 +181,13 @@ func file_service_proto_init() {
 	file_tls_proto_init()
 	file_conflict_zones_proto_init()
 	file_record_proto_init()
+	file_stats_proto_init()
+	file_limits_proto_init()
+	file_route_proto_init()
+	file_od_proto_init()
+	file_vehicle_proto_init()
+	file_speed_limit_proto_init()
+	file_incident_proto_init()
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
@@ -29,6 +29,21 @@ const (
 	Service_RunAndRecord_FullMethodName             = "/micro_traffic_sim.Service/RunAndRecord"
 	Service_RecordingStatus_FullMethodName          = "/micro_traffic_sim.Service/RecordingStatus"
 	Service_StopRecording_FullMethodName            = "/micro_traffic_sim.Service/StopRecording"
+	Service_GetTripStats_FullMethodName             = "/micro_traffic_sim.Service/GetTripStats"
+	Service_GetTrafficLightStats_FullMethodName     = "/micro_traffic_sim.Service/GetTrafficLightStats"
+	Service_InjectIncident_FullMethodName           = "/micro_traffic_sim.Service/InjectIncident"
+	Service_ClearIncident_FullMethodName            = "/micro_traffic_sim.Service/ClearIncident"
+	Service_SetSpeedLimit_FullMethodName            = "/micro_traffic_sim.Service/SetSpeedLimit"
+	Service_SpawnVehicle_FullMethodName             = "/micro_traffic_sim.Service/SpawnVehicle"
+	Service_RemoveVehicle_FullMethodName            = "/micro_traffic_sim.Service/RemoveVehicle"
+	Service_GetVehicle_FullMethodName               = "/micro_traffic_sim.Service/GetVehicle"
+	Service_UpdateSessionTrip_FullMethodName        = "/micro_traffic_sim.Service/UpdateSessionTrip"
+	Service_RemoveSessionTrip_FullMethodName        = "/micro_traffic_sim.Service/RemoveSessionTrip"
+	Service_SetSessionTripPaused_FullMethodName     = "/micro_traffic_sim.Service/SetSessionTripPaused"
+	Service_PushSessionOdMatrix_FullMethodName      = "/micro_traffic_sim.Service/PushSessionOdMatrix"
+	Service_ComputeRoute_FullMethodName             = "/micro_traffic_sim.Service/ComputeRoute"
+	Service_GenerateConflictZones_FullMethodName    = "/micro_traffic_sim.Service/GenerateConflictZones"
+	Service_GetServerLimits_FullMethodName          = "/micro_traffic_sim.Service/GetServerLimits"
 )
 
 // ServiceClient is the client API for Service service.
@@ -56,6 +71,36 @@ type ServiceClient interface {
 	RecordingStatus(ctx context.Context, in *RecordingStatusRequest, opts ...grpc.CallOption) (*RecordingStatusResponse, error)
 	// Request a running recording to stop (cooperative), by session id (from any connection).
 	StopRecording(ctx context.Context, in *StopRecordingRequest, opts ...grpc.CallOption) (*StopRecordingResponse, error)
+	// Get per-trip vehicle counts and travel time / delay statistics for the given session
+	GetTripStats(ctx context.Context, in *TripStatsRequest, opts ...grpc.CallOption) (*TripStatsResponse, error)
+	// Get per-traffic-light and per-group signal performance statistics for the given session
+	GetTrafficLightStats(ctx context.Context, in *TrafficLightStatsRequest, opts ...grpc.CallOption) (*TrafficLightStatsResponse, error)
+	// Block cells or reduce their speed limit for a time window (also on a session owned by RunAndRecord)
+	InjectIncident(ctx context.Context, in *InjectIncidentRequest, opts ...grpc.CallOption) (*InjectIncidentResponse, error)
+	// Lift an incident injected by InjectIncident
+	ClearIncident(ctx context.Context, in *ClearIncidentRequest, opts ...grpc.CallOption) (*ClearIncidentResponse, error)
+	// Change speed limit of cells or of a whole meso link from the next tick on (also on a session owned by RunAndRecord)
+	SetSpeedLimit(ctx context.Context, in *SetSpeedLimitRequest, opts ...grpc.CallOption) (*SetSpeedLimitResponse, error)
+	// Put a single vehicle into the simulation (origin, destination, transits, initial speed)
+	SpawnVehicle(ctx context.Context, in *SpawnVehicleRequest, opts ...grpc.CallOption) (*SpawnVehicleResponse, error)
+	// Take a vehicle out of the simulation by its identifier
+	RemoveVehicle(ctx context.Context, in *RemoveVehicleRequest, opts ...grpc.CallOption) (*RemoveVehicleResponse, error)
+	// Get the state, remaining route and intent of a single vehicle
+	GetVehicle(ctx context.Context, in *GetVehicleRequest, opts ...grpc.CallOption) (*GetVehicleResponse, error)
+	// Replace an existing trip of the session (also on a session owned by RunAndRecord)
+	UpdateSessionTrip(ctx context.Context, in *UpdateSessionTripRequest, opts ...grpc.CallOption) (*UpdateSessionTripResponse, error)
+	// Remove a trip from the session (also on a session owned by RunAndRecord)
+	RemoveSessionTrip(ctx context.Context, in *RemoveSessionTripRequest, opts ...grpc.CallOption) (*RemoveSessionTripResponse, error)
+	// Pause or resume vehicle generation of a trip (also on a session owned by RunAndRecord)
+	SetSessionTripPaused(ctx context.Context, in *SetSessionTripPausedRequest, opts ...grpc.CallOption) (*SetSessionTripPausedResponse, error)
+	// Expand an OD matrix (zones, vehicles/hour per time slice, agent mix) into trips of the session
+	PushSessionOdMatrix(ctx context.Context, in *PushSessionOdMatrixRequest, opts ...grpc.CallOption) (*PushSessionOdMatrixResponse, error)
+	// Shortest route between two cells (through optional transits) over the session grid
+	ComputeRoute(ctx context.Context, in *ComputeRouteRequest, opts ...grpc.CallOption) (*ComputeRouteResponse, error)
+	// Detect crossing / merging movements within coordination zones and build conflict zones for them
+	GenerateConflictZones(ctx context.Context, in *GenerateConflictZonesRequest, opts ...grpc.CallOption) (*GenerateConflictZonesResponse, error)
+	// Limits applying to the caller (server-wide and per tenant) and current usage
+	GetServerLimits(ctx context.Context, in *GetServerLimitsRequest, opts ...grpc.CallOption) (*GetServerLimitsResponse, error)
 }
 
 type serviceClient struct {
@@ -190,6 +235,156 @@ func (c *serviceClient) StopRecording(ctx context.Context, in *StopRecordingRequ
 	return out, nil
 }
 
+func (c *serviceClient) GetTripStats(ctx context.Context, in *TripStatsRequest, opts ...grpc.CallOption) (*TripStatsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(TripStatsResponse)
+	err := c.cc.Invoke(ctx, Service_GetTripStats_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) GetTrafficLightStats(ctx context.Context, in *TrafficLightStatsRequest, opts ...grpc.CallOption) (*TrafficLightStatsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(TrafficLightStatsResponse)
+	err := c.cc.Invoke(ctx, Service_GetTrafficLightStats_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) InjectIncident(ctx context.Context, in *InjectIncidentRequest, opts ...grpc.CallOption) (*InjectIncidentResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(InjectIncidentResponse)
+	err := c.cc.Invoke(ctx, Service_InjectIncident_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) ClearIncident(ctx context.Context, in *ClearIncidentRequest, opts ...grpc.CallOption) (*ClearIncidentResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ClearIncidentResponse)
+	err := c.cc.Invoke(ctx, Service_ClearIncident_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) SetSpeedLimit(ctx context.Context, in *SetSpeedLimitRequest, opts ...grpc.CallOption) (*SetSpeedLimitResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SetSpeedLimitResponse)
+	err := c.cc.Invoke(ctx, Service_SetSpeedLimit_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) SpawnVehicle(ctx context.Context, in *SpawnVehicleRequest, opts ...grpc.CallOption) (*SpawnVehicleResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SpawnVehicleResponse)
+	err := c.cc.Invoke(ctx, Service_SpawnVehicle_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) RemoveVehicle(ctx context.Context, in *RemoveVehicleRequest, opts ...grpc.CallOption) (*RemoveVehicleResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(RemoveVehicleResponse)
+	err := c.cc.Invoke(ctx, Service_RemoveVehicle_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) GetVehicle(ctx context.Context, in *GetVehicleRequest, opts ...grpc.CallOption) (*GetVehicleResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(GetVehicleResponse)
+	err := c.cc.Invoke(ctx, Service_GetVehicle_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) UpdateSessionTrip(ctx context.Context, in *UpdateSessionTripRequest, opts ...grpc.CallOption) (*UpdateSessionTripResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(UpdateSessionTripResponse)
+	err := c.cc.Invoke(ctx, Service_UpdateSessionTrip_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) RemoveSessionTrip(ctx context.Context, in *RemoveSessionTripRequest, opts ...grpc.CallOption) (*RemoveSessionTripResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(RemoveSessionTripResponse)
+	err := c.cc.Invoke(ctx, Service_RemoveSessionTrip_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) SetSessionTripPaused(ctx context.Context, in *SetSessionTripPausedRequest, opts ...grpc.CallOption) (*SetSessionTripPausedResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(SetSessionTripPausedResponse)
+	err := c.cc.Invoke(ctx, Service_SetSessionTripPaused_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) PushSessionOdMatrix(ctx context.Context, in *PushSessionOdMatrixRequest, opts ...grpc.CallOption) (*PushSessionOdMatrixResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(PushSessionOdMatrixResponse)
+	err := c.cc.Invoke(ctx, Service_PushSessionOdMatrix_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) ComputeRoute(ctx context.Context, in *ComputeRouteRequest, opts ...grpc.CallOption) (*ComputeRouteResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(ComputeRouteResponse)
+	err := c.cc.Invoke(ctx, Service_ComputeRoute_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) GenerateConflictZones(ctx context.Context, in *GenerateConflictZonesRequest, opts ...grpc.CallOption) (*GenerateConflictZonesResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(GenerateConflictZonesResponse)
+	err := c.cc.Invoke(ctx, Service_GenerateConflictZones_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *serviceClient) GetServerLimits(ctx context.Context, in *GetServerLimitsRequest, opts ...grpc.CallOption) (*GetServerLimitsResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(GetServerLimitsResponse)
+	err := c.cc.Invoke(ctx, Service_GetServerLimits_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // ServiceServer is the server API for Service service.
 // All implementations must embed UnimplementedServiceServer
 // for forward compatibility.
@@ -215,6 +410,36 @@ type ServiceServer interface {
 	RecordingStatus(context.Context, *RecordingStatusRequest) (*RecordingStatusResponse, error)
 	// Request a running recording to stop (cooperative), by session id (from any connection).
 	StopRecording(context.Context, *StopRecordingRequest) (*StopRecordingResponse, error)
+	// Get per-trip vehicle counts and travel time / delay statistics for the given session
+	GetTripStats(context.Context, *TripStatsRequest) (*TripStatsResponse, error)
+	// Get per-traffic-light and per-group signal performance statistics for the given session
+	GetTrafficLightStats(context.Context, *TrafficLightStatsRequest) (*TrafficLightStatsResponse, error)
+	// Block cells or reduce their speed limit for a time window (also on a session owned by RunAndRecord)
+	InjectIncident(context.Context, *InjectIncidentRequest) (*InjectIncidentResponse, error)
+	// Lift an incident injected by InjectIncident
+	ClearIncident(context.Context, *ClearIncidentRequest) (*ClearIncidentResponse, error)
+	// Change speed limit of cells or of a whole meso link from the next tick on (also on a session owned by RunAndRecord)
+	SetSpeedLimit(context.Context, *SetSpeedLimitRequest) (*SetSpeedLimitResponse, error)
+	// Put a single vehicle into the simulation (origin, destination, transits, initial speed)
+	SpawnVehicle(context.Context, *SpawnVehicleRequest) (*SpawnVehicleResponse, error)
+	// Take a vehicle out of the simulation by its identifier
+	RemoveVehicle(context.Context, *RemoveVehicleRequest) (*RemoveVehicleResponse, error)
+	// Get the state, remaining route and intent of a single vehicle
+	GetVehicle(context.Context, *GetVehicleRequest) (*GetVehicleResponse, error)
+	// Replace an existing trip of the session (also on a session owned by RunAndRecord)
+	UpdateSessionTrip(context.Context, *UpdateSessionTripRequest) (*UpdateSessionTripResponse, error)
+	// Remove a trip from the session (also on a session owned by RunAndRecord)
+	RemoveSessionTrip(context.Context, *RemoveSessionTripRequest) (*RemoveSessionTripResponse, error)
+	// Pause or resume vehicle generation of a trip (also on a session owned by RunAndRecord)
+	SetSessionTripPaused(context.Context, *SetSessionTripPausedRequest) (*SetSessionTripPausedResponse, error)
+	// Expand an OD matrix (zones, vehicles/hour per time slice, agent mix) into trips of the session
+	PushSessionOdMatrix(context.Context, *PushSessionOdMatrixRequest) (*PushSessionOdMatrixResponse, error)
+	// Shortest route between two cells (through optional transits) over the session grid
+	ComputeRoute(context.Context, *ComputeRouteRequest) (*ComputeRouteResponse, error)
+	// Detect crossing / merging movements within coordination zones and build conflict zones for them
+	GenerateConflictZones(context.Context, *GenerateConflictZonesRequest) (*GenerateConflictZonesResponse, error)
+	// Limits applying to the caller (server-wide and per tenant) and current usage
+	GetServerLimits(context.Context, *GetServerLimitsRequest) (*GetServerLimitsResponse, error)
 	mustEmbedUnimplementedServiceServer()
 }
 
@@ -255,6 +480,51 @@ func (UnimplementedServiceServer) RecordingStatus(context.Context, *RecordingSta
 func (UnimplementedServiceServer) StopRecording(context.Context, *StopRecordingRequest) (*StopRecordingResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method StopRecording not implemented")
 }
+func (UnimplementedServiceServer) GetTripStats(context.Context, *TripStatsRequest) (*TripStatsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetTripStats not implemented")
+}
+func (UnimplementedServiceServer) GetTrafficLightStats(context.Context, *TrafficLightStatsRequest) (*TrafficLightStatsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetTrafficLightStats not implemented")
+}
+func (UnimplementedServiceServer) InjectIncident(context.Context, *InjectIncidentRequest) (*InjectIncidentResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method InjectIncident not implemented")
+}
+func (UnimplementedServiceServer) ClearIncident(context.Context, *ClearIncidentRequest) (*ClearIncidentResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ClearIncident not implemented")
+}
+func (UnimplementedServiceServer) SetSpeedLimit(context.Context, *SetSpeedLimitRequest) (*SetSpeedLimitResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetSpeedLimit not implemented")
+}
+func (UnimplementedServiceServer) SpawnVehicle(context.Context, *SpawnVehicleRequest) (*SpawnVehicleResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SpawnVehicle not implemented")
+}
+func (UnimplementedServiceServer) RemoveVehicle(context.Context, *RemoveVehicleRequest) (*RemoveVehicleResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RemoveVehicle not implemented")
+}
+func (UnimplementedServiceServer) GetVehicle(context.Context, *GetVehicleRequest) (*GetVehicleResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetVehicle not implemented")
+}
+func (UnimplementedServiceServer) UpdateSessionTrip(context.Context, *UpdateSessionTripRequest) (*UpdateSessionTripResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateSessionTrip not implemented")
+}
+func (UnimplementedServiceServer) RemoveSessionTrip(context.Context, *RemoveSessionTripRequest) (*RemoveSessionTripResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RemoveSessionTrip not implemented")
+}
+func (UnimplementedServiceServer) SetSessionTripPaused(context.Context, *SetSessionTripPausedRequest) (*SetSessionTripPausedResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SetSessionTripPaused not implemented")
+}
+func (UnimplementedServiceServer) PushSessionOdMatrix(context.Context, *PushSessionOdMatrixRequest) (*PushSessionOdMatrixResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PushSessionOdMatrix not implemented")
+}
+func (UnimplementedServiceServer) ComputeRoute(context.Context, *ComputeRouteRequest) (*ComputeRouteResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ComputeRoute not implemented")
+}
+func (UnimplementedServiceServer) GenerateConflictZones(context.Context, *GenerateConflictZonesRequest) (*GenerateConflictZonesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GenerateConflictZones not implemented")
+}
+func (UnimplementedServiceServer) GetServerLimits(context.Context, *GetServerLimitsRequest) (*GetServerLimitsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetServerLimits not implemented")
+}
 func (UnimplementedServiceServer) mustEmbedUnimplementedServiceServer() {}
 func (UnimplementedServiceServer) testEmbeddedByValue()                 {}
 
@@ -394,6 +664,276 @@ func _Service_StopRecording_Handler(srv interface{}, ctx context.Context, dec fu
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Service_GetTripStats_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TripStatsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).GetTripStats(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_GetTripStats_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).GetTripStats(ctx, req.(*TripStatsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_GetTrafficLightStats_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(TrafficLightStatsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).GetTrafficLightStats(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_GetTrafficLightStats_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).GetTrafficLightStats(ctx, req.(*TrafficLightStatsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_InjectIncident_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(InjectIncidentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).InjectIncident(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_InjectIncident_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).InjectIncident(ctx, req.(*InjectIncidentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_ClearIncident_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ClearIncidentRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).ClearIncident(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_ClearIncident_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).ClearIncident(ctx, req.(*ClearIncidentRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_SetSpeedLimit_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SetSpeedLimitRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).SetSpeedLimit(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_SetSpeedLimit_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).SetSpeedLimit(ctx, req.(*SetSpeedLimitRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_SpawnVehicle_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SpawnVehicleRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).SpawnVehicle(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_SpawnVehicle_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).SpawnVehicle(ctx, req.(*SpawnVehicleRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_RemoveVehicle_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RemoveVehicleRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).RemoveVehicle(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_RemoveVehicle_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).RemoveVehicle(ctx, req.(*RemoveVehicleRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_GetVehicle_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetVehicleRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).GetVehicle(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_GetVehicle_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).GetVehicle(ctx, req.(*GetVehicleRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_UpdateSessionTrip_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateSessionTripRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).UpdateSessionTrip(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_UpdateSessionTrip_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).UpdateSessionTrip(ctx, req.(*UpdateSessionTripRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_RemoveSessionTrip_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RemoveSessionTripRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).RemoveSessionTrip(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_RemoveSessionTrip_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).RemoveSessionTrip(ctx, req.(*RemoveSessionTripRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_SetSessionTripPaused_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SetSessionTripPausedRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).SetSessionTripPaused(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_SetSessionTripPaused_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).SetSessionTripPaused(ctx, req.(*SetSessionTripPausedRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_PushSessionOdMatrix_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PushSessionOdMatrixRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).PushSessionOdMatrix(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_PushSessionOdMatrix_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).PushSessionOdMatrix(ctx, req.(*PushSessionOdMatrixRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_ComputeRoute_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ComputeRouteRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).ComputeRoute(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_ComputeRoute_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).ComputeRoute(ctx, req.(*ComputeRouteRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_GenerateConflictZones_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GenerateConflictZonesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).GenerateConflictZones(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_GenerateConflictZones_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).GenerateConflictZones(ctx, req.(*GenerateConflictZonesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Service_GetServerLimits_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetServerLimitsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ServiceServer).GetServerLimits(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: Service_GetServerLimits_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ServiceServer).GetServerLimits(ctx, req.(*GetServerLimitsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // Service_ServiceDesc is the grpc.ServiceDesc for Service service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -417,6 +957,66 @@ var Service_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "StopRecording",
 			Handler:    _Service_StopRecording_Handler,
 		},
+		{
+			MethodName: "GetTripStats",
+			Handler:    _Service_GetTripStats_Handler,
+		},
+		{
+			MethodName: "GetTrafficLightStats",
+			Handler:    _Service_GetTrafficLightStats_Handler,
+		},
+		{
+			MethodName: "InjectIncident",
+			Handler:    _Service_InjectIncident_Handler,
+		},
+		{
+			MethodName: "ClearIncident",
+			Handler:    _Service_ClearIncident_Handler,
+		},
+		{
+			MethodName: "SetSpeedLimit",
+			Handler:    _Service_SetSpeedLimit_Handler,
+		},
+		{
+			MethodName: "SpawnVehicle",
+			Handler:    _Service_SpawnVehicle_Handler,
+		},
+		{
+			MethodName: "RemoveVehicle",
+			Handler:    _Service_RemoveVehicle_Handler,
+		},
+		{
+			MethodName: "GetVehicle",
+			Handler:    _Service_GetVehicle_Handler,
+		},
+		{
+			MethodName: "UpdateSessionTrip",
+			Handler:    _Service_UpdateSessionTrip_Handler,
+		},
+		{
+			MethodName: "RemoveSessionTrip",
+			Handler:    _Service_RemoveSessionTrip_Handler,
+		},
+		{
+			MethodName: "SetSessionTripPaused",
+			Handler:    _Service_SetSessionTripPaused_Handler,
+		},
+		{
+			MethodName: "PushSessionOdMatrix",
+			Handler:    _Service_PushSessionOdMatrix_Handler,
+		},
+		{
+			MethodName: "ComputeRoute",
+			Handler:    _Service_ComputeRoute_Handler,
+		},
+		{
+			MethodName: "GenerateConflictZones",
+			Handler:    _Service_GenerateConflictZones_Handler,
+		},
+		{
+			MethodName: "GetServerLimits",
+			Handler:    _Service_GetServerLimits_Handler,
+		},
 	},
 	Streams: []grpc.StreamDesc{
 		{
@@ -0,0 +1,226 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v6.33.1
+// source: snapshot.proto
+
+package microtraffic
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Definition of a session as saved by the server on shutdown (one file per session in
+// `shutdown.snapshot_dir`) and restored on the next start. Not part of the service API.
+// Only what has been pushed into the session is kept: vehicles, the current step,
+// statistics and incidents are not.
+type SessionSnapshot struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Layout version of this message
+	FormatVersion uint32 `protobuf:"varint,1,opt,name=format_version,json=formatVersion,proto3" json:"format_version,omitempty"`
+	// Session identifier (UUIDv4), kept on restore
+	SessionId *UUIDv4 `protobuf:"bytes,2,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// SRID of the session: 0 = Euclidean, 4326 = WGS84
+	Srid int32 `protobuf:"varint,3,opt,name=srid,proto3" json:"srid,omitempty"`
+	// Owner tenant (authentication enabled), empty otherwise
+	Tenant string `protobuf:"bytes,4,opt,name=tenant,proto3" json:"tenant,omitempty"`
+	// Grid cells
+	Cells []*Cell `protobuf:"bytes,5,rep,name=cells,proto3" json:"cells,omitempty"`
+	// Trips
+	Trips []*Trip `protobuf:"bytes,6,rep,name=trips,proto3" json:"trips,omitempty"`
+	// Trips whose vehicle generation is paused
+	PausedTrips []int64 `protobuf:"varint,7,rep,packed,name=paused_trips,json=pausedTrips,proto3" json:"paused_trips,omitempty"`
+	// Traffic lights
+	TrafficLights []*TrafficLight `protobuf:"bytes,8,rep,name=traffic_lights,json=trafficLights,proto3" json:"traffic_lights,omitempty"`
+	// Conflict zones
+	ConflictZones []*ConflictZone `protobuf:"bytes,9,rep,name=conflict_zones,json=conflictZones,proto3" json:"conflict_zones,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SessionSnapshot) Reset() {
+	*x = SessionSnapshot{}
+	mi := &file_snapshot_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SessionSnapshot) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SessionSnapshot) ProtoMessage() {}
+
+func (x *SessionSnapshot) ProtoReflect() protoreflect.Message {
+	mi := &file_snapshot_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SessionSnapshot.ProtoReflect.Descriptor instead.
+func (*SessionSnapshot) Descriptor() ([]byte, []int) {
+	return file_snapshot_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *SessionSnapshot) GetFormatVersion() uint32 {
+	if x != nil {
+		return x.FormatVersion
+	}
+	return 0
+}
+
+func (x *SessionSnapshot) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *SessionSnapshot) GetSrid() int32 {
+	if x != nil {
+		return x.Srid
+	}
+	return 0
+}
+
+func (x *SessionSnapshot) GetTenant() string {
+	if x != nil {
+		return x.Tenant
+	}
+	return ""
+}
+
+func (x *SessionSnapshot) GetCells() []*Cell {
+	if x != nil {
+		return x.Cells
+	}
+	return nil
+}
+
+func (x *SessionSnapshot) GetTrips() []*Trip {
+	if x != nil {
+		return x.Trips
+	}
+	return nil
+}
+
+func (x *SessionSnapshot) GetPausedTrips() []int64 {
+	if x != nil {
+		return x.PausedTrips
+	}
+	return nil
+}
+
+func (x *SessionSnapshot) GetTrafficLights() []*TrafficLight {
+	if x != nil {
+		return x.TrafficLights
+	}
+	return nil
+}
+
+func (x *SessionSnapshot) GetConflictZones() []*ConflictZone {
+	if x != nil {
+		return x.ConflictZones
+	}
+	return nil
+}
+
+var File_snapshot_proto protoreflect.FileDescriptor
+
+const file_snapshot_proto_rawDesc = "" +
+	"\n" +
+	"\x0esnapshot.proto\x12\x11micro_traffic_sim\x1a\n" +
+	"uuid.proto\x1a\n" +
+	"cell.proto\x1a\n" +
+	"trip.proto\x1a\ttls.proto\x1a\x14conflict_zones.proto\"\xaf\x03\n" +
+	"\x0fSessionSnapshot\x12%\n" +
+	"\x0eformat_version\x18\x01 \x01(\rR\rformatVersion\x128\n" +
+	"\n" +
+	"session_id\x18\x02 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x12\n" +
+	"\x04srid\x18\x03 \x01(\x05R\x04srid\x12\x16\n" +
+	"\x06tenant\x18\x04 \x01(\tR\x06tenant\x12-\n" +
+	"\x05cells\x18\x05 \x03(\v2\x17.micro_traffic_sim.CellR\x05cells\x12-\n" +
+	"\x05trips\x18\x06 \x03(\v2\x17.micro_traffic_sim.TripR\x05trips\x12!\n" +
+	"\fpaused_trips\x18\a \x03(\x03R\vpausedTrips\x12F\n" +
+	"\x0etraffic_lights\x18\b \x03(\v2\x1f.micro_traffic_sim.TrafficLightR\rtrafficLights\x12F\n" +
+	"\x0econflict_zones\x18\t \x03(\v2\x1f.micro_traffic_sim.ConflictZoneR\rconflictZonesB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+
+var (
+	file_snapshot_proto_rawDescOnce sync.Once
+	file_snapshot_proto_rawDescData []byte
+)
+
+func file_snapshot_proto_rawDescGZIP() []byte {
+	file_snapshot_proto_rawDescOnce.Do(func() {
+		file_snapshot_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_snapshot_proto_rawDesc), len(file_snapshot_proto_rawDesc)))
+	})
+	return file_snapshot_proto_rawDescData
+}
+
+var file_snapshot_proto_msgTypes = make([]protoimpl.MessageInfo, 1)
+var file_snapshot_proto_goTypes = []any{
+	(*SessionSnapshot)(nil), // 0: micro_traffic_sim.SessionSnapshot
+	(*UUIDv4)(nil),          // 1: micro_traffic_sim.UUIDv4
+	(*Cell)(nil),            // 2: micro_traffic_sim.Cell
+	(*Trip)(nil),            // 3: micro_traffic_sim.Trip
+	(*TrafficLight)(nil),    // 4: micro_traffic_sim.TrafficLight
+	(*ConflictZone)(nil),    // 5: micro_traffic_sim.ConflictZone
+}
+var file_snapshot_proto_depIdxs = []int32{
+	1, // 0: micro_traffic_sim.SessionSnapshot.session_id:type_name -> micro_traffic_sim.UUIDv4
+	2, // 1: micro_traffic_sim.SessionSnapshot.cells:type_name -> micro_traffic_sim.Cell
+	3, // 2: micro_traffic_sim.SessionSnapshot.trips:type_name -> micro_traffic_sim.Trip
+	4, // 3: micro_traffic_sim.SessionSnapshot.traffic_lights:type_name -> micro_traffic_sim.TrafficLight
+	5, // 4: micro_traffic_sim.SessionSnapshot.conflict_zones:type_name -> micro_traffic_sim.ConflictZone
+	5, // [5:5] is the sub-list for method output_type
+	5, // [5:5] is the sub-list for method input_type
+	5, // [5:5] is the sub-list for extension type_name
+	5, // [5:5] is the sub-list for extension extendee
+	0, // [0:5] is the sub-list for field type_name
+}
+
+func init() { file_snapshot_proto_init() }
+func file_snapshot_proto_init() {
+	if File_snapshot_proto != nil {
+		return
+	}
+	file_uuid_proto_init()
+	file_cell_proto_init()
+	file_trip_proto_init()
+	file_tls_proto_init()
+	file_conflict_zones_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_snapshot_proto_rawDesc), len(file_snapshot_proto_rawDesc)),
+			NumEnums:      0,
+			NumMessages:   1,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_snapshot_proto_goTypes,
+		DependencyIndexes: file_snapshot_proto_depIdxs,
+		MessageInfos:      file_snapshot_proto_msgTypes,
+	}.Build()
+	File_snapshot_proto = out.File
+	file_snapshot_proto_goTypes = nil
+	file_snapshot_proto_depIdxs = nil
+}
@@ -0,0 +1,296 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v6.33.1
+// source: speed_limit.proto
+
+package microtraffic
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Runtime speed limit change (variable speed sign). The new value replaces the
+// speed limit pushed with the grid and is in effect from the next step on.
+// Works on interactive sessions and on sessions currently owned by RunAndRecord
+// (the change is then applied before the recording's next tick and reported in
+// RecordBatch.speed_limit_changes; changes targeting cells unknown to the grid
+// are dropped there).
+// Incidents of type INCIDENT_TYPE_SPEED_LIMIT keep capping the new value while active.
+type SetSpeedLimitRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Cells to update
+	Cells []int64 `protobuf:"varint,2,rep,packed,name=cells,proto3" json:"cells,omitempty"`
+	// Meso link whose cells are all updated. Used only when cells is empty
+	MesoLinkId int64 `protobuf:"varint,3,opt,name=meso_link_id,json=mesoLinkId,proto3" json:"meso_link_id,omitempty"`
+	// New speed limit (cells per step), must be positive
+	SpeedLimit    int32 `protobuf:"varint,4,opt,name=speed_limit,json=speedLimit,proto3" json:"speed_limit,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SetSpeedLimitRequest) Reset() {
+	*x = SetSpeedLimitRequest{}
+	mi := &file_speed_limit_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SetSpeedLimitRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SetSpeedLimitRequest) ProtoMessage() {}
+
+func (x *SetSpeedLimitRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_speed_limit_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SetSpeedLimitRequest.ProtoReflect.Descriptor instead.
+func (*SetSpeedLimitRequest) Descriptor() ([]byte, []int) {
+	return file_speed_limit_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *SetSpeedLimitRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *SetSpeedLimitRequest) GetCells() []int64 {
+	if x != nil {
+		return x.Cells
+	}
+	return nil
+}
+
+func (x *SetSpeedLimitRequest) GetMesoLinkId() int64 {
+	if x != nil {
+		return x.MesoLinkId
+	}
+	return 0
+}
+
+func (x *SetSpeedLimitRequest) GetSpeedLimit() int32 {
+	if x != nil {
+		return x.SpeedLimit
+	}
+	return 0
+}
+
+// Server response for speed limit change
+type SetSpeedLimitResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text          string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SetSpeedLimitResponse) Reset() {
+	*x = SetSpeedLimitResponse{}
+	mi := &file_speed_limit_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SetSpeedLimitResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SetSpeedLimitResponse) ProtoMessage() {}
+
+func (x *SetSpeedLimitResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_speed_limit_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SetSpeedLimitResponse.ProtoReflect.Descriptor instead.
+func (*SetSpeedLimitResponse) Descriptor() ([]byte, []int) {
+	return file_speed_limit_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *SetSpeedLimitResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *SetSpeedLimitResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+// Speed limit change applied during a recording
+type SpeedLimitChange struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Tick (step timestamp) from which the new speed limit is in effect
+	Tick uint64 `protobuf:"varint,1,opt,name=tick,proto3" json:"tick,omitempty"`
+	// Updated cells
+	Cells []int64 `protobuf:"varint,2,rep,packed,name=cells,proto3" json:"cells,omitempty"`
+	// New speed limit
+	SpeedLimit    int32 `protobuf:"varint,3,opt,name=speed_limit,json=speedLimit,proto3" json:"speed_limit,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SpeedLimitChange) Reset() {
+	*x = SpeedLimitChange{}
+	mi := &file_speed_limit_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SpeedLimitChange) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SpeedLimitChange) ProtoMessage() {}
+
+func (x *SpeedLimitChange) ProtoReflect() protoreflect.Message {
+	mi := &file_speed_limit_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SpeedLimitChange.ProtoReflect.Descriptor instead.
+func (*SpeedLimitChange) Descriptor() ([]byte, []int) {
+	return file_speed_limit_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *SpeedLimitChange) GetTick() uint64 {
+	if x != nil {
+		return x.Tick
+	}
+	return 0
+}
+
+func (x *SpeedLimitChange) GetCells() []int64 {
+	if x != nil {
+		return x.Cells
+	}
+	return nil
+}
+
+func (x *SpeedLimitChange) GetSpeedLimit() int32 {
+	if x != nil {
+		return x.SpeedLimit
+	}
+	return 0
+}
+
+var File_speed_limit_proto protoreflect.FileDescriptor
+
+const file_speed_limit_proto_rawDesc = "" +
+	"\n" +
+	"\x11speed_limit.proto\x12\x11micro_traffic_sim\x1a\n" +
+	"uuid.proto\"\xa9\x01\n" +
+	"\x14SetSpeedLimitRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x14\n" +
+	"\x05cells\x18\x02 \x03(\x03R\x05cells\x12 \n" +
+	"\fmeso_link_id\x18\x03 \x01(\x03R\n" +
+	"mesoLinkId\x12\x1f\n" +
+	"\vspeed_limit\x18\x04 \x01(\x05R\n" +
+	"speedLimit\"?\n" +
+	"\x15SetSpeedLimitResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\"]\n" +
+	"\x10SpeedLimitChange\x12\x12\n" +
+	"\x04tick\x18\x01 \x01(\x04R\x04tick\x12\x14\n" +
+	"\x05cells\x18\x02 \x03(\x03R\x05cells\x12\x1f\n" +
+	"\vspeed_limit\x18\x03 \x01(\x05R\n" +
+	"speedLimitB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+
+var (
+	file_speed_limit_proto_rawDescOnce sync.Once
+	file_speed_limit_proto_rawDescData []byte
+)
+
+func file_speed_limit_proto_rawDescGZIP() []byte {
+	file_speed_limit_proto_rawDescOnce.Do(func() {
+		file_speed_limit_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_speed_limit_proto_rawDesc), len(file_speed_limit_proto_rawDesc)))
+	})
+	return file_speed_limit_proto_rawDescData
+}
+
+var file_speed_limit_proto_msgTypes = make([]protoimpl.MessageInfo, 3)
+var file_speed_limit_proto_goTypes = []any{
+	(*SetSpeedLimitRequest)(nil),  // 0: micro_traffic_sim.SetSpeedLimitRequest
+	(*SetSpeedLimitResponse)(nil), // 1: micro_traffic_sim.SetSpeedLimitResponse
+	(*SpeedLimitChange)(nil),      // 2: micro_traffic_sim.SpeedLimitChange
+	(*UUIDv4)(nil),                // 3: micro_traffic_sim.UUIDv4
+}
+var file_speed_limit_proto_depIdxs = []int32{
+	3, // 0: micro_traffic_sim.SetSpeedLimitRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	1, // [1:1] is the sub-list for method output_type
+	1, // [1:1] is the sub-list for method input_type
+	1, // [1:1] is the sub-list for extension type_name
+	1, // [1:1] is the sub-list for extension extendee
+	0, // [0:1] is the sub-list for field type_name
+}
+
+func init() { file_speed_limit_proto_init() }
+func file_speed_limit_proto_init() {
+	if File_speed_limit_proto != nil {
+		return
+	}
+	file_uuid_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_speed_limit_proto_rawDesc), len(file_speed_limit_proto_rawDesc)),
+			NumEnums:      0,
+			NumMessages:   3,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_speed_limit_proto_goTypes,
+		DependencyIndexes: file_speed_limit_proto_depIdxs,
+		MessageInfos:      file_speed_limit_proto_msgTypes,
+	}.Build()
+	File_speed_limit_proto = out.File
+	file_speed_limit_proto_goTypes = nil
+	file_speed_limit_proto_depIdxs = nil
+}
@@ -0,0 +1,957 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v6.33.1
+// source: stats.proto
+
+package microtraffic
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Trip statistics query
+type TripStatsRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Trips to report. Empty means all trips of the session
+	TripIds       []int64 `protobuf:"varint,2,rep,packed,name=trip_ids,json=tripIds,proto3" json:"trip_ids,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TripStatsRequest) Reset() {
+	*x = TripStatsRequest{}
+	mi := &file_stats_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TripStatsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TripStatsRequest) ProtoMessage() {}
+
+func (x *TripStatsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TripStatsRequest.ProtoReflect.Descriptor instead.
+func (*TripStatsRequest) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *TripStatsRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *TripStatsRequest) GetTripIds() []int64 {
+	if x != nil {
+		return x.TripIds
+	}
+	return nil
+}
+
+// Statistics of single trip, accumulated since the trip has been pushed.
+// Travel times are measured in simulation steps (the core's `travel_time`) and
+// cover arrived vehicles only.
+// Only steps made by SimulationStepSession are counted: RunAndRecord consumes the
+// session, so a recorded run never shows up here. Derive per-trip figures of a
+// recording from its vehicle events (include_events) instead.
+type TripStats struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Trip identifier
+	TripId int64 `protobuf:"varint,1,opt,name=trip_id,json=tripId,proto3" json:"trip_id,omitempty"`
+	// Vehicles generated by the trip
+	Generated uint64 `protobuf:"varint,2,opt,name=generated,proto3" json:"generated,omitempty"`
+	// Vehicles which reached the trip destination
+	Arrived uint64 `protobuf:"varint,3,opt,name=arrived,proto3" json:"arrived,omitempty"`
+	// Vehicles despawned in a death zone without arriving
+	Lost uint64 `protobuf:"varint,4,opt,name=lost,proto3" json:"lost,omitempty"`
+	// Mean travel time of arrived vehicles (0 when none arrived)
+	MeanTravelTime float64 `protobuf:"fixed64,5,opt,name=mean_travel_time,json=meanTravelTime,proto3" json:"mean_travel_time,omitempty"`
+	// Travel time percentiles of arrived vehicles (nearest-rank, 0 when none arrived).
+	// Travel times are kept in a bounded histogram: exact below 1024 steps, above that
+	// the upper bound of a bucket less than 1/64 of the value wide is reported.
+	P50TravelTime float64 `protobuf:"fixed64,6,opt,name=p50_travel_time,json=p50TravelTime,proto3" json:"p50_travel_time,omitempty"`
+	P85TravelTime float64 `protobuf:"fixed64,7,opt,name=p85_travel_time,json=p85TravelTime,proto3" json:"p85_travel_time,omitempty"`
+	P95TravelTime float64 `protobuf:"fixed64,8,opt,name=p95_travel_time,json=p95TravelTime,proto3" json:"p95_travel_time,omitempty"`
+	// Free-flow travel time along the shortest route from from_node to to_node (via transits):
+	// sum of 1 / speed_limit over the traversed cells. 0 when no route exists
+	FreeFlowTravelTime float64 `protobuf:"fixed64,9,opt,name=free_flow_travel_time,json=freeFlowTravelTime,proto3" json:"free_flow_travel_time,omitempty"`
+	// Delay index: mean_travel_time / free_flow_travel_time (0 when either is 0)
+	DelayIndex    float64 `protobuf:"fixed64,10,opt,name=delay_index,json=delayIndex,proto3" json:"delay_index,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TripStats) Reset() {
+	*x = TripStats{}
+	mi := &file_stats_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TripStats) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TripStats) ProtoMessage() {}
+
+func (x *TripStats) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TripStats.ProtoReflect.Descriptor instead.
+func (*TripStats) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *TripStats) GetTripId() int64 {
+	if x != nil {
+		return x.TripId
+	}
+	return 0
+}
+
+func (x *TripStats) GetGenerated() uint64 {
+	if x != nil {
+		return x.Generated
+	}
+	return 0
+}
+
+func (x *TripStats) GetArrived() uint64 {
+	if x != nil {
+		return x.Arrived
+	}
+	return 0
+}
+
+func (x *TripStats) GetLost() uint64 {
+	if x != nil {
+		return x.Lost
+	}
+	return 0
+}
+
+func (x *TripStats) GetMeanTravelTime() float64 {
+	if x != nil {
+		return x.MeanTravelTime
+	}
+	return 0
+}
+
+func (x *TripStats) GetP50TravelTime() float64 {
+	if x != nil {
+		return x.P50TravelTime
+	}
+	return 0
+}
+
+func (x *TripStats) GetP85TravelTime() float64 {
+	if x != nil {
+		return x.P85TravelTime
+	}
+	return 0
+}
+
+func (x *TripStats) GetP95TravelTime() float64 {
+	if x != nil {
+		return x.P95TravelTime
+	}
+	return 0
+}
+
+func (x *TripStats) GetFreeFlowTravelTime() float64 {
+	if x != nil {
+		return x.FreeFlowTravelTime
+	}
+	return 0
+}
+
+func (x *TripStats) GetDelayIndex() float64 {
+	if x != nil {
+		return x.DelayIndex
+	}
+	return 0
+}
+
+// Server response for trip statistics query
+type TripStatsResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Statistics ordered by trip_id
+	Data          []*TripStats `protobuf:"bytes,3,rep,name=data,proto3" json:"data,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TripStatsResponse) Reset() {
+	*x = TripStatsResponse{}
+	mi := &file_stats_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TripStatsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TripStatsResponse) ProtoMessage() {}
+
+func (x *TripStatsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TripStatsResponse.ProtoReflect.Descriptor instead.
+func (*TripStatsResponse) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *TripStatsResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *TripStatsResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *TripStatsResponse) GetData() []*TripStats {
+	if x != nil {
+		return x.Data
+	}
+	return nil
+}
+
+// Traffic light statistics query
+type TrafficLightStatsRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Traffic lights to report. Empty means all traffic lights of the session
+	TrafficLightIds []int64 `protobuf:"varint,2,rep,packed,name=traffic_light_ids,json=trafficLightIds,proto3" json:"traffic_light_ids,omitempty"`
+	unknownFields   protoimpl.UnknownFields
+	sizeCache       protoimpl.SizeCache
+}
+
+func (x *TrafficLightStatsRequest) Reset() {
+	*x = TrafficLightStatsRequest{}
+	mi := &file_stats_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TrafficLightStatsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TrafficLightStatsRequest) ProtoMessage() {}
+
+func (x *TrafficLightStatsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TrafficLightStatsRequest.ProtoReflect.Descriptor instead.
+func (*TrafficLightStatsRequest) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *TrafficLightStatsRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *TrafficLightStatsRequest) GetTrafficLightIds() []int64 {
+	if x != nil {
+		return x.TrafficLightIds
+	}
+	return nil
+}
+
+// Statistics of single signal group, accumulated over the steps made by
+// SimulationStepSession since the traffic light has been pushed.
+// A vehicle is served when it enters one of the group's cells (the cells banned
+// by the group's signal). The queue consists of the cells occupied by stopped
+// vehicles, walked upstream from the group's cells.
+type TrafficLightGroupStats struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Group identifier
+	GroupId int64 `protobuf:"varint,1,opt,name=group_id,json=groupId,proto3" json:"group_id,omitempty"`
+	// Vehicles served
+	VehiclesServed uint64 `protobuf:"varint,2,opt,name=vehicles_served,json=vehiclesServed,proto3" json:"vehicles_served,omitempty"`
+	// Green intervals started
+	GreenPhases uint64 `protobuf:"varint,3,opt,name=green_phases,json=greenPhases,proto3" json:"green_phases,omitempty"`
+	// Mean number of vehicles served per green interval
+	VehiclesPerGreen float64 `protobuf:"fixed64,4,opt,name=vehicles_per_green,json=vehiclesPerGreen,proto3" json:"vehicles_per_green,omitempty"`
+	// Average control delay per served vehicle, in simulation steps (stopped time in the queue)
+	MeanControlDelay float64 `protobuf:"fixed64,5,opt,name=mean_control_delay,json=meanControlDelay,proto3" json:"mean_control_delay,omitempty"`
+	// Stops in the queue per served vehicle
+	StopsPerVehicle float64 `protobuf:"fixed64,6,opt,name=stops_per_vehicle,json=stopsPerVehicle,proto3" json:"stops_per_vehicle,omitempty"`
+	// Longest queue observed, in cells
+	MaxQueue uint32 `protobuf:"varint,7,opt,name=max_queue,json=maxQueue,proto3" json:"max_queue,omitempty"`
+	// Share of green steps during which at least one vehicle has been served (0..1)
+	GreenUtilization float64 `protobuf:"fixed64,8,opt,name=green_utilization,json=greenUtilization,proto3" json:"green_utilization,omitempty"`
+	unknownFields    protoimpl.UnknownFields
+	sizeCache        protoimpl.SizeCache
+}
+
+func (x *TrafficLightGroupStats) Reset() {
+	*x = TrafficLightGroupStats{}
+	mi := &file_stats_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TrafficLightGroupStats) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TrafficLightGroupStats) ProtoMessage() {}
+
+func (x *TrafficLightGroupStats) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[4]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TrafficLightGroupStats.ProtoReflect.Descriptor instead.
+func (*TrafficLightGroupStats) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *TrafficLightGroupStats) GetGroupId() int64 {
+	if x != nil {
+		return x.GroupId
+	}
+	return 0
+}
+
+func (x *TrafficLightGroupStats) GetVehiclesServed() uint64 {
+	if x != nil {
+		return x.VehiclesServed
+	}
+	return 0
+}
+
+func (x *TrafficLightGroupStats) GetGreenPhases() uint64 {
+	if x != nil {
+		return x.GreenPhases
+	}
+	return 0
+}
+
+func (x *TrafficLightGroupStats) GetVehiclesPerGreen() float64 {
+	if x != nil {
+		return x.VehiclesPerGreen
+	}
+	return 0
+}
+
+func (x *TrafficLightGroupStats) GetMeanControlDelay() float64 {
+	if x != nil {
+		return x.MeanControlDelay
+	}
+	return 0
+}
+
+func (x *TrafficLightGroupStats) GetStopsPerVehicle() float64 {
+	if x != nil {
+		return x.StopsPerVehicle
+	}
+	return 0
+}
+
+func (x *TrafficLightGroupStats) GetMaxQueue() uint32 {
+	if x != nil {
+		return x.MaxQueue
+	}
+	return 0
+}
+
+func (x *TrafficLightGroupStats) GetGreenUtilization() float64 {
+	if x != nil {
+		return x.GreenUtilization
+	}
+	return 0
+}
+
+// Statistics of single traffic light: totals over its groups plus per-group figures
+type TrafficLightStats struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Traffic light identifier
+	TrafficLightId int64 `protobuf:"varint,1,opt,name=traffic_light_id,json=trafficLightId,proto3" json:"traffic_light_id,omitempty"`
+	// Vehicles served by all groups
+	VehiclesServed uint64 `protobuf:"varint,2,opt,name=vehicles_served,json=vehiclesServed,proto3" json:"vehicles_served,omitempty"`
+	// Mean number of vehicles served per green interval (over all groups)
+	VehiclesPerGreen float64 `protobuf:"fixed64,3,opt,name=vehicles_per_green,json=vehiclesPerGreen,proto3" json:"vehicles_per_green,omitempty"`
+	// Average control delay per served vehicle, in simulation steps
+	MeanControlDelay float64 `protobuf:"fixed64,4,opt,name=mean_control_delay,json=meanControlDelay,proto3" json:"mean_control_delay,omitempty"`
+	// Stops per served vehicle
+	StopsPerVehicle float64 `protobuf:"fixed64,5,opt,name=stops_per_vehicle,json=stopsPerVehicle,proto3" json:"stops_per_vehicle,omitempty"`
+	// Longest queue observed on any group, in cells
+	MaxQueue uint32 `protobuf:"varint,6,opt,name=max_queue,json=maxQueue,proto3" json:"max_queue,omitempty"`
+	// Share of green steps used over all groups (0..1)
+	GreenUtilization float64 `protobuf:"fixed64,7,opt,name=green_utilization,json=greenUtilization,proto3" json:"green_utilization,omitempty"`
+	// Per-group statistics, in the order the groups were pushed
+	Groups        []*TrafficLightGroupStats `protobuf:"bytes,8,rep,name=groups,proto3" json:"groups,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TrafficLightStats) Reset() {
+	*x = TrafficLightStats{}
+	mi := &file_stats_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TrafficLightStats) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TrafficLightStats) ProtoMessage() {}
+
+func (x *TrafficLightStats) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TrafficLightStats.ProtoReflect.Descriptor instead.
+func (*TrafficLightStats) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *TrafficLightStats) GetTrafficLightId() int64 {
+	if x != nil {
+		return x.TrafficLightId
+	}
+	return 0
+}
+
+func (x *TrafficLightStats) GetVehiclesServed() uint64 {
+	if x != nil {
+		return x.VehiclesServed
+	}
+	return 0
+}
+
+func (x *TrafficLightStats) GetVehiclesPerGreen() float64 {
+	if x != nil {
+		return x.VehiclesPerGreen
+	}
+	return 0
+}
+
+func (x *TrafficLightStats) GetMeanControlDelay() float64 {
+	if x != nil {
+		return x.MeanControlDelay
+	}
+	return 0
+}
+
+func (x *TrafficLightStats) GetStopsPerVehicle() float64 {
+	if x != nil {
+		return x.StopsPerVehicle
+	}
+	return 0
+}
+
+func (x *TrafficLightStats) GetMaxQueue() uint32 {
+	if x != nil {
+		return x.MaxQueue
+	}
+	return 0
+}
+
+func (x *TrafficLightStats) GetGreenUtilization() float64 {
+	if x != nil {
+		return x.GreenUtilization
+	}
+	return 0
+}
+
+func (x *TrafficLightStats) GetGroups() []*TrafficLightGroupStats {
+	if x != nil {
+		return x.Groups
+	}
+	return nil
+}
+
+// Server response for traffic light statistics query
+type TrafficLightStatsResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Statistics ordered by traffic_light_id
+	Data          []*TrafficLightStats `protobuf:"bytes,3,rep,name=data,proto3" json:"data,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TrafficLightStatsResponse) Reset() {
+	*x = TrafficLightStatsResponse{}
+	mi := &file_stats_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TrafficLightStatsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TrafficLightStatsResponse) ProtoMessage() {}
+
+func (x *TrafficLightStatsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TrafficLightStatsResponse.ProtoReflect.Descriptor instead.
+func (*TrafficLightStatsResponse) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *TrafficLightStatsResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *TrafficLightStatsResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *TrafficLightStatsResponse) GetData() []*TrafficLightStats {
+	if x != nil {
+		return x.Data
+	}
+	return nil
+}
+
+// Macroscopic fundamental diagram measures over one sampling window
+type MfdMeasure struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Vehicle-kilometres travelled. Distances are taken between cell points: meters
+	// for WGS84 grids (so kilometres here), grid units otherwise (thousands of units here)
+	Vkt float64 `protobuf:"fixed64,1,opt,name=vkt,proto3" json:"vkt,omitempty"`
+	// Total time spent by vehicles in the network (or on the meso link), in vehicle-hours
+	TotalTimeSpent float64 `protobuf:"fixed64,2,opt,name=total_time_spent,json=totalTimeSpent,proto3" json:"total_time_spent,omitempty"`
+	// Production: vkt per hour of simulated time
+	Production float64 `protobuf:"fixed64,3,opt,name=production,proto3" json:"production,omitempty"`
+	// Accumulation: mean number of vehicles present over the window
+	Accumulation  float64 `protobuf:"fixed64,4,opt,name=accumulation,proto3" json:"accumulation,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *MfdMeasure) Reset() {
+	*x = MfdMeasure{}
+	mi := &file_stats_proto_msgTypes[7]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *MfdMeasure) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*MfdMeasure) ProtoMessage() {}
+
+func (x *MfdMeasure) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[7]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use MfdMeasure.ProtoReflect.Descriptor instead.
+func (*MfdMeasure) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{7}
+}
+
+func (x *MfdMeasure) GetVkt() float64 {
+	if x != nil {
+		return x.Vkt
+	}
+	return 0
+}
+
+func (x *MfdMeasure) GetTotalTimeSpent() float64 {
+	if x != nil {
+		return x.TotalTimeSpent
+	}
+	return 0
+}
+
+func (x *MfdMeasure) GetProduction() float64 {
+	if x != nil {
+		return x.Production
+	}
+	return 0
+}
+
+func (x *MfdMeasure) GetAccumulation() float64 {
+	if x != nil {
+		return x.Accumulation
+	}
+	return 0
+}
+
+// MFD measures of single meso link
+type MfdLinkMeasure struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Meso link identifier (see Cell.meso_link_id)
+	MesoLinkId int64 `protobuf:"varint,1,opt,name=meso_link_id,json=mesoLinkId,proto3" json:"meso_link_id,omitempty"`
+	// Measures of the cells belonging to the meso link
+	Measure       *MfdMeasure `protobuf:"bytes,2,opt,name=measure,proto3" json:"measure,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *MfdLinkMeasure) Reset() {
+	*x = MfdLinkMeasure{}
+	mi := &file_stats_proto_msgTypes[8]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *MfdLinkMeasure) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*MfdLinkMeasure) ProtoMessage() {}
+
+func (x *MfdLinkMeasure) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[8]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use MfdLinkMeasure.ProtoReflect.Descriptor instead.
+func (*MfdLinkMeasure) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{8}
+}
+
+func (x *MfdLinkMeasure) GetMesoLinkId() int64 {
+	if x != nil {
+		return x.MesoLinkId
+	}
+	return 0
+}
+
+func (x *MfdLinkMeasure) GetMeasure() *MfdMeasure {
+	if x != nil {
+		return x.Measure
+	}
+	return nil
+}
+
+// MFD sample covering ticks [tick_start, tick_start + tick_count)
+type MfdSample struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// First tick of the window
+	TickStart uint64 `protobuf:"varint,1,opt,name=tick_start,json=tickStart,proto3" json:"tick_start,omitempty"`
+	// Ticks in the window (the last window of a run may be shorter)
+	TickCount uint32 `protobuf:"varint,2,opt,name=tick_count,json=tickCount,proto3" json:"tick_count,omitempty"`
+	// Network-wide measures
+	Network *MfdMeasure `protobuf:"bytes,3,opt,name=network,proto3" json:"network,omitempty"`
+	// Per meso link measures, ordered by meso_link_id. Links without vehicles are omitted
+	Links         []*MfdLinkMeasure `protobuf:"bytes,4,rep,name=links,proto3" json:"links,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *MfdSample) Reset() {
+	*x = MfdSample{}
+	mi := &file_stats_proto_msgTypes[9]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *MfdSample) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*MfdSample) ProtoMessage() {}
+
+func (x *MfdSample) ProtoReflect() protoreflect.Message {
+	mi := &file_stats_proto_msgTypes[9]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use MfdSample.ProtoReflect.Descriptor instead.
+func (*MfdSample) Descriptor() ([]byte, []int) {
+	return file_stats_proto_rawDescGZIP(), []int{9}
+}
+
+func (x *MfdSample) GetTickStart() uint64 {
+	if x != nil {
+		return x.TickStart
+	}
+	return 0
+}
+
+func (x *MfdSample) GetTickCount() uint32 {
+	if x != nil {
+		return x.TickCount
+	}
+	return 0
+}
+
+func (x *MfdSample) GetNetwork() *MfdMeasure {
+	if x != nil {
+		return x.Network
+	}
+	return nil
+}
+
+func (x *MfdSample) GetLinks() []*MfdLinkMeasure {
+	if x != nil {
+		return x.Links
+	}
+	return nil
+}
+
+var File_stats_proto protoreflect.FileDescriptor
+
+const file_stats_proto_rawDesc = "" +
+	"\n" +
+	"\vstats.proto\x12\x11micro_traffic_sim\x1a\n" +
+	"uuid.proto\"g\n" +
+	"\x10TripStatsRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x19\n" +
+	"\btrip_ids\x18\x02 \x03(\x03R\atripIds\"\xe6\x02\n" +
+	"\tTripStats\x12\x17\n" +
+	"\atrip_id\x18\x01 \x01(\x03R\x06tripId\x12\x1c\n" +
+	"\tgenerated\x18\x02 \x01(\x04R\tgenerated\x12\x18\n" +
+	"\aarrived\x18\x03 \x01(\x04R\aarrived\x12\x12\n" +
+	"\x04lost\x18\x04 \x01(\x04R\x04lost\x12(\n" +
+	"\x10mean_travel_time\x18\x05 \x01(\x01R\x0emeanTravelTime\x12&\n" +
+	"\x0fp50_travel_time\x18\x06 \x01(\x01R\rp50TravelTime\x12&\n" +
+	"\x0fp85_travel_time\x18\a \x01(\x01R\rp85TravelTime\x12&\n" +
+	"\x0fp95_travel_time\x18\b \x01(\x01R\rp95TravelTime\x121\n" +
+	"\x15free_flow_travel_time\x18\t \x01(\x01R\x12freeFlowTravelTime\x12\x1f\n" +
+	"\vdelay_index\x18\n" +
+	" \x01(\x01R\n" +
+	"delayIndex\"m\n" +
+	"\x11TripStatsResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x120\n" +
+	"\x04data\x18\x03 \x03(\v2\x1c.micro_traffic_sim.TripStatsR\x04data\"\x80\x01\n" +
+	"\x18TrafficLightStatsRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12*\n" +
+	"\x11traffic_light_ids\x18\x02 \x03(\x03R\x0ftrafficLightIds\"\xd1\x02\n" +
+	"\x16TrafficLightGroupStats\x12\x19\n" +
+	"\bgroup_id\x18\x01 \x01(\x03R\agroupId\x12'\n" +
+	"\x0fvehicles_served\x18\x02 \x01(\x04R\x0evehiclesServed\x12!\n" +
+	"\fgreen_phases\x18\x03 \x01(\x04R\vgreenPhases\x12,\n" +
+	"\x12vehicles_per_green\x18\x04 \x01(\x01R\x10vehiclesPerGreen\x12,\n" +
+	"\x12mean_control_delay\x18\x05 \x01(\x01R\x10meanControlDelay\x12*\n" +
+	"\x11stops_per_vehicle\x18\x06 \x01(\x01R\x0fstopsPerVehicle\x12\x1b\n" +
+	"\tmax_queue\x18\a \x01(\rR\bmaxQueue\x12+\n" +
+	"\x11green_utilization\x18\b \x01(\x01R\x10greenUtilization\"\xfb\x02\n" +
+	"\x11TrafficLightStats\x12(\n" +
+	"\x10traffic_light_id\x18\x01 \x01(\x03R\x0etrafficLightId\x12'\n" +
+	"\x0fvehicles_served\x18\x02 \x01(\x04R\x0evehiclesServed\x12,\n" +
+	"\x12vehicles_per_green\x18\x03 \x01(\x01R\x10vehiclesPerGreen\x12,\n" +
+	"\x12mean_control_delay\x18\x04 \x01(\x01R\x10meanControlDelay\x12*\n" +
+	"\x11stops_per_vehicle\x18\x05 \x01(\x01R\x0fstopsPerVehicle\x12\x1b\n" +
+	"\tmax_queue\x18\x06 \x01(\rR\bmaxQueue\x12+\n" +
+	"\x11green_utilization\x18\a \x01(\x01R\x10greenUtilization\x12A\n" +
+	"\x06groups\x18\b \x03(\v2).micro_traffic_sim.TrafficLightGroupStatsR\x06groups\"}\n" +
+	"\x19TrafficLightStatsResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x128\n" +
+	"\x04data\x18\x03 \x03(\v2$.micro_traffic_sim.TrafficLightStatsR\x04data\"\x8c\x01\n" +
+	"\n" +
+	"MfdMeasure\x12\x10\n" +
+	"\x03vkt\x18\x01 \x01(\x01R\x03vkt\x12(\n" +
+	"\x10total_time_spent\x18\x02 \x01(\x01R\x0etotalTimeSpent\x12\x1e\n" +
+	"\n" +
+	"production\x18\x03 \x01(\x01R\n" +
+	"production\x12\"\n" +
+	"\faccumulation\x18\x04 \x01(\x01R\faccumulation\"k\n" +
+	"\x0eMfdLinkMeasure\x12 \n" +
+	"\fmeso_link_id\x18\x01 \x01(\x03R\n" +
+	"mesoLinkId\x127\n" +
+	"\ameasure\x18\x02 \x01(\v2\x1d.micro_traffic_sim.MfdMeasureR\ameasure\"\xbb\x01\n" +
+	"\tMfdSample\x12\x1d\n" +
+	"\n" +
+	"tick_start\x18\x01 \x01(\x04R\ttickStart\x12\x1d\n" +
+	"\n" +
+	"tick_count\x18\x02 \x01(\rR\ttickCount\x127\n" +
+	"\anetwork\x18\x03 \x01(\v2\x1d.micro_traffic_sim.MfdMeasureR\anetwork\x127\n" +
+	"\x05links\x18\x04 \x03(\v2!.micro_traffic_sim.MfdLinkMeasureR\x05linksB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+
+var (
+	file_stats_proto_rawDescOnce sync.Once
+	file_stats_proto_rawDescData []byte
+)
+
+func file_stats_proto_rawDescGZIP() []byte {
+	file_stats_proto_rawDescOnce.Do(func() {
+		file_stats_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_stats_proto_rawDesc), len(file_stats_proto_rawDesc)))
+	})
+	return file_stats_proto_rawDescData
+}
+
+var file_stats_proto_msgTypes = make([]protoimpl.MessageInfo, 10)
+var file_stats_proto_goTypes = []any{
+	(*TripStatsRequest)(nil),          // 0: micro_traffic_sim.TripStatsRequest
+	(*TripStats)(nil),                 // 1: micro_traffic_sim.TripStats
+	(*TripStatsResponse)(nil),         // 2: micro_traffic_sim.TripStatsResponse
+	(*TrafficLightStatsRequest)(nil),  // 3: micro_traffic_sim.TrafficLightStatsRequest
+	(*TrafficLightGroupStats)(nil),    // 4: micro_traffic_sim.TrafficLightGroupStats
+	(*TrafficLightStats)(nil),         // 5: micro_traffic_sim.TrafficLightStats
+	(*TrafficLightStatsResponse)(nil), // 6: micro_traffic_sim.TrafficLightStatsResponse
+	(*MfdMeasure)(nil),                // 7: micro_traffic_sim.MfdMeasure
+	(*MfdLinkMeasure)(nil),            // 8: micro_traffic_sim.MfdLinkMeasure
+	(*MfdSample)(nil),                 // 9: micro_traffic_sim.MfdSample
+	(*UUIDv4)(nil),                    // 10: micro_traffic_sim.UUIDv4
+}
+var file_stats_proto_depIdxs = []int32{
+	10, // 0: micro_traffic_sim.TripStatsRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	1,  // 1: micro_traffic_sim.TripStatsResponse.data:type_name -> micro_traffic_sim.TripStats
+	10, // 2: micro_traffic_sim.TrafficLightStatsRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	4,  // 3: micro_traffic_sim.TrafficLightStats.groups:type_name -> micro_traffic_sim.TrafficLightGroupStats
+	5,  // 4: micro_traffic_sim.TrafficLightStatsResponse.data:type_name -> micro_traffic_sim.TrafficLightStats
+	7,  // 5: micro_traffic_sim.MfdLinkMeasure.measure:type_name -> micro_traffic_sim.MfdMeasure
+	7,  // 6: micro_traffic_sim.MfdSample.network:type_name -> micro_traffic_sim.MfdMeasure
+	8,  // 7: micro_traffic_sim.MfdSample.links:type_name -> micro_traffic_sim.MfdLinkMeasure
+	8,  // [8:8] is the sub-list for method output_type
+	8,  // [8:8] is the sub-list for method input_type
+	8,  // [8:8] is the sub-list for extension type_name
+	8,  // [8:8] is the sub-list for extension extendee
+	0,  // [0:8] is the sub-list for field type_name
+}
+
+func init() { file_stats_proto_init() }
+func file_stats_proto_init() {
+	if File_stats_proto != nil {
+		return
+	}
+	file_uuid_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_stats_proto_rawDesc), len(file_stats_proto_rawDesc)),
+			NumEnums:      0,
+			NumMessages:   10,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_stats_proto_goTypes,
+		DependencyIndexes: file_stats_proto_depIdxs,
+		MessageInfos:      file_stats_proto_msgTypes,
+	}.Build()
+	File_stats_proto = out.File
+	file_stats_proto_goTypes = nil
+	file_stats_proto_depIdxs = nil
+}
@@ -91,6 +91,85 @@ func (AgentType) EnumDescriptor() ([]byte, []int) {
 	return file_step_proto_rawDescGZIP(), []int{0}
 }
 
+// Kind of a per-step vehicle lifecycle event. The values are also the u8 event_type of
+// record blobs (see RECORD BLOB LAYOUT in record.proto), 1 to 7.
+type VehicleEventType int32
+
+const (
+	// Undefined event type.
+	VehicleEventType_VEHICLE_EVENT_TYPE_UNDEFINED VehicleEventType = 0
+	// Vehicle has been generated by a trip (or by SpawnVehicle). `cell` is the birth cell
+	// (trip's from_node, or the requested origin cell).
+	VehicleEventType_VEHICLE_EVENT_TYPE_SPAWNED VehicleEventType = 1
+	// Vehicle has reached its destination and left the simulation. `cell` is the destination.
+	VehicleEventType_VEHICLE_EVENT_TYPE_ARRIVED VehicleEventType = 2
+	// Vehicle has been despawned in a death zone without reaching its destination.
+	// `cell` is the death cell when it could be resolved, -1 otherwise.
+	VehicleEventType_VEHICLE_EVENT_TYPE_LOST VehicleEventType = 3
+	// Vehicle has changed lane. `from_cell` is the cell it left, `cell` is the cell it entered.
+	VehicleEventType_VEHICLE_EVENT_TYPE_LANE_CHANGED VehicleEventType = 4
+	// Vehicle is held in `cell` because the next cell is under a red (or otherwise prohibiting) signal.
+	// Emitted on every step the vehicle is held.
+	VehicleEventType_VEHICLE_EVENT_TYPE_RED_LIGHT_STOP VehicleEventType = 5
+	// Vehicle is held in `cell` (source of a conflict zone edge) although the edge target is free,
+	// i.e. it gave way in the conflict zone. Emitted on every step the vehicle yields.
+	VehicleEventType_VEHICLE_EVENT_TYPE_CONFLICT_YIELD VehicleEventType = 6
+	// Vehicle has been removed by RemoveVehicle. `cell` is its last cell. Such vehicles are
+	// not counted in vehicles_completed / vehicles_lost.
+	VehicleEventType_VEHICLE_EVENT_TYPE_REMOVED VehicleEventType = 7
+)
+
+// Enum value maps for VehicleEventType.
+var (
+	VehicleEventType_name = map[int32]string{
+		0: "VEHICLE_EVENT_TYPE_UNDEFINED",
+		1: "VEHICLE_EVENT_TYPE_SPAWNED",
+		2: "VEHICLE_EVENT_TYPE_ARRIVED",
+		3: "VEHICLE_EVENT_TYPE_LOST",
+		4: "VEHICLE_EVENT_TYPE_LANE_CHANGED",
+		5: "VEHICLE_EVENT_TYPE_RED_LIGHT_STOP",
+		6: "VEHICLE_EVENT_TYPE_CONFLICT_YIELD",
+		7: "VEHICLE_EVENT_TYPE_REMOVED",
+	}
+	VehicleEventType_value = map[string]int32{
+		"VEHICLE_EVENT_TYPE_UNDEFINED":      0,
+		"VEHICLE_EVENT_TYPE_SPAWNED":        1,
+		"VEHICLE_EVENT_TYPE_ARRIVED":        2,
+		"VEHICLE_EVENT_TYPE_LOST":           3,
+		"VEHICLE_EVENT_TYPE_LANE_CHANGED":   4,
+		"VEHICLE_EVENT_TYPE_RED_LIGHT_STOP": 5,
+		"VEHICLE_EVENT_TYPE_CONFLICT_YIELD": 6,
+		"VEHICLE_EVENT_TYPE_REMOVED":        7,
+	}
+)
+
+func (x VehicleEventType) Enum() *VehicleEventType {
+	p := new(VehicleEventType)
+	*p = x
+	return p
+}
+
+func (x VehicleEventType) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (VehicleEventType) Descriptor() protoreflect.EnumDescriptor {
+	return file_step_proto_enumTypes[1].Descriptor()
+}
+
+func (VehicleEventType) Type() protoreflect.EnumType {
+	return &file_step_proto_enumTypes[1]
+}
+
+func (x VehicleEventType) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use VehicleEventType.Descriptor instead.
+func (VehicleEventType) EnumDescriptor() ([]byte, []int) {
+	return file_step_proto_rawDescGZIP(), []int{1}
+}
+
 // Run a single simulation step (computation session-bound)
 type SessionStep struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
@@ -149,7 +228,11 @@ type SessionStepResponse struct {
 	// Vehicle state data for the current step
 	VehicleData []*VehicleState `protobuf:"bytes,4,rep,name=vehicle_data,json=vehicleData,proto3" json:"vehicle_data,omitempty"`
 	// Traffic light state data for the current step
-	TlsData       []*TLSState `protobuf:"bytes,5,rep,name=tls_data,json=tlsData,proto3" json:"tls_data,omitempty"`
+	TlsData []*TLSState `protobuf:"bytes,5,rep,name=tls_data,json=tlsData,proto3" json:"tls_data,omitempty"`
+	// Vehicle lifecycle events that happened during the current step, ordered by vehicle_id
+	Events []*VehicleEvent `protobuf:"bytes,6,rep,name=events,proto3" json:"events,omitempty"`
+	// Aggregate KPIs of the current step (not available for steps decoded from recordings)
+	Stats         *TickStats `protobuf:"bytes,7,opt,name=stats,proto3" json:"stats,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -219,6 +302,138 @@ func (x *SessionStepResponse) GetTlsData() []*TLSState {
 	return nil
 }
 
+func (x *SessionStepResponse) GetEvents() []*VehicleEvent {
+	if x != nil {
+		return x.Events
+	}
+	return nil
+}
+
+func (x *SessionStepResponse) GetStats() *TickStats {
+	if x != nil {
+		return x.Stats
+	}
+	return nil
+}
+
+// Aggregate KPIs for single step
+type TickStats struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Vehicles present after the step
+	ActiveVehicles uint32 `protobuf:"varint,1,opt,name=active_vehicles,json=activeVehicles,proto3" json:"active_vehicles,omitempty"`
+	// Vehicles generated during the step
+	Spawned uint32 `protobuf:"varint,2,opt,name=spawned,proto3" json:"spawned,omitempty"`
+	// Vehicles which reached their destination during the step
+	Arrived uint32 `protobuf:"varint,3,opt,name=arrived,proto3" json:"arrived,omitempty"`
+	// Vehicles despawned in a death zone during the step
+	Lost uint32 `protobuf:"varint,4,opt,name=lost,proto3" json:"lost,omitempty"`
+	// Mean speed of active vehicles. Measured in cells per simulation step (0 when there are no vehicles).
+	MeanSpeed float64 `protobuf:"fixed64,5,opt,name=mean_speed,json=meanSpeed,proto3" json:"mean_speed,omitempty"`
+	// Active vehicles with zero speed
+	StoppedVehicles uint32 `protobuf:"varint,6,opt,name=stopped_vehicles,json=stoppedVehicles,proto3" json:"stopped_vehicles,omitempty"`
+	// Total queue length: number of cells (head and tail) occupied by stopped vehicles
+	QueueLength uint32 `protobuf:"varint,7,opt,name=queue_length,json=queueLength,proto3" json:"queue_length,omitempty"`
+	// Cumulative count of vehicles that reached their destination
+	VehiclesCompleted int32 `protobuf:"varint,8,opt,name=vehicles_completed,json=vehiclesCompleted,proto3" json:"vehicles_completed,omitempty"`
+	// Cumulative count of vehicles lost (despawned in a death zone without arriving)
+	VehiclesLost  int32 `protobuf:"varint,9,opt,name=vehicles_lost,json=vehiclesLost,proto3" json:"vehicles_lost,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TickStats) Reset() {
+	*x = TickStats{}
+	mi := &file_step_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TickStats) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TickStats) ProtoMessage() {}
+
+func (x *TickStats) ProtoReflect() protoreflect.Message {
+	mi := &file_step_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TickStats.ProtoReflect.Descriptor instead.
+func (*TickStats) Descriptor() ([]byte, []int) {
+	return file_step_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *TickStats) GetActiveVehicles() uint32 {
+	if x != nil {
+		return x.ActiveVehicles
+	}
+	return 0
+}
+
+func (x *TickStats) GetSpawned() uint32 {
+	if x != nil {
+		return x.Spawned
+	}
+	return 0
+}
+
+func (x *TickStats) GetArrived() uint32 {
+	if x != nil {
+		return x.Arrived
+	}
+	return 0
+}
+
+func (x *TickStats) GetLost() uint32 {
+	if x != nil {
+		return x.Lost
+	}
+	return 0
+}
+
+func (x *TickStats) GetMeanSpeed() float64 {
+	if x != nil {
+		return x.MeanSpeed
+	}
+	return 0
+}
+
+func (x *TickStats) GetStoppedVehicles() uint32 {
+	if x != nil {
+		return x.StoppedVehicles
+	}
+	return 0
+}
+
+func (x *TickStats) GetQueueLength() uint32 {
+	if x != nil {
+		return x.QueueLength
+	}
+	return 0
+}
+
+func (x *TickStats) GetVehiclesCompleted() int32 {
+	if x != nil {
+		return x.VehiclesCompleted
+	}
+	return 0
+}
+
+func (x *TickStats) GetVehiclesLost() int32 {
+	if x != nil {
+		return x.VehiclesLost
+	}
+	return 0
+}
+
 // Vehicle state data for single step
 type VehicleState struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
@@ -246,7 +461,7 @@ type VehicleState struct {
 
 func (x *VehicleState) Reset() {
 	*x = VehicleState{}
-	mi := &file_step_proto_msgTypes[2]
+	mi := &file_step_proto_msgTypes[3]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -258,7 +473,7 @@ func (x *VehicleState) String() string {
 func (*VehicleState) ProtoMessage() {}
 
 func (x *VehicleState) ProtoReflect() protoreflect.Message {
-	mi := &file_step_proto_msgTypes[2]
+	mi := &file_step_proto_msgTypes[3]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -271,7 +486,7 @@ func (x *VehicleState) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VehicleState.ProtoReflect.Descriptor instead.
 func (*VehicleState) Descriptor() ([]byte, []int) {
-	return file_step_proto_rawDescGZIP(), []int{2}
+	return file_step_proto_rawDescGZIP(), []int{3}
 }
 
 func (x *VehicleState) GetVehicleId() int64 {
@@ -350,7 +565,7 @@ type TLSState struct {
 
 func (x *TLSState) Reset() {
 	*x = TLSState{}
-	mi := &file_step_proto_msgTypes[3]
+	mi := &file_step_proto_msgTypes[4]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -362,7 +577,7 @@ func (x *TLSState) String() string {
 func (*TLSState) ProtoMessage() {}
 
 func (x *TLSState) ProtoReflect() protoreflect.Message {
-	mi := &file_step_proto_msgTypes[3]
+	mi := &file_step_proto_msgTypes[4]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -375,7 +590,7 @@ func (x *TLSState) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use TLSState.ProtoReflect.Descriptor instead.
 func (*TLSState) Descriptor() ([]byte, []int) {
-	return file_step_proto_rawDescGZIP(), []int{3}
+	return file_step_proto_rawDescGZIP(), []int{4}
 }
 
 func (x *TLSState) GetId() int64 {
@@ -405,7 +620,7 @@ type TLGroup struct {
 
 func (x *TLGroup) Reset() {
 	*x = TLGroup{}
-	mi := &file_step_proto_msgTypes[4]
+	mi := &file_step_proto_msgTypes[5]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -417,7 +632,7 @@ func (x *TLGroup) String() string {
 func (*TLGroup) ProtoMessage() {}
 
 func (x *TLGroup) ProtoReflect() protoreflect.Message {
-	mi := &file_step_proto_msgTypes[4]
+	mi := &file_step_proto_msgTypes[5]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -430,7 +645,7 @@ func (x *TLGroup) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use TLGroup.ProtoReflect.Descriptor instead.
 func (*TLGroup) Descriptor() ([]byte, []int) {
-	return file_step_proto_rawDescGZIP(), []int{4}
+	return file_step_proto_rawDescGZIP(), []int{5}
 }
 
 func (x *TLGroup) GetId() int64 {
@@ -447,6 +662,88 @@ func (x *TLGroup) GetSignal() string {
 	return ""
 }
 
+// Vehicle lifecycle event for single step
+type VehicleEvent struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Vehicle identifier
+	VehicleId int64 `protobuf:"varint,1,opt,name=vehicle_id,json=vehicleId,proto3" json:"vehicle_id,omitempty"`
+	// Event kind
+	EventType VehicleEventType `protobuf:"varint,2,opt,name=event_type,json=eventType,proto3,enum=micro_traffic_sim.VehicleEventType" json:"event_type,omitempty"`
+	// Trip identifier (-1 when unknown, e.g. vehicle spawned and left within the same step)
+	TripId int64 `protobuf:"varint,3,opt,name=trip_id,json=tripId,proto3" json:"trip_id,omitempty"`
+	// Cell the event refers to (see VehicleEventType), -1 when unknown
+	Cell int64 `protobuf:"varint,4,opt,name=cell,proto3" json:"cell,omitempty"`
+	// Cell the vehicle came from (LANE_CHANGED only, -1 otherwise)
+	FromCell      int64 `protobuf:"varint,5,opt,name=from_cell,json=fromCell,proto3" json:"from_cell,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *VehicleEvent) Reset() {
+	*x = VehicleEvent{}
+	mi := &file_step_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *VehicleEvent) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*VehicleEvent) ProtoMessage() {}
+
+func (x *VehicleEvent) ProtoReflect() protoreflect.Message {
+	mi := &file_step_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use VehicleEvent.ProtoReflect.Descriptor instead.
+func (*VehicleEvent) Descriptor() ([]byte, []int) {
+	return file_step_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *VehicleEvent) GetVehicleId() int64 {
+	if x != nil {
+		return x.VehicleId
+	}
+	return 0
+}
+
+func (x *VehicleEvent) GetEventType() VehicleEventType {
+	if x != nil {
+		return x.EventType
+	}
+	return VehicleEventType_VEHICLE_EVENT_TYPE_UNDEFINED
+}
+
+func (x *VehicleEvent) GetTripId() int64 {
+	if x != nil {
+		return x.TripId
+	}
+	return 0
+}
+
+func (x *VehicleEvent) GetCell() int64 {
+	if x != nil {
+		return x.Cell
+	}
+	return 0
+}
+
+func (x *VehicleEvent) GetFromCell() int64 {
+	if x != nil {
+		return x.FromCell
+	}
+	return 0
+}
+
 var File_step_proto protoreflect.FileDescriptor
 
 const file_step_proto_rawDesc = "" +
@@ -456,13 +753,26 @@ const file_step_proto_rawDesc = "" +
 	"uuid.proto\"G\n" +
 	"\vSessionStep\x128\n" +
 	"\n" +
-	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\"\xd7\x01\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\"\xc4\x02\n" +
 	"\x13SessionStepResponse\x12\x12\n" +
 	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
 	"\x04text\x18\x02 \x01(\tR\x04text\x12\x1c\n" +
 	"\ttimestamp\x18\x03 \x01(\x03R\ttimestamp\x12B\n" +
 	"\fvehicle_data\x18\x04 \x03(\v2\x1f.micro_traffic_sim.VehicleStateR\vvehicleData\x126\n" +
-	"\btls_data\x18\x05 \x03(\v2\x1b.micro_traffic_sim.TLSStateR\atlsData\"\xba\x02\n" +
+	"\btls_data\x18\x05 \x03(\v2\x1b.micro_traffic_sim.TLSStateR\atlsData\x127\n" +
+	"\x06events\x18\x06 \x03(\v2\x1f.micro_traffic_sim.VehicleEventR\x06events\x122\n" +
+	"\x05stats\x18\a \x01(\v2\x1c.micro_traffic_sim.TickStatsR\x05stats\"\xbd\x02\n" +
+	"\tTickStats\x12'\n" +
+	"\x0factive_vehicles\x18\x01 \x01(\rR\x0eactiveVehicles\x12\x18\n" +
+	"\aspawned\x18\x02 \x01(\rR\aspawned\x12\x18\n" +
+	"\aarrived\x18\x03 \x01(\rR\aarrived\x12\x12\n" +
+	"\x04lost\x18\x04 \x01(\rR\x04lost\x12\x1d\n" +
+	"\n" +
+	"mean_speed\x18\x05 \x01(\x01R\tmeanSpeed\x12)\n" +
+	"\x10stopped_vehicles\x18\x06 \x01(\rR\x0fstoppedVehicles\x12!\n" +
+	"\fqueue_length\x18\a \x01(\rR\vqueueLength\x12-\n" +
+	"\x12vehicles_completed\x18\b \x01(\x05R\x11vehiclesCompleted\x12#\n" +
+	"\rvehicles_lost\x18\t \x01(\x05R\fvehiclesLost\"\xba\x02\n" +
 	"\fVehicleState\x12\x1d\n" +
 	"\n" +
 	"vehicle_id\x18\x01 \x01(\x03R\tvehicleId\x12?\n" +
@@ -481,7 +791,15 @@ const file_step_proto_rawDesc = "" +
 	"\x06groups\x18\x02 \x03(\v2\x1a.micro_traffic_sim.TLGroupR\x06groups\"1\n" +
 	"\aTLGroup\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\x03R\x02id\x12\x16\n" +
-	"\x06signal\x18\x02 \x01(\tR\x06signal*\xad\x01\n" +
+	"\x06signal\x18\x02 \x01(\tR\x06signal\"\xbb\x01\n" +
+	"\fVehicleEvent\x12\x1d\n" +
+	"\n" +
+	"vehicle_id\x18\x01 \x01(\x03R\tvehicleId\x12B\n" +
+	"\n" +
+	"event_type\x18\x02 \x01(\x0e2#.micro_traffic_sim.VehicleEventTypeR\teventType\x12\x17\n" +
+	"\atrip_id\x18\x03 \x01(\x03R\x06tripId\x12\x12\n" +
+	"\x04cell\x18\x04 \x01(\x03R\x04cell\x12\x1b\n" +
+	"\tfrom_cell\x18\x05 \x01(\x03R\bfromCell*\xad\x01\n" +
 	"\tAgentType\x12\x18\n" +
 	"\x14AGENT_TYPE_UNDEFINED\x10\x00\x12\x12\n" +
 	"\x0eAGENT_TYPE_CAR\x10\x01\x12\x12\n" +
@@ -489,7 +807,16 @@ const file_step_proto_rawDesc = "" +
 	"\x0fAGENT_TYPE_TAXI\x10\x03\x12\x19\n" +
 	"\x15AGENT_TYPE_PEDESTRIAN\x10\x04\x12\x14\n" +
 	"\x10AGENT_TYPE_TRUCK\x10\x05\x12\x18\n" +
-	"\x14AGENT_TYPE_LARGE_BUS\x10\x06B@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+	"\x14AGENT_TYPE_LARGE_BUS\x10\x06*\xa4\x02\n" +
+	"\x10VehicleEventType\x12 \n" +
+	"\x1cVEHICLE_EVENT_TYPE_UNDEFINED\x10\x00\x12\x1e\n" +
+	"\x1aVEHICLE_EVENT_TYPE_SPAWNED\x10\x01\x12\x1e\n" +
+	"\x1aVEHICLE_EVENT_TYPE_ARRIVED\x10\x02\x12\x1b\n" +
+	"\x17VEHICLE_EVENT_TYPE_LOST\x10\x03\x12#\n" +
+	"\x1fVEHICLE_EVENT_TYPE_LANE_CHANGED\x10\x04\x12%\n" +
+	"!VEHICLE_EVENT_TYPE_RED_LIGHT_STOP\x10\x05\x12%\n" +
+	"!VEHICLE_EVENT_TYPE_CONFLICT_YIELD\x10\x06\x12\x1e\n" +
+	"\x1aVEHICLE_EVENT_TYPE_REMOVED\x10\aB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
 
 var (
 	file_step_proto_rawDescOnce sync.Once
@@ -503,28 +830,34 @@ func file_step_proto_rawDescGZIP() []byte {
 	return file_step_proto_rawDescData
 }
 
-var file_step_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
-var file_step_proto_msgTypes = make([]protoimpl.MessageInfo, 5)
+var file_step_proto_enumTypes = make([]protoimpl.EnumInfo, 2)
+var file_step_proto_msgTypes = make([]protoimpl.MessageInfo, 7)
 var file_step_proto_goTypes = []any{
 	(AgentType)(0),              // 0: micro_traffic_sim.AgentType
-	(*SessionStep)(nil),         // 1: micro_traffic_sim.SessionStep
-	(*SessionStepResponse)(nil), // 2: micro_traffic_sim.SessionStepResponse
-	(*VehicleState)(nil),        // 3: micro_traffic_sim.VehicleState
-	(*TLSState)(nil),            // 4: micro_traffic_sim.TLSState
-	(*TLGroup)(nil),             // 5: micro_traffic_sim.TLGroup
-	(*UUIDv4)(nil),              // 6: micro_traffic_sim.UUIDv4
+	(VehicleEventType)(0),       // 1: micro_traffic_sim.VehicleEventType
+	(*SessionStep)(nil),         // 2: micro_traffic_sim.SessionStep
+	(*SessionStepResponse)(nil), // 3: micro_traffic_sim.SessionStepResponse
+	(*TickStats)(nil),           // 4: micro_traffic_sim.TickStats
+	(*VehicleState)(nil),        // 5: micro_traffic_sim.VehicleState
+	(*TLSState)(nil),            // 6: micro_traffic_sim.TLSState
+	(*TLGroup)(nil),             // 7: micro_traffic_sim.TLGroup
+	(*VehicleEvent)(nil),        // 8: micro_traffic_sim.VehicleEvent
+	(*UUIDv4)(nil),              // 9: micro_traffic_sim.UUIDv4
 }
 var file_step_proto_depIdxs = []int32{
-	6, // 0: micro_traffic_sim.SessionStep.session_id:type_name -> micro_traffic_sim.UUIDv4
-	3, // 1: micro_traffic_sim.SessionStepResponse.vehicle_data:type_name -> micro_traffic_sim.VehicleState
-	4, // 2: micro_traffic_sim.SessionStepResponse.tls_data:type_name -> micro_traffic_sim.TLSState
-	0, // 3: micro_traffic_sim.VehicleState.vehicle_type:type_name -> micro_traffic_sim.AgentType
-	5, // 4: micro_traffic_sim.TLSState.groups:type_name -> micro_traffic_sim.TLGroup
-	5, // [5:5] is the sub-list for method output_type
-	5, // [5:5] is the sub-list for method input_type
-	5, // [5:5] is the sub-list for extension type_name
-	5, // [5:5] is the sub-list for extension extendee
-	0, // [0:5] is the sub-list for field type_name
+	9, // 0: micro_traffic_sim.SessionStep.session_id:type_name -> micro_traffic_sim.UUIDv4
+	5, // 1: micro_traffic_sim.SessionStepResponse.vehicle_data:type_name -> micro_traffic_sim.VehicleState
+	6, // 2: micro_traffic_sim.SessionStepResponse.tls_data:type_name -> micro_traffic_sim.TLSState
+	8, // 3: micro_traffic_sim.SessionStepResponse.events:type_name -> micro_traffic_sim.VehicleEvent
+	4, // 4: micro_traffic_sim.SessionStepResponse.stats:type_name -> micro_traffic_sim.TickStats
+	0, // 5: micro_traffic_sim.VehicleState.vehicle_type:type_name -> micro_traffic_sim.AgentType
+	7, // 6: micro_traffic_sim.TLSState.groups:type_name -> micro_traffic_sim.TLGroup
+	1, // 7: micro_traffic_sim.VehicleEvent.event_type:type_name -> micro_traffic_sim.VehicleEventType
+	8, // [8:8] is the sub-list for method output_type
+	8, // [8:8] is the sub-list for method input_type
+	8, // [8:8] is the sub-list for extension type_name
+	8, // [8:8] is the sub-list for extension extendee
+	0, // [0:8] is the sub-list for field type_name
 }
 
 func init() { file_step_proto_init() }
@@ -538,8 +871,8 @@ func file_step_proto_init() {
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_step_proto_rawDesc), len(file_step_proto_rawDesc)),
-			NumEnums:      1,
-			NumMessages:   5,
+			NumEnums:      2,
+			NumMessages:   7,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
@@ -165,7 +165,11 @@ type Trip struct {
 	// Bus dwell time at stops for agent_type = BUS (2)
 	RelaxTime int64 `protobuf:"varint,12,opt,name=relax_time,json=relaxTime,proto3" json:"relax_time,omitempty"`
 	// Mandatory bus stops to pass for agent_type = BUS (2)
-	Transits      []int64 `protobuf:"varint,13,rep,packed,name=transits,proto3" json:"transits,omitempty"`
+	Transits []int64 `protobuf:"varint,13,rep,packed,name=transits,proto3" json:"transits,omitempty"`
+	// Optional piecewise demand profile. Each bin replaces probability (RANDOM) or
+	// time (CONSTANT) from its start_time until the next bin starts; before the first
+	// bin the values above are used. Bins must be sorted by strictly increasing start_time
+	DemandProfile []*DemandBin `protobuf:"bytes,14,rep,name=demand_profile,json=demandProfile,proto3" json:"demand_profile,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -291,20 +295,95 @@ func (x *Trip) GetTransits() []int64 {
 	return nil
 }
 
+func (x *Trip) GetDemandProfile() []*DemandBin {
+	if x != nil {
+		return x.DemandProfile
+	}
+	return nil
+}
+
+// Demand of a trip from a given step on (see Trip.demand_profile)
+type DemandBin struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// First step the bin applies to
+	StartTime int64 `protobuf:"varint,1,opt,name=start_time,json=startTime,proto3" json:"start_time,omitempty"`
+	// Vehicle generation probability for trip_type = RANDOM (2), in [0, 1]
+	Probability float64 `protobuf:"fixed64,2,opt,name=probability,proto3" json:"probability,omitempty"`
+	// Vehicle generation time for trip_type = CONSTANT (1). 0 = no vehicles
+	Time          int64 `protobuf:"varint,3,opt,name=time,proto3" json:"time,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *DemandBin) Reset() {
+	*x = DemandBin{}
+	mi := &file_trip_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *DemandBin) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DemandBin) ProtoMessage() {}
+
+func (x *DemandBin) ProtoReflect() protoreflect.Message {
+	mi := &file_trip_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DemandBin.ProtoReflect.Descriptor instead.
+func (*DemandBin) Descriptor() ([]byte, []int) {
+	return file_trip_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *DemandBin) GetStartTime() int64 {
+	if x != nil {
+		return x.StartTime
+	}
+	return 0
+}
+
+func (x *DemandBin) GetProbability() float64 {
+	if x != nil {
+		return x.Probability
+	}
+	return 0
+}
+
+func (x *DemandBin) GetTime() int64 {
+	if x != nil {
+		return x.Time
+	}
+	return 0
+}
+
 // Trip payload bound to a session
 type SessionTrip struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
 	// Session identifier (UUIDv4)
 	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
-	// Trip data. Maximum number of entities per message is 10000 (server-side limit)
-	Data          []*Trip `protobuf:"bytes,2,rep,name=data,proto3" json:"data,omitempty"`
-	unknownFields protoimpl.UnknownFields
-	sizeCache     protoimpl.SizeCache
+	// Trip data. Maximum number of entities per message is 10000 (server-side limit, configurable)
+	Data []*Trip `protobuf:"bytes,2,rep,name=data,proto3" json:"data,omitempty"`
+	// Reject the whole message (FAILED_PRECONDITION, no trip added) if any trip is
+	// unreachable over the grid pushed so far. Otherwise unreachable trips are added
+	// and only reported in SessionTripResponse.reachability
+	RejectUnreachable bool `protobuf:"varint,3,opt,name=reject_unreachable,json=rejectUnreachable,proto3" json:"reject_unreachable,omitempty"`
+	unknownFields     protoimpl.UnknownFields
+	sizeCache         protoimpl.SizeCache
 }
 
 func (x *SessionTrip) Reset() {
 	*x = SessionTrip{}
-	mi := &file_trip_proto_msgTypes[1]
+	mi := &file_trip_proto_msgTypes[2]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -316,7 +395,7 @@ func (x *SessionTrip) String() string {
 func (*SessionTrip) ProtoMessage() {}
 
 func (x *SessionTrip) ProtoReflect() protoreflect.Message {
-	mi := &file_trip_proto_msgTypes[1]
+	mi := &file_trip_proto_msgTypes[2]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -329,7 +408,7 @@ func (x *SessionTrip) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SessionTrip.ProtoReflect.Descriptor instead.
 func (*SessionTrip) Descriptor() ([]byte, []int) {
-	return file_trip_proto_rawDescGZIP(), []int{1}
+	return file_trip_proto_rawDescGZIP(), []int{2}
 }
 
 func (x *SessionTrip) GetSessionId() *UUIDv4 {
@@ -346,20 +425,112 @@ func (x *SessionTrip) GetData() []*Trip {
 	return nil
 }
 
+func (x *SessionTrip) GetRejectUnreachable() bool {
+	if x != nil {
+		return x.RejectUnreachable
+	}
+	return false
+}
+
+// Result of the route check of a trip: from_node -> transits -> to_node over the
+// forward / left / right connections of the grid pushed so far
+type TripReachability struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Trip identifier
+	TripId int64 `protobuf:"varint,1,opt,name=trip_id,json=tripId,proto3" json:"trip_id,omitempty"`
+	// Whether to_node (and every transit, in order) can be reached from from_node
+	Reachable bool `protobuf:"varint,2,opt,name=reachable,proto3" json:"reachable,omitempty"`
+	// Shortest path length in cells (0 when unreachable)
+	PathCells uint32 `protobuf:"varint,3,opt,name=path_cells,json=pathCells,proto3" json:"path_cells,omitempty"`
+	// Shortest path geometric length: sum of distances between consecutive cells
+	PathLength float64 `protobuf:"fixed64,4,opt,name=path_length,json=pathLength,proto3" json:"path_length,omitempty"`
+	// Why the trip is unreachable (empty when reachable)
+	Reason        string `protobuf:"bytes,5,opt,name=reason,proto3" json:"reason,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TripReachability) Reset() {
+	*x = TripReachability{}
+	mi := &file_trip_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TripReachability) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TripReachability) ProtoMessage() {}
+
+func (x *TripReachability) ProtoReflect() protoreflect.Message {
+	mi := &file_trip_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TripReachability.ProtoReflect.Descriptor instead.
+func (*TripReachability) Descriptor() ([]byte, []int) {
+	return file_trip_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *TripReachability) GetTripId() int64 {
+	if x != nil {
+		return x.TripId
+	}
+	return 0
+}
+
+func (x *TripReachability) GetReachable() bool {
+	if x != nil {
+		return x.Reachable
+	}
+	return false
+}
+
+func (x *TripReachability) GetPathCells() uint32 {
+	if x != nil {
+		return x.PathCells
+	}
+	return 0
+}
+
+func (x *TripReachability) GetPathLength() float64 {
+	if x != nil {
+		return x.PathLength
+	}
+	return 0
+}
+
+func (x *TripReachability) GetReason() string {
+	if x != nil {
+		return x.Reason
+	}
+	return ""
+}
+
 // Server response for trip ingest
 type SessionTripResponse struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
 	// Response code (0 = OK)
 	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
 	// Human-readable message
-	Text          string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Route check of every trip of the message, in the same order
+	Reachability  []*TripReachability `protobuf:"bytes,3,rep,name=reachability,proto3" json:"reachability,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *SessionTripResponse) Reset() {
 	*x = SessionTripResponse{}
-	mi := &file_trip_proto_msgTypes[2]
+	mi := &file_trip_proto_msgTypes[4]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -371,7 +542,7 @@ func (x *SessionTripResponse) String() string {
 func (*SessionTripResponse) ProtoMessage() {}
 
 func (x *SessionTripResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_trip_proto_msgTypes[2]
+	mi := &file_trip_proto_msgTypes[4]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -384,7 +555,7 @@ func (x *SessionTripResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use SessionTripResponse.ProtoReflect.Descriptor instead.
 func (*SessionTripResponse) Descriptor() ([]byte, []int) {
-	return file_trip_proto_rawDescGZIP(), []int{2}
+	return file_trip_proto_rawDescGZIP(), []int{4}
 }
 
 func (x *SessionTripResponse) GetCode() uint32 {
@@ -401,6 +572,362 @@ func (x *SessionTripResponse) GetText() string {
 	return ""
 }
 
+func (x *SessionTripResponse) GetReachability() []*TripReachability {
+	if x != nil {
+		return x.Reachability
+	}
+	return nil
+}
+
+// Replaces an existing trip of a live session (generation pattern, probability, time,
+// start/end times, agent or behaviour type, ...). `trip.id` selects the trip to update.
+// Works on interactive sessions and on sessions currently owned by RunAndRecord (the
+// change is then applied before the recording's next tick; changes targeting unknown
+// trips are dropped there).
+// A paused trip stays paused: the new definition is used once it is resumed.
+type UpdateSessionTripRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// New trip definition
+	Trip          *Trip `protobuf:"bytes,2,opt,name=trip,proto3" json:"trip,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *UpdateSessionTripRequest) Reset() {
+	*x = UpdateSessionTripRequest{}
+	mi := &file_trip_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *UpdateSessionTripRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateSessionTripRequest) ProtoMessage() {}
+
+func (x *UpdateSessionTripRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_trip_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateSessionTripRequest.ProtoReflect.Descriptor instead.
+func (*UpdateSessionTripRequest) Descriptor() ([]byte, []int) {
+	return file_trip_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *UpdateSessionTripRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *UpdateSessionTripRequest) GetTrip() *Trip {
+	if x != nil {
+		return x.Trip
+	}
+	return nil
+}
+
+// Server response for trip update
+type UpdateSessionTripResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text          string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *UpdateSessionTripResponse) Reset() {
+	*x = UpdateSessionTripResponse{}
+	mi := &file_trip_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *UpdateSessionTripResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateSessionTripResponse) ProtoMessage() {}
+
+func (x *UpdateSessionTripResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_trip_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateSessionTripResponse.ProtoReflect.Descriptor instead.
+func (*UpdateSessionTripResponse) Descriptor() ([]byte, []int) {
+	return file_trip_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *UpdateSessionTripResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *UpdateSessionTripResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+// Stops generating vehicles for a trip and forgets it. Vehicles already generated by
+// the trip keep driving; its statistics remain available through GetTripStats.
+// The core can not drop trips: a removed trip stays in the session as a copy which
+// generates no vehicles, until the session expires. Its id is not reused for trips
+// numbered by the server (PushSessionOdMatrix with first_trip_id = 0).
+type RemoveSessionTripRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Trip identifier
+	TripId        int64 `protobuf:"varint,2,opt,name=trip_id,json=tripId,proto3" json:"trip_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RemoveSessionTripRequest) Reset() {
+	*x = RemoveSessionTripRequest{}
+	mi := &file_trip_proto_msgTypes[7]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RemoveSessionTripRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RemoveSessionTripRequest) ProtoMessage() {}
+
+func (x *RemoveSessionTripRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_trip_proto_msgTypes[7]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RemoveSessionTripRequest.ProtoReflect.Descriptor instead.
+func (*RemoveSessionTripRequest) Descriptor() ([]byte, []int) {
+	return file_trip_proto_rawDescGZIP(), []int{7}
+}
+
+func (x *RemoveSessionTripRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *RemoveSessionTripRequest) GetTripId() int64 {
+	if x != nil {
+		return x.TripId
+	}
+	return 0
+}
+
+// Server response for trip removal
+type RemoveSessionTripResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text          string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RemoveSessionTripResponse) Reset() {
+	*x = RemoveSessionTripResponse{}
+	mi := &file_trip_proto_msgTypes[8]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RemoveSessionTripResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RemoveSessionTripResponse) ProtoMessage() {}
+
+func (x *RemoveSessionTripResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_trip_proto_msgTypes[8]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RemoveSessionTripResponse.ProtoReflect.Descriptor instead.
+func (*RemoveSessionTripResponse) Descriptor() ([]byte, []int) {
+	return file_trip_proto_rawDescGZIP(), []int{8}
+}
+
+func (x *RemoveSessionTripResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *RemoveSessionTripResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+// Pauses or resumes vehicle generation of a trip. The trip keeps its definition (and
+// start/end times, which are not shifted by the pause).
+type SetSessionTripPausedRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Trip identifier
+	TripId int64 `protobuf:"varint,2,opt,name=trip_id,json=tripId,proto3" json:"trip_id,omitempty"`
+	// true = pause generation, false = resume it
+	Paused        bool `protobuf:"varint,3,opt,name=paused,proto3" json:"paused,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SetSessionTripPausedRequest) Reset() {
+	*x = SetSessionTripPausedRequest{}
+	mi := &file_trip_proto_msgTypes[9]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SetSessionTripPausedRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SetSessionTripPausedRequest) ProtoMessage() {}
+
+func (x *SetSessionTripPausedRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_trip_proto_msgTypes[9]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SetSessionTripPausedRequest.ProtoReflect.Descriptor instead.
+func (*SetSessionTripPausedRequest) Descriptor() ([]byte, []int) {
+	return file_trip_proto_rawDescGZIP(), []int{9}
+}
+
+func (x *SetSessionTripPausedRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *SetSessionTripPausedRequest) GetTripId() int64 {
+	if x != nil {
+		return x.TripId
+	}
+	return 0
+}
+
+func (x *SetSessionTripPausedRequest) GetPaused() bool {
+	if x != nil {
+		return x.Paused
+	}
+	return false
+}
+
+// Server response for trip pause/resume
+type SetSessionTripPausedResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text          string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SetSessionTripPausedResponse) Reset() {
+	*x = SetSessionTripPausedResponse{}
+	mi := &file_trip_proto_msgTypes[10]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SetSessionTripPausedResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SetSessionTripPausedResponse) ProtoMessage() {}
+
+func (x *SetSessionTripPausedResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_trip_proto_msgTypes[10]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SetSessionTripPausedResponse.ProtoReflect.Descriptor instead.
+func (*SetSessionTripPausedResponse) Descriptor() ([]byte, []int) {
+	return file_trip_proto_rawDescGZIP(), []int{10}
+}
+
+func (x *SetSessionTripPausedResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *SetSessionTripPausedResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
 var File_trip_proto protoreflect.FileDescriptor
 
 const file_trip_proto_rawDesc = "" +
@@ -408,7 +935,7 @@ const file_trip_proto_rawDesc = "" +
 	"\n" +
 	"trip.proto\x12\x11micro_traffic_sim\x1a\n" +
 	"uuid.proto\x1a\n" +
-	"step.proto\"\xdc\x03\n" +
+	"step.proto\"\xa1\x04\n" +
 	"\x04Trip\x12\x0e\n" +
 	"\x02id\x18\x01 \x01(\x03R\x02id\x128\n" +
 	"\ttrip_type\x18\x02 \x01(\x0e2\x1b.micro_traffic_sim.TripTypeR\btripType\x12\x1b\n" +
@@ -426,13 +953,51 @@ const file_trip_proto_rawDesc = "" +
 	"\bend_time\x18\v \x01(\x03R\aendTime\x12\x1d\n" +
 	"\n" +
 	"relax_time\x18\f \x01(\x03R\trelaxTime\x12\x1a\n" +
-	"\btransits\x18\r \x03(\x03R\btransits\"t\n" +
+	"\btransits\x18\r \x03(\x03R\btransits\x12C\n" +
+	"\x0edemand_profile\x18\x0e \x03(\v2\x1c.micro_traffic_sim.DemandBinR\rdemandProfile\"`\n" +
+	"\tDemandBin\x12\x1d\n" +
+	"\n" +
+	"start_time\x18\x01 \x01(\x03R\tstartTime\x12 \n" +
+	"\vprobability\x18\x02 \x01(\x01R\vprobability\x12\x12\n" +
+	"\x04time\x18\x03 \x01(\x03R\x04time\"\xa3\x01\n" +
 	"\vSessionTrip\x128\n" +
 	"\n" +
 	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12+\n" +
-	"\x04data\x18\x02 \x03(\v2\x17.micro_traffic_sim.TripR\x04data\"=\n" +
+	"\x04data\x18\x02 \x03(\v2\x17.micro_traffic_sim.TripR\x04data\x12-\n" +
+	"\x12reject_unreachable\x18\x03 \x01(\bR\x11rejectUnreachable\"\xa1\x01\n" +
+	"\x10TripReachability\x12\x17\n" +
+	"\atrip_id\x18\x01 \x01(\x03R\x06tripId\x12\x1c\n" +
+	"\treachable\x18\x02 \x01(\bR\treachable\x12\x1d\n" +
+	"\n" +
+	"path_cells\x18\x03 \x01(\rR\tpathCells\x12\x1f\n" +
+	"\vpath_length\x18\x04 \x01(\x01R\n" +
+	"pathLength\x12\x16\n" +
+	"\x06reason\x18\x05 \x01(\tR\x06reason\"\x86\x01\n" +
 	"\x13SessionTripResponse\x12\x12\n" +
 	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x12G\n" +
+	"\freachability\x18\x03 \x03(\v2#.micro_traffic_sim.TripReachabilityR\freachability\"\x81\x01\n" +
+	"\x18UpdateSessionTripRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12+\n" +
+	"\x04trip\x18\x02 \x01(\v2\x17.micro_traffic_sim.TripR\x04trip\"C\n" +
+	"\x19UpdateSessionTripResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\"m\n" +
+	"\x18RemoveSessionTripRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x17\n" +
+	"\atrip_id\x18\x02 \x01(\x03R\x06tripId\"C\n" +
+	"\x19RemoveSessionTripResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\"\x88\x01\n" +
+	"\x1bSetSessionTripPausedRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x17\n" +
+	"\atrip_id\x18\x02 \x01(\x03R\x06tripId\x12\x16\n" +
+	"\x06paused\x18\x03 \x01(\bR\x06paused\"F\n" +
+	"\x1cSetSessionTripPausedResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
 	"\x04text\x18\x02 \x01(\tR\x04text*Q\n" +
 	"\bTripType\x12\x17\n" +
 	"\x13TRIP_TYPE_UNDEFINED\x10\x00\x12\x16\n" +
@@ -458,27 +1023,41 @@ func file_trip_proto_rawDescGZIP() []byte {
 }
 
 var file_trip_proto_enumTypes = make([]protoimpl.EnumInfo, 2)
-var file_trip_proto_msgTypes = make([]protoimpl.MessageInfo, 3)
+var file_trip_proto_msgTypes = make([]protoimpl.MessageInfo, 11)
 var file_trip_proto_goTypes = []any{
-	(TripType)(0),               // 0: micro_traffic_sim.TripType
-	(BehaviourType)(0),          // 1: micro_traffic_sim.BehaviourType
-	(*Trip)(nil),                // 2: micro_traffic_sim.Trip
-	(*SessionTrip)(nil),         // 3: micro_traffic_sim.SessionTrip
-	(*SessionTripResponse)(nil), // 4: micro_traffic_sim.SessionTripResponse
-	(AgentType)(0),              // 5: micro_traffic_sim.AgentType
-	(*UUIDv4)(nil),              // 6: micro_traffic_sim.UUIDv4
+	(TripType)(0),                        // 0: micro_traffic_sim.TripType
+	(BehaviourType)(0),                   // 1: micro_traffic_sim.BehaviourType
+	(*Trip)(nil),                         // 2: micro_traffic_sim.Trip
+	(*DemandBin)(nil),                    // 3: micro_traffic_sim.DemandBin
+	(*SessionTrip)(nil),                  // 4: micro_traffic_sim.SessionTrip
+	(*TripReachability)(nil),             // 5: micro_traffic_sim.TripReachability
+	(*SessionTripResponse)(nil),          // 6: micro_traffic_sim.SessionTripResponse
+	(*UpdateSessionTripRequest)(nil),     // 7: micro_traffic_sim.UpdateSessionTripRequest
+	(*UpdateSessionTripResponse)(nil),    // 8: micro_traffic_sim.UpdateSessionTripResponse
+	(*RemoveSessionTripRequest)(nil),     // 9: micro_traffic_sim.RemoveSessionTripRequest
+	(*RemoveSessionTripResponse)(nil),    // 10: micro_traffic_sim.RemoveSessionTripResponse
+	(*SetSessionTripPausedRequest)(nil),  // 11: micro_traffic_sim.SetSessionTripPausedRequest
+	(*SetSessionTripPausedResponse)(nil), // 12: micro_traffic_sim.SetSessionTripPausedResponse
+	(AgentType)(0),                       // 13: micro_traffic_sim.AgentType
+	(*UUIDv4)(nil),                       // 14: micro_traffic_sim.UUIDv4
 }
 var file_trip_proto_depIdxs = []int32{
-	0, // 0: micro_traffic_sim.Trip.trip_type:type_name -> micro_traffic_sim.TripType
-	5, // 1: micro_traffic_sim.Trip.agent_type:type_name -> micro_traffic_sim.AgentType
-	1, // 2: micro_traffic_sim.Trip.behaviour_type:type_name -> micro_traffic_sim.BehaviourType
-	6, // 3: micro_traffic_sim.SessionTrip.session_id:type_name -> micro_traffic_sim.UUIDv4
-	2, // 4: micro_traffic_sim.SessionTrip.data:type_name -> micro_traffic_sim.Trip
-	5, // [5:5] is the sub-list for method output_type
-	5, // [5:5] is the sub-list for method input_type
-	5, // [5:5] is the sub-list for extension type_name
-	5, // [5:5] is the sub-list for extension extendee
-	0, // [0:5] is the sub-list for field type_name
+	0,  // 0: micro_traffic_sim.Trip.trip_type:type_name -> micro_traffic_sim.TripType
+	13, // 1: micro_traffic_sim.Trip.agent_type:type_name -> micro_traffic_sim.AgentType
+	1,  // 2: micro_traffic_sim.Trip.behaviour_type:type_name -> micro_traffic_sim.BehaviourType
+	3,  // 3: micro_traffic_sim.Trip.demand_profile:type_name -> micro_traffic_sim.DemandBin
+	14, // 4: micro_traffic_sim.SessionTrip.session_id:type_name -> micro_traffic_sim.UUIDv4
+	2,  // 5: micro_traffic_sim.SessionTrip.data:type_name -> micro_traffic_sim.Trip
+	5,  // 6: micro_traffic_sim.SessionTripResponse.reachability:type_name -> micro_traffic_sim.TripReachability
+	14, // 7: micro_traffic_sim.UpdateSessionTripRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	2,  // 8: micro_traffic_sim.UpdateSessionTripRequest.trip:type_name -> micro_traffic_sim.Trip
+	14, // 9: micro_traffic_sim.RemoveSessionTripRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	14, // 10: micro_traffic_sim.SetSessionTripPausedRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	11, // [11:11] is the sub-list for method output_type
+	11, // [11:11] is the sub-list for method input_type
+	11, // [11:11] is the sub-list for extension type_name
+	11, // [11:11] is the sub-list for extension extendee
+	0,  // [0:11] is the sub-list for field type_name
 }
 
 func init() { file_trip_proto_init() }
@@ -494,7 +1073,7 @@ func file_trip_proto_init() {
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_trip_proto_rawDesc), len(file_trip_proto_rawDesc)),
 			NumEnums:      2,
-			NumMessages:   3,
+			NumMessages:   11,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
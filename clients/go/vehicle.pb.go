@@ -0,0 +1,680 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.36.11
+// 	protoc        v6.33.1
+// source: vehicle.proto
+
+package microtraffic
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+	unsafe "unsafe"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+// Request to put a single vehicle into the simulation, outside of trip-based generation.
+// The vehicle is placed on the origin cell right away and moves from the next step on
+// (a SPAWNED event is reported on that step).
+type SpawnVehicleRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Agent type
+	AgentType AgentType `protobuf:"varint,2,opt,name=agent_type,json=agentType,proto3,enum=micro_traffic_sim.AgentType" json:"agent_type,omitempty"`
+	// Behaviour type
+	BehaviourType BehaviourType `protobuf:"varint,3,opt,name=behaviour_type,json=behaviourType,proto3,enum=micro_traffic_sim.BehaviourType" json:"behaviour_type,omitempty"`
+	// Cell to place the vehicle on. Must be free
+	OriginCell int64 `protobuf:"varint,4,opt,name=origin_cell,json=originCell,proto3" json:"origin_cell,omitempty"`
+	// Destination cell
+	DestinationCell int64 `protobuf:"varint,5,opt,name=destination_cell,json=destinationCell,proto3" json:"destination_cell,omitempty"`
+	// Optional cells to pass on the way (mandatory stops for agent_type = BUS)
+	Transits []int64 `protobuf:"varint,6,rep,packed,name=transits,proto3" json:"transits,omitempty"`
+	// Initial speed (cells per step)
+	InitialSpeed  int32 `protobuf:"varint,7,opt,name=initial_speed,json=initialSpeed,proto3" json:"initial_speed,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SpawnVehicleRequest) Reset() {
+	*x = SpawnVehicleRequest{}
+	mi := &file_vehicle_proto_msgTypes[0]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SpawnVehicleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SpawnVehicleRequest) ProtoMessage() {}
+
+func (x *SpawnVehicleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_vehicle_proto_msgTypes[0]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SpawnVehicleRequest.ProtoReflect.Descriptor instead.
+func (*SpawnVehicleRequest) Descriptor() ([]byte, []int) {
+	return file_vehicle_proto_rawDescGZIP(), []int{0}
+}
+
+func (x *SpawnVehicleRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *SpawnVehicleRequest) GetAgentType() AgentType {
+	if x != nil {
+		return x.AgentType
+	}
+	return AgentType_AGENT_TYPE_UNDEFINED
+}
+
+func (x *SpawnVehicleRequest) GetBehaviourType() BehaviourType {
+	if x != nil {
+		return x.BehaviourType
+	}
+	return BehaviourType_BEHAVIOUR_TYPE_UNDEFINED
+}
+
+func (x *SpawnVehicleRequest) GetOriginCell() int64 {
+	if x != nil {
+		return x.OriginCell
+	}
+	return 0
+}
+
+func (x *SpawnVehicleRequest) GetDestinationCell() int64 {
+	if x != nil {
+		return x.DestinationCell
+	}
+	return 0
+}
+
+func (x *SpawnVehicleRequest) GetTransits() []int64 {
+	if x != nil {
+		return x.Transits
+	}
+	return nil
+}
+
+func (x *SpawnVehicleRequest) GetInitialSpeed() int32 {
+	if x != nil {
+		return x.InitialSpeed
+	}
+	return 0
+}
+
+// Server response for vehicle spawning
+type SpawnVehicleResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Identifier of the spawned vehicle
+	VehicleId     int64 `protobuf:"varint,3,opt,name=vehicle_id,json=vehicleId,proto3" json:"vehicle_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SpawnVehicleResponse) Reset() {
+	*x = SpawnVehicleResponse{}
+	mi := &file_vehicle_proto_msgTypes[1]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SpawnVehicleResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SpawnVehicleResponse) ProtoMessage() {}
+
+func (x *SpawnVehicleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_vehicle_proto_msgTypes[1]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SpawnVehicleResponse.ProtoReflect.Descriptor instead.
+func (*SpawnVehicleResponse) Descriptor() ([]byte, []int) {
+	return file_vehicle_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *SpawnVehicleResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *SpawnVehicleResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *SpawnVehicleResponse) GetVehicleId() int64 {
+	if x != nil {
+		return x.VehicleId
+	}
+	return 0
+}
+
+// Request to take a vehicle out of the simulation. The vehicle disappears on the next
+// step (a REMOVED event is reported on that step) and is not counted as completed or lost.
+type RemoveVehicleRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Vehicle identifier
+	VehicleId     int64 `protobuf:"varint,2,opt,name=vehicle_id,json=vehicleId,proto3" json:"vehicle_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RemoveVehicleRequest) Reset() {
+	*x = RemoveVehicleRequest{}
+	mi := &file_vehicle_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RemoveVehicleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RemoveVehicleRequest) ProtoMessage() {}
+
+func (x *RemoveVehicleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_vehicle_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RemoveVehicleRequest.ProtoReflect.Descriptor instead.
+func (*RemoveVehicleRequest) Descriptor() ([]byte, []int) {
+	return file_vehicle_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *RemoveVehicleRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *RemoveVehicleRequest) GetVehicleId() int64 {
+	if x != nil {
+		return x.VehicleId
+	}
+	return 0
+}
+
+// Server response for vehicle removal
+type RemoveVehicleResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text          string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RemoveVehicleResponse) Reset() {
+	*x = RemoveVehicleResponse{}
+	mi := &file_vehicle_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RemoveVehicleResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RemoveVehicleResponse) ProtoMessage() {}
+
+func (x *RemoveVehicleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_vehicle_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RemoveVehicleResponse.ProtoReflect.Descriptor instead.
+func (*RemoveVehicleResponse) Descriptor() ([]byte, []int) {
+	return file_vehicle_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *RemoveVehicleResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *RemoveVehicleResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+// Request for the state and intent of a single vehicle
+type GetVehicleRequest struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Session identifier (UUIDv4)
+	SessionId *UUIDv4 `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	// Vehicle identifier
+	VehicleId     int64 `protobuf:"varint,2,opt,name=vehicle_id,json=vehicleId,proto3" json:"vehicle_id,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetVehicleRequest) Reset() {
+	*x = GetVehicleRequest{}
+	mi := &file_vehicle_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetVehicleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetVehicleRequest) ProtoMessage() {}
+
+func (x *GetVehicleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_vehicle_proto_msgTypes[4]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetVehicleRequest.ProtoReflect.Descriptor instead.
+func (*GetVehicleRequest) Descriptor() ([]byte, []int) {
+	return file_vehicle_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *GetVehicleRequest) GetSessionId() *UUIDv4 {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *GetVehicleRequest) GetVehicleId() int64 {
+	if x != nil {
+		return x.VehicleId
+	}
+	return 0
+}
+
+// Live state and intent of a vehicle, as of the last step
+type VehicleInfo struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// State, as reported by SimulationStepSession
+	State *VehicleState `protobuf:"bytes,1,opt,name=state,proto3" json:"state,omitempty"`
+	// Behaviour type
+	BehaviourType BehaviourType `protobuf:"varint,2,opt,name=behaviour_type,json=behaviourType,proto3,enum=micro_traffic_sim.BehaviourType" json:"behaviour_type,omitempty"`
+	// Maximum speed of the vehicle (cells per step)
+	SpeedLimit int32 `protobuf:"varint,3,opt,name=speed_limit,json=speedLimit,proto3" json:"speed_limit,omitempty"`
+	// Cell the vehicle is currently heading to (the next stop for buses with transits)
+	Destination int64 `protobuf:"varint,4,opt,name=destination,proto3" json:"destination,omitempty"`
+	// Final cell of the vehicle's trip
+	TripDestination int64 `protobuf:"varint,5,opt,name=trip_destination,json=tripDestination,proto3" json:"trip_destination,omitempty"`
+	// Transit cells still to visit, in order (buses only)
+	RemainingTransits []int64 `protobuf:"varint,6,rep,packed,name=remaining_transits,json=remainingTransits,proto3" json:"remaining_transits,omitempty"`
+	// Shortest path from the current cell through the remaining transits to the trip
+	// destination (both ends included). Empty when the destination can not be reached
+	RemainingPath []int64 `protobuf:"varint,7,rep,packed,name=remaining_path,json=remainingPath,proto3" json:"remaining_path,omitempty"`
+	// Whether the trip destination can be reached from the current cell
+	DestinationReachable bool `protobuf:"varint,8,opt,name=destination_reachable,json=destinationReachable,proto3" json:"destination_reachable,omitempty"`
+	// Steps simulated since the vehicle has been spawned
+	StepsSinceSpawn int64 `protobuf:"varint,9,opt,name=steps_since_spawn,json=stepsSinceSpawn,proto3" json:"steps_since_spawn,omitempty"`
+	// Cell the vehicle intended to occupy on the last step
+	IntentionCell int64 `protobuf:"varint,10,opt,name=intention_cell,json=intentionCell,proto3" json:"intention_cell,omitempty"`
+	// Whether the vehicle takes part in a conflict
+	ConflictParticipant bool `protobuf:"varint,11,opt,name=conflict_participant,json=conflictParticipant,proto3" json:"conflict_participant,omitempty"`
+	unknownFields       protoimpl.UnknownFields
+	sizeCache           protoimpl.SizeCache
+}
+
+func (x *VehicleInfo) Reset() {
+	*x = VehicleInfo{}
+	mi := &file_vehicle_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *VehicleInfo) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*VehicleInfo) ProtoMessage() {}
+
+func (x *VehicleInfo) ProtoReflect() protoreflect.Message {
+	mi := &file_vehicle_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use VehicleInfo.ProtoReflect.Descriptor instead.
+func (*VehicleInfo) Descriptor() ([]byte, []int) {
+	return file_vehicle_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *VehicleInfo) GetState() *VehicleState {
+	if x != nil {
+		return x.State
+	}
+	return nil
+}
+
+func (x *VehicleInfo) GetBehaviourType() BehaviourType {
+	if x != nil {
+		return x.BehaviourType
+	}
+	return BehaviourType_BEHAVIOUR_TYPE_UNDEFINED
+}
+
+func (x *VehicleInfo) GetSpeedLimit() int32 {
+	if x != nil {
+		return x.SpeedLimit
+	}
+	return 0
+}
+
+func (x *VehicleInfo) GetDestination() int64 {
+	if x != nil {
+		return x.Destination
+	}
+	return 0
+}
+
+func (x *VehicleInfo) GetTripDestination() int64 {
+	if x != nil {
+		return x.TripDestination
+	}
+	return 0
+}
+
+func (x *VehicleInfo) GetRemainingTransits() []int64 {
+	if x != nil {
+		return x.RemainingTransits
+	}
+	return nil
+}
+
+func (x *VehicleInfo) GetRemainingPath() []int64 {
+	if x != nil {
+		return x.RemainingPath
+	}
+	return nil
+}
+
+func (x *VehicleInfo) GetDestinationReachable() bool {
+	if x != nil {
+		return x.DestinationReachable
+	}
+	return false
+}
+
+func (x *VehicleInfo) GetStepsSinceSpawn() int64 {
+	if x != nil {
+		return x.StepsSinceSpawn
+	}
+	return 0
+}
+
+func (x *VehicleInfo) GetIntentionCell() int64 {
+	if x != nil {
+		return x.IntentionCell
+	}
+	return 0
+}
+
+func (x *VehicleInfo) GetConflictParticipant() bool {
+	if x != nil {
+		return x.ConflictParticipant
+	}
+	return false
+}
+
+// Server response for vehicle inspection
+type GetVehicleResponse struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Response code (0 = OK)
+	Code uint32 `protobuf:"varint,1,opt,name=code,proto3" json:"code,omitempty"`
+	// Human-readable message
+	Text string `protobuf:"bytes,2,opt,name=text,proto3" json:"text,omitempty"`
+	// Vehicle data
+	Vehicle       *VehicleInfo `protobuf:"bytes,3,opt,name=vehicle,proto3" json:"vehicle,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetVehicleResponse) Reset() {
+	*x = GetVehicleResponse{}
+	mi := &file_vehicle_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetVehicleResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetVehicleResponse) ProtoMessage() {}
+
+func (x *GetVehicleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_vehicle_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetVehicleResponse.ProtoReflect.Descriptor instead.
+func (*GetVehicleResponse) Descriptor() ([]byte, []int) {
+	return file_vehicle_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *GetVehicleResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *GetVehicleResponse) GetText() string {
+	if x != nil {
+		return x.Text
+	}
+	return ""
+}
+
+func (x *GetVehicleResponse) GetVehicle() *VehicleInfo {
+	if x != nil {
+		return x.Vehicle
+	}
+	return nil
+}
+
+var File_vehicle_proto protoreflect.FileDescriptor
+
+const file_vehicle_proto_rawDesc = "" +
+	"\n" +
+	"\rvehicle.proto\x12\x11micro_traffic_sim\x1a\n" +
+	"uuid.proto\x1a\n" +
+	"step.proto\x1a\n" +
+	"trip.proto\"\xe2\x02\n" +
+	"\x13SpawnVehicleRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12;\n" +
+	"\n" +
+	"agent_type\x18\x02 \x01(\x0e2\x1c.micro_traffic_sim.AgentTypeR\tagentType\x12G\n" +
+	"\x0ebehaviour_type\x18\x03 \x01(\x0e2 .micro_traffic_sim.BehaviourTypeR\rbehaviourType\x12\x1f\n" +
+	"\vorigin_cell\x18\x04 \x01(\x03R\n" +
+	"originCell\x12)\n" +
+	"\x10destination_cell\x18\x05 \x01(\x03R\x0fdestinationCell\x12\x1a\n" +
+	"\btransits\x18\x06 \x03(\x03R\btransits\x12#\n" +
+	"\rinitial_speed\x18\a \x01(\x05R\finitialSpeed\"]\n" +
+	"\x14SpawnVehicleResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x12\x1d\n" +
+	"\n" +
+	"vehicle_id\x18\x03 \x01(\x03R\tvehicleId\"o\n" +
+	"\x14RemoveVehicleRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x1d\n" +
+	"\n" +
+	"vehicle_id\x18\x02 \x01(\x03R\tvehicleId\"?\n" +
+	"\x15RemoveVehicleResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\"l\n" +
+	"\x11GetVehicleRequest\x128\n" +
+	"\n" +
+	"session_id\x18\x01 \x01(\v2\x19.micro_traffic_sim.UUIDv4R\tsessionId\x12\x1d\n" +
+	"\n" +
+	"vehicle_id\x18\x02 \x01(\x03R\tvehicleId\"\x8c\x04\n" +
+	"\vVehicleInfo\x125\n" +
+	"\x05state\x18\x01 \x01(\v2\x1f.micro_traffic_sim.VehicleStateR\x05state\x12G\n" +
+	"\x0ebehaviour_type\x18\x02 \x01(\x0e2 .micro_traffic_sim.BehaviourTypeR\rbehaviourType\x12\x1f\n" +
+	"\vspeed_limit\x18\x03 \x01(\x05R\n" +
+	"speedLimit\x12 \n" +
+	"\vdestination\x18\x04 \x01(\x03R\vdestination\x12)\n" +
+	"\x10trip_destination\x18\x05 \x01(\x03R\x0ftripDestination\x12-\n" +
+	"\x12remaining_transits\x18\x06 \x03(\x03R\x11remainingTransits\x12%\n" +
+	"\x0eremaining_path\x18\a \x03(\x03R\rremainingPath\x123\n" +
+	"\x15destination_reachable\x18\b \x01(\bR\x14destinationReachable\x12*\n" +
+	"\x11steps_since_spawn\x18\t \x01(\x03R\x0fstepsSinceSpawn\x12%\n" +
+	"\x0eintention_cell\x18\n" +
+	" \x01(\x03R\rintentionCell\x121\n" +
+	"\x14conflict_participant\x18\v \x01(\bR\x13conflictParticipant\"v\n" +
+	"\x12GetVehicleResponse\x12\x12\n" +
+	"\x04code\x18\x01 \x01(\rR\x04code\x12\x12\n" +
+	"\x04text\x18\x02 \x01(\tR\x04text\x128\n" +
+	"\avehicle\x18\x03 \x01(\v2\x1e.micro_traffic_sim.VehicleInfoR\avehicleB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3"
+
+var (
+	file_vehicle_proto_rawDescOnce sync.Once
+	file_vehicle_proto_rawDescData []byte
+)
+
+func file_vehicle_proto_rawDescGZIP() []byte {
+	file_vehicle_proto_rawDescOnce.Do(func() {
+		file_vehicle_proto_rawDescData = protoimpl.X.CompressGZIP(unsafe.Slice(unsafe.StringData(file_vehicle_proto_rawDesc), len(file_vehicle_proto_rawDesc)))
+	})
+	return file_vehicle_proto_rawDescData
+}
+
+var file_vehicle_proto_msgTypes = make([]protoimpl.MessageInfo, 7)
+var file_vehicle_proto_goTypes = []any{
+	(*SpawnVehicleRequest)(nil),   // 0: micro_traffic_sim.SpawnVehicleRequest
+	(*SpawnVehicleResponse)(nil),  // 1: micro_traffic_sim.SpawnVehicleResponse
+	(*RemoveVehicleRequest)(nil),  // 2: micro_traffic_sim.RemoveVehicleRequest
+	(*RemoveVehicleResponse)(nil), // 3: micro_traffic_sim.RemoveVehicleResponse
+	(*GetVehicleRequest)(nil),     // 4: micro_traffic_sim.GetVehicleRequest
+	(*VehicleInfo)(nil),           // 5: micro_traffic_sim.VehicleInfo
+	(*GetVehicleResponse)(nil),    // 6: micro_traffic_sim.GetVehicleResponse
+	(*UUIDv4)(nil),                // 7: micro_traffic_sim.UUIDv4
+	(AgentType)(0),                // 8: micro_traffic_sim.AgentType
+	(BehaviourType)(0),            // 9: micro_traffic_sim.BehaviourType
+	(*VehicleState)(nil),          // 10: micro_traffic_sim.VehicleState
+}
+var file_vehicle_proto_depIdxs = []int32{
+	7,  // 0: micro_traffic_sim.SpawnVehicleRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	8,  // 1: micro_traffic_sim.SpawnVehicleRequest.agent_type:type_name -> micro_traffic_sim.AgentType
+	9,  // 2: micro_traffic_sim.SpawnVehicleRequest.behaviour_type:type_name -> micro_traffic_sim.BehaviourType
+	7,  // 3: micro_traffic_sim.RemoveVehicleRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	7,  // 4: micro_traffic_sim.GetVehicleRequest.session_id:type_name -> micro_traffic_sim.UUIDv4
+	10, // 5: micro_traffic_sim.VehicleInfo.state:type_name -> micro_traffic_sim.VehicleState
+	9,  // 6: micro_traffic_sim.VehicleInfo.behaviour_type:type_name -> micro_traffic_sim.BehaviourType
+	5,  // 7: micro_traffic_sim.GetVehicleResponse.vehicle:type_name -> micro_traffic_sim.VehicleInfo
+	8,  // [8:8] is the sub-list for method output_type
+	8,  // [8:8] is the sub-list for method input_type
+	8,  // [8:8] is the sub-list for extension type_name
+	8,  // [8:8] is the sub-list for extension extendee
+	0,  // [0:8] is the sub-list for field type_name
+}
+
+func init() { file_vehicle_proto_init() }
+func file_vehicle_proto_init() {
+	if File_vehicle_proto != nil {
+		return
+	}
+	file_uuid_proto_init()
+	file_step_proto_init()
+	file_trip_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: unsafe.Slice(unsafe.StringData(file_vehicle_proto_rawDesc), len(file_vehicle_proto_rawDesc)),
+			NumEnums:      0,
+			NumMessages:   7,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_vehicle_proto_goTypes,
+		DependencyIndexes: file_vehicle_proto_depIdxs,
+		MessageInfos:      file_vehicle_proto_msgTypes,
+	}.Build()
+	File_vehicle_proto = out.File
+	file_vehicle_proto_goTypes = nil
+	file_vehicle_proto_depIdxs = nil
+}
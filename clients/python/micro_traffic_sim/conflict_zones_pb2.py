@@ -25,7 +25,7 @@ _sym_db = _symbol_database.Default()
 from . import uuid_pb2 as uuid__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x14\x63onflict_zones.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"\xde\x01\n\x0c\x43onflictZone\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x10\n\x08source_x\x18\x02 \x01(\x03\x12\x10\n\x08source_y\x18\x03 \x01(\x03\x12\x10\n\x08target_x\x18\x04 \x01(\x03\x12\x10\n\x08target_y\x18\x05 \x01(\x03\x12>\n\x0f\x63onflict_winner\x18\x06 \x01(\x0e\x32%.micro_traffic_sim.ConflictWinnerType\x12:\n\rconflict_type\x18\x07 \x01(\x0e\x32#.micro_traffic_sim.ConflictZoneType\"t\n\x14SessionConflictZones\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12-\n\x04\x64\x61ta\x18\x02 \x03(\x0b\x32\x1f.micro_traffic_sim.ConflictZone\":\n\x1cSessionConflictZonesResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t*\x85\x01\n\x12\x43onflictWinnerType\x12\x1d\n\x19\x43ONFLICT_WINNER_UNDEFINED\x10\x00\x12\x19\n\x15\x43ONFLICT_WINNER_EQUAL\x10\x01\x12\x19\n\x15\x43ONFLICT_WINNER_FIRST\x10\x02\x12\x1a\n\x16\x43ONFLICT_WINNER_SECOND\x10\x03*4\n\x10\x43onflictZoneType\x12 \n\x1c\x43ONFLICT_ZONE_TYPE_UNDEFINED\x10\x00\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x14\x63onflict_zones.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"e\n\x10\x43onflictPriority\x12\x39\n\x06preset\x18\x01 \x01(\x0e\x32).micro_traffic_sim.ConflictPriorityPreset\x12\x16\n\x0epriority_cells\x18\x02 \x03(\x03\"\xde\x01\n\x0c\x43onflictZone\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x10\n\x08source_x\x18\x02 \x01(\x03\x12\x10\n\x08source_y\x18\x03 \x01(\x03\x12\x10\n\x08target_x\x18\x04 \x01(\x03\x12\x10\n\x08target_y\x18\x05 \x01(\x03\x12>\n\x0f\x63onflict_winner\x18\x06 \x01(\x0e\x32%.micro_traffic_sim.ConflictWinnerType\x12:\n\rconflict_type\x18\x07 \x01(\x0e\x32#.micro_traffic_sim.ConflictZoneType\"\xab\x01\n\x14SessionConflictZones\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12-\n\x04\x64\x61ta\x18\x02 \x03(\x0b\x32\x1f.micro_traffic_sim.ConflictZone\x12\x35\n\x08priority\x18\x03 \x01(\x0b\x32#.micro_traffic_sim.ConflictPriority\":\n\x1cSessionConflictZonesResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\"\xe6\x01\n\x1cGenerateConflictZonesRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12=\n\x0e\x64\x65\x66\x61ult_winner\x18\x02 \x01(\x0e\x32%.micro_traffic_sim.ConflictWinnerType\x12\x0f\n\x07\x64ry_run\x18\x03 \x01(\x08\x12\x10\n\x08\x66irst_id\x18\x04 \x01(\x03\x12\x35\n\x08priority\x18\x05 \x01(\x0b\x32#.micro_traffic_sim.ConflictPriority\"{\n\x1dGenerateConflictZonesResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12-\n\x04\x64\x61ta\x18\x03 \x03(\x0b\x32\x1f.micro_traffic_sim.ConflictZone\x12\x0f\n\x07skipped\x18\x04 \x01(\r*\x85\x01\n\x12\x43onflictWinnerType\x12\x1d\n\x19\x43ONFLICT_WINNER_UNDEFINED\x10\x00\x12\x19\n\x15\x43ONFLICT_WINNER_EQUAL\x10\x01\x12\x19\n\x15\x43ONFLICT_WINNER_FIRST\x10\x02\x12\x1a\n\x16\x43ONFLICT_WINNER_SECOND\x10\x03*4\n\x10\x43onflictZoneType\x12 \n\x1c\x43ONFLICT_ZONE_TYPE_UNDEFINED\x10\x00*\xbb\x01\n\x16\x43onflictPriorityPreset\x12!\n\x1d\x43ONFLICT_PRIORITY_PRESET_NONE\x10\x00\x12*\n&CONFLICT_PRIORITY_PRESET_PRIORITY_ROAD\x10\x01\x12\'\n#CONFLICT_PRIORITY_PRESET_RIGHT_HAND\x10\x02\x12)\n%CONFLICT_PRIORITY_PRESET_ALL_WAY_STOP\x10\x03\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
 
 _globals = globals()
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
@@ -33,14 +33,22 @@ _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'conflict_zones_pb2', _globa
 if not _descriptor._USE_C_DESCRIPTORS:
   _globals['DESCRIPTOR']._loaded_options = None
   _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
-  _globals['_CONFLICTWINNERTYPE']._serialized_start=459
-  _globals['_CONFLICTWINNERTYPE']._serialized_end=592
-  _globals['_CONFLICTZONETYPE']._serialized_start=594
-  _globals['_CONFLICTZONETYPE']._serialized_end=646
-  _globals['_CONFLICTZONE']._serialized_start=56
-  _globals['_CONFLICTZONE']._serialized_end=278
-  _globals['_SESSIONCONFLICTZONES']._serialized_start=280
-  _globals['_SESSIONCONFLICTZONES']._serialized_end=396
-  _globals['_SESSIONCONFLICTZONESRESPONSE']._serialized_start=398
-  _globals['_SESSIONCONFLICTZONESRESPONSE']._serialized_end=456
+  _globals['_CONFLICTWINNERTYPE']._serialized_start=976
+  _globals['_CONFLICTWINNERTYPE']._serialized_end=1109
+  _globals['_CONFLICTZONETYPE']._serialized_start=1111
+  _globals['_CONFLICTZONETYPE']._serialized_end=1163
+  _globals['_CONFLICTPRIORITYPRESET']._serialized_start=1166
+  _globals['_CONFLICTPRIORITYPRESET']._serialized_end=1353
+  _globals['_CONFLICTPRIORITY']._serialized_start=55
+  _globals['_CONFLICTPRIORITY']._serialized_end=156
+  _globals['_CONFLICTZONE']._serialized_start=159
+  _globals['_CONFLICTZONE']._serialized_end=381
+  _globals['_SESSIONCONFLICTZONES']._serialized_start=384
+  _globals['_SESSIONCONFLICTZONES']._serialized_end=555
+  _globals['_SESSIONCONFLICTZONESRESPONSE']._serialized_start=557
+  _globals['_SESSIONCONFLICTZONESRESPONSE']._serialized_end=615
+  _globals['_GENERATECONFLICTZONESREQUEST']._serialized_start=618
+  _globals['_GENERATECONFLICTZONESREQUEST']._serialized_end=848
+  _globals['_GENERATECONFLICTZONESRESPONSE']._serialized_start=850
+  _globals['_GENERATECONFLICTZONESRESPONSE']._serialized_end=973
 # @@protoc_insertion_point(module_scope)
@@ -18,11 +18,30 @@ class ConflictWinnerType(int, metaclass=_enum_type_wrapper.EnumTypeWrapper):
 class ConflictZoneType(int, metaclass=_enum_type_wrapper.EnumTypeWrapper):
     __slots__ = ()
     CONFLICT_ZONE_TYPE_UNDEFINED: _ClassVar[ConflictZoneType]
+
+class ConflictPriorityPreset(int, metaclass=_enum_type_wrapper.EnumTypeWrapper):
+    __slots__ = ()
+    CONFLICT_PRIORITY_PRESET_NONE: _ClassVar[ConflictPriorityPreset]
+    CONFLICT_PRIORITY_PRESET_PRIORITY_ROAD: _ClassVar[ConflictPriorityPreset]
+    CONFLICT_PRIORITY_PRESET_RIGHT_HAND: _ClassVar[ConflictPriorityPreset]
+    CONFLICT_PRIORITY_PRESET_ALL_WAY_STOP: _ClassVar[ConflictPriorityPreset]
 CONFLICT_WINNER_UNDEFINED: ConflictWinnerType
 CONFLICT_WINNER_EQUAL: ConflictWinnerType
 CONFLICT_WINNER_FIRST: ConflictWinnerType
 CONFLICT_WINNER_SECOND: ConflictWinnerType
 CONFLICT_ZONE_TYPE_UNDEFINED: ConflictZoneType
+CONFLICT_PRIORITY_PRESET_NONE: ConflictPriorityPreset
+CONFLICT_PRIORITY_PRESET_PRIORITY_ROAD: ConflictPriorityPreset
+CONFLICT_PRIORITY_PRESET_RIGHT_HAND: ConflictPriorityPreset
+CONFLICT_PRIORITY_PRESET_ALL_WAY_STOP: ConflictPriorityPreset
+
+class ConflictPriority(_message.Message):
+    __slots__ = ("preset", "priority_cells")
+    PRESET_FIELD_NUMBER: _ClassVar[int]
+    PRIORITY_CELLS_FIELD_NUMBER: _ClassVar[int]
+    preset: ConflictPriorityPreset
+    priority_cells: _containers.RepeatedScalarFieldContainer[int]
+    def __init__(self, preset: _Optional[_Union[ConflictPriorityPreset, str]] = ..., priority_cells: _Optional[_Iterable[int]] = ...) -> None: ...
 
 class ConflictZone(_message.Message):
     __slots__ = ("id", "source_x", "source_y", "target_x", "target_y", "conflict_winner", "conflict_type")
@@ -43,12 +62,14 @@ class ConflictZone(_message.Message):
     def __init__(self, id: _Optional[int] = ..., source_x: _Optional[int] = ..., source_y: _Optional[int] = ..., target_x: _Optional[int] = ..., target_y: _Optional[int] = ..., conflict_winner: _Optional[_Union[ConflictWinnerType, str]] = ..., conflict_type: _Optional[_Union[ConflictZoneType, str]] = ...) -> None: ...
 
 class SessionConflictZones(_message.Message):
-    __slots__ = ("session_id", "data")
+    __slots__ = ("session_id", "data", "priority")
     SESSION_ID_FIELD_NUMBER: _ClassVar[int]
     DATA_FIELD_NUMBER: _ClassVar[int]
+    PRIORITY_FIELD_NUMBER: _ClassVar[int]
     session_id: _uuid_pb2.UUIDv4
     data: _containers.RepeatedCompositeFieldContainer[ConflictZone]
-    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., data: _Optional[_Iterable[_Union[ConflictZone, _Mapping]]] = ...) -> None: ...
+    priority: ConflictPriority
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., data: _Optional[_Iterable[_Union[ConflictZone, _Mapping]]] = ..., priority: _Optional[_Union[ConflictPriority, _Mapping]] = ...) -> None: ...
 
 class SessionConflictZonesResponse(_message.Message):
     __slots__ = ("code", "text")
@@ -57,3 +78,29 @@ class SessionConflictZonesResponse(_message.Message):
     code: int
     text: str
     def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ...) -> None: ...
+
+class GenerateConflictZonesRequest(_message.Message):
+    __slots__ = ("session_id", "default_winner", "dry_run", "first_id", "priority")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    DEFAULT_WINNER_FIELD_NUMBER: _ClassVar[int]
+    DRY_RUN_FIELD_NUMBER: _ClassVar[int]
+    FIRST_ID_FIELD_NUMBER: _ClassVar[int]
+    PRIORITY_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    default_winner: ConflictWinnerType
+    dry_run: bool
+    first_id: int
+    priority: ConflictPriority
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., default_winner: _Optional[_Union[ConflictWinnerType, str]] = ..., dry_run: bool = ..., first_id: _Optional[int] = ..., priority: _Optional[_Union[ConflictPriority, _Mapping]] = ...) -> None: ...
+
+class GenerateConflictZonesResponse(_message.Message):
+    __slots__ = ("code", "text", "data", "skipped")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    DATA_FIELD_NUMBER: _ClassVar[int]
+    SKIPPED_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    data: _containers.RepeatedCompositeFieldContainer[ConflictZone]
+    skipped: int
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., data: _Optional[_Iterable[_Union[ConflictZone, _Mapping]]] = ..., skipped: _Optional[int] = ...) -> None: ...
@@ -0,0 +1,46 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# NO CHECKED-IN PROTOBUF GENCODE
+# source: incident.proto
+# Protobuf Python Version: 6.31.1
+"""Generated protocol buffer code."""
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import runtime_version as _runtime_version
+from google.protobuf import symbol_database as _symbol_database
+from google.protobuf.internal import builder as _builder
+_runtime_version.ValidateProtobufRuntimeVersion(
+    _runtime_version.Domain.PUBLIC,
+    6,
+    31,
+    1,
+    '',
+    'incident.proto'
+)
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import uuid_pb2 as uuid__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0eincident.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"\xc5\x01\n\x15InjectIncidentRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12-\n\x04type\x18\x02 \x01(\x0e\x32\x1f.micro_traffic_sim.IncidentType\x12\r\n\x05\x63\x65lls\x18\x03 \x03(\x03\x12\x13\n\x0bspeed_limit\x18\x04 \x01(\x05\x12\x12\n\nstart_tick\x18\x05 \x01(\x04\x12\x16\n\x0e\x64uration_ticks\x18\x06 \x01(\x04\"I\n\x16InjectIncidentResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x13\n\x0bincident_id\x18\x03 \x01(\x03\"Z\n\x14\x43learIncidentRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x13\n\x0bincident_id\x18\x02 \x01(\x03\"3\n\x15\x43learIncidentResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t*c\n\x0cIncidentType\x12\x1b\n\x17INCIDENT_TYPE_UNDEFINED\x10\x00\x12\x17\n\x13INCIDENT_TYPE_BLOCK\x10\x01\x12\x1d\n\x19INCIDENT_TYPE_SPEED_LIMIT\x10\x02\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+
+_globals = globals()
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'incident_pb2', _globals)
+if not _descriptor._USE_C_DESCRIPTORS:
+  _globals['DESCRIPTOR']._loaded_options = None
+  _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
+  _globals['_INCIDENTTYPE']._serialized_start=469
+  _globals['_INCIDENTTYPE']._serialized_end=568
+  _globals['_INJECTINCIDENTREQUEST']._serialized_start=50
+  _globals['_INJECTINCIDENTREQUEST']._serialized_end=247
+  _globals['_INJECTINCIDENTRESPONSE']._serialized_start=249
+  _globals['_INJECTINCIDENTRESPONSE']._serialized_end=322
+  _globals['_CLEARINCIDENTREQUEST']._serialized_start=324
+  _globals['_CLEARINCIDENTREQUEST']._serialized_end=414
+  _globals['_CLEARINCIDENTRESPONSE']._serialized_start=416
+  _globals['_CLEARINCIDENTRESPONSE']._serialized_end=467
+# @@protoc_insertion_point(module_scope)
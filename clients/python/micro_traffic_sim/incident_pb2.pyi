@@ -0,0 +1,60 @@
+import uuid_pb2 as _uuid_pb2
+from google.protobuf.internal import containers as _containers
+from google.protobuf.internal import enum_type_wrapper as _enum_type_wrapper
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import message as _message
+from collections.abc import Iterable as _Iterable, Mapping as _Mapping
+from typing import ClassVar as _ClassVar, Optional as _Optional, Union as _Union
+
+DESCRIPTOR: _descriptor.FileDescriptor
+
+class IncidentType(int, metaclass=_enum_type_wrapper.EnumTypeWrapper):
+    __slots__ = ()
+    INCIDENT_TYPE_UNDEFINED: _ClassVar[IncidentType]
+    INCIDENT_TYPE_BLOCK: _ClassVar[IncidentType]
+    INCIDENT_TYPE_SPEED_LIMIT: _ClassVar[IncidentType]
+INCIDENT_TYPE_UNDEFINED: IncidentType
+INCIDENT_TYPE_BLOCK: IncidentType
+INCIDENT_TYPE_SPEED_LIMIT: IncidentType
+
+class InjectIncidentRequest(_message.Message):
+    __slots__ = ("session_id", "type", "cells", "speed_limit", "start_tick", "duration_ticks")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    TYPE_FIELD_NUMBER: _ClassVar[int]
+    CELLS_FIELD_NUMBER: _ClassVar[int]
+    SPEED_LIMIT_FIELD_NUMBER: _ClassVar[int]
+    START_TICK_FIELD_NUMBER: _ClassVar[int]
+    DURATION_TICKS_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    type: IncidentType
+    cells: _containers.RepeatedScalarFieldContainer[int]
+    speed_limit: int
+    start_tick: int
+    duration_ticks: int
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., type: _Optional[_Union[IncidentType, str]] = ..., cells: _Optional[_Iterable[int]] = ..., speed_limit: _Optional[int] = ..., start_tick: _Optional[int] = ..., duration_ticks: _Optional[int] = ...) -> None: ...
+
+class InjectIncidentResponse(_message.Message):
+    __slots__ = ("code", "text", "incident_id")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    INCIDENT_ID_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    incident_id: int
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., incident_id: _Optional[int] = ...) -> None: ...
+
+class ClearIncidentRequest(_message.Message):
+    __slots__ = ("session_id", "incident_id")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    INCIDENT_ID_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    incident_id: int
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., incident_id: _Optional[int] = ...) -> None: ...
+
+class ClearIncidentResponse(_message.Message):
+    __slots__ = ("code", "text")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ...) -> None: ...
@@ -0,0 +1,43 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# NO CHECKED-IN PROTOBUF GENCODE
+# source: limits.proto
+# Protobuf Python Version: 6.31.1
+"""Generated protocol buffer code."""
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import runtime_version as _runtime_version
+from google.protobuf import symbol_database as _symbol_database
+from google.protobuf.internal import builder as _builder
+_runtime_version.ValidateProtobufRuntimeVersion(
+    _runtime_version.Domain.PUBLIC,
+    6,
+    31,
+    1,
+    '',
+    'limits.proto'
+)
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0climits.proto\x12\x11micro_traffic_sim\"\xa2\x01\n\x0eResourceLimits\x12\x14\n\x0cmax_sessions\x18\x01 \x01(\x04\x12\x1d\n\x15max_cells_per_session\x18\x02 \x01(\x04\x12\x1d\n\x15max_trips_per_session\x18\x03 \x01(\x04\x12!\n\x19max_concurrent_recordings\x18\x04 \x01(\x04\x12\x19\n\x11max_horizon_ticks\x18\x05 \x01(\x04\"=\n\rResourceUsage\x12\x10\n\x08sessions\x18\x01 \x01(\x04\x12\x1a\n\x12running_recordings\x18\x02 \x01(\x04\"\x18\n\x16GetServerLimitsRequest\"\xfb\x02\n\x17GetServerLimitsResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x31\n\x06server\x18\x03 \x01(\x0b\x32!.micro_traffic_sim.ResourceLimits\x12\x36\n\x0cserver_usage\x18\x04 \x01(\x0b\x32 .micro_traffic_sim.ResourceUsage\x12\x0e\n\x06tenant\x18\x05 \x01(\t\x12\x38\n\rtenant_limits\x18\x06 \x01(\x0b\x32!.micro_traffic_sim.ResourceLimits\x12\x36\n\x0ctenant_usage\x18\x07 \x01(\x0b\x32 .micro_traffic_sim.ResourceUsage\x12 \n\x18max_entities_per_message\x18\x08 \x01(\x04\x12\x18\n\x10horizon_hard_cap\x18\t \x01(\x04\x12\x1b\n\x13\x64\x65\x66\x61ult_batch_ticks\x18\n \x01(\rB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+
+_globals = globals()
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'limits_pb2', _globals)
+if not _descriptor._USE_C_DESCRIPTORS:
+  _globals['DESCRIPTOR']._loaded_options = None
+  _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
+  _globals['_RESOURCELIMITS']._serialized_start=36
+  _globals['_RESOURCELIMITS']._serialized_end=198
+  _globals['_RESOURCEUSAGE']._serialized_start=200
+  _globals['_RESOURCEUSAGE']._serialized_end=261
+  _globals['_GETSERVERLIMITSREQUEST']._serialized_start=263
+  _globals['_GETSERVERLIMITSREQUEST']._serialized_end=287
+  _globals['_GETSERVERLIMITSRESPONSE']._serialized_start=290
+  _globals['_GETSERVERLIMITSRESPONSE']._serialized_end=669
+# @@protoc_insertion_point(module_scope)
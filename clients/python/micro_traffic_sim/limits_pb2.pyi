@@ -0,0 +1,56 @@
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import message as _message
+from collections.abc import Mapping as _Mapping
+from typing import ClassVar as _ClassVar, Optional as _Optional, Union as _Union
+
+DESCRIPTOR: _descriptor.FileDescriptor
+
+class ResourceLimits(_message.Message):
+    __slots__ = ("max_sessions", "max_cells_per_session", "max_trips_per_session", "max_concurrent_recordings", "max_horizon_ticks")
+    MAX_SESSIONS_FIELD_NUMBER: _ClassVar[int]
+    MAX_CELLS_PER_SESSION_FIELD_NUMBER: _ClassVar[int]
+    MAX_TRIPS_PER_SESSION_FIELD_NUMBER: _ClassVar[int]
+    MAX_CONCURRENT_RECORDINGS_FIELD_NUMBER: _ClassVar[int]
+    MAX_HORIZON_TICKS_FIELD_NUMBER: _ClassVar[int]
+    max_sessions: int
+    max_cells_per_session: int
+    max_trips_per_session: int
+    max_concurrent_recordings: int
+    max_horizon_ticks: int
+    def __init__(self, max_sessions: _Optional[int] = ..., max_cells_per_session: _Optional[int] = ..., max_trips_per_session: _Optional[int] = ..., max_concurrent_recordings: _Optional[int] = ..., max_horizon_ticks: _Optional[int] = ...) -> None: ...
+
+class ResourceUsage(_message.Message):
+    __slots__ = ("sessions", "running_recordings")
+    SESSIONS_FIELD_NUMBER: _ClassVar[int]
+    RUNNING_RECORDINGS_FIELD_NUMBER: _ClassVar[int]
+    sessions: int
+    running_recordings: int
+    def __init__(self, sessions: _Optional[int] = ..., running_recordings: _Optional[int] = ...) -> None: ...
+
+class GetServerLimitsRequest(_message.Message):
+    __slots__ = ()
+    def __init__(self) -> None: ...
+
+class GetServerLimitsResponse(_message.Message):
+    __slots__ = ("code", "text", "server", "server_usage", "tenant", "tenant_limits", "tenant_usage", "max_entities_per_message", "horizon_hard_cap", "default_batch_ticks")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    SERVER_FIELD_NUMBER: _ClassVar[int]
+    SERVER_USAGE_FIELD_NUMBER: _ClassVar[int]
+    TENANT_FIELD_NUMBER: _ClassVar[int]
+    TENANT_LIMITS_FIELD_NUMBER: _ClassVar[int]
+    TENANT_USAGE_FIELD_NUMBER: _ClassVar[int]
+    MAX_ENTITIES_PER_MESSAGE_FIELD_NUMBER: _ClassVar[int]
+    HORIZON_HARD_CAP_FIELD_NUMBER: _ClassVar[int]
+    DEFAULT_BATCH_TICKS_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    server: ResourceLimits
+    server_usage: ResourceUsage
+    tenant: str
+    tenant_limits: ResourceLimits
+    tenant_usage: ResourceUsage
+    max_entities_per_message: int
+    horizon_hard_cap: int
+    default_batch_ticks: int
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., server: _Optional[_Union[ResourceLimits, _Mapping]] = ..., server_usage: _Optional[_Union[ResourceUsage, _Mapping]] = ..., tenant: _Optional[str] = ..., tenant_limits: _Optional[_Union[ResourceLimits, _Mapping]] = ..., tenant_usage: _Optional[_Union[ResourceUsage, _Mapping]] = ..., max_entities_per_message: _Optional[int] = ..., horizon_hard_cap: _Optional[int] = ..., default_batch_ticks: _Optional[int] = ...) -> None: ...
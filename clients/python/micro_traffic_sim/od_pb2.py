@@ -0,0 +1,52 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# NO CHECKED-IN PROTOBUF GENCODE
+# source: od.proto
+# Protobuf Python Version: 6.31.1
+"""Generated protocol buffer code."""
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import runtime_version as _runtime_version
+from google.protobuf import symbol_database as _symbol_database
+from google.protobuf.internal import builder as _builder
+_runtime_version.ValidateProtobufRuntimeVersion(
+    _runtime_version.Domain.PUBLIC,
+    6,
+    31,
+    1,
+    '',
+    'od.proto'
+)
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import uuid_pb2 as uuid__pb2
+from . import step_pb2 as step__pb2
+from . import trip_pb2 as trip__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x08od.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\x1a\nstep.proto\x1a\ntrip.proto\">\n\x06OdZone\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x13\n\x0b\x62irth_cells\x18\x02 \x03(\x03\x12\x13\n\x0b\x64\x65\x61th_cells\x18\x03 \x03(\x03\"R\n\x06OdFlow\x12\x13\n\x0borigin_zone\x18\x01 \x01(\x03\x12\x18\n\x10\x64\x65stination_zone\x18\x02 \x01(\x03\x12\x19\n\x11vehicles_per_hour\x18\x03 \x01(\x01\"]\n\x0bOdTimeSlice\x12\x12\n\nstart_time\x18\x01 \x01(\x03\x12\x10\n\x08\x65nd_time\x18\x02 \x01(\x03\x12(\n\x05\x66lows\x18\x03 \x03(\x0b\x32\x19.micro_traffic_sim.OdFlow\"\x89\x01\n\x0cOdAgentShare\x12\x30\n\nagent_type\x18\x01 \x01(\x0e\x32\x1c.micro_traffic_sim.AgentType\x12\x38\n\x0e\x62\x65haviour_type\x18\x02 \x01(\x0e\x32 .micro_traffic_sim.BehaviourType\x12\r\n\x05share\x18\x03 \x01(\x01\"\xdf\x01\n\x08OdMatrix\x12(\n\x05zones\x18\x01 \x03(\x0b\x32\x19.micro_traffic_sim.OdZone\x12.\n\x06slices\x18\x02 \x03(\x0b\x32\x1e.micro_traffic_sim.OdTimeSlice\x12\x32\n\tagent_mix\x18\x03 \x03(\x0b\x32\x1f.micro_traffic_sim.OdAgentShare\x12.\n\ttrip_type\x18\x04 \x01(\x0e\x32\x1b.micro_traffic_sim.TripType\x12\x15\n\rinitial_speed\x18\x05 \x01(\x03\"\xab\x01\n\x1aPushSessionOdMatrixRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12+\n\x06matrix\x18\x02 \x01(\x0b\x32\x1b.micro_traffic_sim.OdMatrix\x12\x15\n\rfirst_trip_id\x18\x03 \x01(\x03\x12\x1a\n\x12reject_unreachable\x18\x04 \x01(\x08\"\xa0\x01\n\x1bPushSessionOdMatrixResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x15\n\rfirst_trip_id\x18\x03 \x01(\x03\x12\x13\n\x0btrips_count\x18\x04 \x01(\r\x12\x39\n\x0creachability\x18\x05 \x03(\x0b\x32#.micro_traffic_sim.TripReachabilityB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+
+_globals = globals()
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'od_pb2', _globals)
+if not _descriptor._USE_C_DESCRIPTORS:
+  _globals['DESCRIPTOR']._loaded_options = None
+  _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
+  _globals['_ODZONE']._serialized_start=67
+  _globals['_ODZONE']._serialized_end=129
+  _globals['_ODFLOW']._serialized_start=131
+  _globals['_ODFLOW']._serialized_end=213
+  _globals['_ODTIMESLICE']._serialized_start=215
+  _globals['_ODTIMESLICE']._serialized_end=308
+  _globals['_ODAGENTSHARE']._serialized_start=311
+  _globals['_ODAGENTSHARE']._serialized_end=448
+  _globals['_ODMATRIX']._serialized_start=451
+  _globals['_ODMATRIX']._serialized_end=674
+  _globals['_PUSHSESSIONODMATRIXREQUEST']._serialized_start=677
+  _globals['_PUSHSESSIONODMATRIXREQUEST']._serialized_end=848
+  _globals['_PUSHSESSIONODMATRIXRESPONSE']._serialized_start=851
+  _globals['_PUSHSESSIONODMATRIXRESPONSE']._serialized_end=1011
+# @@protoc_insertion_point(module_scope)
@@ -0,0 +1,90 @@
+import uuid_pb2 as _uuid_pb2
+import step_pb2 as _step_pb2
+import trip_pb2 as _trip_pb2
+from google.protobuf.internal import containers as _containers
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import message as _message
+from collections.abc import Iterable as _Iterable, Mapping as _Mapping
+from typing import ClassVar as _ClassVar, Optional as _Optional, Union as _Union
+
+DESCRIPTOR: _descriptor.FileDescriptor
+
+class OdZone(_message.Message):
+    __slots__ = ("id", "birth_cells", "death_cells")
+    ID_FIELD_NUMBER: _ClassVar[int]
+    BIRTH_CELLS_FIELD_NUMBER: _ClassVar[int]
+    DEATH_CELLS_FIELD_NUMBER: _ClassVar[int]
+    id: int
+    birth_cells: _containers.RepeatedScalarFieldContainer[int]
+    death_cells: _containers.RepeatedScalarFieldContainer[int]
+    def __init__(self, id: _Optional[int] = ..., birth_cells: _Optional[_Iterable[int]] = ..., death_cells: _Optional[_Iterable[int]] = ...) -> None: ...
+
+class OdFlow(_message.Message):
+    __slots__ = ("origin_zone", "destination_zone", "vehicles_per_hour")
+    ORIGIN_ZONE_FIELD_NUMBER: _ClassVar[int]
+    DESTINATION_ZONE_FIELD_NUMBER: _ClassVar[int]
+    VEHICLES_PER_HOUR_FIELD_NUMBER: _ClassVar[int]
+    origin_zone: int
+    destination_zone: int
+    vehicles_per_hour: float
+    def __init__(self, origin_zone: _Optional[int] = ..., destination_zone: _Optional[int] = ..., vehicles_per_hour: _Optional[float] = ...) -> None: ...
+
+class OdTimeSlice(_message.Message):
+    __slots__ = ("start_time", "end_time", "flows")
+    START_TIME_FIELD_NUMBER: _ClassVar[int]
+    END_TIME_FIELD_NUMBER: _ClassVar[int]
+    FLOWS_FIELD_NUMBER: _ClassVar[int]
+    start_time: int
+    end_time: int
+    flows: _containers.RepeatedCompositeFieldContainer[OdFlow]
+    def __init__(self, start_time: _Optional[int] = ..., end_time: _Optional[int] = ..., flows: _Optional[_Iterable[_Union[OdFlow, _Mapping]]] = ...) -> None: ...
+
+class OdAgentShare(_message.Message):
+    __slots__ = ("agent_type", "behaviour_type", "share")
+    AGENT_TYPE_FIELD_NUMBER: _ClassVar[int]
+    BEHAVIOUR_TYPE_FIELD_NUMBER: _ClassVar[int]
+    SHARE_FIELD_NUMBER: _ClassVar[int]
+    agent_type: _step_pb2.AgentType
+    behaviour_type: _trip_pb2.BehaviourType
+    share: float
+    def __init__(self, agent_type: _Optional[_Union[_step_pb2.AgentType, str]] = ..., behaviour_type: _Optional[_Union[_trip_pb2.BehaviourType, str]] = ..., share: _Optional[float] = ...) -> None: ...
+
+class OdMatrix(_message.Message):
+    __slots__ = ("zones", "slices", "agent_mix", "trip_type", "initial_speed")
+    ZONES_FIELD_NUMBER: _ClassVar[int]
+    SLICES_FIELD_NUMBER: _ClassVar[int]
+    AGENT_MIX_FIELD_NUMBER: _ClassVar[int]
+    TRIP_TYPE_FIELD_NUMBER: _ClassVar[int]
+    INITIAL_SPEED_FIELD_NUMBER: _ClassVar[int]
+    zones: _containers.RepeatedCompositeFieldContainer[OdZone]
+    slices: _containers.RepeatedCompositeFieldContainer[OdTimeSlice]
+    agent_mix: _containers.RepeatedCompositeFieldContainer[OdAgentShare]
+    trip_type: _trip_pb2.TripType
+    initial_speed: int
+    def __init__(self, zones: _Optional[_Iterable[_Union[OdZone, _Mapping]]] = ..., slices: _Optional[_Iterable[_Union[OdTimeSlice, _Mapping]]] = ..., agent_mix: _Optional[_Iterable[_Union[OdAgentShare, _Mapping]]] = ..., trip_type: _Optional[_Union[_trip_pb2.TripType, str]] = ..., initial_speed: _Optional[int] = ...) -> None: ...
+
+class PushSessionOdMatrixRequest(_message.Message):
+    __slots__ = ("session_id", "matrix", "first_trip_id", "reject_unreachable")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    MATRIX_FIELD_NUMBER: _ClassVar[int]
+    FIRST_TRIP_ID_FIELD_NUMBER: _ClassVar[int]
+    REJECT_UNREACHABLE_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    matrix: OdMatrix
+    first_trip_id: int
+    reject_unreachable: bool
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., matrix: _Optional[_Union[OdMatrix, _Mapping]] = ..., first_trip_id: _Optional[int] = ..., reject_unreachable: bool = ...) -> None: ...
+
+class PushSessionOdMatrixResponse(_message.Message):
+    __slots__ = ("code", "text", "first_trip_id", "trips_count", "reachability")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    FIRST_TRIP_ID_FIELD_NUMBER: _ClassVar[int]
+    TRIPS_COUNT_FIELD_NUMBER: _ClassVar[int]
+    REACHABILITY_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    first_trip_id: int
+    trips_count: int
+    reachability: _containers.RepeatedCompositeFieldContainer[_trip_pb2.TripReachability]
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., first_trip_id: _Optional[int] = ..., trips_count: _Optional[int] = ..., reachability: _Optional[_Iterable[_Union[_trip_pb2.TripReachability, _Mapping]]] = ...) -> None: ...
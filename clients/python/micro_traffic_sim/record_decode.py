@@ -1,4 +1,4 @@
-"""Decoder for RecordBatch columns blobs (record format version 1).
+"""Decoder for RecordBatch columns blobs (record format versions 1 and 2).
 
 This mirrors the binary layout produced by the Rust server in
 ``src/server/record.rs`` (``BatchAcc::to_blob``) and documented in
@@ -7,9 +7,9 @@ This mirrors the binary layout produced by the Rust server in
 ``SessionStepResponse`` messages the live step RPC returns, so replaying a
 recording yields the same types as live stepping.
 
-Blob layout (all little-endian), version 1:
+Blob layout (all little-endian):
 
-    u8   version            (must equal 1)
+    u8   version            (1, or 2 with the vehicle events section)
     u32  tick_start
     u32  tick_count  (K)
     u32  total_rows  (R)
@@ -27,6 +27,16 @@ Blob layout (all little-endian), version 1:
     u32  tl_group_count (G)
     { u32 tl_id; u32 group_id } * G
     u8   signal[K*G]         (tick-major)
+
+Version 2 appends the vehicle events section (``RunMetadata.events_schema``),
+E being the total number of events; -1 ids are stored as 0xFFFFFFFF:
+
+    u32  events_per_tick[K]
+    u32  event_vehicle_id[E]
+    u8   event_type[E]       (VehicleEventType)
+    u32  event_trip_id[E]
+    u32  event_cell[E]
+    u32  event_from_cell[E]
 """
 
 from __future__ import annotations
@@ -38,6 +48,8 @@ from .step_pb2 import (
     SessionStepResponse,
     TLGroup,
     TLSState,
+    VehicleEvent,
+    VehicleEventType,
     VehicleState,
 )
 
@@ -56,6 +68,11 @@ _SIGNAL_STR = {
 }
 
 
+def _id_or_none(v: int) -> int:
+    """Map a stored u32 id back to an int, restoring the -1 sentinel."""
+    return -1 if v == 0xFFFFFFFF else v
+
+
 def decode_record_batch(columns: bytes) -> list[SessionStepResponse]:
     """Decode a RecordBatch columns blob into a per-tick list of responses.
 
@@ -63,8 +80,10 @@ def decode_record_batch(columns: bytes) -> list[SessionStepResponse]:
     each populated exactly like the live step RPC: ``timestamp`` is the
     absolute tick, ``vehicle_data`` holds ``VehicleState`` messages and
     ``tls_data`` holds ``TLSState`` messages (grouped by traffic-light id).
+    For version 2 blobs ``events`` holds the tick's ``VehicleEvent`` messages
+    (empty otherwise).
 
-    Raises ``ValueError`` if the version byte is not 1 or if the blob is
+    Raises ``ValueError`` if the version byte is not 1 or 2 or if the blob is
     truncated. Empty batches (no ticks, no vehicles and/or no traffic
     lights) decode without error.
     """
@@ -84,7 +103,7 @@ def decode_record_batch(columns: bytes) -> list[SessionStepResponse]:
     except struct.error as exc:
         raise ValueError("truncated record batch blob") from exc
     o += 1
-    if version != 1:
+    if version not in (1, 2):
         raise ValueError(f"unsupported record version: {version}")
 
     tick_start = rd("<I", 4)
@@ -115,12 +134,38 @@ def decode_record_batch(columns: bytes) -> list[SessionStepResponse]:
     tl_signals = columns[o:o + tick_count * g_count]
     o += tick_count * g_count
 
+    # Vehicle events section (version 2 only).
+    events_per_tick = [0] * tick_count
+    events: list[VehicleEvent] = []
+    if version == 2:
+        events_per_tick = [rd("<I", 4) for _ in range(tick_count)]
+        e = sum(events_per_tick)
+        ev_vehicle_id = [rd("<I", 4) for _ in range(e)]
+        if o + e > len(columns):
+            raise ValueError("truncated record batch blob")
+        ev_type = columns[o:o + e]
+        o += e
+        ev_trip = [rd("<I", 4) for _ in range(e)]
+        ev_cell = [rd("<I", 4) for _ in range(e)]
+        ev_from_cell = [rd("<I", 4) for _ in range(e)]
+        events = [
+            VehicleEvent(
+                vehicle_id=ev_vehicle_id[i],
+                event_type=VehicleEventType.ValueType(ev_type[i]),
+                trip_id=_id_or_none(ev_trip[i]),
+                cell=_id_or_none(ev_cell[i]),
+                from_cell=_id_or_none(ev_from_cell[i]),
+            )
+            for i in range(e)
+        ]
+
     def slice_vals(off: list[int], vals: list[int], idx: int) -> list[int]:
         start = off[idx - 1] if idx > 0 else 0
         return list(vals[start:off[idx]])
 
     responses: list[SessionStepResponse] = []
     row = 0
+    event = 0
     for t in range(tick_count):
         resp = SessionStepResponse(
             code=0,
@@ -152,6 +197,9 @@ def decode_record_batch(columns: bytes) -> list[SessionStepResponse]:
                 current = resp.tls_data.add(id=tl_id)
             current.groups.append(TLGroup(id=group_id, signal=signal))
 
+        resp.events.extend(events[event:event + events_per_tick[t]])
+        event += events_per_tick[t]
+
         responses.append(resp)
 
     return responses
@@ -23,9 +23,11 @@ _sym_db = _symbol_database.Default()
 
 
 from . import uuid_pb2 as uuid__pb2
+from . import stats_pb2 as stats__pb2
+from . import speed_limit_pb2 as speed__limit__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0crecord.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"\xa1\x01\n\x13RunAndRecordRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x15\n\rhorizon_ticks\x18\x02 \x01(\x04\x12\x13\n\x0b\x62\x61tch_ticks\x18\x03 \x01(\r\x12/\n\x06\x66ilter\x18\x04 \x01(\x0b\x32\x1f.micro_traffic_sim.RecordFilter\"<\n\x0cRecordFilter\x12\x15\n\rsample_period\x18\x01 \x01(\r\x12\x15\n\rmeso_link_ids\x18\x02 \x03(\x03\"\xb8\x01\n\x14RunAndRecordResponse\x12\x32\n\x08metadata\x18\x01 \x01(\x0b\x32\x1e.micro_traffic_sim.RunMetadataH\x00\x12/\n\x05\x62\x61tch\x18\x02 \x01(\x0b\x32\x1e.micro_traffic_sim.RecordBatchH\x00\x12\x30\n\x07summary\x18\x03 \x01(\x0b\x32\x1d.micro_traffic_sim.RunSummaryH\x00\x42\t\n\x07payload\"\x8e\x02\n\x0bRunMetadata\x12\x16\n\x0e\x66ormat_version\x18\x01 \x01(\r\x12\x14\n\x0ctick_seconds\x18\x02 \x01(\x01\x12\x12\n\nspawn_seed\x18\x03 \x01(\x04\x12\x17\n\x0fstochastic_seed\x18\x04 \x01(\x04\x12\x14\n\x0c\x63ore_version\x18\x05 \x01(\t\x12\x14\n\x0crand_version\x18\x06 \x01(\t\x12\x13\n\x0b\x63onfig_hash\x18\x07 \x01(\t\x12/\n\x06schema\x18\x08 \x01(\x0b\x32\x1f.micro_traffic_sim.ColumnSchema\x12\x32\n\ttl_schema\x18\t \x01(\x0b\x32\x1f.micro_traffic_sim.ColumnSchema\"=\n\x0c\x43olumnSchema\x12-\n\x07\x63olumns\x18\x01 \x03(\x0b\x32\x1c.micro_traffic_sim.ColumnDef\"\'\n\tColumnDef\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04type\x18\x02 \x01(\t\"Z\n\x0bRecordBatch\x12\x12\n\ntick_start\x18\x01 \x01(\x04\x12\x12\n\ntick_count\x18\x02 \x01(\r\x12\x12\n\ntotal_rows\x18\x03 \x01(\r\x12\x0f\n\x07\x63olumns\x18\x04 \x01(\x0c\"}\n\nRunSummary\x12\x13\n\x0btotal_ticks\x18\x01 \x01(\x04\x12\x12\n\ntotal_rows\x18\x02 \x01(\x04\x12\x13\n\x0btotal_bytes\x18\x03 \x01(\x04\x12\x1a\n\x12vehicles_completed\x18\x04 \x01(\x05\x12\x15\n\rvehicles_lost\x18\x05 \x01(\x05\"G\n\x16RecordingStatusRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\"\x89\x01\n\x17RecordingStatusResponse\x12\x30\n\x05state\x18\x01 \x01(\x0e\x32!.micro_traffic_sim.RecordingState\x12\x14\n\x0c\x63urrent_tick\x18\x02 \x01(\x04\x12\x0c\n\x04rows\x18\x03 \x01(\x04\x12\x18\n\x10\x63\x61ncel_requested\x18\x04 \x01(\x08\"E\n\x14StopRecordingRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\")\n\x15StopRecordingResponse\x12\x10\n\x08\x61\x63\x63\x65pted\x18\x01 \x01(\x08*o\n\x0eRecordingState\x12\x1f\n\x1bRECORDING_STATE_UNSPECIFIED\x10\x00\x12\x1f\n\x1bRECORDING_STATE_NOT_RUNNING\x10\x01\x12\x1b\n\x17RECORDING_STATE_RUNNING\x10\x02\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0crecord.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\x1a\x0bstats.proto\x1a\x11speed_limit.proto\"\xd5\x01\n\x13RunAndRecordRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x15\n\rhorizon_ticks\x18\x02 \x01(\x04\x12\x13\n\x0b\x62\x61tch_ticks\x18\x03 \x01(\r\x12/\n\x06\x66ilter\x18\x04 \x01(\x0b\x32\x1f.micro_traffic_sim.RecordFilter\x12\x16\n\x0einclude_events\x18\x05 \x01(\x08\x12\x1a\n\x12mfd_interval_ticks\x18\x06 \x01(\r\"<\n\x0cRecordFilter\x12\x15\n\rsample_period\x18\x01 \x01(\r\x12\x15\n\rmeso_link_ids\x18\x02 \x03(\x03\"\xb8\x01\n\x14RunAndRecordResponse\x12\x32\n\x08metadata\x18\x01 \x01(\x0b\x32\x1e.micro_traffic_sim.RunMetadataH\x00\x12/\n\x05\x62\x61tch\x18\x02 \x01(\x0b\x32\x1e.micro_traffic_sim.RecordBatchH\x00\x12\x30\n\x07summary\x18\x03 \x01(\x0b\x32\x1d.micro_traffic_sim.RunSummaryH\x00\x42\t\n\x07payload\"\xc6\x02\n\x0bRunMetadata\x12\x16\n\x0e\x66ormat_version\x18\x01 \x01(\r\x12\x14\n\x0ctick_seconds\x18\x02 \x01(\x01\x12\x12\n\nspawn_seed\x18\x03 \x01(\x04\x12\x17\n\x0fstochastic_seed\x18\x04 \x01(\x04\x12\x14\n\x0c\x63ore_version\x18\x05 \x01(\t\x12\x14\n\x0crand_version\x18\x06 \x01(\t\x12\x13\n\x0b\x63onfig_hash\x18\x07 \x01(\t\x12/\n\x06schema\x18\x08 \x01(\x0b\x32\x1f.micro_traffic_sim.ColumnSchema\x12\x32\n\ttl_schema\x18\t \x01(\x0b\x32\x1f.micro_traffic_sim.ColumnSchema\x12\x36\n\revents_schema\x18\n \x01(\x0b\x32\x1f.micro_traffic_sim.ColumnSchema\"=\n\x0c\x43olumnSchema\x12-\n\x07\x63olumns\x18\x01 \x03(\x0b\x32\x1c.micro_traffic_sim.ColumnDef\"\'\n\tColumnDef\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04type\x18\x02 \x01(\t\"\xc7\x01\n\x0bRecordBatch\x12\x12\n\ntick_start\x18\x01 \x01(\x04\x12\x12\n\ntick_count\x18\x02 \x01(\r\x12\x12\n\ntotal_rows\x18\x03 \x01(\r\x12\x0f\n\x07\x63olumns\x18\x04 \x01(\x0c\x12@\n\x13speed_limit_changes\x18\x05 \x03(\x0b\x32#.micro_traffic_sim.SpeedLimitChange\x12)\n\x03mfd\x18\x06 \x03(\x0b\x32\x1c.micro_traffic_sim.MfdSample\"\xa8\x01\n\nRunSummary\x12\x13\n\x0btotal_ticks\x18\x01 \x01(\x04\x12\x12\n\ntotal_rows\x18\x02 \x01(\x04\x12\x13\n\x0btotal_bytes\x18\x03 \x01(\x04\x12\x1a\n\x12vehicles_completed\x18\x04 \x01(\x05\x12\x15\n\rvehicles_lost\x18\x05 \x01(\x05\x12)\n\x03mfd\x18\x06 \x03(\x0b\x32\x1c.micro_traffic_sim.MfdSample\"G\n\x16RecordingStatusRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\"\x89\x01\n\x17RecordingStatusResponse\x12\x30\n\x05state\x18\x01 \x01(\x0e\x32!.micro_traffic_sim.RecordingState\x12\x14\n\x0c\x63urrent_tick\x18\x02 \x01(\x04\x12\x0c\n\x04rows\x18\x03 \x01(\x04\x12\x18\n\x10\x63\x61ncel_requested\x18\x04 \x01(\x08\"E\n\x14StopRecordingRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\")\n\x15StopRecordingResponse\x12\x10\n\x08\x61\x63\x63\x65pted\x18\x01 \x01(\x08*o\n\x0eRecordingState\x12\x1f\n\x1bRECORDING_STATE_UNSPECIFIED\x10\x00\x12\x1f\n\x1bRECORDING_STATE_NOT_RUNNING\x10\x01\x12\x1b\n\x17RECORDING_STATE_RUNNING\x10\x02\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
 
 _globals = globals()
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
@@ -33,30 +35,30 @@ _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'record_pb2', _globals)
 if not _descriptor._USE_C_DESCRIPTORS:
   _globals['DESCRIPTOR']._loaded_options = None
   _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
-  _globals['_RECORDINGSTATE']._serialized_start=1383
-  _globals['_RECORDINGSTATE']._serialized_end=1494
-  _globals['_RUNANDRECORDREQUEST']._serialized_start=48
-  _globals['_RUNANDRECORDREQUEST']._serialized_end=209
-  _globals['_RECORDFILTER']._serialized_start=211
-  _globals['_RECORDFILTER']._serialized_end=271
-  _globals['_RUNANDRECORDRESPONSE']._serialized_start=274
-  _globals['_RUNANDRECORDRESPONSE']._serialized_end=458
-  _globals['_RUNMETADATA']._serialized_start=461
-  _globals['_RUNMETADATA']._serialized_end=731
-  _globals['_COLUMNSCHEMA']._serialized_start=733
-  _globals['_COLUMNSCHEMA']._serialized_end=794
-  _globals['_COLUMNDEF']._serialized_start=796
-  _globals['_COLUMNDEF']._serialized_end=835
-  _globals['_RECORDBATCH']._serialized_start=837
-  _globals['_RECORDBATCH']._serialized_end=927
-  _globals['_RUNSUMMARY']._serialized_start=929
-  _globals['_RUNSUMMARY']._serialized_end=1054
-  _globals['_RECORDINGSTATUSREQUEST']._serialized_start=1056
-  _globals['_RECORDINGSTATUSREQUEST']._serialized_end=1127
-  _globals['_RECORDINGSTATUSRESPONSE']._serialized_start=1130
-  _globals['_RECORDINGSTATUSRESPONSE']._serialized_end=1267
-  _globals['_STOPRECORDINGREQUEST']._serialized_start=1269
-  _globals['_STOPRECORDINGREQUEST']._serialized_end=1338
-  _globals['_STOPRECORDINGRESPONSE']._serialized_start=1340
-  _globals['_STOPRECORDINGRESPONSE']._serialized_end=1381
+  _globals['_RECORDINGSTATE']._serialized_start=1677
+  _globals['_RECORDINGSTATE']._serialized_end=1788
+  _globals['_RUNANDRECORDREQUEST']._serialized_start=80
+  _globals['_RUNANDRECORDREQUEST']._serialized_end=293
+  _globals['_RECORDFILTER']._serialized_start=295
+  _globals['_RECORDFILTER']._serialized_end=355
+  _globals['_RUNANDRECORDRESPONSE']._serialized_start=358
+  _globals['_RUNANDRECORDRESPONSE']._serialized_end=542
+  _globals['_RUNMETADATA']._serialized_start=545
+  _globals['_RUNMETADATA']._serialized_end=871
+  _globals['_COLUMNSCHEMA']._serialized_start=873
+  _globals['_COLUMNSCHEMA']._serialized_end=934
+  _globals['_COLUMNDEF']._serialized_start=936
+  _globals['_COLUMNDEF']._serialized_end=975
+  _globals['_RECORDBATCH']._serialized_start=978
+  _globals['_RECORDBATCH']._serialized_end=1177
+  _globals['_RUNSUMMARY']._serialized_start=1180
+  _globals['_RUNSUMMARY']._serialized_end=1348
+  _globals['_RECORDINGSTATUSREQUEST']._serialized_start=1350
+  _globals['_RECORDINGSTATUSREQUEST']._serialized_end=1421
+  _globals['_RECORDINGSTATUSRESPONSE']._serialized_start=1424
+  _globals['_RECORDINGSTATUSRESPONSE']._serialized_end=1561
+  _globals['_STOPRECORDINGREQUEST']._serialized_start=1563
+  _globals['_STOPRECORDINGREQUEST']._serialized_end=1632
+  _globals['_STOPRECORDINGRESPONSE']._serialized_start=1634
+  _globals['_STOPRECORDINGRESPONSE']._serialized_end=1675
 # @@protoc_insertion_point(module_scope)
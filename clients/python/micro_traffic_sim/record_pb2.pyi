@@ -1,4 +1,6 @@
 import uuid_pb2 as _uuid_pb2
+import stats_pb2 as _stats_pb2
+import speed_limit_pb2 as _speed_limit_pb2
 from google.protobuf.internal import containers as _containers
 from google.protobuf.internal import enum_type_wrapper as _enum_type_wrapper
 from google.protobuf import descriptor as _descriptor
@@ -18,16 +20,20 @@ RECORDING_STATE_NOT_RUNNING: RecordingState
 RECORDING_STATE_RUNNING: RecordingState
 
 class RunAndRecordRequest(_message.Message):
-    __slots__ = ("session_id", "horizon_ticks", "batch_ticks", "filter")
+    __slots__ = ("session_id", "horizon_ticks", "batch_ticks", "filter", "include_events", "mfd_interval_ticks")
     SESSION_ID_FIELD_NUMBER: _ClassVar[int]
     HORIZON_TICKS_FIELD_NUMBER: _ClassVar[int]
     BATCH_TICKS_FIELD_NUMBER: _ClassVar[int]
     FILTER_FIELD_NUMBER: _ClassVar[int]
+    INCLUDE_EVENTS_FIELD_NUMBER: _ClassVar[int]
+    MFD_INTERVAL_TICKS_FIELD_NUMBER: _ClassVar[int]
     session_id: _uuid_pb2.UUIDv4
     horizon_ticks: int
     batch_ticks: int
     filter: RecordFilter
-    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., horizon_ticks: _Optional[int] = ..., batch_ticks: _Optional[int] = ..., filter: _Optional[_Union[RecordFilter, _Mapping]] = ...) -> None: ...
+    include_events: bool
+    mfd_interval_ticks: int
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., horizon_ticks: _Optional[int] = ..., batch_ticks: _Optional[int] = ..., filter: _Optional[_Union[RecordFilter, _Mapping]] = ..., include_events: bool = ..., mfd_interval_ticks: _Optional[int] = ...) -> None: ...
 
 class RecordFilter(_message.Message):
     __slots__ = ("sample_period", "meso_link_ids")
@@ -48,7 +54,7 @@ class RunAndRecordResponse(_message.Message):
     def __init__(self, metadata: _Optional[_Union[RunMetadata, _Mapping]] = ..., batch: _Optional[_Union[RecordBatch, _Mapping]] = ..., summary: _Optional[_Union[RunSummary, _Mapping]] = ...) -> None: ...
 
 class RunMetadata(_message.Message):
-    __slots__ = ("format_version", "tick_seconds", "spawn_seed", "stochastic_seed", "core_version", "rand_version", "config_hash", "schema", "tl_schema")
+    __slots__ = ("format_version", "tick_seconds", "spawn_seed", "stochastic_seed", "core_version", "rand_version", "config_hash", "schema", "tl_schema", "events_schema")
     FORMAT_VERSION_FIELD_NUMBER: _ClassVar[int]
     TICK_SECONDS_FIELD_NUMBER: _ClassVar[int]
     SPAWN_SEED_FIELD_NUMBER: _ClassVar[int]
@@ -58,6 +64,7 @@ class RunMetadata(_message.Message):
     CONFIG_HASH_FIELD_NUMBER: _ClassVar[int]
     SCHEMA_FIELD_NUMBER: _ClassVar[int]
     TL_SCHEMA_FIELD_NUMBER: _ClassVar[int]
+    EVENTS_SCHEMA_FIELD_NUMBER: _ClassVar[int]
     format_version: int
     tick_seconds: float
     spawn_seed: int
@@ -67,7 +74,8 @@ class RunMetadata(_message.Message):
     config_hash: str
     schema: ColumnSchema
     tl_schema: ColumnSchema
-    def __init__(self, format_version: _Optional[int] = ..., tick_seconds: _Optional[float] = ..., spawn_seed: _Optional[int] = ..., stochastic_seed: _Optional[int] = ..., core_version: _Optional[str] = ..., rand_version: _Optional[str] = ..., config_hash: _Optional[str] = ..., schema: _Optional[_Union[ColumnSchema, _Mapping]] = ..., tl_schema: _Optional[_Union[ColumnSchema, _Mapping]] = ...) -> None: ...
+    events_schema: ColumnSchema
+    def __init__(self, format_version: _Optional[int] = ..., tick_seconds: _Optional[float] = ..., spawn_seed: _Optional[int] = ..., stochastic_seed: _Optional[int] = ..., core_version: _Optional[str] = ..., rand_version: _Optional[str] = ..., config_hash: _Optional[str] = ..., schema: _Optional[_Union[ColumnSchema, _Mapping]] = ..., tl_schema: _Optional[_Union[ColumnSchema, _Mapping]] = ..., events_schema: _Optional[_Union[ColumnSchema, _Mapping]] = ...) -> None: ...
 
 class ColumnSchema(_message.Message):
     __slots__ = ("columns",)
@@ -84,30 +92,36 @@ class ColumnDef(_message.Message):
     def __init__(self, name: _Optional[str] = ..., type: _Optional[str] = ...) -> None: ...
 
 class RecordBatch(_message.Message):
-    __slots__ = ("tick_start", "tick_count", "total_rows", "columns")
+    __slots__ = ("tick_start", "tick_count", "total_rows", "columns", "speed_limit_changes", "mfd")
     TICK_START_FIELD_NUMBER: _ClassVar[int]
     TICK_COUNT_FIELD_NUMBER: _ClassVar[int]
     TOTAL_ROWS_FIELD_NUMBER: _ClassVar[int]
     COLUMNS_FIELD_NUMBER: _ClassVar[int]
+    SPEED_LIMIT_CHANGES_FIELD_NUMBER: _ClassVar[int]
+    MFD_FIELD_NUMBER: _ClassVar[int]
     tick_start: int
     tick_count: int
     total_rows: int
     columns: bytes
-    def __init__(self, tick_start: _Optional[int] = ..., tick_count: _Optional[int] = ..., total_rows: _Optional[int] = ..., columns: _Optional[bytes] = ...) -> None: ...
+    speed_limit_changes: _containers.RepeatedCompositeFieldContainer[_speed_limit_pb2.SpeedLimitChange]
+    mfd: _containers.RepeatedCompositeFieldContainer[_stats_pb2.MfdSample]
+    def __init__(self, tick_start: _Optional[int] = ..., tick_count: _Optional[int] = ..., total_rows: _Optional[int] = ..., columns: _Optional[bytes] = ..., speed_limit_changes: _Optional[_Iterable[_Union[_speed_limit_pb2.SpeedLimitChange, _Mapping]]] = ..., mfd: _Optional[_Iterable[_Union[_stats_pb2.MfdSample, _Mapping]]] = ...) -> None: ...
 
 class RunSummary(_message.Message):
-    __slots__ = ("total_ticks", "total_rows", "total_bytes", "vehicles_completed", "vehicles_lost")
+    __slots__ = ("total_ticks", "total_rows", "total_bytes", "vehicles_completed", "vehicles_lost", "mfd")
     TOTAL_TICKS_FIELD_NUMBER: _ClassVar[int]
     TOTAL_ROWS_FIELD_NUMBER: _ClassVar[int]
     TOTAL_BYTES_FIELD_NUMBER: _ClassVar[int]
     VEHICLES_COMPLETED_FIELD_NUMBER: _ClassVar[int]
     VEHICLES_LOST_FIELD_NUMBER: _ClassVar[int]
+    MFD_FIELD_NUMBER: _ClassVar[int]
     total_ticks: int
     total_rows: int
     total_bytes: int
     vehicles_completed: int
     vehicles_lost: int
-    def __init__(self, total_ticks: _Optional[int] = ..., total_rows: _Optional[int] = ..., total_bytes: _Optional[int] = ..., vehicles_completed: _Optional[int] = ..., vehicles_lost: _Optional[int] = ...) -> None: ...
+    mfd: _containers.RepeatedCompositeFieldContainer[_stats_pb2.MfdSample]
+    def __init__(self, total_ticks: _Optional[int] = ..., total_rows: _Optional[int] = ..., total_bytes: _Optional[int] = ..., vehicles_completed: _Optional[int] = ..., vehicles_lost: _Optional[int] = ..., mfd: _Optional[_Iterable[_Union[_stats_pb2.MfdSample, _Mapping]]] = ...) -> None: ...
 
 class RecordingStatusRequest(_message.Message):
     __slots__ = ("session_id",)
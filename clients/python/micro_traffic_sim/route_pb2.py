@@ -0,0 +1,40 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# NO CHECKED-IN PROTOBUF GENCODE
+# source: route.proto
+# Protobuf Python Version: 6.31.1
+"""Generated protocol buffer code."""
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import runtime_version as _runtime_version
+from google.protobuf import symbol_database as _symbol_database
+from google.protobuf.internal import builder as _builder
+_runtime_version.ValidateProtobufRuntimeVersion(
+    _runtime_version.Domain.PUBLIC,
+    6,
+    31,
+    1,
+    '',
+    'route.proto'
+)
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import uuid_pb2 as uuid__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0broute.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"z\n\x13\x43omputeRouteRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x11\n\tfrom_cell\x18\x02 \x01(\x03\x12\x0f\n\x07to_cell\x18\x03 \x01(\x03\x12\x10\n\x08transits\x18\x04 \x03(\x03\"\x93\x01\n\x14\x43omputeRouteResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\r\n\x05\x63\x65lls\x18\x03 \x03(\x03\x12\x14\n\x0clength_cells\x18\x04 \x01(\r\x12\x0e\n\x06length\x18\x05 \x01(\x01\x12\x16\n\x0e\x66ree_flow_time\x18\x06 \x01(\x01\x12\x12\n\nmeso_links\x18\x07 \x03(\x03\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+
+_globals = globals()
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'route_pb2', _globals)
+if not _descriptor._USE_C_DESCRIPTORS:
+  _globals['DESCRIPTOR']._loaded_options = None
+  _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
+  _globals['_COMPUTEROUTEREQUEST']._serialized_start=46
+  _globals['_COMPUTEROUTEREQUEST']._serialized_end=168
+  _globals['_COMPUTEROUTERESPONSE']._serialized_start=171
+  _globals['_COMPUTEROUTERESPONSE']._serialized_end=318
+# @@protoc_insertion_point(module_scope)
@@ -0,0 +1,38 @@
+import uuid_pb2 as _uuid_pb2
+from google.protobuf.internal import containers as _containers
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import message as _message
+from collections.abc import Iterable as _Iterable, Mapping as _Mapping
+from typing import ClassVar as _ClassVar, Optional as _Optional, Union as _Union
+
+DESCRIPTOR: _descriptor.FileDescriptor
+
+class ComputeRouteRequest(_message.Message):
+    __slots__ = ("session_id", "from_cell", "to_cell", "transits")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    FROM_CELL_FIELD_NUMBER: _ClassVar[int]
+    TO_CELL_FIELD_NUMBER: _ClassVar[int]
+    TRANSITS_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    from_cell: int
+    to_cell: int
+    transits: _containers.RepeatedScalarFieldContainer[int]
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., from_cell: _Optional[int] = ..., to_cell: _Optional[int] = ..., transits: _Optional[_Iterable[int]] = ...) -> None: ...
+
+class ComputeRouteResponse(_message.Message):
+    __slots__ = ("code", "text", "cells", "length_cells", "length", "free_flow_time", "meso_links")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    CELLS_FIELD_NUMBER: _ClassVar[int]
+    LENGTH_CELLS_FIELD_NUMBER: _ClassVar[int]
+    LENGTH_FIELD_NUMBER: _ClassVar[int]
+    FREE_FLOW_TIME_FIELD_NUMBER: _ClassVar[int]
+    MESO_LINKS_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    cells: _containers.RepeatedScalarFieldContainer[int]
+    length_cells: int
+    length: float
+    free_flow_time: float
+    meso_links: _containers.RepeatedScalarFieldContainer[int]
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., cells: _Optional[_Iterable[int]] = ..., length_cells: _Optional[int] = ..., length: _Optional[float] = ..., free_flow_time: _Optional[float] = ..., meso_links: _Optional[_Iterable[int]] = ...) -> None: ...
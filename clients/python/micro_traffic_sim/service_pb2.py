@@ -30,9 +30,16 @@ from . import step_pb2 as step__pb2
 from . import tls_pb2 as tls__pb2
 from . import conflict_zones_pb2 as conflict__zones__pb2
 from . import record_pb2 as record__pb2
+from . import stats_pb2 as stats__pb2
+from . import limits_pb2 as limits__pb2
+from . import route_pb2 as route__pb2
+from . import od_pb2 as od__pb2
+from . import vehicle_pb2 as vehicle__pb2
+from . import speed_limit_pb2 as speed__limit__pb2
+from . import incident_pb2 as incident__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\rservice.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\x1a\rsession.proto\x1a\ncell.proto\x1a\ntrip.proto\x1a\nstep.proto\x1a\ttls.proto\x1a\x14\x63onflict_zones.proto\x1a\x0crecord.proto2\xed\x07\n\x07Service\x12T\n\nNewSession\x12\x1d.micro_traffic_sim.SessionReq\x1a%.micro_traffic_sim.NewSessionResponse\"\x00\x12R\n\x0bInfoSession\x12\x19.micro_traffic_sim.UUIDv4\x1a&.micro_traffic_sim.InfoSessionResponse\"\x00\x12_\n\x0fPushSessionGrid\x12\x1e.micro_traffic_sim.SessionGrid\x1a&.micro_traffic_sim.SessionGridResponse\"\x00(\x01\x30\x01\x12_\n\x0fPushSessionTrip\x12\x1e.micro_traffic_sim.SessionTrip\x1a&.micro_traffic_sim.SessionTripResponse\"\x00(\x01\x30\x01\x12\x65\n\x15SimulationStepSession\x12\x1e.micro_traffic_sim.SessionStep\x1a&.micro_traffic_sim.SessionStepResponse\"\x00(\x01\x30\x01\x12\\\n\x0ePushSessionTLS\x12\x1d.micro_traffic_sim.SessionTLS\x1a%.micro_traffic_sim.SessionTLSResponse\"\x00(\x01\x30\x01\x12z\n\x18PushSessionConflictZones\x12\'.micro_traffic_sim.SessionConflictZones\x1a/.micro_traffic_sim.SessionConflictZonesResponse\"\x00(\x01\x30\x01\x12\x63\n\x0cRunAndRecord\x12&.micro_traffic_sim.RunAndRecordRequest\x1a\'.micro_traffic_sim.RunAndRecordResponse\"\x00\x30\x01\x12j\n\x0fRecordingStatus\x12).micro_traffic_sim.RecordingStatusRequest\x1a*.micro_traffic_sim.RecordingStatusResponse\"\x00\x12\x64\n\rStopRecording\x12\'.micro_traffic_sim.StopRecordingRequest\x1a(.micro_traffic_sim.StopRecordingResponse\"\x00\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\rservice.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\x1a\rsession.proto\x1a\ncell.proto\x1a\ntrip.proto\x1a\nstep.proto\x1a\ttls.proto\x1a\x14\x63onflict_zones.proto\x1a\x0crecord.proto\x1a\x0bstats.proto\x1a\x0climits.proto\x1a\x0broute.proto\x1a\x08od.proto\x1a\rvehicle.proto\x1a\x11speed_limit.proto\x1a\x0eincident.proto2\xbe\x14\n\x07Service\x12T\n\nNewSession\x12\x1d.micro_traffic_sim.SessionReq\x1a%.micro_traffic_sim.NewSessionResponse\"\x00\x12R\n\x0bInfoSession\x12\x19.micro_traffic_sim.UUIDv4\x1a&.micro_traffic_sim.InfoSessionResponse\"\x00\x12_\n\x0fPushSessionGrid\x12\x1e.micro_traffic_sim.SessionGrid\x1a&.micro_traffic_sim.SessionGridResponse\"\x00(\x01\x30\x01\x12_\n\x0fPushSessionTrip\x12\x1e.micro_traffic_sim.SessionTrip\x1a&.micro_traffic_sim.SessionTripResponse\"\x00(\x01\x30\x01\x12\x65\n\x15SimulationStepSession\x12\x1e.micro_traffic_sim.SessionStep\x1a&.micro_traffic_sim.SessionStepResponse\"\x00(\x01\x30\x01\x12\\\n\x0ePushSessionTLS\x12\x1d.micro_traffic_sim.SessionTLS\x1a%.micro_traffic_sim.SessionTLSResponse\"\x00(\x01\x30\x01\x12z\n\x18PushSessionConflictZones\x12\'.micro_traffic_sim.SessionConflictZones\x1a/.micro_traffic_sim.SessionConflictZonesResponse\"\x00(\x01\x30\x01\x12\x63\n\x0cRunAndRecord\x12&.micro_traffic_sim.RunAndRecordRequest\x1a\'.micro_traffic_sim.RunAndRecordResponse\"\x00\x30\x01\x12j\n\x0fRecordingStatus\x12).micro_traffic_sim.RecordingStatusRequest\x1a*.micro_traffic_sim.RecordingStatusResponse\"\x00\x12\x64\n\rStopRecording\x12\'.micro_traffic_sim.StopRecordingRequest\x1a(.micro_traffic_sim.StopRecordingResponse\"\x00\x12[\n\x0cGetTripStats\x12#.micro_traffic_sim.TripStatsRequest\x1a$.micro_traffic_sim.TripStatsResponse\"\x00\x12s\n\x14GetTrafficLightStats\x12+.micro_traffic_sim.TrafficLightStatsRequest\x1a,.micro_traffic_sim.TrafficLightStatsResponse\"\x00\x12g\n\x0eInjectIncident\x12(.micro_traffic_sim.InjectIncidentRequest\x1a).micro_traffic_sim.InjectIncidentResponse\"\x00\x12\x64\n\rClearIncident\x12\'.micro_traffic_sim.ClearIncidentRequest\x1a(.micro_traffic_sim.ClearIncidentResponse\"\x00\x12\x64\n\rSetSpeedLimit\x12\'.micro_traffic_sim.SetSpeedLimitRequest\x1a(.micro_traffic_sim.SetSpeedLimitResponse\"\x00\x12\x61\n\x0cSpawnVehicle\x12&.micro_traffic_sim.SpawnVehicleRequest\x1a\'.micro_traffic_sim.SpawnVehicleResponse\"\x00\x12\x64\n\rRemoveVehicle\x12\'.micro_traffic_sim.RemoveVehicleRequest\x1a(.micro_traffic_sim.RemoveVehicleResponse\"\x00\x12[\n\nGetVehicle\x12$.micro_traffic_sim.GetVehicleRequest\x1a%.micro_traffic_sim.GetVehicleResponse\"\x00\x12p\n\x11UpdateSessionTrip\x12+.micro_traffic_sim.UpdateSessionTripRequest\x1a,.micro_traffic_sim.UpdateSessionTripResponse\"\x00\x12p\n\x11RemoveSessionTrip\x12+.micro_traffic_sim.RemoveSessionTripRequest\x1a,.micro_traffic_sim.RemoveSessionTripResponse\"\x00\x12y\n\x14SetSessionTripPaused\x12..micro_traffic_sim.SetSessionTripPausedRequest\x1a/.micro_traffic_sim.SetSessionTripPausedResponse\"\x00\x12v\n\x13PushSessionOdMatrix\x12-.micro_traffic_sim.PushSessionOdMatrixRequest\x1a..micro_traffic_sim.PushSessionOdMatrixResponse\"\x00\x12\x61\n\x0c\x43omputeRoute\x12&.micro_traffic_sim.ComputeRouteRequest\x1a\'.micro_traffic_sim.ComputeRouteResponse\"\x00\x12|\n\x15GenerateConflictZones\x12/.micro_traffic_sim.GenerateConflictZonesRequest\x1a\x30.micro_traffic_sim.GenerateConflictZonesResponse\"\x00\x12j\n\x0fGetServerLimits\x12).micro_traffic_sim.GetServerLimitsRequest\x1a*.micro_traffic_sim.GetServerLimitsResponse\"\x00\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
 
 _globals = globals()
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
@@ -40,6 +47,6 @@ _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'service_pb2', _globals)
 if not _descriptor._USE_C_DESCRIPTORS:
   _globals['DESCRIPTOR']._loaded_options = None
   _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
-  _globals['_SERVICE']._serialized_start=147
-  _globals['_SERVICE']._serialized_end=1152
+  _globals['_SERVICE']._serialized_start=247
+  _globals['_SERVICE']._serialized_end=2869
 # @@protoc_insertion_point(module_scope)
@@ -6,6 +6,13 @@ import step_pb2 as _step_pb2
 import tls_pb2 as _tls_pb2
 import conflict_zones_pb2 as _conflict_zones_pb2
 import record_pb2 as _record_pb2
+import stats_pb2 as _stats_pb2
+import limits_pb2 as _limits_pb2
+import route_pb2 as _route_pb2
+import od_pb2 as _od_pb2
+import vehicle_pb2 as _vehicle_pb2
+import speed_limit_pb2 as _speed_limit_pb2
+import incident_pb2 as _incident_pb2
 from google.protobuf import descriptor as _descriptor
 from typing import ClassVar as _ClassVar
 
@@ -5,12 +5,19 @@ import warnings
 
 from . import cell_pb2 as cell__pb2
 from . import conflict_zones_pb2 as conflict__zones__pb2
+from . import incident_pb2 as incident__pb2
+from . import limits_pb2 as limits__pb2
+from . import od_pb2 as od__pb2
 from . import record_pb2 as record__pb2
+from . import route_pb2 as route__pb2
 from . import session_pb2 as session__pb2
+from . import speed_limit_pb2 as speed__limit__pb2
+from . import stats_pb2 as stats__pb2
 from . import step_pb2 as step__pb2
 from . import tls_pb2 as tls__pb2
 from . import trip_pb2 as trip__pb2
 from . import uuid_pb2 as uuid__pb2
+from . import vehicle_pb2 as vehicle__pb2
 
 GRPC_GENERATED_VERSION = '1.78.0'
 GRPC_VERSION = grpc.__version__
@@ -91,6 +98,81 @@ class ServiceStub(object):
                 request_serializer=record__pb2.StopRecordingRequest.SerializeToString,
                 response_deserializer=record__pb2.StopRecordingResponse.FromString,
                 _registered_method=True)
+        self.GetTripStats = channel.unary_unary(
+                '/micro_traffic_sim.Service/GetTripStats',
+                request_serializer=stats__pb2.TripStatsRequest.SerializeToString,
+                response_deserializer=stats__pb2.TripStatsResponse.FromString,
+                _registered_method=True)
+        self.GetTrafficLightStats = channel.unary_unary(
+                '/micro_traffic_sim.Service/GetTrafficLightStats',
+                request_serializer=stats__pb2.TrafficLightStatsRequest.SerializeToString,
+                response_deserializer=stats__pb2.TrafficLightStatsResponse.FromString,
+                _registered_method=True)
+        self.InjectIncident = channel.unary_unary(
+                '/micro_traffic_sim.Service/InjectIncident',
+                request_serializer=incident__pb2.InjectIncidentRequest.SerializeToString,
+                response_deserializer=incident__pb2.InjectIncidentResponse.FromString,
+                _registered_method=True)
+        self.ClearIncident = channel.unary_unary(
+                '/micro_traffic_sim.Service/ClearIncident',
+                request_serializer=incident__pb2.ClearIncidentRequest.SerializeToString,
+                response_deserializer=incident__pb2.ClearIncidentResponse.FromString,
+                _registered_method=True)
+        self.SetSpeedLimit = channel.unary_unary(
+                '/micro_traffic_sim.Service/SetSpeedLimit',
+                request_serializer=speed__limit__pb2.SetSpeedLimitRequest.SerializeToString,
+                response_deserializer=speed__limit__pb2.SetSpeedLimitResponse.FromString,
+                _registered_method=True)
+        self.SpawnVehicle = channel.unary_unary(
+                '/micro_traffic_sim.Service/SpawnVehicle',
+                request_serializer=vehicle__pb2.SpawnVehicleRequest.SerializeToString,
+                response_deserializer=vehicle__pb2.SpawnVehicleResponse.FromString,
+                _registered_method=True)
+        self.RemoveVehicle = channel.unary_unary(
+                '/micro_traffic_sim.Service/RemoveVehicle',
+                request_serializer=vehicle__pb2.RemoveVehicleRequest.SerializeToString,
+                response_deserializer=vehicle__pb2.RemoveVehicleResponse.FromString,
+                _registered_method=True)
+        self.GetVehicle = channel.unary_unary(
+                '/micro_traffic_sim.Service/GetVehicle',
+                request_serializer=vehicle__pb2.GetVehicleRequest.SerializeToString,
+                response_deserializer=vehicle__pb2.GetVehicleResponse.FromString,
+                _registered_method=True)
+        self.UpdateSessionTrip = channel.unary_unary(
+                '/micro_traffic_sim.Service/UpdateSessionTrip',
+                request_serializer=trip__pb2.UpdateSessionTripRequest.SerializeToString,
+                response_deserializer=trip__pb2.UpdateSessionTripResponse.FromString,
+                _registered_method=True)
+        self.RemoveSessionTrip = channel.unary_unary(
+                '/micro_traffic_sim.Service/RemoveSessionTrip',
+                request_serializer=trip__pb2.RemoveSessionTripRequest.SerializeToString,
+                response_deserializer=trip__pb2.RemoveSessionTripResponse.FromString,
+                _registered_method=True)
+        self.SetSessionTripPaused = channel.unary_unary(
+                '/micro_traffic_sim.Service/SetSessionTripPaused',
+                request_serializer=trip__pb2.SetSessionTripPausedRequest.SerializeToString,
+                response_deserializer=trip__pb2.SetSessionTripPausedResponse.FromString,
+                _registered_method=True)
+        self.PushSessionOdMatrix = channel.unary_unary(
+                '/micro_traffic_sim.Service/PushSessionOdMatrix',
+                request_serializer=od__pb2.PushSessionOdMatrixRequest.SerializeToString,
+                response_deserializer=od__pb2.PushSessionOdMatrixResponse.FromString,
+                _registered_method=True)
+        self.ComputeRoute = channel.unary_unary(
+                '/micro_traffic_sim.Service/ComputeRoute',
+                request_serializer=route__pb2.ComputeRouteRequest.SerializeToString,
+                response_deserializer=route__pb2.ComputeRouteResponse.FromString,
+                _registered_method=True)
+        self.GenerateConflictZones = channel.unary_unary(
+                '/micro_traffic_sim.Service/GenerateConflictZones',
+                request_serializer=conflict__zones__pb2.GenerateConflictZonesRequest.SerializeToString,
+                response_deserializer=conflict__zones__pb2.GenerateConflictZonesResponse.FromString,
+                _registered_method=True)
+        self.GetServerLimits = channel.unary_unary(
+                '/micro_traffic_sim.Service/GetServerLimits',
+                request_serializer=limits__pb2.GetServerLimitsRequest.SerializeToString,
+                response_deserializer=limits__pb2.GetServerLimitsResponse.FromString,
+                _registered_method=True)
 
 
 class ServiceServicer(object):
@@ -167,6 +249,111 @@ class ServiceServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def GetTripStats(self, request, context):
+        """Get per-trip vehicle counts and travel time / delay statistics for the given session
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def GetTrafficLightStats(self, request, context):
+        """Get per-traffic-light and per-group signal performance statistics for the given session
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def InjectIncident(self, request, context):
+        """Block cells or reduce their speed limit for a time window (also on a session owned by RunAndRecord)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ClearIncident(self, request, context):
+        """Lift an incident injected by InjectIncident
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def SetSpeedLimit(self, request, context):
+        """Change speed limit of cells or of a whole meso link from the next tick on (also on a session owned by RunAndRecord)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def SpawnVehicle(self, request, context):
+        """Put a single vehicle into the simulation (origin, destination, transits, initial speed)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def RemoveVehicle(self, request, context):
+        """Take a vehicle out of the simulation by its identifier
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def GetVehicle(self, request, context):
+        """Get the state, remaining route and intent of a single vehicle
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def UpdateSessionTrip(self, request, context):
+        """Replace an existing trip of the session (also on a session owned by RunAndRecord)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def RemoveSessionTrip(self, request, context):
+        """Remove a trip from the session (also on a session owned by RunAndRecord)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def SetSessionTripPaused(self, request, context):
+        """Pause or resume vehicle generation of a trip (also on a session owned by RunAndRecord)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def PushSessionOdMatrix(self, request, context):
+        """Expand an OD matrix (zones, vehicles/hour per time slice, agent mix) into trips of the session
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ComputeRoute(self, request, context):
+        """Shortest route between two cells (through optional transits) over the session grid
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def GenerateConflictZones(self, request, context):
+        """Detect crossing / merging movements within coordination zones and build conflict zones for them
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def GetServerLimits(self, request, context):
+        """Limits applying to the caller (server-wide and per tenant) and current usage
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
 
 def add_ServiceServicer_to_server(servicer, server):
     rpc_method_handlers = {
@@ -220,6 +407,81 @@ def add_ServiceServicer_to_server(servicer, server):
                     request_deserializer=record__pb2.StopRecordingRequest.FromString,
                     response_serializer=record__pb2.StopRecordingResponse.SerializeToString,
             ),
+            'GetTripStats': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetTripStats,
+                    request_deserializer=stats__pb2.TripStatsRequest.FromString,
+                    response_serializer=stats__pb2.TripStatsResponse.SerializeToString,
+            ),
+            'GetTrafficLightStats': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetTrafficLightStats,
+                    request_deserializer=stats__pb2.TrafficLightStatsRequest.FromString,
+                    response_serializer=stats__pb2.TrafficLightStatsResponse.SerializeToString,
+            ),
+            'InjectIncident': grpc.unary_unary_rpc_method_handler(
+                    servicer.InjectIncident,
+                    request_deserializer=incident__pb2.InjectIncidentRequest.FromString,
+                    response_serializer=incident__pb2.InjectIncidentResponse.SerializeToString,
+            ),
+            'ClearIncident': grpc.unary_unary_rpc_method_handler(
+                    servicer.ClearIncident,
+                    request_deserializer=incident__pb2.ClearIncidentRequest.FromString,
+                    response_serializer=incident__pb2.ClearIncidentResponse.SerializeToString,
+            ),
+            'SetSpeedLimit': grpc.unary_unary_rpc_method_handler(
+                    servicer.SetSpeedLimit,
+                    request_deserializer=speed__limit__pb2.SetSpeedLimitRequest.FromString,
+                    response_serializer=speed__limit__pb2.SetSpeedLimitResponse.SerializeToString,
+            ),
+            'SpawnVehicle': grpc.unary_unary_rpc_method_handler(
+                    servicer.SpawnVehicle,
+                    request_deserializer=vehicle__pb2.SpawnVehicleRequest.FromString,
+                    response_serializer=vehicle__pb2.SpawnVehicleResponse.SerializeToString,
+            ),
+            'RemoveVehicle': grpc.unary_unary_rpc_method_handler(
+                    servicer.RemoveVehicle,
+                    request_deserializer=vehicle__pb2.RemoveVehicleRequest.FromString,
+                    response_serializer=vehicle__pb2.RemoveVehicleResponse.SerializeToString,
+            ),
+            'GetVehicle': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetVehicle,
+                    request_deserializer=vehicle__pb2.GetVehicleRequest.FromString,
+                    response_serializer=vehicle__pb2.GetVehicleResponse.SerializeToString,
+            ),
+            'UpdateSessionTrip': grpc.unary_unary_rpc_method_handler(
+                    servicer.UpdateSessionTrip,
+                    request_deserializer=trip__pb2.UpdateSessionTripRequest.FromString,
+                    response_serializer=trip__pb2.UpdateSessionTripResponse.SerializeToString,
+            ),
+            'RemoveSessionTrip': grpc.unary_unary_rpc_method_handler(
+                    servicer.RemoveSessionTrip,
+                    request_deserializer=trip__pb2.RemoveSessionTripRequest.FromString,
+                    response_serializer=trip__pb2.RemoveSessionTripResponse.SerializeToString,
+            ),
+            'SetSessionTripPaused': grpc.unary_unary_rpc_method_handler(
+                    servicer.SetSessionTripPaused,
+                    request_deserializer=trip__pb2.SetSessionTripPausedRequest.FromString,
+                    response_serializer=trip__pb2.SetSessionTripPausedResponse.SerializeToString,
+            ),
+            'PushSessionOdMatrix': grpc.unary_unary_rpc_method_handler(
+                    servicer.PushSessionOdMatrix,
+                    request_deserializer=od__pb2.PushSessionOdMatrixRequest.FromString,
+                    response_serializer=od__pb2.PushSessionOdMatrixResponse.SerializeToString,
+            ),
+            'ComputeRoute': grpc.unary_unary_rpc_method_handler(
+                    servicer.ComputeRoute,
+                    request_deserializer=route__pb2.ComputeRouteRequest.FromString,
+                    response_serializer=route__pb2.ComputeRouteResponse.SerializeToString,
+            ),
+            'GenerateConflictZones': grpc.unary_unary_rpc_method_handler(
+                    servicer.GenerateConflictZones,
+                    request_deserializer=conflict__zones__pb2.GenerateConflictZonesRequest.FromString,
+                    response_serializer=conflict__zones__pb2.GenerateConflictZonesResponse.SerializeToString,
+            ),
+            'GetServerLimits': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetServerLimits,
+                    request_deserializer=limits__pb2.GetServerLimitsRequest.FromString,
+                    response_serializer=limits__pb2.GetServerLimitsResponse.SerializeToString,
+            ),
     }
     generic_handler = grpc.method_handlers_generic_handler(
             'micro_traffic_sim.Service', rpc_method_handlers)
@@ -500,3 +762,408 @@ class Service(object):
             timeout,
             metadata,
             _registered_method=True)
+
+    @staticmethod
+    def GetTripStats(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/GetTripStats',
+            stats__pb2.TripStatsRequest.SerializeToString,
+            stats__pb2.TripStatsResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def GetTrafficLightStats(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/GetTrafficLightStats',
+            stats__pb2.TrafficLightStatsRequest.SerializeToString,
+            stats__pb2.TrafficLightStatsResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def InjectIncident(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/InjectIncident',
+            incident__pb2.InjectIncidentRequest.SerializeToString,
+            incident__pb2.InjectIncidentResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ClearIncident(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/ClearIncident',
+            incident__pb2.ClearIncidentRequest.SerializeToString,
+            incident__pb2.ClearIncidentResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def SetSpeedLimit(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/SetSpeedLimit',
+            speed__limit__pb2.SetSpeedLimitRequest.SerializeToString,
+            speed__limit__pb2.SetSpeedLimitResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def SpawnVehicle(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/SpawnVehicle',
+            vehicle__pb2.SpawnVehicleRequest.SerializeToString,
+            vehicle__pb2.SpawnVehicleResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def RemoveVehicle(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/RemoveVehicle',
+            vehicle__pb2.RemoveVehicleRequest.SerializeToString,
+            vehicle__pb2.RemoveVehicleResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def GetVehicle(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/GetVehicle',
+            vehicle__pb2.GetVehicleRequest.SerializeToString,
+            vehicle__pb2.GetVehicleResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def UpdateSessionTrip(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/UpdateSessionTrip',
+            trip__pb2.UpdateSessionTripRequest.SerializeToString,
+            trip__pb2.UpdateSessionTripResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def RemoveSessionTrip(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/RemoveSessionTrip',
+            trip__pb2.RemoveSessionTripRequest.SerializeToString,
+            trip__pb2.RemoveSessionTripResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def SetSessionTripPaused(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/SetSessionTripPaused',
+            trip__pb2.SetSessionTripPausedRequest.SerializeToString,
+            trip__pb2.SetSessionTripPausedResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def PushSessionOdMatrix(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/PushSessionOdMatrix',
+            od__pb2.PushSessionOdMatrixRequest.SerializeToString,
+            od__pb2.PushSessionOdMatrixResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ComputeRoute(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/ComputeRoute',
+            route__pb2.ComputeRouteRequest.SerializeToString,
+            route__pb2.ComputeRouteResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def GenerateConflictZones(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/GenerateConflictZones',
+            conflict__zones__pb2.GenerateConflictZonesRequest.SerializeToString,
+            conflict__zones__pb2.GenerateConflictZonesResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def GetServerLimits(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/micro_traffic_sim.Service/GetServerLimits',
+            limits__pb2.GetServerLimitsRequest.SerializeToString,
+            limits__pb2.GetServerLimitsResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
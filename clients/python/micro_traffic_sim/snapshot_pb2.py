@@ -0,0 +1,42 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# NO CHECKED-IN PROTOBUF GENCODE
+# source: snapshot.proto
+# Protobuf Python Version: 6.31.1
+"""Generated protocol buffer code."""
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import runtime_version as _runtime_version
+from google.protobuf import symbol_database as _symbol_database
+from google.protobuf.internal import builder as _builder
+_runtime_version.ValidateProtobufRuntimeVersion(
+    _runtime_version.Domain.PUBLIC,
+    6,
+    31,
+    1,
+    '',
+    'snapshot.proto'
+)
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import uuid_pb2 as uuid__pb2
+from . import cell_pb2 as cell__pb2
+from . import trip_pb2 as trip__pb2
+from . import tls_pb2 as tls__pb2
+from . import conflict_zones_pb2 as conflict__zones__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0esnapshot.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\x1a\ncell.proto\x1a\ntrip.proto\x1a\ttls.proto\x1a\x14\x63onflict_zones.proto\"\xce\x02\n\x0fSessionSnapshot\x12\x16\n\x0e\x66ormat_version\x18\x01 \x01(\r\x12-\n\nsession_id\x18\x02 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x0c\n\x04srid\x18\x03 \x01(\x05\x12\x0e\n\x06tenant\x18\x04 \x01(\t\x12&\n\x05\x63\x65lls\x18\x05 \x03(\x0b\x32\x17.micro_traffic_sim.Cell\x12&\n\x05trips\x18\x06 \x03(\x0b\x32\x17.micro_traffic_sim.Trip\x12\x14\n\x0cpaused_trips\x18\x07 \x03(\x03\x12\x37\n\x0etraffic_lights\x18\x08 \x03(\x0b\x32\x1f.micro_traffic_sim.TrafficLight\x12\x37\n\x0e\x63onflict_zones\x18\t \x03(\x0b\x32\x1f.micro_traffic_sim.ConflictZoneB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+
+_globals = globals()
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'snapshot_pb2', _globals)
+if not _descriptor._USE_C_DESCRIPTORS:
+  _globals['DESCRIPTOR']._loaded_options = None
+  _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
+  _globals['_SESSIONSNAPSHOT']._serialized_start=107
+  _globals['_SESSIONSNAPSHOT']._serialized_end=441
+# @@protoc_insertion_point(module_scope)
@@ -0,0 +1,34 @@
+import uuid_pb2 as _uuid_pb2
+import cell_pb2 as _cell_pb2
+import trip_pb2 as _trip_pb2
+import tls_pb2 as _tls_pb2
+import conflict_zones_pb2 as _conflict_zones_pb2
+from google.protobuf.internal import containers as _containers
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import message as _message
+from collections.abc import Iterable as _Iterable, Mapping as _Mapping
+from typing import ClassVar as _ClassVar, Optional as _Optional, Union as _Union
+
+DESCRIPTOR: _descriptor.FileDescriptor
+
+class SessionSnapshot(_message.Message):
+    __slots__ = ("format_version", "session_id", "srid", "tenant", "cells", "trips", "paused_trips", "traffic_lights", "conflict_zones")
+    FORMAT_VERSION_FIELD_NUMBER: _ClassVar[int]
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    SRID_FIELD_NUMBER: _ClassVar[int]
+    TENANT_FIELD_NUMBER: _ClassVar[int]
+    CELLS_FIELD_NUMBER: _ClassVar[int]
+    TRIPS_FIELD_NUMBER: _ClassVar[int]
+    PAUSED_TRIPS_FIELD_NUMBER: _ClassVar[int]
+    TRAFFIC_LIGHTS_FIELD_NUMBER: _ClassVar[int]
+    CONFLICT_ZONES_FIELD_NUMBER: _ClassVar[int]
+    format_version: int
+    session_id: _uuid_pb2.UUIDv4
+    srid: int
+    tenant: str
+    cells: _containers.RepeatedCompositeFieldContainer[_cell_pb2.Cell]
+    trips: _containers.RepeatedCompositeFieldContainer[_trip_pb2.Trip]
+    paused_trips: _containers.RepeatedScalarFieldContainer[int]
+    traffic_lights: _containers.RepeatedCompositeFieldContainer[_tls_pb2.TrafficLight]
+    conflict_zones: _containers.RepeatedCompositeFieldContainer[_conflict_zones_pb2.ConflictZone]
+    def __init__(self, format_version: _Optional[int] = ..., session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., srid: _Optional[int] = ..., tenant: _Optional[str] = ..., cells: _Optional[_Iterable[_Union[_cell_pb2.Cell, _Mapping]]] = ..., trips: _Optional[_Iterable[_Union[_trip_pb2.Trip, _Mapping]]] = ..., paused_trips: _Optional[_Iterable[int]] = ..., traffic_lights: _Optional[_Iterable[_Union[_tls_pb2.TrafficLight, _Mapping]]] = ..., conflict_zones: _Optional[_Iterable[_Union[_conflict_zones_pb2.ConflictZone, _Mapping]]] = ...) -> None: ...
@@ -0,0 +1,42 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# NO CHECKED-IN PROTOBUF GENCODE
+# source: speed_limit.proto
+# Protobuf Python Version: 6.31.1
+"""Generated protocol buffer code."""
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import runtime_version as _runtime_version
+from google.protobuf import symbol_database as _symbol_database
+from google.protobuf.internal import builder as _builder
+_runtime_version.ValidateProtobufRuntimeVersion(
+    _runtime_version.Domain.PUBLIC,
+    6,
+    31,
+    1,
+    '',
+    'speed_limit.proto'
+)
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import uuid_pb2 as uuid__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x11speed_limit.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"\x7f\n\x14SetSpeedLimitRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\r\n\x05\x63\x65lls\x18\x02 \x03(\x03\x12\x14\n\x0cmeso_link_id\x18\x03 \x01(\x03\x12\x13\n\x0bspeed_limit\x18\x04 \x01(\x05\"3\n\x15SetSpeedLimitResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\"D\n\x10SpeedLimitChange\x12\x0c\n\x04tick\x18\x01 \x01(\x04\x12\r\n\x05\x63\x65lls\x18\x02 \x03(\x03\x12\x13\n\x0bspeed_limit\x18\x03 \x01(\x05\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+
+_globals = globals()
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'speed_limit_pb2', _globals)
+if not _descriptor._USE_C_DESCRIPTORS:
+  _globals['DESCRIPTOR']._loaded_options = None
+  _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
+  _globals['_SETSPEEDLIMITREQUEST']._serialized_start=52
+  _globals['_SETSPEEDLIMITREQUEST']._serialized_end=179
+  _globals['_SETSPEEDLIMITRESPONSE']._serialized_start=181
+  _globals['_SETSPEEDLIMITRESPONSE']._serialized_end=232
+  _globals['_SPEEDLIMITCHANGE']._serialized_start=234
+  _globals['_SPEEDLIMITCHANGE']._serialized_end=302
+# @@protoc_insertion_point(module_scope)
@@ -0,0 +1,38 @@
+import uuid_pb2 as _uuid_pb2
+from google.protobuf.internal import containers as _containers
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import message as _message
+from collections.abc import Iterable as _Iterable, Mapping as _Mapping
+from typing import ClassVar as _ClassVar, Optional as _Optional, Union as _Union
+
+DESCRIPTOR: _descriptor.FileDescriptor
+
+class SetSpeedLimitRequest(_message.Message):
+    __slots__ = ("session_id", "cells", "meso_link_id", "speed_limit")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    CELLS_FIELD_NUMBER: _ClassVar[int]
+    MESO_LINK_ID_FIELD_NUMBER: _ClassVar[int]
+    SPEED_LIMIT_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    cells: _containers.RepeatedScalarFieldContainer[int]
+    meso_link_id: int
+    speed_limit: int
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., cells: _Optional[_Iterable[int]] = ..., meso_link_id: _Optional[int] = ..., speed_limit: _Optional[int] = ...) -> None: ...
+
+class SetSpeedLimitResponse(_message.Message):
+    __slots__ = ("code", "text")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ...) -> None: ...
+
+class SpeedLimitChange(_message.Message):
+    __slots__ = ("tick", "cells", "speed_limit")
+    TICK_FIELD_NUMBER: _ClassVar[int]
+    CELLS_FIELD_NUMBER: _ClassVar[int]
+    SPEED_LIMIT_FIELD_NUMBER: _ClassVar[int]
+    tick: int
+    cells: _containers.RepeatedScalarFieldContainer[int]
+    speed_limit: int
+    def __init__(self, tick: _Optional[int] = ..., cells: _Optional[_Iterable[int]] = ..., speed_limit: _Optional[int] = ...) -> None: ...
@@ -0,0 +1,56 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# NO CHECKED-IN PROTOBUF GENCODE
+# source: stats.proto
+# Protobuf Python Version: 6.31.1
+"""Generated protocol buffer code."""
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import runtime_version as _runtime_version
+from google.protobuf import symbol_database as _symbol_database
+from google.protobuf.internal import builder as _builder
+_runtime_version.ValidateProtobufRuntimeVersion(
+    _runtime_version.Domain.PUBLIC,
+    6,
+    31,
+    1,
+    '',
+    'stats.proto'
+)
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import uuid_pb2 as uuid__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0bstats.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"S\n\x10TripStatsRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x10\n\x08trip_ids\x18\x02 \x03(\x03\"\xe7\x01\n\tTripStats\x12\x0f\n\x07trip_id\x18\x01 \x01(\x03\x12\x11\n\tgenerated\x18\x02 \x01(\x04\x12\x0f\n\x07\x61rrived\x18\x03 \x01(\x04\x12\x0c\n\x04lost\x18\x04 \x01(\x04\x12\x18\n\x10mean_travel_time\x18\x05 \x01(\x01\x12\x17\n\x0fp50_travel_time\x18\x06 \x01(\x01\x12\x17\n\x0fp85_travel_time\x18\x07 \x01(\x01\x12\x17\n\x0fp95_travel_time\x18\x08 \x01(\x01\x12\x1d\n\x15\x66ree_flow_travel_time\x18\t \x01(\x01\x12\x13\n\x0b\x64\x65lay_index\x18\n \x01(\x01\"[\n\x11TripStatsResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12*\n\x04\x64\x61ta\x18\x03 \x03(\x0b\x32\x1c.micro_traffic_sim.TripStats\"d\n\x18TrafficLightStatsRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x19\n\x11traffic_light_ids\x18\x02 \x03(\x03\"\xda\x01\n\x16TrafficLightGroupStats\x12\x10\n\x08group_id\x18\x01 \x01(\x03\x12\x17\n\x0fvehicles_served\x18\x02 \x01(\x04\x12\x14\n\x0cgreen_phases\x18\x03 \x01(\x04\x12\x1a\n\x12vehicles_per_green\x18\x04 \x01(\x01\x12\x1a\n\x12mean_control_delay\x18\x05 \x01(\x01\x12\x19\n\x11stops_per_vehicle\x18\x06 \x01(\x01\x12\x11\n\tmax_queue\x18\x07 \x01(\r\x12\x19\n\x11green_utilization\x18\x08 \x01(\x01\"\x82\x02\n\x11TrafficLightStats\x12\x18\n\x10traffic_light_id\x18\x01 \x01(\x03\x12\x17\n\x0fvehicles_served\x18\x02 \x01(\x04\x12\x1a\n\x12vehicles_per_green\x18\x03 \x01(\x01\x12\x1a\n\x12mean_control_delay\x18\x04 \x01(\x01\x12\x19\n\x11stops_per_vehicle\x18\x05 \x01(\x01\x12\x11\n\tmax_queue\x18\x06 \x01(\r\x12\x19\n\x11green_utilization\x18\x07 \x01(\x01\x12\x39\n\x06groups\x18\x08 \x03(\x0b\x32).micro_traffic_sim.TrafficLightGroupStats\"k\n\x19TrafficLightStatsResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x32\n\x04\x64\x61ta\x18\x03 \x03(\x0b\x32$.micro_traffic_sim.TrafficLightStats\"]\n\nMfdMeasure\x12\x0b\n\x03vkt\x18\x01 \x01(\x01\x12\x18\n\x10total_time_spent\x18\x02 \x01(\x01\x12\x12\n\nproduction\x18\x03 \x01(\x01\x12\x14\n\x0c\x61\x63\x63umulation\x18\x04 \x01(\x01\"V\n\x0eMfdLinkMeasure\x12\x14\n\x0cmeso_link_id\x18\x01 \x01(\x03\x12.\n\x07measure\x18\x02 \x01(\x0b\x32\x1d.micro_traffic_sim.MfdMeasure\"\x95\x01\n\tMfdSample\x12\x12\n\ntick_start\x18\x01 \x01(\x04\x12\x12\n\ntick_count\x18\x02 \x01(\r\x12.\n\x07network\x18\x03 \x01(\x0b\x32\x1d.micro_traffic_sim.MfdMeasure\x12\x30\n\x05links\x18\x04 \x03(\x0b\x32!.micro_traffic_sim.MfdLinkMeasureB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+
+_globals = globals()
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'stats_pb2', _globals)
+if not _descriptor._USE_C_DESCRIPTORS:
+  _globals['DESCRIPTOR']._loaded_options = None
+  _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
+  _globals['_TRIPSTATSREQUEST']._serialized_start=46
+  _globals['_TRIPSTATSREQUEST']._serialized_end=129
+  _globals['_TRIPSTATS']._serialized_start=132
+  _globals['_TRIPSTATS']._serialized_end=363
+  _globals['_TRIPSTATSRESPONSE']._serialized_start=365
+  _globals['_TRIPSTATSRESPONSE']._serialized_end=456
+  _globals['_TRAFFICLIGHTSTATSREQUEST']._serialized_start=458
+  _globals['_TRAFFICLIGHTSTATSREQUEST']._serialized_end=558
+  _globals['_TRAFFICLIGHTGROUPSTATS']._serialized_start=561
+  _globals['_TRAFFICLIGHTGROUPSTATS']._serialized_end=779
+  _globals['_TRAFFICLIGHTSTATS']._serialized_start=782
+  _globals['_TRAFFICLIGHTSTATS']._serialized_end=1040
+  _globals['_TRAFFICLIGHTSTATSRESPONSE']._serialized_start=1042
+  _globals['_TRAFFICLIGHTSTATSRESPONSE']._serialized_end=1149
+  _globals['_MFDMEASURE']._serialized_start=1151
+  _globals['_MFDMEASURE']._serialized_end=1244
+  _globals['_MFDLINKMEASURE']._serialized_start=1246
+  _globals['_MFDLINKMEASURE']._serialized_end=1332
+  _globals['_MFDSAMPLE']._serialized_start=1335
+  _globals['_MFDSAMPLE']._serialized_end=1484
+# @@protoc_insertion_point(module_scope)
@@ -0,0 +1,140 @@
+import uuid_pb2 as _uuid_pb2
+from google.protobuf.internal import containers as _containers
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import message as _message
+from collections.abc import Iterable as _Iterable, Mapping as _Mapping
+from typing import ClassVar as _ClassVar, Optional as _Optional, Union as _Union
+
+DESCRIPTOR: _descriptor.FileDescriptor
+
+class TripStatsRequest(_message.Message):
+    __slots__ = ("session_id", "trip_ids")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    TRIP_IDS_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    trip_ids: _containers.RepeatedScalarFieldContainer[int]
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., trip_ids: _Optional[_Iterable[int]] = ...) -> None: ...
+
+class TripStats(_message.Message):
+    __slots__ = ("trip_id", "generated", "arrived", "lost", "mean_travel_time", "p50_travel_time", "p85_travel_time", "p95_travel_time", "free_flow_travel_time", "delay_index")
+    TRIP_ID_FIELD_NUMBER: _ClassVar[int]
+    GENERATED_FIELD_NUMBER: _ClassVar[int]
+    ARRIVED_FIELD_NUMBER: _ClassVar[int]
+    LOST_FIELD_NUMBER: _ClassVar[int]
+    MEAN_TRAVEL_TIME_FIELD_NUMBER: _ClassVar[int]
+    P50_TRAVEL_TIME_FIELD_NUMBER: _ClassVar[int]
+    P85_TRAVEL_TIME_FIELD_NUMBER: _ClassVar[int]
+    P95_TRAVEL_TIME_FIELD_NUMBER: _ClassVar[int]
+    FREE_FLOW_TRAVEL_TIME_FIELD_NUMBER: _ClassVar[int]
+    DELAY_INDEX_FIELD_NUMBER: _ClassVar[int]
+    trip_id: int
+    generated: int
+    arrived: int
+    lost: int
+    mean_travel_time: float
+    p50_travel_time: float
+    p85_travel_time: float
+    p95_travel_time: float
+    free_flow_travel_time: float
+    delay_index: float
+    def __init__(self, trip_id: _Optional[int] = ..., generated: _Optional[int] = ..., arrived: _Optional[int] = ..., lost: _Optional[int] = ..., mean_travel_time: _Optional[float] = ..., p50_travel_time: _Optional[float] = ..., p85_travel_time: _Optional[float] = ..., p95_travel_time: _Optional[float] = ..., free_flow_travel_time: _Optional[float] = ..., delay_index: _Optional[float] = ...) -> None: ...
+
+class TripStatsResponse(_message.Message):
+    __slots__ = ("code", "text", "data")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    DATA_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    data: _containers.RepeatedCompositeFieldContainer[TripStats]
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., data: _Optional[_Iterable[_Union[TripStats, _Mapping]]] = ...) -> None: ...
+
+class TrafficLightStatsRequest(_message.Message):
+    __slots__ = ("session_id", "traffic_light_ids")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    TRAFFIC_LIGHT_IDS_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    traffic_light_ids: _containers.RepeatedScalarFieldContainer[int]
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., traffic_light_ids: _Optional[_Iterable[int]] = ...) -> None: ...
+
+class TrafficLightGroupStats(_message.Message):
+    __slots__ = ("group_id", "vehicles_served", "green_phases", "vehicles_per_green", "mean_control_delay", "stops_per_vehicle", "max_queue", "green_utilization")
+    GROUP_ID_FIELD_NUMBER: _ClassVar[int]
+    VEHICLES_SERVED_FIELD_NUMBER: _ClassVar[int]
+    GREEN_PHASES_FIELD_NUMBER: _ClassVar[int]
+    VEHICLES_PER_GREEN_FIELD_NUMBER: _ClassVar[int]
+    MEAN_CONTROL_DELAY_FIELD_NUMBER: _ClassVar[int]
+    STOPS_PER_VEHICLE_FIELD_NUMBER: _ClassVar[int]
+    MAX_QUEUE_FIELD_NUMBER: _ClassVar[int]
+    GREEN_UTILIZATION_FIELD_NUMBER: _ClassVar[int]
+    group_id: int
+    vehicles_served: int
+    green_phases: int
+    vehicles_per_green: float
+    mean_control_delay: float
+    stops_per_vehicle: float
+    max_queue: int
+    green_utilization: float
+    def __init__(self, group_id: _Optional[int] = ..., vehicles_served: _Optional[int] = ..., green_phases: _Optional[int] = ..., vehicles_per_green: _Optional[float] = ..., mean_control_delay: _Optional[float] = ..., stops_per_vehicle: _Optional[float] = ..., max_queue: _Optional[int] = ..., green_utilization: _Optional[float] = ...) -> None: ...
+
+class TrafficLightStats(_message.Message):
+    __slots__ = ("traffic_light_id", "vehicles_served", "vehicles_per_green", "mean_control_delay", "stops_per_vehicle", "max_queue", "green_utilization", "groups")
+    TRAFFIC_LIGHT_ID_FIELD_NUMBER: _ClassVar[int]
+    VEHICLES_SERVED_FIELD_NUMBER: _ClassVar[int]
+    VEHICLES_PER_GREEN_FIELD_NUMBER: _ClassVar[int]
+    MEAN_CONTROL_DELAY_FIELD_NUMBER: _ClassVar[int]
+    STOPS_PER_VEHICLE_FIELD_NUMBER: _ClassVar[int]
+    MAX_QUEUE_FIELD_NUMBER: _ClassVar[int]
+    GREEN_UTILIZATION_FIELD_NUMBER: _ClassVar[int]
+    GROUPS_FIELD_NUMBER: _ClassVar[int]
+    traffic_light_id: int
+    vehicles_served: int
+    vehicles_per_green: float
+    mean_control_delay: float
+    stops_per_vehicle: float
+    max_queue: int
+    green_utilization: float
+    groups: _containers.RepeatedCompositeFieldContainer[TrafficLightGroupStats]
+    def __init__(self, traffic_light_id: _Optional[int] = ..., vehicles_served: _Optional[int] = ..., vehicles_per_green: _Optional[float] = ..., mean_control_delay: _Optional[float] = ..., stops_per_vehicle: _Optional[float] = ..., max_queue: _Optional[int] = ..., green_utilization: _Optional[float] = ..., groups: _Optional[_Iterable[_Union[TrafficLightGroupStats, _Mapping]]] = ...) -> None: ...
+
+class TrafficLightStatsResponse(_message.Message):
+    __slots__ = ("code", "text", "data")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    DATA_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    data: _containers.RepeatedCompositeFieldContainer[TrafficLightStats]
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., data: _Optional[_Iterable[_Union[TrafficLightStats, _Mapping]]] = ...) -> None: ...
+
+class MfdMeasure(_message.Message):
+    __slots__ = ("vkt", "total_time_spent", "production", "accumulation")
+    VKT_FIELD_NUMBER: _ClassVar[int]
+    TOTAL_TIME_SPENT_FIELD_NUMBER: _ClassVar[int]
+    PRODUCTION_FIELD_NUMBER: _ClassVar[int]
+    ACCUMULATION_FIELD_NUMBER: _ClassVar[int]
+    vkt: float
+    total_time_spent: float
+    production: float
+    accumulation: float
+    def __init__(self, vkt: _Optional[float] = ..., total_time_spent: _Optional[float] = ..., production: _Optional[float] = ..., accumulation: _Optional[float] = ...) -> None: ...
+
+class MfdLinkMeasure(_message.Message):
+    __slots__ = ("meso_link_id", "measure")
+    MESO_LINK_ID_FIELD_NUMBER: _ClassVar[int]
+    MEASURE_FIELD_NUMBER: _ClassVar[int]
+    meso_link_id: int
+    measure: MfdMeasure
+    def __init__(self, meso_link_id: _Optional[int] = ..., measure: _Optional[_Union[MfdMeasure, _Mapping]] = ...) -> None: ...
+
+class MfdSample(_message.Message):
+    __slots__ = ("tick_start", "tick_count", "network", "links")
+    TICK_START_FIELD_NUMBER: _ClassVar[int]
+    TICK_COUNT_FIELD_NUMBER: _ClassVar[int]
+    NETWORK_FIELD_NUMBER: _ClassVar[int]
+    LINKS_FIELD_NUMBER: _ClassVar[int]
+    tick_start: int
+    tick_count: int
+    network: MfdMeasure
+    links: _containers.RepeatedCompositeFieldContainer[MfdLinkMeasure]
+    def __init__(self, tick_start: _Optional[int] = ..., tick_count: _Optional[int] = ..., network: _Optional[_Union[MfdMeasure, _Mapping]] = ..., links: _Optional[_Iterable[_Union[MfdLinkMeasure, _Mapping]]] = ...) -> None: ...
@@ -25,7 +25,7 @@ _sym_db = _symbol_database.Default()
 from . import uuid_pb2 as uuid__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\nstep.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"<\n\x0bSessionStep\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\"\xaa\x01\n\x13SessionStepResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x11\n\ttimestamp\x18\x03 \x01(\x03\x12\x35\n\x0cvehicle_data\x18\x04 \x03(\x0b\x32\x1f.micro_traffic_sim.VehicleState\x12-\n\x08tls_data\x18\x05 \x03(\x0b\x32\x1b.micro_traffic_sim.TLSState\"\xda\x01\n\x0cVehicleState\x12\x12\n\nvehicle_id\x18\x01 \x01(\x03\x12\x32\n\x0cvehicle_type\x18\x02 \x01(\x0e\x32\x1c.micro_traffic_sim.AgentType\x12\x0f\n\x07\x62\x65\x61ring\x18\x03 \x01(\x01\x12\r\n\x05speed\x18\x04 \x01(\x03\x12\x0c\n\x04\x63\x65ll\x18\x05 \x01(\x03\x12\x1a\n\x12intermediate_cells\x18\x06 \x03(\x03\x12\x13\n\x0btravel_time\x18\x07 \x01(\x03\x12\x0f\n\x07trip_id\x18\x08 \x01(\x03\x12\x12\n\ntail_cells\x18\t \x03(\x03\"B\n\x08TLSState\x12\n\n\x02id\x18\x01 \x01(\x03\x12*\n\x06groups\x18\x02 \x03(\x0b\x32\x1a.micro_traffic_sim.TLGroup\"%\n\x07TLGroup\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x0e\n\x06signal\x18\x02 \x01(\t*\xad\x01\n\tAgentType\x12\x18\n\x14\x41GENT_TYPE_UNDEFINED\x10\x00\x12\x12\n\x0e\x41GENT_TYPE_CAR\x10\x01\x12\x12\n\x0e\x41GENT_TYPE_BUS\x10\x02\x12\x13\n\x0f\x41GENT_TYPE_TAXI\x10\x03\x12\x19\n\x15\x41GENT_TYPE_PEDESTRIAN\x10\x04\x12\x14\n\x10\x41GENT_TYPE_TRUCK\x10\x05\x12\x18\n\x14\x41GENT_TYPE_LARGE_BUS\x10\x06\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\nstep.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\"<\n\x0bSessionStep\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\"\x88\x02\n\x13SessionStepResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x11\n\ttimestamp\x18\x03 \x01(\x03\x12\x35\n\x0cvehicle_data\x18\x04 \x03(\x0b\x32\x1f.micro_traffic_sim.VehicleState\x12-\n\x08tls_data\x18\x05 \x03(\x0b\x32\x1b.micro_traffic_sim.TLSState\x12/\n\x06\x65vents\x18\x06 \x03(\x0b\x32\x1f.micro_traffic_sim.VehicleEvent\x12+\n\x05stats\x18\x07 \x01(\x0b\x32\x1c.micro_traffic_sim.TickStats\"\xcb\x01\n\tTickStats\x12\x17\n\x0f\x61\x63tive_vehicles\x18\x01 \x01(\r\x12\x0f\n\x07spawned\x18\x02 \x01(\r\x12\x0f\n\x07\x61rrived\x18\x03 \x01(\r\x12\x0c\n\x04lost\x18\x04 \x01(\r\x12\x12\n\nmean_speed\x18\x05 \x01(\x01\x12\x18\n\x10stopped_vehicles\x18\x06 \x01(\r\x12\x14\n\x0cqueue_length\x18\x07 \x01(\r\x12\x1a\n\x12vehicles_completed\x18\x08 \x01(\x05\x12\x15\n\rvehicles_lost\x18\t \x01(\x05\"\xda\x01\n\x0cVehicleState\x12\x12\n\nvehicle_id\x18\x01 \x01(\x03\x12\x32\n\x0cvehicle_type\x18\x02 \x01(\x0e\x32\x1c.micro_traffic_sim.AgentType\x12\x0f\n\x07\x62\x65\x61ring\x18\x03 \x01(\x01\x12\r\n\x05speed\x18\x04 \x01(\x03\x12\x0c\n\x04\x63\x65ll\x18\x05 \x01(\x03\x12\x1a\n\x12intermediate_cells\x18\x06 \x03(\x03\x12\x13\n\x0btravel_time\x18\x07 \x01(\x03\x12\x0f\n\x07trip_id\x18\x08 \x01(\x03\x12\x12\n\ntail_cells\x18\t \x03(\x03\"B\n\x08TLSState\x12\n\n\x02id\x18\x01 \x01(\x03\x12*\n\x06groups\x18\x02 \x03(\x0b\x32\x1a.micro_traffic_sim.TLGroup\"%\n\x07TLGroup\x12\n\n\x02id\x18\x01 \x01(\x03\x12\x0e\n\x06signal\x18\x02 \x01(\t\"\x8d\x01\n\x0cVehicleEvent\x12\x12\n\nvehicle_id\x18\x01 \x01(\x03\x12\x37\n\nevent_type\x18\x02 \x01(\x0e\x32#.micro_traffic_sim.VehicleEventType\x12\x0f\n\x07trip_id\x18\x03 \x01(\x03\x12\x0c\n\x04\x63\x65ll\x18\x04 \x01(\x03\x12\x11\n\tfrom_cell\x18\x05 \x01(\x03*\xad\x01\n\tAgentType\x12\x18\n\x14\x41GENT_TYPE_UNDEFINED\x10\x00\x12\x12\n\x0e\x41GENT_TYPE_CAR\x10\x01\x12\x12\n\x0e\x41GENT_TYPE_BUS\x10\x02\x12\x13\n\x0f\x41GENT_TYPE_TAXI\x10\x03\x12\x19\n\x15\x41GENT_TYPE_PEDESTRIAN\x10\x04\x12\x14\n\x10\x41GENT_TYPE_TRUCK\x10\x05\x12\x18\n\x14\x41GENT_TYPE_LARGE_BUS\x10\x06*\xa4\x02\n\x10VehicleEventType\x12 \n\x1cVEHICLE_EVENT_TYPE_UNDEFINED\x10\x00\x12\x1e\n\x1aVEHICLE_EVENT_TYPE_SPAWNED\x10\x01\x12\x1e\n\x1aVEHICLE_EVENT_TYPE_ARRIVED\x10\x02\x12\x1b\n\x17VEHICLE_EVENT_TYPE_LOST\x10\x03\x12#\n\x1fVEHICLE_EVENT_TYPE_LANE_CHANGED\x10\x04\x12%\n!VEHICLE_EVENT_TYPE_RED_LIGHT_STOP\x10\x05\x12%\n!VEHICLE_EVENT_TYPE_CONFLICT_YIELD\x10\x06\x12\x1e\n\x1aVEHICLE_EVENT_TYPE_REMOVED\x10\x07\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
 
 _globals = globals()
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
@@ -33,16 +33,22 @@ _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'step_pb2', _globals)
 if not _descriptor._USE_C_DESCRIPTORS:
   _globals['DESCRIPTOR']._loaded_options = None
   _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
-  _globals['_AGENTTYPE']._serialized_start=609
-  _globals['_AGENTTYPE']._serialized_end=782
+  _globals['_AGENTTYPE']._serialized_start=1053
+  _globals['_AGENTTYPE']._serialized_end=1226
+  _globals['_VEHICLEEVENTTYPE']._serialized_start=1229
+  _globals['_VEHICLEEVENTTYPE']._serialized_end=1521
   _globals['_SESSIONSTEP']._serialized_start=45
   _globals['_SESSIONSTEP']._serialized_end=105
   _globals['_SESSIONSTEPRESPONSE']._serialized_start=108
-  _globals['_SESSIONSTEPRESPONSE']._serialized_end=278
-  _globals['_VEHICLESTATE']._serialized_start=281
-  _globals['_VEHICLESTATE']._serialized_end=499
-  _globals['_TLSSTATE']._serialized_start=501
-  _globals['_TLSSTATE']._serialized_end=567
-  _globals['_TLGROUP']._serialized_start=569
-  _globals['_TLGROUP']._serialized_end=606
+  _globals['_SESSIONSTEPRESPONSE']._serialized_end=372
+  _globals['_TICKSTATS']._serialized_start=375
+  _globals['_TICKSTATS']._serialized_end=578
+  _globals['_VEHICLESTATE']._serialized_start=581
+  _globals['_VEHICLESTATE']._serialized_end=799
+  _globals['_TLSSTATE']._serialized_start=801
+  _globals['_TLSSTATE']._serialized_end=867
+  _globals['_TLGROUP']._serialized_start=869
+  _globals['_TLGROUP']._serialized_end=906
+  _globals['_VEHICLEEVENT']._serialized_start=909
+  _globals['_VEHICLEEVENT']._serialized_end=1050
 # @@protoc_insertion_point(module_scope)
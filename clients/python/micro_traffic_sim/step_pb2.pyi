@@ -17,6 +17,17 @@ class AgentType(int, metaclass=_enum_type_wrapper.EnumTypeWrapper):
     AGENT_TYPE_PEDESTRIAN: _ClassVar[AgentType]
     AGENT_TYPE_TRUCK: _ClassVar[AgentType]
     AGENT_TYPE_LARGE_BUS: _ClassVar[AgentType]
+
+class VehicleEventType(int, metaclass=_enum_type_wrapper.EnumTypeWrapper):
+    __slots__ = ()
+    VEHICLE_EVENT_TYPE_UNDEFINED: _ClassVar[VehicleEventType]
+    VEHICLE_EVENT_TYPE_SPAWNED: _ClassVar[VehicleEventType]
+    VEHICLE_EVENT_TYPE_ARRIVED: _ClassVar[VehicleEventType]
+    VEHICLE_EVENT_TYPE_LOST: _ClassVar[VehicleEventType]
+    VEHICLE_EVENT_TYPE_LANE_CHANGED: _ClassVar[VehicleEventType]
+    VEHICLE_EVENT_TYPE_RED_LIGHT_STOP: _ClassVar[VehicleEventType]
+    VEHICLE_EVENT_TYPE_CONFLICT_YIELD: _ClassVar[VehicleEventType]
+    VEHICLE_EVENT_TYPE_REMOVED: _ClassVar[VehicleEventType]
 AGENT_TYPE_UNDEFINED: AgentType
 AGENT_TYPE_CAR: AgentType
 AGENT_TYPE_BUS: AgentType
@@ -24,6 +35,14 @@ AGENT_TYPE_TAXI: AgentType
 AGENT_TYPE_PEDESTRIAN: AgentType
 AGENT_TYPE_TRUCK: AgentType
 AGENT_TYPE_LARGE_BUS: AgentType
+VEHICLE_EVENT_TYPE_UNDEFINED: VehicleEventType
+VEHICLE_EVENT_TYPE_SPAWNED: VehicleEventType
+VEHICLE_EVENT_TYPE_ARRIVED: VehicleEventType
+VEHICLE_EVENT_TYPE_LOST: VehicleEventType
+VEHICLE_EVENT_TYPE_LANE_CHANGED: VehicleEventType
+VEHICLE_EVENT_TYPE_RED_LIGHT_STOP: VehicleEventType
+VEHICLE_EVENT_TYPE_CONFLICT_YIELD: VehicleEventType
+VEHICLE_EVENT_TYPE_REMOVED: VehicleEventType
 
 class SessionStep(_message.Message):
     __slots__ = ("session_id",)
@@ -32,18 +51,44 @@ class SessionStep(_message.Message):
     def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ...) -> None: ...
 
 class SessionStepResponse(_message.Message):
-    __slots__ = ("code", "text", "timestamp", "vehicle_data", "tls_data")
+    __slots__ = ("code", "text", "timestamp", "vehicle_data", "tls_data", "events", "stats")
     CODE_FIELD_NUMBER: _ClassVar[int]
     TEXT_FIELD_NUMBER: _ClassVar[int]
     TIMESTAMP_FIELD_NUMBER: _ClassVar[int]
     VEHICLE_DATA_FIELD_NUMBER: _ClassVar[int]
     TLS_DATA_FIELD_NUMBER: _ClassVar[int]
+    EVENTS_FIELD_NUMBER: _ClassVar[int]
+    STATS_FIELD_NUMBER: _ClassVar[int]
     code: int
     text: str
     timestamp: int
     vehicle_data: _containers.RepeatedCompositeFieldContainer[VehicleState]
     tls_data: _containers.RepeatedCompositeFieldContainer[TLSState]
-    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., timestamp: _Optional[int] = ..., vehicle_data: _Optional[_Iterable[_Union[VehicleState, _Mapping]]] = ..., tls_data: _Optional[_Iterable[_Union[TLSState, _Mapping]]] = ...) -> None: ...
+    events: _containers.RepeatedCompositeFieldContainer[VehicleEvent]
+    stats: TickStats
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., timestamp: _Optional[int] = ..., vehicle_data: _Optional[_Iterable[_Union[VehicleState, _Mapping]]] = ..., tls_data: _Optional[_Iterable[_Union[TLSState, _Mapping]]] = ..., events: _Optional[_Iterable[_Union[VehicleEvent, _Mapping]]] = ..., stats: _Optional[_Union[TickStats, _Mapping]] = ...) -> None: ...
+
+class TickStats(_message.Message):
+    __slots__ = ("active_vehicles", "spawned", "arrived", "lost", "mean_speed", "stopped_vehicles", "queue_length", "vehicles_completed", "vehicles_lost")
+    ACTIVE_VEHICLES_FIELD_NUMBER: _ClassVar[int]
+    SPAWNED_FIELD_NUMBER: _ClassVar[int]
+    ARRIVED_FIELD_NUMBER: _ClassVar[int]
+    LOST_FIELD_NUMBER: _ClassVar[int]
+    MEAN_SPEED_FIELD_NUMBER: _ClassVar[int]
+    STOPPED_VEHICLES_FIELD_NUMBER: _ClassVar[int]
+    QUEUE_LENGTH_FIELD_NUMBER: _ClassVar[int]
+    VEHICLES_COMPLETED_FIELD_NUMBER: _ClassVar[int]
+    VEHICLES_LOST_FIELD_NUMBER: _ClassVar[int]
+    active_vehicles: int
+    spawned: int
+    arrived: int
+    lost: int
+    mean_speed: float
+    stopped_vehicles: int
+    queue_length: int
+    vehicles_completed: int
+    vehicles_lost: int
+    def __init__(self, active_vehicles: _Optional[int] = ..., spawned: _Optional[int] = ..., arrived: _Optional[int] = ..., lost: _Optional[int] = ..., mean_speed: _Optional[float] = ..., stopped_vehicles: _Optional[int] = ..., queue_length: _Optional[int] = ..., vehicles_completed: _Optional[int] = ..., vehicles_lost: _Optional[int] = ...) -> None: ...
 
 class VehicleState(_message.Message):
     __slots__ = ("vehicle_id", "vehicle_type", "bearing", "speed", "cell", "intermediate_cells", "travel_time", "trip_id", "tail_cells")
@@ -82,3 +127,17 @@ class TLGroup(_message.Message):
     id: int
     signal: str
     def __init__(self, id: _Optional[int] = ..., signal: _Optional[str] = ...) -> None: ...
+
+class VehicleEvent(_message.Message):
+    __slots__ = ("vehicle_id", "event_type", "trip_id", "cell", "from_cell")
+    VEHICLE_ID_FIELD_NUMBER: _ClassVar[int]
+    EVENT_TYPE_FIELD_NUMBER: _ClassVar[int]
+    TRIP_ID_FIELD_NUMBER: _ClassVar[int]
+    CELL_FIELD_NUMBER: _ClassVar[int]
+    FROM_CELL_FIELD_NUMBER: _ClassVar[int]
+    vehicle_id: int
+    event_type: VehicleEventType
+    trip_id: int
+    cell: int
+    from_cell: int
+    def __init__(self, vehicle_id: _Optional[int] = ..., event_type: _Optional[_Union[VehicleEventType, str]] = ..., trip_id: _Optional[int] = ..., cell: _Optional[int] = ..., from_cell: _Optional[int] = ...) -> None: ...
@@ -26,7 +26,7 @@ from . import uuid_pb2 as uuid__pb2
 from . import step_pb2 as step__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\ntrip.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\x1a\nstep.proto\"\xd8\x02\n\x04Trip\x12\n\n\x02id\x18\x01 \x01(\x03\x12.\n\ttrip_type\x18\x02 \x01(\x0e\x32\x1b.micro_traffic_sim.TripType\x12\x11\n\tfrom_node\x18\x03 \x01(\x03\x12\x0f\n\x07to_node\x18\x04 \x01(\x03\x12\x15\n\rinitial_speed\x18\x05 \x01(\x03\x12\x13\n\x0bprobability\x18\x06 \x01(\x01\x12\x30\n\nagent_type\x18\x07 \x01(\x0e\x32\x1c.micro_traffic_sim.AgentType\x12\x38\n\x0e\x62\x65haviour_type\x18\x08 \x01(\x0e\x32 .micro_traffic_sim.BehaviourType\x12\x0c\n\x04time\x18\t \x01(\x03\x12\x12\n\nstart_time\x18\n \x01(\x03\x12\x10\n\x08\x65nd_time\x18\x0b \x01(\x03\x12\x12\n\nrelax_time\x18\x0c \x01(\x03\x12\x10\n\x08transits\x18\r \x03(\x03\"c\n\x0bSessionTrip\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12%\n\x04\x64\x61ta\x18\x02 \x03(\x0b\x32\x17.micro_traffic_sim.Trip\"1\n\x13SessionTripResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t*Q\n\x08TripType\x12\x17\n\x13TRIP_TYPE_UNDEFINED\x10\x00\x12\x16\n\x12TRIP_TYPE_CONSTANT\x10\x01\x12\x14\n\x10TRIP_TYPE_RANDOM\x10\x02*\xae\x01\n\rBehaviourType\x12\x1c\n\x18\x42\x45HAVIOUR_TYPE_UNDEFINED\x10\x00\x12\x18\n\x14\x42\x45HAVIOUR_TYPE_BLOCK\x10\x01\x12\x1d\n\x19\x42\x45HAVIOUR_TYPE_AGGRESSIVE\x10\x02\x12\x1e\n\x1a\x42\x45HAVIOUR_TYPE_COOPERATIVE\x10\x03\x12&\n\"BEHAVIOUR_TYPE_LIMIT_SPEED_BY_TRIP\x10\x04\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\ntrip.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\x1a\nstep.proto\"\x8e\x03\n\x04Trip\x12\n\n\x02id\x18\x01 \x01(\x03\x12.\n\ttrip_type\x18\x02 \x01(\x0e\x32\x1b.micro_traffic_sim.TripType\x12\x11\n\tfrom_node\x18\x03 \x01(\x03\x12\x0f\n\x07to_node\x18\x04 \x01(\x03\x12\x15\n\rinitial_speed\x18\x05 \x01(\x03\x12\x13\n\x0bprobability\x18\x06 \x01(\x01\x12\x30\n\nagent_type\x18\x07 \x01(\x0e\x32\x1c.micro_traffic_sim.AgentType\x12\x38\n\x0e\x62\x65haviour_type\x18\x08 \x01(\x0e\x32 .micro_traffic_sim.BehaviourType\x12\x0c\n\x04time\x18\t \x01(\x03\x12\x12\n\nstart_time\x18\n \x01(\x03\x12\x10\n\x08\x65nd_time\x18\x0b \x01(\x03\x12\x12\n\nrelax_time\x18\x0c \x01(\x03\x12\x10\n\x08transits\x18\r \x03(\x03\x12\x34\n\x0e\x64\x65mand_profile\x18\x0e \x03(\x0b\x32\x1c.micro_traffic_sim.DemandBin\"B\n\tDemandBin\x12\x12\n\nstart_time\x18\x01 \x01(\x03\x12\x13\n\x0bprobability\x18\x02 \x01(\x01\x12\x0c\n\x04time\x18\x03 \x01(\x03\"\x7f\n\x0bSessionTrip\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12%\n\x04\x64\x61ta\x18\x02 \x03(\x0b\x32\x17.micro_traffic_sim.Trip\x12\x1a\n\x12reject_unreachable\x18\x03 \x01(\x08\"o\n\x10TripReachability\x12\x0f\n\x07trip_id\x18\x01 \x01(\x03\x12\x11\n\treachable\x18\x02 \x01(\x08\x12\x12\n\npath_cells\x18\x03 \x01(\r\x12\x13\n\x0bpath_length\x18\x04 \x01(\x01\x12\x0e\n\x06reason\x18\x05 \x01(\t\"l\n\x13SessionTripResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x39\n\x0creachability\x18\x03 \x03(\x0b\x32#.micro_traffic_sim.TripReachability\"p\n\x18UpdateSessionTripRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12%\n\x04trip\x18\x02 \x01(\x0b\x32\x17.micro_traffic_sim.Trip\"7\n\x19UpdateSessionTripResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\"Z\n\x18RemoveSessionTripRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x0f\n\x07trip_id\x18\x02 \x01(\x03\"7\n\x19RemoveSessionTripResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\"m\n\x1bSetSessionTripPausedRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x0f\n\x07trip_id\x18\x02 \x01(\x03\x12\x0e\n\x06paused\x18\x03 \x01(\x08\":\n\x1cSetSessionTripPausedResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t*Q\n\x08TripType\x12\x17\n\x13TRIP_TYPE_UNDEFINED\x10\x00\x12\x16\n\x12TRIP_TYPE_CONSTANT\x10\x01\x12\x14\n\x10TRIP_TYPE_RANDOM\x10\x02*\xae\x01\n\rBehaviourType\x12\x1c\n\x18\x42\x45HAVIOUR_TYPE_UNDEFINED\x10\x00\x12\x18\n\x14\x42\x45HAVIOUR_TYPE_BLOCK\x10\x01\x12\x1d\n\x19\x42\x45HAVIOUR_TYPE_AGGRESSIVE\x10\x02\x12\x1e\n\x1a\x42\x45HAVIOUR_TYPE_COOPERATIVE\x10\x03\x12&\n\"BEHAVIOUR_TYPE_LIMIT_SPEED_BY_TRIP\x10\x04\x42@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
 
 _globals = globals()
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
@@ -34,14 +34,30 @@ _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'trip_pb2', _globals)
 if not _descriptor._USE_C_DESCRIPTORS:
   _globals['DESCRIPTOR']._loaded_options = None
   _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
-  _globals['_TRIPTYPE']._serialized_start=556
-  _globals['_TRIPTYPE']._serialized_end=637
-  _globals['_BEHAVIOURTYPE']._serialized_start=640
-  _globals['_BEHAVIOURTYPE']._serialized_end=814
+  _globals['_TRIPTYPE']._serialized_start=1369
+  _globals['_TRIPTYPE']._serialized_end=1450
+  _globals['_BEHAVIOURTYPE']._serialized_start=1453
+  _globals['_BEHAVIOURTYPE']._serialized_end=1627
   _globals['_TRIP']._serialized_start=58
-  _globals['_TRIP']._serialized_end=402
-  _globals['_SESSIONTRIP']._serialized_start=404
-  _globals['_SESSIONTRIP']._serialized_end=503
-  _globals['_SESSIONTRIPRESPONSE']._serialized_start=505
-  _globals['_SESSIONTRIPRESPONSE']._serialized_end=554
+  _globals['_TRIP']._serialized_end=456
+  _globals['_DEMANDBIN']._serialized_start=458
+  _globals['_DEMANDBIN']._serialized_end=524
+  _globals['_SESSIONTRIP']._serialized_start=526
+  _globals['_SESSIONTRIP']._serialized_end=653
+  _globals['_TRIPREACHABILITY']._serialized_start=655
+  _globals['_TRIPREACHABILITY']._serialized_end=766
+  _globals['_SESSIONTRIPRESPONSE']._serialized_start=768
+  _globals['_SESSIONTRIPRESPONSE']._serialized_end=876
+  _globals['_UPDATESESSIONTRIPREQUEST']._serialized_start=878
+  _globals['_UPDATESESSIONTRIPREQUEST']._serialized_end=990
+  _globals['_UPDATESESSIONTRIPRESPONSE']._serialized_start=992
+  _globals['_UPDATESESSIONTRIPRESPONSE']._serialized_end=1047
+  _globals['_REMOVESESSIONTRIPREQUEST']._serialized_start=1049
+  _globals['_REMOVESESSIONTRIPREQUEST']._serialized_end=1139
+  _globals['_REMOVESESSIONTRIPRESPONSE']._serialized_start=1141
+  _globals['_REMOVESESSIONTRIPRESPONSE']._serialized_end=1196
+  _globals['_SETSESSIONTRIPPAUSEDREQUEST']._serialized_start=1198
+  _globals['_SETSESSIONTRIPPAUSEDREQUEST']._serialized_end=1307
+  _globals['_SETSESSIONTRIPPAUSEDRESPONSE']._serialized_start=1309
+  _globals['_SETSESSIONTRIPPAUSEDRESPONSE']._serialized_end=1367
 # @@protoc_insertion_point(module_scope)
@@ -32,7 +32,7 @@ BEHAVIOUR_TYPE_COOPERATIVE: BehaviourType
 BEHAVIOUR_TYPE_LIMIT_SPEED_BY_TRIP: BehaviourType
 
 class Trip(_message.Message):
-    __slots__ = ("id", "trip_type", "from_node", "to_node", "initial_speed", "probability", "agent_type", "behaviour_type", "time", "start_time", "end_time", "relax_time", "transits")
+    __slots__ = ("id", "trip_type", "from_node", "to_node", "initial_speed", "probability", "agent_type", "behaviour_type", "time", "start_time", "end_time", "relax_time", "transits", "demand_profile")
     ID_FIELD_NUMBER: _ClassVar[int]
     TRIP_TYPE_FIELD_NUMBER: _ClassVar[int]
     FROM_NODE_FIELD_NUMBER: _ClassVar[int]
@@ -46,6 +46,7 @@ class Trip(_message.Message):
     END_TIME_FIELD_NUMBER: _ClassVar[int]
     RELAX_TIME_FIELD_NUMBER: _ClassVar[int]
     TRANSITS_FIELD_NUMBER: _ClassVar[int]
+    DEMAND_PROFILE_FIELD_NUMBER: _ClassVar[int]
     id: int
     trip_type: TripType
     from_node: int
@@ -59,17 +60,96 @@ class Trip(_message.Message):
     end_time: int
     relax_time: int
     transits: _containers.RepeatedScalarFieldContainer[int]
-    def __init__(self, id: _Optional[int] = ..., trip_type: _Optional[_Union[TripType, str]] = ..., from_node: _Optional[int] = ..., to_node: _Optional[int] = ..., initial_speed: _Optional[int] = ..., probability: _Optional[float] = ..., agent_type: _Optional[_Union[_step_pb2.AgentType, str]] = ..., behaviour_type: _Optional[_Union[BehaviourType, str]] = ..., time: _Optional[int] = ..., start_time: _Optional[int] = ..., end_time: _Optional[int] = ..., relax_time: _Optional[int] = ..., transits: _Optional[_Iterable[int]] = ...) -> None: ...
+    demand_profile: _containers.RepeatedCompositeFieldContainer[DemandBin]
+    def __init__(self, id: _Optional[int] = ..., trip_type: _Optional[_Union[TripType, str]] = ..., from_node: _Optional[int] = ..., to_node: _Optional[int] = ..., initial_speed: _Optional[int] = ..., probability: _Optional[float] = ..., agent_type: _Optional[_Union[_step_pb2.AgentType, str]] = ..., behaviour_type: _Optional[_Union[BehaviourType, str]] = ..., time: _Optional[int] = ..., start_time: _Optional[int] = ..., end_time: _Optional[int] = ..., relax_time: _Optional[int] = ..., transits: _Optional[_Iterable[int]] = ..., demand_profile: _Optional[_Iterable[_Union[DemandBin, _Mapping]]] = ...) -> None: ...
+
+class DemandBin(_message.Message):
+    __slots__ = ("start_time", "probability", "time")
+    START_TIME_FIELD_NUMBER: _ClassVar[int]
+    PROBABILITY_FIELD_NUMBER: _ClassVar[int]
+    TIME_FIELD_NUMBER: _ClassVar[int]
+    start_time: int
+    probability: float
+    time: int
+    def __init__(self, start_time: _Optional[int] = ..., probability: _Optional[float] = ..., time: _Optional[int] = ...) -> None: ...
 
 class SessionTrip(_message.Message):
-    __slots__ = ("session_id", "data")
+    __slots__ = ("session_id", "data", "reject_unreachable")
     SESSION_ID_FIELD_NUMBER: _ClassVar[int]
     DATA_FIELD_NUMBER: _ClassVar[int]
+    REJECT_UNREACHABLE_FIELD_NUMBER: _ClassVar[int]
     session_id: _uuid_pb2.UUIDv4
     data: _containers.RepeatedCompositeFieldContainer[Trip]
-    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., data: _Optional[_Iterable[_Union[Trip, _Mapping]]] = ...) -> None: ...
+    reject_unreachable: bool
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., data: _Optional[_Iterable[_Union[Trip, _Mapping]]] = ..., reject_unreachable: bool = ...) -> None: ...
+
+class TripReachability(_message.Message):
+    __slots__ = ("trip_id", "reachable", "path_cells", "path_length", "reason")
+    TRIP_ID_FIELD_NUMBER: _ClassVar[int]
+    REACHABLE_FIELD_NUMBER: _ClassVar[int]
+    PATH_CELLS_FIELD_NUMBER: _ClassVar[int]
+    PATH_LENGTH_FIELD_NUMBER: _ClassVar[int]
+    REASON_FIELD_NUMBER: _ClassVar[int]
+    trip_id: int
+    reachable: bool
+    path_cells: int
+    path_length: float
+    reason: str
+    def __init__(self, trip_id: _Optional[int] = ..., reachable: bool = ..., path_cells: _Optional[int] = ..., path_length: _Optional[float] = ..., reason: _Optional[str] = ...) -> None: ...
 
 class SessionTripResponse(_message.Message):
+    __slots__ = ("code", "text", "reachability")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    REACHABILITY_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    reachability: _containers.RepeatedCompositeFieldContainer[TripReachability]
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., reachability: _Optional[_Iterable[_Union[TripReachability, _Mapping]]] = ...) -> None: ...
+
+class UpdateSessionTripRequest(_message.Message):
+    __slots__ = ("session_id", "trip")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    TRIP_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    trip: Trip
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., trip: _Optional[_Union[Trip, _Mapping]] = ...) -> None: ...
+
+class UpdateSessionTripResponse(_message.Message):
+    __slots__ = ("code", "text")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ...) -> None: ...
+
+class RemoveSessionTripRequest(_message.Message):
+    __slots__ = ("session_id", "trip_id")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    TRIP_ID_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    trip_id: int
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., trip_id: _Optional[int] = ...) -> None: ...
+
+class RemoveSessionTripResponse(_message.Message):
+    __slots__ = ("code", "text")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ...) -> None: ...
+
+class SetSessionTripPausedRequest(_message.Message):
+    __slots__ = ("session_id", "trip_id", "paused")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    TRIP_ID_FIELD_NUMBER: _ClassVar[int]
+    PAUSED_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    trip_id: int
+    paused: bool
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., trip_id: _Optional[int] = ..., paused: bool = ...) -> None: ...
+
+class SetSessionTripPausedResponse(_message.Message):
     __slots__ = ("code", "text")
     CODE_FIELD_NUMBER: _ClassVar[int]
     TEXT_FIELD_NUMBER: _ClassVar[int]
@@ -0,0 +1,52 @@
+# -*- coding: utf-8 -*-
+# Generated by the protocol buffer compiler.  DO NOT EDIT!
+# NO CHECKED-IN PROTOBUF GENCODE
+# source: vehicle.proto
+# Protobuf Python Version: 6.31.1
+"""Generated protocol buffer code."""
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import descriptor_pool as _descriptor_pool
+from google.protobuf import runtime_version as _runtime_version
+from google.protobuf import symbol_database as _symbol_database
+from google.protobuf.internal import builder as _builder
+_runtime_version.ValidateProtobufRuntimeVersion(
+    _runtime_version.Domain.PUBLIC,
+    6,
+    31,
+    1,
+    '',
+    'vehicle.proto'
+)
+# @@protoc_insertion_point(imports)
+
+_sym_db = _symbol_database.Default()
+
+
+from . import uuid_pb2 as uuid__pb2
+from . import step_pb2 as step__pb2
+from . import trip_pb2 as trip__pb2
+
+
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\rvehicle.proto\x12\x11micro_traffic_sim\x1a\nuuid.proto\x1a\nstep.proto\x1a\ntrip.proto\"\x88\x02\n\x13SpawnVehicleRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x30\n\nagent_type\x18\x02 \x01(\x0e\x32\x1c.micro_traffic_sim.AgentType\x12\x38\n\x0e\x62\x65haviour_type\x18\x03 \x01(\x0e\x32 .micro_traffic_sim.BehaviourType\x12\x13\n\x0borigin_cell\x18\x04 \x01(\x03\x12\x18\n\x10\x64\x65stination_cell\x18\x05 \x01(\x03\x12\x10\n\x08transits\x18\x06 \x03(\x03\x12\x15\n\rinitial_speed\x18\x07 \x01(\x05\"F\n\x14SpawnVehicleResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12\x12\n\nvehicle_id\x18\x03 \x01(\x03\"Y\n\x14RemoveVehicleRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x12\n\nvehicle_id\x18\x02 \x01(\x03\"3\n\x15RemoveVehicleResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\"V\n\x11GetVehicleRequest\x12-\n\nsession_id\x18\x01 \x01(\x0b\x32\x19.micro_traffic_sim.UUIDv4\x12\x12\n\nvehicle_id\x18\x02 \x01(\x03\"\xdf\x02\n\x0bVehicleInfo\x12.\n\x05state\x18\x01 \x01(\x0b\x32\x1f.micro_traffic_sim.VehicleState\x12\x38\n\x0e\x62\x65haviour_type\x18\x02 \x01(\x0e\x32 .micro_traffic_sim.BehaviourType\x12\x13\n\x0bspeed_limit\x18\x03 \x01(\x05\x12\x13\n\x0b\x64\x65stination\x18\x04 \x01(\x03\x12\x18\n\x10trip_destination\x18\x05 \x01(\x03\x12\x1a\n\x12remaining_transits\x18\x06 \x03(\x03\x12\x16\n\x0eremaining_path\x18\x07 \x03(\x03\x12\x1d\n\x15\x64\x65stination_reachable\x18\x08 \x01(\x08\x12\x19\n\x11steps_since_spawn\x18\t \x01(\x03\x12\x16\n\x0eintention_cell\x18\n \x01(\x03\x12\x1c\n\x14\x63onflict_participant\x18\x0b \x01(\x08\"a\n\x12GetVehicleResponse\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0c\n\x04text\x18\x02 \x01(\t\x12/\n\x07vehicle\x18\x03 \x01(\x0b\x32\x1e.micro_traffic_sim.VehicleInfoB@Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtrafficb\x06proto3')
+
+_globals = globals()
+_builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
+_builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'vehicle_pb2', _globals)
+if not _descriptor._USE_C_DESCRIPTORS:
+  _globals['DESCRIPTOR']._loaded_options = None
+  _globals['DESCRIPTOR']._serialized_options = b'Z>github.com/LdDl/micro_traffic_sim_grpc/clients/go;microtraffic'
+  _globals['_SPAWNVEHICLEREQUEST']._serialized_start=73
+  _globals['_SPAWNVEHICLEREQUEST']._serialized_end=337
+  _globals['_SPAWNVEHICLERESPONSE']._serialized_start=339
+  _globals['_SPAWNVEHICLERESPONSE']._serialized_end=409
+  _globals['_REMOVEVEHICLEREQUEST']._serialized_start=411
+  _globals['_REMOVEVEHICLEREQUEST']._serialized_end=500
+  _globals['_REMOVEVEHICLERESPONSE']._serialized_start=502
+  _globals['_REMOVEVEHICLERESPONSE']._serialized_end=553
+  _globals['_GETVEHICLEREQUEST']._serialized_start=555
+  _globals['_GETVEHICLEREQUEST']._serialized_end=641
+  _globals['_VEHICLEINFO']._serialized_start=644
+  _globals['_VEHICLEINFO']._serialized_end=995
+  _globals['_GETVEHICLERESPONSE']._serialized_start=997
+  _globals['_GETVEHICLERESPONSE']._serialized_end=1094
+# @@protoc_insertion_point(module_scope)
@@ -0,0 +1,98 @@
+import uuid_pb2 as _uuid_pb2
+import step_pb2 as _step_pb2
+import trip_pb2 as _trip_pb2
+from google.protobuf.internal import containers as _containers
+from google.protobuf import descriptor as _descriptor
+from google.protobuf import message as _message
+from collections.abc import Iterable as _Iterable, Mapping as _Mapping
+from typing import ClassVar as _ClassVar, Optional as _Optional, Union as _Union
+
+DESCRIPTOR: _descriptor.FileDescriptor
+
+class SpawnVehicleRequest(_message.Message):
+    __slots__ = ("session_id", "agent_type", "behaviour_type", "origin_cell", "destination_cell", "transits", "initial_speed")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    AGENT_TYPE_FIELD_NUMBER: _ClassVar[int]
+    BEHAVIOUR_TYPE_FIELD_NUMBER: _ClassVar[int]
+    ORIGIN_CELL_FIELD_NUMBER: _ClassVar[int]
+    DESTINATION_CELL_FIELD_NUMBER: _ClassVar[int]
+    TRANSITS_FIELD_NUMBER: _ClassVar[int]
+    INITIAL_SPEED_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    agent_type: _step_pb2.AgentType
+    behaviour_type: _trip_pb2.BehaviourType
+    origin_cell: int
+    destination_cell: int
+    transits: _containers.RepeatedScalarFieldContainer[int]
+    initial_speed: int
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., agent_type: _Optional[_Union[_step_pb2.AgentType, str]] = ..., behaviour_type: _Optional[_Union[_trip_pb2.BehaviourType, str]] = ..., origin_cell: _Optional[int] = ..., destination_cell: _Optional[int] = ..., transits: _Optional[_Iterable[int]] = ..., initial_speed: _Optional[int] = ...) -> None: ...
+
+class SpawnVehicleResponse(_message.Message):
+    __slots__ = ("code", "text", "vehicle_id")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    VEHICLE_ID_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    vehicle_id: int
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., vehicle_id: _Optional[int] = ...) -> None: ...
+
+class RemoveVehicleRequest(_message.Message):
+    __slots__ = ("session_id", "vehicle_id")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    VEHICLE_ID_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    vehicle_id: int
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., vehicle_id: _Optional[int] = ...) -> None: ...
+
+class RemoveVehicleResponse(_message.Message):
+    __slots__ = ("code", "text")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ...) -> None: ...
+
+class GetVehicleRequest(_message.Message):
+    __slots__ = ("session_id", "vehicle_id")
+    SESSION_ID_FIELD_NUMBER: _ClassVar[int]
+    VEHICLE_ID_FIELD_NUMBER: _ClassVar[int]
+    session_id: _uuid_pb2.UUIDv4
+    vehicle_id: int
+    def __init__(self, session_id: _Optional[_Union[_uuid_pb2.UUIDv4, _Mapping]] = ..., vehicle_id: _Optional[int] = ...) -> None: ...
+
+class VehicleInfo(_message.Message):
+    __slots__ = ("state", "behaviour_type", "speed_limit", "destination", "trip_destination", "remaining_transits", "remaining_path", "destination_reachable", "steps_since_spawn", "intention_cell", "conflict_participant")
+    STATE_FIELD_NUMBER: _ClassVar[int]
+    BEHAVIOUR_TYPE_FIELD_NUMBER: _ClassVar[int]
+    SPEED_LIMIT_FIELD_NUMBER: _ClassVar[int]
+    DESTINATION_FIELD_NUMBER: _ClassVar[int]
+    TRIP_DESTINATION_FIELD_NUMBER: _ClassVar[int]
+    REMAINING_TRANSITS_FIELD_NUMBER: _ClassVar[int]
+    REMAINING_PATH_FIELD_NUMBER: _ClassVar[int]
+    DESTINATION_REACHABLE_FIELD_NUMBER: _ClassVar[int]
+    STEPS_SINCE_SPAWN_FIELD_NUMBER: _ClassVar[int]
+    INTENTION_CELL_FIELD_NUMBER: _ClassVar[int]
+    CONFLICT_PARTICIPANT_FIELD_NUMBER: _ClassVar[int]
+    state: _step_pb2.VehicleState
+    behaviour_type: _trip_pb2.BehaviourType
+    speed_limit: int
+    destination: int
+    trip_destination: int
+    remaining_transits: _containers.RepeatedScalarFieldContainer[int]
+    remaining_path: _containers.RepeatedScalarFieldContainer[int]
+    destination_reachable: bool
+    steps_since_spawn: int
+    intention_cell: int
+    conflict_participant: bool
+    def __init__(self, state: _Optional[_Union[_step_pb2.VehicleState, _Mapping]] = ..., behaviour_type: _Optional[_Union[_trip_pb2.BehaviourType, str]] = ..., speed_limit: _Optional[int] = ..., destination: _Optional[int] = ..., trip_destination: _Optional[int] = ..., remaining_transits: _Optional[_Iterable[int]] = ..., remaining_path: _Optional[_Iterable[int]] = ..., destination_reachable: bool = ..., steps_since_spawn: _Optional[int] = ..., intention_cell: _Optional[int] = ..., conflict_participant: bool = ...) -> None: ...
+
+class GetVehicleResponse(_message.Message):
+    __slots__ = ("code", "text", "vehicle")
+    CODE_FIELD_NUMBER: _ClassVar[int]
+    TEXT_FIELD_NUMBER: _ClassVar[int]
+    VEHICLE_FIELD_NUMBER: _ClassVar[int]
+    code: int
+    text: str
+    vehicle: VehicleInfo
+    def __init__(self, code: _Optional[int] = ..., text: _Optional[str] = ..., vehicle: _Optional[_Union[VehicleInfo, _Mapping]] = ...) -> None: ...
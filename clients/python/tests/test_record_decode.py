@@ -0,0 +1,101 @@
+"""Tests for the RecordBatch blob decoder, on hand-built blobs."""
+
+import struct
+import unittest
+
+from micro_traffic_sim.record_decode import decode_record_batch
+from micro_traffic_sim.step_pb2 import AgentType, VehicleEventType
+
+
+def u32(*vs: int) -> bytes:
+    return struct.pack(f"<{len(vs)}I", *vs)
+
+
+def build_blob() -> bytes:
+    """Version 1 batch: ticks 100 and 101, three vehicle rows and one traffic
+    light with two groups."""
+    return b"".join([
+        bytes([1]),
+        # tick_start, tick_count K, total_rows R, rows_per_tick[K]
+        u32(100, 2, 3, 2, 1),
+        # vehicle_id[R], cell[R]
+        u32(10, 11, 12),
+        u32(5, 6, 7),
+        # agent_type[R]
+        bytes([1, 2, 5]),
+        # angle_cdeg[R], speed[R]
+        struct.pack("<3H", 0, 9000, 35999),
+        struct.pack("<3h", 3, 0, -1),
+        # trip_id[R]
+        u32(1, 1, 2),
+        # ic_off[R] cumulative END offsets: row0 [100,101], row1 [], row2 [102]
+        u32(2, 2, 3),
+        u32(100, 101, 102),
+        # tail_off[R]: row0 [], row1 [200], row2 [201,202]
+        u32(0, 1, 3),
+        u32(200, 201, 202),
+        # G = 2 keys (tl_id, group_id), then signal[K*G] tick-major
+        u32(2, 1, 100, 1, 200),
+        bytes([3, 1, 1, 3]),
+    ])
+
+
+def events_section() -> bytes:
+    return b"".join([
+        # events_per_tick[K]: 2 events at tick 100, 1 at tick 101
+        u32(2, 1),
+        # event_vehicle_id[E]
+        u32(10, 11, 12),
+        # event_type[E]: spawned, lane_changed, lost
+        bytes([1, 4, 3]),
+        # event_trip_id[E], event_cell[E] (lost vehicle has no known cell),
+        # event_from_cell[E]
+        u32(1, 1, 2),
+        u32(5, 6, 0xFFFFFFFF),
+        u32(0xFFFFFFFF, 9, 0xFFFFFFFF),
+    ])
+
+
+class DecodeRecordBatchTest(unittest.TestCase):
+    def test_version_1(self):
+        responses = decode_record_batch(build_blob())
+        self.assertEqual([r.timestamp for r in responses], [100, 101])
+        v0 = responses[0].vehicle_data[0]
+        self.assertEqual((v0.vehicle_id, v0.cell, v0.speed), (10, 5, 3))
+        self.assertEqual(v0.vehicle_type, AgentType.AGENT_TYPE_CAR)
+        self.assertEqual(v0.travel_time, -1)
+        self.assertEqual(list(v0.intermediate_cells), [100, 101])
+        v2 = responses[1].vehicle_data[0]
+        self.assertEqual(v2.speed, -1)
+        self.assertAlmostEqual(v2.bearing, 359.99)
+        self.assertEqual(list(v2.tail_cells), [201, 202])
+        tls = responses[1].tls_data
+        self.assertEqual(len(tls), 1)
+        self.assertEqual([g.signal for g in tls[0].groups], ["r", "g"])
+        self.assertTrue(all(len(r.events) == 0 for r in responses))
+
+    def test_version_2_events(self):
+        blob = bytes([2]) + build_blob()[1:] + events_section()
+        responses = decode_record_batch(blob)
+        e0 = responses[0].events
+        self.assertEqual(len(e0), 2)
+        self.assertEqual(e0[0].vehicle_id, 10)
+        self.assertEqual(e0[0].event_type, VehicleEventType.VEHICLE_EVENT_TYPE_SPAWNED)
+        self.assertEqual((e0[0].cell, e0[0].from_cell), (5, -1))
+        self.assertEqual(e0[1].event_type, VehicleEventType.VEHICLE_EVENT_TYPE_LANE_CHANGED)
+        self.assertEqual(e0[1].from_cell, 9)
+        e1 = responses[1].events
+        self.assertEqual(len(e1), 1)
+        self.assertEqual(e1[0].event_type, VehicleEventType.VEHICLE_EVENT_TYPE_LOST)
+        self.assertEqual((e1[0].trip_id, e1[0].cell), (2, -1))
+
+        with self.assertRaises(ValueError):
+            decode_record_batch(blob[:-1])
+
+    def test_rejects_unknown_version(self):
+        with self.assertRaises(ValueError):
+            decode_record_batch(bytes([3]) + build_blob()[1:])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -1,24 +1,76 @@
 use std::collections::HashMap;
 use std::env;
+use std::fs;
 use tokio_stream::StreamExt;
-use tonic::transport::Channel;
+use tonic::Request;
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity};
 
 use micro_traffic_sim::pb;
 use micro_traffic_sim::pb::service_client::ServiceClient;
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+// (step, vehicle_id, vehicle_type, speed, bearing, intermediate_cells, cell, x, y, tail_cells, trip_id)
+type VehicleRow = (
+    i64,
+    i64,
+    &'static str,
+    i64,
+    f64,
+    String,
+    i64,
+    f64,
+    f64,
+    String,
+    i64,
+);
+
+/// Connects to the server: over TLS when `MT_SIM_TLS_CA` (PEM CA certificate of the
+/// server) is set, presenting `MT_SIM_TLS_CLIENT_CERT` / `MT_SIM_TLS_CLIENT_KEY` when the
+/// server requires mutual TLS. `MT_SIM_TLS_DOMAIN` overrides the name checked against the
+/// server certificate.
+async fn connect() -> Result<Channel, Box<dyn std::error::Error>> {
     // Server address (override with MT_SIM_ADDR, e.g. http://127.0.0.1:50051)
     let raw = env::var("MT_SIM_ADDR").unwrap_or_else(|_| "127.0.0.1:50051".to_string());
+    let ca = env::var("MT_SIM_TLS_CA").ok();
     let addr = if raw.starts_with("http://") || raw.starts_with("https://") {
         raw
+    } else if ca.is_some() {
+        format!("https://{raw}")
     } else {
         format!("http://{raw}")
     };
+    let mut endpoint = Channel::from_shared(addr)?;
+    if let Some(ca) = ca {
+        let mut tls = ClientTlsConfig::new().ca_certificate(Certificate::from_pem(fs::read(ca)?));
+        if let Ok(domain) = env::var("MT_SIM_TLS_DOMAIN") {
+            tls = tls.domain_name(domain);
+        }
+        if let (Ok(cert), Ok(key)) = (
+            env::var("MT_SIM_TLS_CLIENT_CERT"),
+            env::var("MT_SIM_TLS_CLIENT_KEY"),
+        ) {
+            tls = tls.identity(Identity::from_pem(fs::read(cert)?, fs::read(key)?));
+        }
+        endpoint = endpoint.tls_config(tls)?;
+    }
+    Ok(endpoint.connect().await?)
+}
 
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Connect
-    let channel = Channel::from_shared(addr.clone())?.connect().await?;
-    let mut client = ServiceClient::new(channel);
+    let channel = connect().await?;
+    // Bearer token (MT_SIM_TOKEN) for servers with authentication enabled
+    let token: Option<MetadataValue<Ascii>> = env::var("MT_SIM_TOKEN")
+        .ok()
+        .map(|t| format!("Bearer {t}").parse())
+        .transpose()?;
+    let mut client = ServiceClient::with_interceptor(channel, move |mut req: Request<()>| {
+        if let Some(token) = &token {
+            req.metadata_mut().insert("authorization", token.clone());
+        }
+        Ok(req)
+    });
 
     // ==============================================================
     // STEP 1: CREATE SESSION
@@ -176,6 +228,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cz_request = pb::SessionConflictZones {
         session_id: Some(pb::UuiDv4 { value: sid.clone() }),
         data: conflict_zones,
+        priority: None,
     };
     let cz_stream = tokio_stream::once(cz_request);
     let mut cz_response = client
@@ -261,6 +314,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             end_time: 0,
             relax_time: 0,
             transits: vec![],
+            demand_profile: vec![],
         },
         pb::Trip {
             id: 2,
@@ -276,6 +330,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             end_time: 0,
             relax_time: 0,
             transits: vec![],
+            demand_profile: vec![],
         },
         pb::Trip {
             id: 3,
@@ -291,18 +346,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             end_time: 0,
             relax_time: 0,
             transits: vec![],
+            demand_profile: vec![],
         },
     ];
 
     let trip_request = pb::SessionTrip {
         session_id: Some(pb::UuiDv4 { value: sid.clone() }),
         data: trips,
+        reject_unreachable: true,
     };
     let trip_stream = tokio_stream::once(trip_request);
     let mut trip_response = client.push_session_trip(trip_stream).await?.into_inner();
     while let Some(resp) = trip_response.next().await {
         let resp = resp?;
         println!("Trip push response: code={} text={}", resp.code, resp.text);
+        for r in &resp.reachability {
+            println!("  trip {} shortest path: {} cells", r.trip_id, r.path_cells);
+        }
     }
 
     // ==============================================================
@@ -386,19 +446,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n=== Running {} simulation steps ===\n", steps_num);
 
     // Collect states for printing at the end
-    let mut vehicle_states: Vec<(
-        i64,
-        i64,
-        &'static str,
-        i64,
-        f64,
-        String,
-        i64,
-        f64,
-        f64,
-        String,
-        i64,
-    )> = Vec::new();
+    let mut vehicle_states: Vec<VehicleRow> = Vec::new();
     // TLS states: (step, tl_id, group_id, cell_id, x, y, signal)
     let mut tls_states: Vec<(i64, i64, i64, i64, f64, f64, String)> = Vec::new();
 
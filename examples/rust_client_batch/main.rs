@@ -1,23 +1,62 @@
 use std::collections::HashMap;
 use std::env;
+use std::fs;
 
 use tokio_stream::StreamExt;
-use tonic::transport::Channel;
+use tonic::Request;
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity};
 
 use micro_traffic_sim::pb;
 use micro_traffic_sim::pb::service_client::ServiceClient;
 use micro_traffic_sim::record::decode_record_batch;
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Connects to the server: over TLS when `MT_SIM_TLS_CA` (PEM CA certificate of the
+/// server) is set, presenting `MT_SIM_TLS_CLIENT_CERT` / `MT_SIM_TLS_CLIENT_KEY` when the
+/// server requires mutual TLS. `MT_SIM_TLS_DOMAIN` overrides the name checked against the
+/// server certificate.
+async fn connect() -> Result<Channel, Box<dyn std::error::Error>> {
+    // Server address (override with MT_SIM_ADDR, e.g. http://127.0.0.1:50051)
     let raw = env::var("MT_SIM_ADDR").unwrap_or_else(|_| "127.0.0.1:50051".to_string());
+    let ca = env::var("MT_SIM_TLS_CA").ok();
     let addr = if raw.starts_with("http://") || raw.starts_with("https://") {
         raw
+    } else if ca.is_some() {
+        format!("https://{raw}")
     } else {
         format!("http://{raw}")
     };
-    let channel = Channel::from_shared(addr)?.connect().await?;
-    let mut client = ServiceClient::new(channel);
+    let mut endpoint = Channel::from_shared(addr)?;
+    if let Some(ca) = ca {
+        let mut tls = ClientTlsConfig::new().ca_certificate(Certificate::from_pem(fs::read(ca)?));
+        if let Ok(domain) = env::var("MT_SIM_TLS_DOMAIN") {
+            tls = tls.domain_name(domain);
+        }
+        if let (Ok(cert), Ok(key)) = (
+            env::var("MT_SIM_TLS_CLIENT_CERT"),
+            env::var("MT_SIM_TLS_CLIENT_KEY"),
+        ) {
+            tls = tls.identity(Identity::from_pem(fs::read(cert)?, fs::read(key)?));
+        }
+        endpoint = endpoint.tls_config(tls)?;
+    }
+    Ok(endpoint.connect().await?)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let channel = connect().await?;
+    // Bearer token (MT_SIM_TOKEN) for servers with authentication enabled
+    let token: Option<MetadataValue<Ascii>> = env::var("MT_SIM_TOKEN")
+        .ok()
+        .map(|t| format!("Bearer {t}").parse())
+        .transpose()?;
+    let mut client = ServiceClient::with_interceptor(channel, move |mut req: Request<()>| {
+        if let Some(token) = &token {
+            req.metadata_mut().insert("authorization", token.clone());
+        }
+        Ok(req)
+    });
 
     let resp = client
         .new_session(pb::SessionReq { srid: 0 })
@@ -151,6 +190,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cz_request = pb::SessionConflictZones {
         session_id: Some(pb::UuiDv4 { value: sid.clone() }),
         data: conflict_zones,
+        priority: None,
     };
     let cz_stream = tokio_stream::once(cz_request);
     let mut cz_response = client
@@ -224,6 +264,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             end_time: 0,
             relax_time: 0,
             transits: vec![],
+            demand_profile: vec![],
         },
         pb::Trip {
             id: 2,
@@ -239,6 +280,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             end_time: 0,
             relax_time: 0,
             transits: vec![],
+            demand_profile: vec![],
         },
         pb::Trip {
             id: 3,
@@ -254,18 +296,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             end_time: 0,
             relax_time: 0,
             transits: vec![],
+            demand_profile: vec![],
         },
     ];
 
     let trip_request = pb::SessionTrip {
         session_id: Some(pb::UuiDv4 { value: sid.clone() }),
         data: trips,
+        reject_unreachable: true,
     };
     let trip_stream = tokio_stream::once(trip_request);
     let mut trip_response = client.push_session_trip(trip_stream).await?.into_inner();
     while let Some(resp) = trip_response.next().await {
         let resp = resp?;
         eprintln!("trip push: code={} text={}", resp.code, resp.text);
+        for r in &resp.reachability {
+            eprintln!("trip {} path: {} cells", r.trip_id, r.path_cells);
+        }
     }
 
     let zone_str = |z: i32| -> &'static str {
@@ -338,6 +385,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         horizon_ticks: 50,
         batch_ticks: 20,
         filter: None,
+        include_events: false,
+        mfd_interval_ticks: 0,
     };
     let mut stream = client.run_and_record(rr_req).await?.into_inner();
 
@@ -36,6 +36,14 @@ Mprotos/trip.proto=$PKG_IMPORT_PATH,\
 Mprotos/tls.proto=$PKG_IMPORT_PATH,\
 Mprotos/conflict_zones.proto=$PKG_IMPORT_PATH,\
 Mprotos/record.proto=$PKG_IMPORT_PATH,\
+Mprotos/stats.proto=$PKG_IMPORT_PATH,\
+Mprotos/incident.proto=$PKG_IMPORT_PATH,\
+Mprotos/speed_limit.proto=$PKG_IMPORT_PATH,\
+Mprotos/vehicle.proto=$PKG_IMPORT_PATH,\
+Mprotos/od.proto=$PKG_IMPORT_PATH,\
+Mprotos/route.proto=$PKG_IMPORT_PATH,\
+Mprotos/limits.proto=$PKG_IMPORT_PATH,\
+Mprotos/snapshot.proto=$PKG_IMPORT_PATH,\
 Mprotos/uuid.proto=$PKG_IMPORT_PATH \
   --go-grpc_out="$OUT_DIR" --go-grpc_opt=paths=source_relative \
   "$PROTO_DIR/service.proto" \
@@ -46,6 +54,14 @@ Mprotos/uuid.proto=$PKG_IMPORT_PATH \
   "$PROTO_DIR/tls.proto" \
   "$PROTO_DIR/conflict_zones.proto" \
   "$PROTO_DIR/record.proto" \
+  "$PROTO_DIR/stats.proto" \
+  "$PROTO_DIR/incident.proto" \
+  "$PROTO_DIR/speed_limit.proto" \
+  "$PROTO_DIR/vehicle.proto" \
+  "$PROTO_DIR/od.proto" \
+  "$PROTO_DIR/route.proto" \
+  "$PROTO_DIR/limits.proto" \
+  "$PROTO_DIR/snapshot.proto" \
   "$PROTO_DIR/uuid.proto"
 
 echo "Go client generated:"
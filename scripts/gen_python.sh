@@ -51,6 +51,14 @@ python3 -m grpc_tools.protoc \
   "$PROTO_DIR/tls.proto" \
   "$PROTO_DIR/conflict_zones.proto" \
   "$PROTO_DIR/record.proto" \
+  "$PROTO_DIR/stats.proto" \
+  "$PROTO_DIR/incident.proto" \
+  "$PROTO_DIR/speed_limit.proto" \
+  "$PROTO_DIR/vehicle.proto" \
+  "$PROTO_DIR/od.proto" \
+  "$PROTO_DIR/route.proto" \
+  "$PROTO_DIR/limits.proto" \
+  "$PROTO_DIR/snapshot.proto" \
   "$PROTO_DIR/uuid.proto"
 
 # Fix imports in generated files (change absolute to relative imports)
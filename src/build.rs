@@ -5,7 +5,7 @@ fn main() {
 
     // Compile all protos in one shot; tonic/prost will emit a single
     // Rust module per proto package into OUT_DIR (default behavior).
-    let protos: [&str; 9] = [
+    let protos: [&str; 17] = [
         "protos/service.proto",
         "protos/cell.proto",
         "protos/session.proto",
@@ -14,6 +14,14 @@ fn main() {
         "protos/tls.proto",
         "protos/conflict_zones.proto",
         "protos/record.proto",
+        "protos/stats.proto",
+        "protos/incident.proto",
+        "protos/speed_limit.proto",
+        "protos/vehicle.proto",
+        "protos/od.proto",
+        "protos/route.proto",
+        "protos/limits.proto",
+        "protos/snapshot.proto",
         "protos/uuid.proto",
     ];
 
@@ -118,6 +118,10 @@
 /// [`service_server::ServiceServer`]: service_server::ServiceServer
 pub mod pb {
     include!(concat!(env!("OUT_DIR"), "/micro_traffic_sim.rs"));
+
+    /// Encoded `FileDescriptorSet` of all the service protos (used by server reflection).
+    pub const FILE_DESCRIPTOR_SET: &[u8] =
+        include_bytes!(concat!(env!("OUT_DIR"), "/sim_service.bin"));
 }
 
 /// Decoder for the RunAndRecord RecordBatch columns blob.
@@ -127,5 +131,12 @@ pub mod pb {
 /// This module is always available and does not require the `server` feature).
 pub mod record;
 
+/// Expansion of OD (origin-destination) matrices into trips.
+///
+/// Turns zones, per-slice OD demand and an agent mix into [`pb::Trip`] generators,
+/// exactly as the PushSessionOdMatrix RPC does server-side.
+/// This module is always available and does not require the `server` feature.
+pub mod od;
+
 // Re-export all generated types at crate root for convenience.
 pub use pb::*;
@@ -0,0 +1,414 @@
+//! Expansion of OD (origin-destination) demand into trips.
+//!
+//! Demand produced by four-step models comes as OD matrices between traffic analysis
+//! zones, one per time slice. [`expand_od_matrix`] turns a [`pb::OdMatrix`] into the
+//! [`pb::Trip`] generators understood by the simulation, the same way the server's
+//! PushSessionOdMatrix RPC does, so clients pushing trips themselves get identical
+//! results.
+//!
+//! Every flow is split evenly across the birth cells of its origin zone and the death
+//! cells of its destination zone, then across the agent mix: one trip per (slice,
+//! flow, agent share, birth cell, death cell), trips being numbered consecutively
+//! from `first_trip_id` in that order.
+//!
+//! USAGE
+//!
+//! ```rust
+//! use micro_traffic_sim::od::expand_od_matrix;
+//! use micro_traffic_sim::pb::{OdFlow, OdMatrix, OdTimeSlice, OdZone};
+//!
+//! let matrix = OdMatrix {
+//!     zones: vec![
+//!         OdZone { id: 1, birth_cells: vec![10, 11], death_cells: vec![] },
+//!         OdZone { id: 2, birth_cells: vec![], death_cells: vec![50] },
+//!     ],
+//!     slices: vec![OdTimeSlice {
+//!         start_time: 0,
+//!         end_time: 3600,
+//!         flows: vec![OdFlow { origin_zone: 1, destination_zone: 2, vehicles_per_hour: 720.0 }],
+//!     }],
+//!     ..Default::default()
+//! };
+//! let trips = expand_od_matrix(&matrix, 1).unwrap();
+//! assert_eq!(trips.len(), 2);
+//! assert!((trips[0].probability - 0.1).abs() < 1e-12);
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::pb;
+
+/// Steps per hour (the core advances at 1 s/step).
+const STEPS_PER_HOUR: f64 = 3600.0;
+
+/// Reasons an OD matrix can not be expanded into trips.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OdError {
+    /// Two zones share the same identifier.
+    DuplicateZone(i64),
+    /// A flow references a zone which is not defined.
+    UnknownZone(i64),
+    /// The origin zone of a flow has no birth cells.
+    NoBirthCells(i64),
+    /// The destination zone of a flow has no death cells.
+    NoDeathCells(i64),
+    /// The time slice is empty or can not be represented by a trip.
+    InvalidSlice { start_time: i64, end_time: i64 },
+    /// The demand of a flow is negative or not a number.
+    InvalidFlow {
+        origin_zone: i64,
+        destination_zone: i64,
+    },
+    /// Agent mix weights are negative, not numbers or sum up to zero.
+    InvalidAgentMix,
+    /// Only TRIP_TYPE_RANDOM and TRIP_TYPE_CONSTANT are supported.
+    UnsupportedTripType(i32),
+    /// A birth cell would have to generate more than one vehicle per step, summed over
+    /// all the flows and agent shares of a time slice starting there.
+    RateTooHigh {
+        from_node: i64,
+        vehicles_per_hour: f64,
+    },
+}
+
+impl fmt::Display for OdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OdError::DuplicateZone(id) => write!(f, "duplicate zone ID: '{id}'"),
+            OdError::UnknownZone(id) => write!(f, "not found zone ID: '{id}'"),
+            OdError::NoBirthCells(id) => write!(f, "zone '{id}' has no birth cells"),
+            OdError::NoDeathCells(id) => write!(f, "zone '{id}' has no death cells"),
+            OdError::InvalidSlice {
+                start_time,
+                end_time,
+            } => write!(f, "invalid time slice [{start_time}, {end_time})"),
+            OdError::InvalidFlow {
+                origin_zone,
+                destination_zone,
+            } => write!(
+                f,
+                "invalid demand from zone '{origin_zone}' to zone '{destination_zone}'"
+            ),
+            OdError::InvalidAgentMix => write!(f, "invalid agent mix"),
+            OdError::UnsupportedTripType(t) => write!(f, "unsupported trip type {t}"),
+            OdError::RateTooHigh {
+                from_node,
+                vehicles_per_hour,
+            } => write!(
+                f,
+                "birth cell '{from_node}' needs {vehicles_per_hour} vehicles per hour, at most \
+                 {STEPS_PER_HOUR} are possible (add birth cells to its zones)"
+            ),
+        }
+    }
+}
+
+impl Error for OdError {}
+
+/// Agent mix with weights normalized to shares.
+fn agent_mix(matrix: &pb::OdMatrix) -> Result<Vec<(i32, i32, f64)>, OdError> {
+    if matrix.agent_mix.is_empty() {
+        return Ok(vec![(
+            pb::AgentType::Car as i32,
+            pb::BehaviourType::Cooperative as i32,
+            1.0,
+        )]);
+    }
+    // NaN fails the comparison as well
+    if !matrix.agent_mix.iter().all(|s| s.share >= 0.0) {
+        return Err(OdError::InvalidAgentMix);
+    }
+    let total: f64 = matrix.agent_mix.iter().map(|s| s.share).sum();
+    if !(total > 0.0 && total.is_finite()) {
+        return Err(OdError::InvalidAgentMix);
+    }
+    Ok(matrix
+        .agent_mix
+        .iter()
+        .filter(|s| s.share > 0.0)
+        .map(|s| (s.agent_type, s.behaviour_type, s.share / total))
+        .collect())
+}
+
+/// Number of trips [`expand_od_matrix`] makes out of the matrix, counted without
+/// expanding it so that size limits can be checked first. Flows the expansion would
+/// reject count as no trips.
+pub fn count_od_trips(matrix: &pb::OdMatrix) -> usize {
+    let zones: HashMap<i64, &pb::OdZone> = matrix.zones.iter().map(|z| (z.id, z)).collect();
+    let mix = if matrix.agent_mix.is_empty() {
+        1
+    } else {
+        matrix.agent_mix.iter().filter(|s| s.share > 0.0).count()
+    };
+    let mut count: usize = 0;
+    for flow in matrix.slices.iter().flat_map(|s| &s.flows) {
+        if flow.vehicles_per_hour.is_nan() || flow.vehicles_per_hour <= 0.0 {
+            continue;
+        }
+        if let (Some(origin), Some(destination)) = (
+            zones.get(&flow.origin_zone),
+            zones.get(&flow.destination_zone),
+        ) {
+            let trips = origin
+                .birth_cells
+                .len()
+                .saturating_mul(destination.death_cells.len())
+                .saturating_mul(mix);
+            count = count.saturating_add(trips);
+        }
+    }
+    count
+}
+
+/// Expands an OD matrix into trips numbered consecutively from `first_trip_id`.
+///
+/// Slices are half-open step windows; a trip of TRIP_TYPE_RANDOM generates a vehicle
+/// with the probability matching its hourly rate on every step, a trip of
+/// TRIP_TYPE_CONSTANT every `3600 / rate` steps. Flows with zero demand produce no
+/// trips.
+pub fn expand_od_matrix(
+    matrix: &pb::OdMatrix,
+    first_trip_id: i64,
+) -> Result<Vec<pb::Trip>, OdError> {
+    let trip_type = match pb::TripType::try_from(matrix.trip_type) {
+        Ok(pb::TripType::Undefined) | Ok(pb::TripType::Random) => pb::TripType::Random,
+        Ok(pb::TripType::Constant) => pb::TripType::Constant,
+        Err(_) => return Err(OdError::UnsupportedTripType(matrix.trip_type)),
+    };
+    let mut zones: HashMap<i64, &pb::OdZone> = HashMap::with_capacity(matrix.zones.len());
+    for zone in &matrix.zones {
+        if zones.insert(zone.id, zone).is_some() {
+            return Err(OdError::DuplicateZone(zone.id));
+        }
+    }
+    let mix = agent_mix(matrix)?;
+
+    let mut trips = Vec::new();
+    let mut next_id = first_trip_id;
+    for slice in &matrix.slices {
+        // The core treats end_time = 0 as "no end" and includes end_time itself
+        let end_time = match slice.end_time {
+            0 => 0,
+            1 => {
+                return Err(OdError::InvalidSlice {
+                    start_time: slice.start_time,
+                    end_time: slice.end_time,
+                });
+            }
+            end => end - 1,
+        };
+        if slice.start_time < 0 || (slice.end_time != 0 && slice.end_time <= slice.start_time) {
+            return Err(OdError::InvalidSlice {
+                start_time: slice.start_time,
+                end_time: slice.end_time,
+            });
+        }
+        // Vehicles per hour asked from every birth cell over the slice
+        let mut node_rates: HashMap<i64, f64> = HashMap::new();
+        for flow in &slice.flows {
+            let origin = zones
+                .get(&flow.origin_zone)
+                .ok_or(OdError::UnknownZone(flow.origin_zone))?;
+            let destination = zones
+                .get(&flow.destination_zone)
+                .ok_or(OdError::UnknownZone(flow.destination_zone))?;
+            if origin.birth_cells.is_empty() {
+                return Err(OdError::NoBirthCells(origin.id));
+            }
+            if destination.death_cells.is_empty() {
+                return Err(OdError::NoDeathCells(destination.id));
+            }
+            if !(flow.vehicles_per_hour >= 0.0 && flow.vehicles_per_hour.is_finite()) {
+                return Err(OdError::InvalidFlow {
+                    origin_zone: flow.origin_zone,
+                    destination_zone: flow.destination_zone,
+                });
+            }
+            if flow.vehicles_per_hour == 0.0 {
+                continue;
+            }
+            let node_rate = flow.vehicles_per_hour / origin.birth_cells.len() as f64;
+            for from_node in &origin.birth_cells {
+                let rate = node_rates.entry(*from_node).or_default();
+                *rate += node_rate;
+                if *rate > STEPS_PER_HOUR {
+                    return Err(OdError::RateTooHigh {
+                        from_node: *from_node,
+                        vehicles_per_hour: *rate,
+                    });
+                }
+            }
+            let pairs = (origin.birth_cells.len() * destination.death_cells.len()) as f64;
+            for (agent_type, behaviour_type, share) in &mix {
+                let vehicles_per_hour = flow.vehicles_per_hour * share / pairs;
+                for from_node in &origin.birth_cells {
+                    for to_node in &destination.death_cells {
+                        let mut trip = pb::Trip {
+                            id: next_id,
+                            trip_type: trip_type as i32,
+                            from_node: *from_node,
+                            to_node: *to_node,
+                            initial_speed: matrix.initial_speed,
+                            agent_type: *agent_type,
+                            behaviour_type: *behaviour_type,
+                            start_time: slice.start_time,
+                            end_time,
+                            ..Default::default()
+                        };
+                        match trip_type {
+                            pb::TripType::Constant => {
+                                trip.time =
+                                    (STEPS_PER_HOUR / vehicles_per_hour).round().max(1.0) as i64;
+                            }
+                            _ => trip.probability = vehicles_per_hour / STEPS_PER_HOUR,
+                        }
+                        trips.push(trip);
+                        next_id += 1;
+                    }
+                }
+            }
+        }
+    }
+    Ok(trips)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix() -> pb::OdMatrix {
+        pb::OdMatrix {
+            zones: vec![
+                pb::OdZone {
+                    id: 1,
+                    birth_cells: vec![10, 11],
+                    death_cells: vec![12],
+                },
+                pb::OdZone {
+                    id: 2,
+                    birth_cells: vec![20],
+                    death_cells: vec![21, 22],
+                },
+            ],
+            slices: vec![
+                pb::OdTimeSlice {
+                    start_time: 0,
+                    end_time: 900,
+                    flows: vec![
+                        pb::OdFlow {
+                            origin_zone: 1,
+                            destination_zone: 2,
+                            vehicles_per_hour: 1200.0,
+                        },
+                        pb::OdFlow {
+                            origin_zone: 2,
+                            destination_zone: 1,
+                            vehicles_per_hour: 0.0,
+                        },
+                    ],
+                },
+                pb::OdTimeSlice {
+                    start_time: 900,
+                    end_time: 0,
+                    flows: vec![pb::OdFlow {
+                        origin_zone: 2,
+                        destination_zone: 1,
+                        vehicles_per_hour: 360.0,
+                    }],
+                },
+            ],
+            agent_mix: vec![
+                pb::OdAgentShare {
+                    agent_type: pb::AgentType::Car as i32,
+                    behaviour_type: pb::BehaviourType::Cooperative as i32,
+                    share: 3.0,
+                },
+                pb::OdAgentShare {
+                    agent_type: pb::AgentType::Truck as i32,
+                    behaviour_type: pb::BehaviourType::Cooperative as i32,
+                    share: 1.0,
+                },
+            ],
+            trip_type: pb::TripType::Random as i32,
+            initial_speed: 1,
+        }
+    }
+
+    #[test]
+    fn expands_slices_flows_and_mix() {
+        let trips = expand_od_matrix(&matrix(), 100).unwrap();
+        // 2 births x 2 deaths x 2 agents + 1 birth x 1 death x 2 agents
+        assert_eq!(trips.len(), 10);
+        assert_eq!(count_od_trips(&matrix()), 10);
+        assert_eq!(
+            trips.iter().map(|t| t.id).collect::<Vec<_>>(),
+            (100..110).collect::<Vec<_>>()
+        );
+        // 1200 veh/h over 4 cell pairs, 3/4 cars: 225 veh/h per trip
+        let first = &trips[0];
+        assert_eq!((first.from_node, first.to_node), (10, 21));
+        assert_eq!((first.start_time, first.end_time), (0, 899));
+        assert!((first.probability - 225.0 / 3600.0).abs() < 1e-12);
+        let total: f64 = trips[..8].iter().map(|t| t.probability).sum();
+        assert!((total * 3600.0 - 1200.0).abs() < 1e-9);
+        assert_eq!(trips[9].agent_type, pb::AgentType::Truck as i32);
+        assert_eq!(trips[9].end_time, 0, "open-ended slice");
+
+        let constant = pb::OdMatrix {
+            trip_type: pb::TripType::Constant as i32,
+            ..matrix()
+        };
+        let trips = expand_od_matrix(&constant, 1).unwrap();
+        assert_eq!(trips[0].time, 16); // 3600 / 225
+        assert_eq!(trips[0].probability, 0.0);
+    }
+
+    #[test]
+    fn rejects_invalid_matrices() {
+        let mut m = matrix();
+        m.slices[0].flows[0].destination_zone = 3;
+        assert_eq!(expand_od_matrix(&m, 1), Err(OdError::UnknownZone(3)));
+
+        let mut m = matrix();
+        m.zones[1].death_cells.clear();
+        assert_eq!(expand_od_matrix(&m, 1), Err(OdError::NoDeathCells(2)));
+
+        let mut m = matrix();
+        m.slices[0].end_time = 0;
+        m.slices[0].start_time = 10;
+        m.slices[1].end_time = 5;
+        assert!(matches!(
+            expand_od_matrix(&m, 1),
+            Err(OdError::InvalidSlice { .. })
+        ));
+
+        let mut m = matrix();
+        m.agent_mix[0].share = -1.0;
+        assert_eq!(expand_od_matrix(&m, 1), Err(OdError::InvalidAgentMix));
+
+        let mut m = matrix();
+        m.slices[0].flows[0].vehicles_per_hour = 20000.0;
+        assert!(matches!(
+            expand_od_matrix(&m, 1),
+            Err(OdError::RateTooHigh { from_node: 10, .. })
+        ));
+
+        // Flows leaving the same birth cells add up: 2 x 2000 veh/h per cell
+        let mut m = matrix();
+        m.slices[0].flows[0].vehicles_per_hour = 4000.0;
+        m.slices[0].flows[1] = pb::OdFlow {
+            origin_zone: 1,
+            destination_zone: 1,
+            vehicles_per_hour: 4000.0,
+        };
+        assert_eq!(
+            expand_od_matrix(&m, 1),
+            Err(OdError::RateTooHigh {
+                from_node: 10,
+                vehicles_per_hour: 4000.0
+            })
+        );
+    }
+}
@@ -5,7 +5,8 @@
 //! the SAME proto/gRPC types that the live `simulation_step_session` RPC
 //! returns, so replaying a recording yields identical types to live stepping.
 //!
-//! The authoritative blob layout (version 1) is produced by the server in
+//! The authoritative blob layout (versions 1 and 2; version 2 appends the
+//! vehicle events section) is produced by the server in
 //! src/server/record.rs (BatchAcc::to_blob) and documented in
 //! protos/record.proto. All integers are little-endian.
 //!
@@ -36,9 +37,11 @@ use std::fmt;
 
 use crate::pb;
 
-/// Layout version this decoder understands. Blobs carrying any other version
-/// are rejected with DecodeError::UnsupportedVersion.
+/// Layout version without the vehicle events section.
 const SUPPORTED_VERSION: u8 = 1;
+/// Layout version with the trailing vehicle events section. Blobs carrying any
+/// other version are rejected with DecodeError::UnsupportedVersion.
+const SUPPORTED_VERSION_EVENTS: u8 = 2;
 
 /// Reasons decoding a RecordBatch columns blob can fail.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -56,7 +59,7 @@ impl fmt::Display for DecodeError {
             DecodeError::UnsupportedVersion(v) => {
                 write!(
                     f,
-                    "unsupported record batch version {v} (expected {SUPPORTED_VERSION})"
+                    "unsupported record batch version {v} (expected {SUPPORTED_VERSION} or {SUPPORTED_VERSION_EVENTS})"
                 )
             }
             DecodeError::Truncated => write!(f, "record batch blob is truncated"),
@@ -131,23 +134,30 @@ fn signal_string(code: u8) -> String {
     .to_string()
 }
 
+/// Map a stored u32 id back to i64, restoring the -1 sentinel.
+fn id_or_none(v: u32) -> i64 {
+    if v == u32::MAX { -1 } else { v as i64 }
+}
+
 /// Decode a RecordBatch columns blob into per-tick proto SessionStepResponse
 /// values, one element per tick in the batch.
 ///
 /// Each response mirrors what the live `simulation_step_session` RPC returns:
 /// `timestamp` is the absolute tick, `vehicle_data` holds the tick's
 /// VehicleState rows, and `tls_data` groups the tick's signals by traffic
-/// light id into TlsState/TlGroup. `code` is 0 and `text` is empty.
+/// light id into TlsState/TlGroup. `code` is 0 and `text` is empty. For
+/// version 2 blobs `events` holds the tick's vehicle events (empty otherwise).
+/// `stats` is always None: the cumulative counters are not part of the blob.
 ///
-/// Validates the leading version byte and rejects anything other than version
-/// 1 with DecodeError::UnsupportedVersion. A blob that ends early yields
+/// Validates the leading version byte and rejects anything other than versions
+/// 1 and 2 with DecodeError::UnsupportedVersion. A blob that ends early yields
 /// DecodeError::Truncated. Empty batches (no ticks, no vehicle rows, and/or no
 /// traffic light groups) decode without error.
 pub fn decode_record_batch(columns: &[u8]) -> Result<Vec<pb::SessionStepResponse>, DecodeError> {
     let mut rd = Reader::new(columns);
 
     let version = rd.u8()?;
-    if version != SUPPORTED_VERSION {
+    if version != SUPPORTED_VERSION && version != SUPPORTED_VERSION_EVENTS {
         return Err(DecodeError::UnsupportedVersion(version));
     }
 
@@ -211,6 +221,43 @@ pub fn decode_record_batch(columns: &[u8]) -> Result<Vec<pb::SessionStepResponse
     }
     let tl_signals = rd.take(tick_count.saturating_mul(g_count))?.to_vec();
 
+    // Vehicle events section (version 2 only).
+    let mut events_per_tick = vec![0usize; tick_count];
+    let mut events: Vec<pb::VehicleEvent> = Vec::new();
+    if version == SUPPORTED_VERSION_EVENTS {
+        for e in events_per_tick.iter_mut() {
+            *e = rd.u32()? as usize;
+        }
+        let total_events: usize = events_per_tick.iter().sum();
+        let mut ev_vehicle_id = Vec::with_capacity(total_events);
+        for _ in 0..total_events {
+            ev_vehicle_id.push(rd.u32()?);
+        }
+        let ev_type = rd.take(total_events)?.to_vec();
+        let mut ev_trip = Vec::with_capacity(total_events);
+        for _ in 0..total_events {
+            ev_trip.push(rd.u32()?);
+        }
+        let mut ev_cell = Vec::with_capacity(total_events);
+        for _ in 0..total_events {
+            ev_cell.push(rd.u32()?);
+        }
+        let mut ev_from_cell = Vec::with_capacity(total_events);
+        for _ in 0..total_events {
+            ev_from_cell.push(rd.u32()?);
+        }
+        events = (0..total_events)
+            .map(|i| pb::VehicleEvent {
+                vehicle_id: ev_vehicle_id[i] as i64,
+                event_type: ev_type[i] as i32,
+                trip_id: id_or_none(ev_trip[i]),
+                cell: id_or_none(ev_cell[i]),
+                from_cell: id_or_none(ev_from_cell[i]),
+            })
+            .collect();
+    }
+    let mut events = events.into_iter();
+
     // One SessionStepResponse per tick, mirroring the live step RPC.
     let mut responses = Vec::with_capacity(tick_count);
     let mut row = 0usize;
@@ -265,6 +312,9 @@ pub fn decode_record_batch(columns: &[u8]) -> Result<Vec<pb::SessionStepResponse
             timestamp,
             vehicle_data,
             tls_data,
+            events: events.by_ref().take(events_per_tick[t]).collect(),
+            // Cumulative counters are not recorded in the blob.
+            stats: None,
         });
     }
 
@@ -405,13 +455,65 @@ mod tests {
     #[test]
     fn rejects_bad_version() {
         let mut blob = build_blob();
-        blob[0] = 2;
+        blob[0] = 3;
         match decode_record_batch(&blob) {
-            Err(DecodeError::UnsupportedVersion(2)) => {}
-            other => panic!("expected UnsupportedVersion(2), got {other:?}"),
+            Err(DecodeError::UnsupportedVersion(3)) => {}
+            other => panic!("expected UnsupportedVersion(3), got {other:?}"),
         }
     }
 
+    #[test]
+    fn round_trip_blob_with_events() {
+        let mut blob = build_blob();
+        blob[0] = 2;
+        // events_per_tick[K]: 2 events at tick 100, 1 at tick 101
+        for v in [2u32, 1] {
+            blob.extend_from_slice(&v.to_le_bytes());
+        }
+        // event_vehicle_id[E]
+        for v in [10u32, 11, 12] {
+            blob.extend_from_slice(&v.to_le_bytes());
+        }
+        // event_type[E]: spawned, lane_changed, lost
+        blob.extend_from_slice(&[1u8, 4, 3]);
+        // event_trip_id[E]
+        for v in [1u32, 1, 2] {
+            blob.extend_from_slice(&v.to_le_bytes());
+        }
+        // event_cell[E]: lost vehicle has no known cell
+        for v in [5u32, 6, u32::MAX] {
+            blob.extend_from_slice(&v.to_le_bytes());
+        }
+        // event_from_cell[E]
+        for v in [u32::MAX, 9, u32::MAX] {
+            blob.extend_from_slice(&v.to_le_bytes());
+        }
+
+        let responses = decode_record_batch(&blob).expect("decode");
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].vehicle_data.len(), 2);
+
+        let e0 = &responses[0].events;
+        assert_eq!(e0.len(), 2);
+        assert_eq!(e0[0].vehicle_id, 10);
+        assert_eq!(e0[0].event_type, pb::VehicleEventType::Spawned as i32);
+        assert_eq!(e0[0].cell, 5);
+        assert_eq!(e0[0].from_cell, -1);
+        assert_eq!(e0[1].event_type, pb::VehicleEventType::LaneChanged as i32);
+        assert_eq!(e0[1].from_cell, 9);
+
+        let e1 = &responses[1].events;
+        assert_eq!(e1.len(), 1);
+        assert_eq!(e1[0].vehicle_id, 12);
+        assert_eq!(e1[0].event_type, pb::VehicleEventType::Lost as i32);
+        assert_eq!(e1[0].trip_id, 2);
+        assert_eq!(e1[0].cell, -1);
+
+        // Version 1 blobs carry no events.
+        let plain = decode_record_batch(&build_blob()).expect("decode");
+        assert!(plain.iter().all(|r| r.events.is_empty()));
+    }
+
     #[test]
     fn rejects_truncated() {
         let blob = build_blob();
@@ -1,31 +1,110 @@
 use futures_core::Stream;
-use std::net::SocketAddr;
 use std::pin::Pin;
-use std::sync::{Arc, Mutex};
-use tokio::time::{Duration, sleep};
+use std::sync::Arc;
+use tokio::time::sleep;
 use tonic::{Request, Response, Status, transport::Server};
+use uuid::Uuid;
 
 use micro_traffic_sim::pb;
-use micro_traffic_sim_core::simulation::sessions_storage::SessionsStorage;
+
 use micro_traffic_sim_core::verbose::VerboseLevel;
+use storage::SessionStore;
 
 // Submodules with per-RPC handlers (keep logic out of this file)
+mod auth;
+mod catalog;
+mod config;
 mod conflict_zones;
+mod demand;
+mod events;
 mod grid;
+mod health;
+mod incidents;
+mod metrics;
+mod mfd;
+mod od;
+mod quotas;
 mod record;
 mod recordings;
+mod routing;
 mod sessions;
+mod shutdown;
+mod snapshot;
+mod speed_limits;
+mod stats;
 mod step;
+mod storage;
+mod telemetry;
+#[cfg(test)]
+mod test_util;
+mod tl_stats;
 mod tls;
 mod trip;
+mod vehicles;
 
 // Shared stream type alias for bidirectional streaming
 pub(super) type BoxStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
 
+/// Extracts and parses the session id of a unary request.
+pub(super) fn parse_session_uuid(id: &Option<pb::UuiDv4>) -> Result<Uuid, Status> {
+    let value = id
+        .as_ref()
+        .map(|u| u.value.as_str())
+        .ok_or_else(|| Status::invalid_argument("No session ID has been provided"))?;
+    Uuid::parse_str(value).map_err(|_| {
+        Status::invalid_argument(format!("Session ID should be a UUID v4: '{}'", value))
+    })
+}
+
+/// Rejects messages carrying more data entities than `limits.max_entities_per_message`.
+pub(super) fn check_entities_count(count: usize) -> Result<(), Status> {
+    let max = config::get().limits.max_entities_per_message;
+    if count > max {
+        return Err(Status::invalid_argument(format!(
+            "Max amount of data entities is {}, but provided is {}",
+            max, count
+        )));
+    }
+    Ok(())
+}
+
+/// Runs `f` on the blocking pool, OFF the async workers. Anything taking a session lock
+/// goes through here: the lock may be held by a long step of the same session, and
+/// waiting for it must not stall other clients' RPCs.
+pub(super) async fn blocking<R, F>(f: F) -> Result<R, Status>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let span = tracing::Span::current();
+    tokio::task::spawn_blocking(move || {
+        let _span = span.enter();
+        f()
+    })
+    .await
+    .map_err(|e| Status::internal(format!("Blocking task failed: {}", e)))
+}
+
+/// Checks that the session exists (extending its TTL, as any other access does).
+pub(super) async fn ensure_session(
+    sessions: &Arc<SessionStore>,
+    session_id: &Uuid,
+) -> Result<(), Status> {
+    let (sessions, id) = (sessions.clone(), *session_id);
+    match blocking(move || sessions.with_session_mut(&id, |_| ())).await? {
+        Some(()) => Ok(()),
+        None => Err(Status::not_found(format!(
+            "Not found session ID: '{}'",
+            session_id
+        ))),
+    }
+}
+
 struct SimService {
-    sessions: Arc<Mutex<SessionsStorage>>,
+    sessions: Arc<SessionStore>,
     session_verbose: VerboseLevel,
     recordings: recordings::Recordings,
+    catalogs: catalog::Catalogs,
 }
 
 #[tonic::async_trait]
@@ -41,62 +120,82 @@ impl pb::service_server::Service for SimService {
         &self,
         request: Request<pb::SessionReq>,
     ) -> Result<Response<pb::NewSessionResponse>, Status> {
-        sessions::new_session(self.sessions.clone(), self.session_verbose, request).await
+        sessions::new_session(
+            self.sessions.clone(),
+            self.recordings.clone(),
+            self.session_verbose,
+            request,
+        )
+        .await
     }
 
     async fn info_session(
         &self,
         request: Request<pb::UuiDv4>,
     ) -> Result<Response<pb::InfoSessionResponse>, Status> {
-        sessions::info_session(self.sessions.clone(), request).await
+        auth::authorize(&request)?;
+        sessions::info_session(self.sessions.clone(), self.catalogs.clone(), request).await
     }
 
     async fn push_session_grid(
         &self,
         request: Request<tonic::Streaming<pb::SessionGrid>>,
     ) -> Result<Response<Self::PushSessionGridStream>, Status> {
-        grid::push_session_grid(self.sessions.clone(), request).await
+        grid::push_session_grid(self.sessions.clone(), self.catalogs.clone(), request).await
     }
 
     async fn push_session_trip(
         &self,
         request: Request<tonic::Streaming<pb::SessionTrip>>,
     ) -> Result<Response<Self::PushSessionTripStream>, Status> {
-        trip::push_session_trip(self.sessions.clone(), request).await
+        trip::push_session_trip(self.sessions.clone(), self.catalogs.clone(), request).await
     }
 
     async fn simulation_step_session(
         &self,
         request: Request<tonic::Streaming<pb::SessionStep>>,
     ) -> Result<Response<Self::SimulationStepSessionStream>, Status> {
-        step::simulation_step_session(self.sessions.clone(), request).await
+        step::simulation_step_session(self.sessions.clone(), self.catalogs.clone(), request).await
     }
 
     async fn push_session_tls(
         &self,
         request: Request<tonic::Streaming<pb::SessionTls>>,
     ) -> Result<Response<Self::PushSessionTLSStream>, Status> {
-        tls::push_session_tls(self.sessions.clone(), request).await
+        tls::push_session_tls(self.sessions.clone(), self.catalogs.clone(), request).await
     }
 
     async fn push_session_conflict_zones(
         &self,
         request: Request<tonic::Streaming<pb::SessionConflictZones>>,
     ) -> Result<Response<Self::PushSessionConflictZonesStream>, Status> {
-        conflict_zones::push_session_conflict_zones(self.sessions.clone(), request).await
+        conflict_zones::push_session_conflict_zones(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            request,
+        )
+        .await
     }
 
     async fn run_and_record(
         &self,
         request: Request<pb::RunAndRecordRequest>,
     ) -> Result<Response<Self::RunAndRecordStream>, Status> {
-        record::run_and_record(self.sessions.clone(), self.recordings.clone(), request).await
+        auth::authorize(&request)?;
+        record::run_and_record(
+            self.sessions.clone(),
+            self.recordings.clone(),
+            self.catalogs.clone(),
+            request,
+        )
+        .await
     }
 
     async fn recording_status(
         &self,
         request: Request<pb::RecordingStatusRequest>,
     ) -> Result<Response<pb::RecordingStatusResponse>, Status> {
+        auth::authorize(&request)?;
         recordings::recording_status(self.recordings.clone(), request).await
     }
 
@@ -104,69 +203,343 @@ impl pb::service_server::Service for SimService {
         &self,
         request: Request<pb::StopRecordingRequest>,
     ) -> Result<Response<pb::StopRecordingResponse>, Status> {
+        auth::authorize(&request)?;
         recordings::stop_recording(self.recordings.clone(), request).await
     }
+
+    async fn get_trip_stats(
+        &self,
+        request: Request<pb::TripStatsRequest>,
+    ) -> Result<Response<pb::TripStatsResponse>, Status> {
+        auth::authorize(&request)?;
+        stats::get_trip_stats(self.sessions.clone(), self.catalogs.clone(), request).await
+    }
+
+    async fn get_traffic_light_stats(
+        &self,
+        request: Request<pb::TrafficLightStatsRequest>,
+    ) -> Result<Response<pb::TrafficLightStatsResponse>, Status> {
+        auth::authorize(&request)?;
+        tl_stats::get_traffic_light_stats(self.sessions.clone(), self.catalogs.clone(), request)
+            .await
+    }
+
+    async fn inject_incident(
+        &self,
+        request: Request<pb::InjectIncidentRequest>,
+    ) -> Result<Response<pb::InjectIncidentResponse>, Status> {
+        auth::authorize(&request)?;
+        incidents::inject_incident(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn clear_incident(
+        &self,
+        request: Request<pb::ClearIncidentRequest>,
+    ) -> Result<Response<pb::ClearIncidentResponse>, Status> {
+        auth::authorize(&request)?;
+        incidents::clear_incident(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn set_speed_limit(
+        &self,
+        request: Request<pb::SetSpeedLimitRequest>,
+    ) -> Result<Response<pb::SetSpeedLimitResponse>, Status> {
+        auth::authorize(&request)?;
+        speed_limits::set_speed_limit(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn spawn_vehicle(
+        &self,
+        request: Request<pb::SpawnVehicleRequest>,
+    ) -> Result<Response<pb::SpawnVehicleResponse>, Status> {
+        auth::authorize(&request)?;
+        vehicles::spawn_vehicle(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn remove_vehicle(
+        &self,
+        request: Request<pb::RemoveVehicleRequest>,
+    ) -> Result<Response<pb::RemoveVehicleResponse>, Status> {
+        auth::authorize(&request)?;
+        vehicles::remove_vehicle(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn get_vehicle(
+        &self,
+        request: Request<pb::GetVehicleRequest>,
+    ) -> Result<Response<pb::GetVehicleResponse>, Status> {
+        auth::authorize(&request)?;
+        vehicles::get_vehicle(self.sessions.clone(), self.catalogs.clone(), request).await
+    }
+
+    async fn update_session_trip(
+        &self,
+        request: Request<pb::UpdateSessionTripRequest>,
+    ) -> Result<Response<pb::UpdateSessionTripResponse>, Status> {
+        auth::authorize(&request)?;
+        trip::update_session_trip(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn remove_session_trip(
+        &self,
+        request: Request<pb::RemoveSessionTripRequest>,
+    ) -> Result<Response<pb::RemoveSessionTripResponse>, Status> {
+        auth::authorize(&request)?;
+        trip::remove_session_trip(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn set_session_trip_paused(
+        &self,
+        request: Request<pb::SetSessionTripPausedRequest>,
+    ) -> Result<Response<pb::SetSessionTripPausedResponse>, Status> {
+        auth::authorize(&request)?;
+        trip::set_session_trip_paused(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn push_session_od_matrix(
+        &self,
+        request: Request<pb::PushSessionOdMatrixRequest>,
+    ) -> Result<Response<pb::PushSessionOdMatrixResponse>, Status> {
+        auth::authorize(&request)?;
+        od::push_session_od_matrix(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            self.recordings.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn compute_route(
+        &self,
+        request: Request<pb::ComputeRouteRequest>,
+    ) -> Result<Response<pb::ComputeRouteResponse>, Status> {
+        auth::authorize(&request)?;
+        routing::compute_route(self.sessions.clone(), self.catalogs.clone(), request).await
+    }
+
+    async fn generate_conflict_zones(
+        &self,
+        request: Request<pb::GenerateConflictZonesRequest>,
+    ) -> Result<Response<pb::GenerateConflictZonesResponse>, Status> {
+        auth::authorize(&request)?;
+        conflict_zones::generate_session_conflict_zones(
+            self.sessions.clone(),
+            self.catalogs.clone(),
+            request,
+        )
+        .await
+    }
+
+    async fn get_server_limits(
+        &self,
+        request: Request<pb::GetServerLimitsRequest>,
+    ) -> Result<Response<pb::GetServerLimitsResponse>, Status> {
+        quotas::get_server_limits(self.sessions.clone(), self.recordings.clone(), request).await
+    }
 }
 
-fn spawn_purge_task(sessions: Arc<Mutex<SessionsStorage>>) {
+fn spawn_purge_task(
+    sessions: Arc<SessionStore>,
+    catalogs: catalog::Catalogs,
+    owners: auth::Owners,
+    recordings: recordings::Recordings,
+) {
     tokio::spawn(async move {
-        let interval = Duration::from_secs(30); // keep in sync with with_purge_every
+        let interval = config::get().purge_interval();
         loop {
             sleep(interval).await;
-            if let Ok(mut guard) = sessions.lock() {
-                guard.purge_expired();
-            }
+            sessions.purge_expired();
+            catalog::purge_expired(&catalogs, config::get().session_ttl());
+            auth::purge_expired(&owners, &recordings, config::get().session_ttl());
         }
     });
 }
 
 pub async fn main_async() -> Result<(), Box<dyn std::error::Error>> {
-    let default_addr = "0.0.0.0:50051";
-    let addr: SocketAddr = std::env::var("MT_SIM_ADDR")
-        .unwrap_or_else(|_| default_addr.to_string())
-        .parse()?;
-    // Parse verbose level helper
-    let parse_verbose = |val: &str| -> VerboseLevel {
-        match val {
-            "0" => VerboseLevel::None,
-            "2" => VerboseLevel::Additional,
-            _ => VerboseLevel::Main,
+    let cfg = config::get();
+    let addr = cfg.server.addr;
+    // Sessions are shared by all the handlers, each one behind its own lock
+    let sessions = Arc::new(SessionStore::new(cfg.session_ttl(), cfg.storage_verbose()));
+    let catalogs = catalog::new_registry();
+    let recordings = recordings::new_registry();
+    let owners = auth::new_registry();
+    if let Some(dir) = &cfg.shutdown.snapshot_dir {
+        let restored =
+            snapshot::restore_all(dir, &sessions, &catalogs, &owners, cfg.sim_verbose())?;
+        if restored > 0 {
+            tracing::info!(restored, dir = %dir.display(), "Restored sessions from snapshot");
         }
+    }
+    spawn_purge_task(
+        sessions.clone(),
+        catalogs.clone(),
+        owners.clone(),
+        recordings.clone(),
+    );
+    if let Some(metrics_addr) = cfg.metrics.addr {
+        metrics::serve(
+            metrics_addr,
+            metrics::Sources {
+                sessions: sessions.clone(),
+                catalogs: catalogs.clone(),
+                recordings: recordings.clone(),
+            },
+        )
+        .await?;
+        tracing::info!(addr = %metrics_addr, "Serving Prometheus metrics on /metrics");
+    }
+    let auth_keys = match &cfg.auth.keys_file {
+        Some(path) => Some(Arc::new(auth::AuthKeys::load(path)?)),
+        None => None,
     };
-    // MT_SIM_VERBOSE: per-session simulation logging (steps, conflicts, movement). Default: 0 (None)
-    let sim_verbose =
-        parse_verbose(&std::env::var("MT_SIM_VERBOSE").unwrap_or_else(|_| "0".to_string()));
-    // MT_SIM_SERVICE_VERBOSE: storage-level logging (session create/expire). Default: 1 (Main)
-    let storage_verbose =
-        parse_verbose(&std::env::var("MT_SIM_SERVICE_VERBOSE").unwrap_or_else(|_| "1".to_string()));
-    // Configure a shared SessionsStorage for the server
-    let store = SessionsStorage::new()
-        .with_session_exp_time(Duration::from_secs(4 * 60))
-        .with_purge_every(Duration::from_secs(30))
-        .with_storage_verbose(storage_verbose);
-    let sessions = Arc::new(Mutex::new(store));
-    spawn_purge_task(sessions.clone());
-
-    let svc = pb::service_server::ServiceServer::new(SimService {
-        sessions: sessions.clone(),
-        session_verbose: sim_verbose,
-        recordings: recordings::new_registry(),
-    });
+    if auth_keys.is_some() {
+        tracing::info!("Bearer token authentication enabled");
+    }
 
-    println!("Starting micro_traffic_sim gRPC server on {}", addr);
-    Server::builder()
-        .add_service(svc)
-        .serve_with_shutdown(addr, async {
-            tokio::signal::ctrl_c().await.ok();
-            println!("\nShutting down gRPC server...");
-        })
-        .await?;
+    let svc = pb::service_server::ServiceServer::with_interceptor(
+        SimService {
+            sessions: sessions.clone(),
+            session_verbose: cfg.sim_verbose(),
+            recordings: recordings.clone(),
+            catalogs: catalogs.clone(),
+        },
+        auth::interceptor(
+            auth_keys,
+            owners.clone(),
+            sessions.clone(),
+            recordings.clone(),
+        ),
+    );
+
+    let (health_reporter, health_svc) = health::health_service().await;
+    let (reflection_v1, reflection_v1alpha) = health::reflection_services()?;
+
+    let mut builder = Server::builder()
+        .trace_fn(telemetry::rpc_span)
+        .layer(metrics::RpcMetricsLayer);
+    let transport = match cfg.server_tls()? {
+        Some(tls) => {
+            builder = builder.tls_config(tls)?;
+            if cfg.tls.client_ca.is_some() {
+                "mTLS"
+            } else {
+                "TLS"
+            }
+        }
+        None => "plaintext",
+    };
+    tracing::info!(%addr, transport, "Starting micro_traffic_sim gRPC server");
+    // Set once a shutdown signal arrives: the time in-flight RPCs are given to finish.
+    let (drain_tx, drain_rx) = tokio::sync::watch::channel(None);
+    let shutdown_recordings = recordings.clone();
+    let mut serve = Box::pin(
+        builder
+            .add_service(svc)
+            .add_service(health_svc)
+            .add_service(reflection_v1)
+            .add_service(reflection_v1alpha)
+            .serve_with_shutdown(addr, async move {
+                shutdown::signal().await;
+                health::set_not_serving(&health_reporter).await;
+                let cancelled = shutdown::cancel_recordings(&shutdown_recordings);
+                if cancelled > 0 {
+                    tracing::info!(recordings = cancelled, "Stopping running recordings");
+                }
+                let _ = drain_tx.send(Some(tokio::time::Instant::now() + cfg.drain_timeout()));
+            }),
+    );
+    // New connections and RPCs are refused from the signal on; in-flight ones (running
+    // recordings included, until their RunSummary) are drained until the deadline.
+    tokio::select! {
+        result = &mut serve => result?,
+        _ = shutdown::drain_deadline(drain_rx.clone()) => {
+            tracing::warn!("Drain timeout reached, closing remaining connections");
+        }
+    }
+    // Closes the connections still open
+    drop(serve);
+    let deadline = *drain_rx.borrow();
+    if let Some(deadline) = deadline {
+        let running = shutdown::wait_recordings(&recordings, deadline).await;
+        if running > 0 {
+            tracing::warn!(recordings = running, "Recordings still running at shutdown");
+        }
+    }
+    if let Some(dir) = &cfg.shutdown.snapshot_dir {
+        let saved = snapshot::save_all(dir, &sessions, &catalogs, &owners)?;
+        tracing::info!(saved, dir = %dir.display(), "Saved sessions snapshot");
+    }
     Ok(())
 }
 
 pub fn run_blocking() {
+    let cli = <config::Cli as clap::Parser>::parse();
+    match cli.load() {
+        Ok(cfg) => config::install(cfg),
+        Err(e) => {
+            eprintln!("Invalid configuration: {e}");
+            std::process::exit(2);
+        }
+    }
     let rt = tokio::runtime::Runtime::new().expect("failed to create tokio runtime");
+    // Declared after the runtime: dropped (flushing exported spans) while it still runs
+    let _telemetry = match rt.block_on(async { telemetry::init(&config::get().logging) }) {
+        Ok(telemetry) => telemetry,
+        Err(e) => {
+            eprintln!("Invalid configuration: {e}");
+            std::process::exit(2);
+        }
+    };
     if let Err(e) = rt.block_on(main_async()) {
-        eprintln!("Server failed: {e}");
+        tracing::error!("Server failed: {e}");
     }
 }
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use subtle::ConstantTimeEq;
+use tonic::{Request, Status};
+use uuid::Uuid;
+
+use micro_traffic_sim::pb;
+
+use super::config::ConfigError;
+use super::recordings::Recordings;
+use super::storage::SessionStore;
+use super::telemetry;
+
+/// Bearer tokens of the tenants, loaded from the key file.
+///
+/// One `<tenant> <token>` pair per line; blank lines and lines starting with `#` are
+/// ignored. A tenant may have several tokens, a token belongs to a single tenant.
+#[derive(Debug)]
+pub struct AuthKeys {
+    tenants: HashMap<String, String>,
+}
+
+impl AuthKeys {
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text =
+            std::fs::read_to_string(path).map_err(|e| ConfigError::Read(path.to_path_buf(), e))?;
+        Self::parse(&text).map_err(|msg| {
+            ConfigError::Invalid(format!("auth key file '{}': {}", path.display(), msg))
+        })
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut tenants = HashMap::new();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [tenant, token] = fields[..] else {
+                return Err(format!("line {}: expected '<tenant> <token>'", idx + 1));
+            };
+            if tenants
+                .insert(token.to_string(), tenant.to_string())
+                .is_some()
+            {
+                return Err(format!("line {}: duplicate token", idx + 1));
+            }
+        }
+        if tenants.is_empty() {
+            return Err("no tokens".to_string());
+        }
+        Ok(Self { tenants })
+    }
+
+    /// Tenant owning `token`. Every token is compared in constant time and the scan does
+    /// not stop at a match, so the response time does not tell how close a guess is.
+    fn tenant_of(&self, token: &str) -> Option<&String> {
+        let mut found = None;
+        for (candidate, tenant) in &self.tenants {
+            if bool::from(candidate.as_bytes().ct_eq(token.as_bytes())) {
+                found = Some(tenant);
+            }
+        }
+        found
+    }
+}
+
+pub struct Owner {
+    tenant: String,
+    touched_at: Instant,
+}
+
+/// Owner tenant of every session created with authentication enabled. Entries are
+/// refreshed on every authorized access and purged with the session TTL (except for
+/// sessions being recorded, which live outside of the storage).
+pub type Owners = Arc<Mutex<HashMap<Uuid, Owner>>>;
+
+/// Creates an empty owners registry.
+pub fn new_registry() -> Owners {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+/// Drops the owners of sessions not accessed within `ttl` and not being recorded.
+pub fn purge_expired(owners: &Owners, recordings: &Recordings, ttl: Duration) {
+    let running: Vec<Uuid> = match recordings.lock() {
+        Ok(reg) => reg.keys().copied().collect(),
+        Err(_) => return,
+    };
+    if let Ok(mut guard) = owners.lock() {
+        guard.retain(|id, o| o.touched_at.elapsed() <= ttl || running.contains(id));
+    }
+}
+
+/// Owner tenant of a session (`None` when it was created without authentication).
+pub fn owner_of(owners: &Owners, session_id: &Uuid) -> Option<String> {
+    let guard = owners.lock().ok()?;
+    guard.get(session_id).map(|o| o.tenant.clone())
+}
+
+/// Tags a session restored from a snapshot with its former owner tenant.
+pub fn restore_owner(owners: &Owners, session_id: Uuid, tenant: String) {
+    if let Ok(mut guard) = owners.lock() {
+        guard.insert(
+            session_id,
+            Owner {
+                tenant,
+                touched_at: Instant::now(),
+            },
+        );
+    }
+}
+
+/// Authenticated tenant of a request, put into its extensions by the interceptor.
+#[derive(Clone)]
+pub struct Caller {
+    tenant: String,
+    owners: Owners,
+    sessions: Arc<SessionStore>,
+    recordings: Recordings,
+}
+
+impl Caller {
+    /// Caller of the request (`None` when authentication is disabled).
+    pub fn of<T>(request: &Request<T>) -> Option<Caller> {
+        request.extensions().get::<Caller>().cloned()
+    }
+
+    /// Tenant of the caller.
+    pub fn tenant(&self) -> &str {
+        &self.tenant
+    }
+
+    /// Number of the given sessions owned by the caller's tenant (all of its sessions
+    /// when `among` is `None`).
+    pub fn owned_sessions(&self, among: Option<&[Uuid]>) -> u64 {
+        let guard = self.owners.lock().unwrap();
+        let mine = |o: &Owner| o.tenant == self.tenant;
+        match among {
+            Some(ids) => ids
+                .iter()
+                .filter(|id| guard.get(id).is_some_and(mine))
+                .count() as u64,
+            None => guard.values().filter(|o| mine(o)).count() as u64,
+        }
+    }
+
+    /// Tags a new session with the caller's tenant.
+    pub fn claim(&self, session_id: Uuid) {
+        self.owners.lock().unwrap().insert(
+            session_id,
+            Owner {
+                tenant: self.tenant.clone(),
+                touched_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Rejects access to a session owned by another tenant, or by no tenant at all.
+    /// Unknown sessions are let through for the handler to report them as not found.
+    pub fn authorize(&self, session_id: &Uuid) -> Result<(), Status> {
+        let mut guard = self.owners.lock().unwrap();
+        match guard.get_mut(session_id) {
+            Some(owner) if owner.tenant != self.tenant => Err(Status::permission_denied(format!(
+                "Session '{}' belongs to another tenant",
+                session_id
+            ))),
+            Some(owner) => {
+                owner.touched_at = Instant::now();
+                Ok(())
+            }
+            None if self.exists(session_id) => Err(Status::permission_denied(format!(
+                "Session '{}' has no owner",
+                session_id
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the session lives in the storage or is being recorded.
+    fn exists(&self, session_id: &Uuid) -> bool {
+        self.sessions.contains(session_id)
+            || self
+                .recordings
+                .lock()
+                .map_or(true, |reg| reg.contains_key(session_id))
+    }
+}
+
+/// Request messages addressing a single session.
+pub trait SessionScoped {
+    fn session_id(&self) -> Option<&pb::UuiDv4>;
+}
+
+impl SessionScoped for pb::UuiDv4 {
+    fn session_id(&self) -> Option<&pb::UuiDv4> {
+        Some(self)
+    }
+}
+
+macro_rules! session_scoped {
+    ($($t:ty),* $(,)?) => {
+        $(impl SessionScoped for $t {
+            fn session_id(&self) -> Option<&pb::UuiDv4> {
+                self.session_id.as_ref()
+            }
+        })*
+    };
+}
+
+session_scoped!(
+    pb::RunAndRecordRequest,
+    pb::RecordingStatusRequest,
+    pb::StopRecordingRequest,
+    pb::TripStatsRequest,
+    pb::TrafficLightStatsRequest,
+    pb::InjectIncidentRequest,
+    pb::ClearIncidentRequest,
+    pb::SetSpeedLimitRequest,
+    pb::SpawnVehicleRequest,
+    pb::RemoveVehicleRequest,
+    pb::GetVehicleRequest,
+    pb::UpdateSessionTripRequest,
+    pb::RemoveSessionTripRequest,
+    pb::SetSessionTripPausedRequest,
+    pb::PushSessionOdMatrixRequest,
+    pb::ComputeRouteRequest,
+    pb::GenerateConflictZonesRequest,
+);
+
+/// Checks that the session addressed by a unary request belongs to its caller.
+/// Malformed session ids are left to the handler.
+pub fn authorize<T: SessionScoped>(request: &Request<T>) -> Result<(), Status> {
+    match request
+        .get_ref()
+        .session_id()
+        .and_then(|id| Uuid::parse_str(&id.value).ok())
+    {
+        Some(session_id) => authorize_session(Caller::of(request).as_ref(), &session_id),
+        None => Ok(()),
+    }
+}
+
+/// Checks that a session belongs to the caller (if authentication is enabled), tagging
+/// the current RPC span with the session and the tenant.
+pub fn authorize_session(caller: Option<&Caller>, session_id: &Uuid) -> Result<(), Status> {
+    telemetry::record_session(session_id);
+    let Some(caller) = caller else {
+        return Ok(());
+    };
+    telemetry::record_tenant(caller.tenant());
+    caller.authorize(session_id)
+}
+
+/// Interceptor validating `authorization: Bearer <token>` metadata against the key file
+/// and attaching the [`Caller`] to the request. Passes everything through when
+/// authentication is disabled (`keys` is `None`).
+pub fn interceptor(
+    keys: Option<Arc<AuthKeys>>,
+    owners: Owners,
+    sessions: Arc<SessionStore>,
+    recordings: Recordings,
+) -> impl FnMut(Request<()>) -> Result<Request<()>, Status> + Clone {
+    move |mut request: Request<()>| {
+        let Some(keys) = &keys else {
+            return Ok(request);
+        };
+        let token = request
+            .metadata()
+            .get("authorization")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .ok_or_else(|| Status::unauthenticated("Missing bearer token"))?;
+        let tenant = keys
+            .tenant_of(token.trim())
+            .ok_or_else(|| Status::unauthenticated("Invalid bearer token"))?
+            .clone();
+        request.extensions_mut().insert(Caller {
+            tenant,
+            owners: owners.clone(),
+            sessions: sessions.clone(),
+            recordings: recordings.clone(),
+        });
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use micro_traffic_sim_core::simulation::session::Session;
+    use micro_traffic_sim_core::verbose::VerboseLevel;
+
+    fn request(token: Option<&str>) -> Request<()> {
+        let mut request = Request::new(());
+        if let Some(token) = token {
+            request
+                .metadata_mut()
+                .insert("authorization", format!("Bearer {token}").parse().unwrap());
+        }
+        request
+    }
+
+    #[test]
+    fn tenants_own_their_sessions() {
+        assert!(AuthKeys::parse("# none\n").is_err());
+        assert!(AuthKeys::parse("a t1\nb t1\n").is_err());
+        assert!(AuthKeys::parse("a\n").is_err());
+        let keys = Arc::new(AuthKeys::parse("# tenant token\nteam-a t1\nteam-b t2\n").unwrap());
+        let owners = new_registry();
+        let sessions = Arc::new(SessionStore::new(
+            Duration::from_secs(60),
+            VerboseLevel::None,
+        ));
+        let recordings = super::super::recordings::new_registry();
+        let mut intercept = interceptor(
+            Some(keys),
+            owners.clone(),
+            sessions.clone(),
+            recordings.clone(),
+        );
+
+        let err = intercept(request(None)).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+        for guess in ["t3", "t", "t10"] {
+            let err = intercept(request(Some(guess))).unwrap_err();
+            assert_eq!(err.code(), tonic::Code::Unauthenticated);
+        }
+
+        let a = Caller::of(&intercept(request(Some("t1"))).unwrap()).unwrap();
+        let b = Caller::of(&intercept(request(Some("t2"))).unwrap()).unwrap();
+        let session = Uuid::new_v4();
+        a.claim(session);
+        assert!(a.authorize(&session).is_ok());
+        let err = b.authorize(&session).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+        // Unknown sessions are reported by the handlers
+        assert!(b.authorize(&Uuid::new_v4()).is_ok());
+
+        // Sessions without an owner (created with authentication disabled, or whose
+        // owner entry is gone) are closed to every tenant, recorded ones included
+        let orphan = Uuid::new_v4();
+        assert!(sessions.register_session(orphan, Session::default(None)));
+        let err = a.authorize(&orphan).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+        let recorded = Uuid::new_v4();
+        recordings
+            .lock()
+            .unwrap()
+            .insert(recorded, Default::default());
+        assert!(a.authorize(&recorded).is_err());
+
+        // Disabled authentication lets everything through, without a caller
+        let mut open = interceptor(None, new_registry(), sessions, recordings);
+        assert!(Caller::of(&open(request(None)).unwrap()).is_none());
+    }
+}
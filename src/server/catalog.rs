@@ -0,0 +1,234 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, TryLockError};
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::agents::VehicleID;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+
+use super::incidents::Incidents;
+use super::routing;
+use super::stats::TripAcc;
+use super::tl_stats::TlGroupAcc;
+
+/// Per-session sidecar state kept by the gRPC layer next to the core `Session`.
+///
+/// The core does not expose what was pushed into a session (grid, trips, conflict
+/// zones) nor any per-step bookkeeping beyond the state dump, so everything the
+/// service needs to derive extra outputs (events, statistics, routes) is mirrored
+/// here as it is ingested, and step-derived counters are accumulated here as well.
+/// Entries are created lazily and expire with the same TTL as sessions.
+#[derive(Clone)]
+pub struct SessionCatalog {
+    /// Grid cells as pushed by the client (core representation, used for routing).
+    pub cells: HashMap<CellID, Cell>,
+    /// Meso link of every cell with one (the core does not expose it).
+    pub meso_links: HashMap<CellID, i64>,
+    /// Trips as pushed by the client, keyed by trip id.
+    pub trips: HashMap<i64, pb::Trip>,
+    /// Trips removed by RemoveSessionTrip. Their dormant copies stay in the core, so
+    /// the ids are not handed out to new trips.
+    pub removed_trips: HashSet<i64>,
+    /// Trips whose vehicle generation is paused.
+    pub paused_trips: HashSet<i64>,
+    /// Demand profile bin pushed to the core for trips with a profile (None = the trip's
+    /// own values). No entry = the trip as pushed on ingest.
+    pub demand_bins: HashMap<i64, Option<usize>>,
+    /// Conflict zones as pushed by the client, keyed by conflict zone id.
+    pub conflict_zones: HashMap<i64, pb::ConflictZone>,
+    /// Cumulative `vehicles_completed` seen on the last step.
+    pub vehicles_completed: i32,
+    /// Cumulative `vehicles_lost` seen on the last step.
+    pub vehicles_lost: i32,
+    /// Per-trip counters and travel times, keyed by trip id.
+    pub trip_stats: HashMap<i64, TripAcc>,
+    /// Traffic lights as pushed by the client, keyed by traffic light id.
+    pub traffic_lights: HashMap<i64, pb::TrafficLight>,
+    /// Per-group signal performance counters, keyed by (traffic light id, group id).
+    pub tl_stats: HashMap<(i64, i64), TlGroupAcc>,
+    /// Incidents scheduled on the session.
+    pub incidents: Incidents,
+    /// Vehicles put by SpawnVehicle since the last step, with their origin cells.
+    pub pending_spawns: Vec<(VehicleID, CellID)>,
+    /// Vehicles taken out by RemoveVehicle, gone from the session on the next step,
+    /// with the cell and trip they had when removed.
+    pub pending_removals: HashMap<VehicleID, (CellID, i64)>,
+    /// Cumulative number of vehicles removed by RemoveVehicle. The core counts them
+    /// as completed, so this is subtracted from its `vehicles_completed`.
+    pub vehicles_removed: i32,
+    /// Vehicles on the network as of the last step.
+    pub vehicles_active: usize,
+    /// Reverse adjacency of `cells`: the cells leading into a cell.
+    upstream: HashMap<CellID, Vec<CellID>>,
+    /// Routing network built from `cells`, dropped whenever they change.
+    network: Option<Arc<GridRoads>>,
+    touched_at: Instant,
+}
+
+impl SessionCatalog {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            meso_links: HashMap::new(),
+            trips: HashMap::new(),
+            removed_trips: HashSet::new(),
+            paused_trips: HashSet::new(),
+            demand_bins: HashMap::new(),
+            conflict_zones: HashMap::new(),
+            vehicles_completed: 0,
+            vehicles_lost: 0,
+            trip_stats: HashMap::new(),
+            traffic_lights: HashMap::new(),
+            tl_stats: HashMap::new(),
+            incidents: Incidents::default(),
+            pending_spawns: Vec::new(),
+            pending_removals: HashMap::new(),
+            vehicles_removed: 0,
+            vehicles_active: 0,
+            upstream: HashMap::new(),
+            network: None,
+            touched_at: Instant::now(),
+        }
+    }
+
+    /// Mirrors a grid cell, overwriting a previous cell with the same id (as the core does).
+    /// A negative `meso_link_id` means the cell belongs to no meso link.
+    pub fn insert_cell(&mut self, cell: Cell, meso_link_id: i64) {
+        let id = cell.get_id();
+        self.network = None;
+        if meso_link_id >= 0 {
+            self.meso_links.insert(id, meso_link_id);
+        } else {
+            self.meso_links.remove(&id);
+        }
+        if let Some(old) = self.cells.insert(id, cell) {
+            for next in outgoing(&old) {
+                if let Some(prev) = self.upstream.get_mut(&next) {
+                    prev.retain(|c| *c != id);
+                }
+            }
+        }
+        for next in outgoing(&self.cells[&id]) {
+            self.upstream.entry(next).or_default().push(id);
+        }
+    }
+
+    /// Id following every trip pushed so far, removed trips included.
+    pub fn next_trip_id(&self) -> i64 {
+        self.trips
+            .keys()
+            .chain(&self.removed_trips)
+            .max()
+            .map_or(1, |id| id + 1)
+    }
+
+    /// Routing network of the mirrored grid, built on first use after a grid change.
+    /// Cheap to clone out of the lock: routing on it does not need the catalog.
+    pub fn network(&mut self) -> Arc<GridRoads> {
+        let cells = &self.cells;
+        self.network
+            .get_or_insert_with(|| Arc::new(routing::build_network(cells)))
+            .clone()
+    }
+
+    /// Drops the cached routing network after `cells` have been changed in place.
+    pub fn invalidate_network(&mut self) {
+        self.network = None;
+    }
+
+    /// Cells having `cell_id` as their forward, left or right neighbour.
+    pub fn upstream_of(&self, cell_id: CellID) -> &[CellID] {
+        self.upstream.get(&cell_id).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Cells reachable from `cell` in one move: its forward, left and right neighbours.
+pub fn outgoing(cell: &Cell) -> impl Iterator<Item = CellID> {
+    [
+        cell.get_forward_id(),
+        cell.get_left_id(),
+        cell.get_right_id(),
+    ]
+    .into_iter()
+    .filter(|id| *id >= 0)
+}
+
+impl Default for SessionCatalog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Registry of session catalogs, keyed by session id. Every catalog has its own lock,
+/// the registry lock is only held to look one up: work on one session's catalog does
+/// not block the others.
+pub type Catalogs = Arc<Mutex<HashMap<Uuid, Arc<Mutex<SessionCatalog>>>>>;
+
+/// Creates an empty catalogs registry.
+pub fn new_registry() -> Catalogs {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+/// Runs `f` against the catalog of the given session, creating it on first use and
+/// refreshing its TTL. Lock order: always take the session lock (if needed) first.
+pub fn with_catalog_mut<R, F: FnOnce(&mut SessionCatalog) -> R>(
+    catalogs: &Catalogs,
+    session_id: &Uuid,
+    f: F,
+) -> R {
+    let entry = catalogs
+        .lock()
+        .unwrap()
+        .entry(*session_id)
+        .or_default()
+        .clone();
+    let mut catalog = entry.lock().unwrap();
+    catalog.touched_at = Instant::now();
+    f(&mut catalog)
+}
+
+/// Takes the catalog of the given session out of the registry (empty if none yet).
+pub fn take_catalog(catalogs: &Catalogs, session_id: &Uuid) -> SessionCatalog {
+    let entry = catalogs
+        .lock()
+        .ok()
+        .and_then(|mut guard| guard.remove(session_id));
+    entry
+        .and_then(|entry| entry.lock().ok().map(|mut c| std::mem::take(&mut *c)))
+        .unwrap_or_default()
+}
+
+/// Puts back a catalog taken with [`take_catalog`].
+pub fn restore_catalog(catalogs: &Catalogs, session_id: Uuid, mut catalog: SessionCatalog) {
+    catalog.touched_at = Instant::now();
+    if let Ok(mut guard) = catalogs.lock() {
+        guard.insert(session_id, Arc::new(Mutex::new(catalog)));
+    }
+}
+
+/// Vehicles on the network as of the last step, summed over all the sessions.
+pub fn vehicles_active(catalogs: &Catalogs) -> u64 {
+    let entries: Vec<_> = match catalogs.lock() {
+        Ok(guard) => guard.values().cloned().collect(),
+        Err(_) => return 0,
+    };
+    entries
+        .iter()
+        .filter_map(|entry| entry.lock().ok().map(|c| c.vehicles_active as u64))
+        .sum()
+}
+
+/// Drops catalogs which have not been touched for longer than `ttl`. Catalogs in use
+/// are kept.
+pub fn purge_expired(catalogs: &Catalogs, ttl: Duration) {
+    if let Ok(mut guard) = catalogs.lock() {
+        guard.retain(|_, entry| match entry.try_lock() {
+            Ok(c) => c.touched_at.elapsed() <= ttl,
+            Err(TryLockError::WouldBlock) => true,
+            Err(TryLockError::Poisoned(_)) => false,
+        });
+    }
+}
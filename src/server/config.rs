@@ -0,0 +1,610 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use clap::Parser;
+use serde::Deserialize;
+use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+/// Server configuration: defaults, overridden by the TOML file (`--config`), then by
+/// environment variables, then by command line flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub server: ServerSection,
+    pub logging: LoggingSection,
+    pub sessions: SessionsSection,
+    pub limits: LimitsSection,
+    pub tls: TlsSection,
+    pub auth: AuthSection,
+    pub quotas: QuotasSection,
+    pub metrics: MetricsSection,
+    pub shutdown: ShutdownSection,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerSection {
+    /// Listen address.
+    pub addr: SocketAddr,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoggingSection {
+    /// Filter of the server logs, `tracing` directive syntax (`info`, `warn,micro_traffic_sim=debug`).
+    pub level: String,
+    /// Output format of the server logs.
+    pub format: LogFormat,
+    /// OTLP/gRPC collector receiving the RPC spans (requires the `otlp` feature).
+    pub otlp_endpoint: Option<String>,
+    /// Per-session simulation logging of the core engine (steps, conflicts, movement):
+    /// 0 = None, 2 = Additional, anything else = Main.
+    pub verbose: u8,
+    /// Storage-level logging (session create/expire): 0 = None, 2 = Additional,
+    /// anything else = Main.
+    pub service_verbose: u8,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line, with the fields of the current span.
+    Json,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SessionsSection {
+    /// Session time-to-live, extended on every access. Also applies to session catalogs.
+    pub ttl_secs: u64,
+    /// Period of the expired sessions purge.
+    pub purge_interval_secs: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsSection {
+    /// Maximum number of data entities (cells, trips, zones, ...) in a single message.
+    pub max_entities_per_message: usize,
+    /// Ticks per RecordBatch when a RunAndRecord request leaves `batch_ticks = 0`.
+    pub default_batch_ticks: u32,
+    /// Safety cap on the number of ticks when RunAndRecord leaves `horizon_ticks = 0`.
+    pub horizon_hard_cap: u64,
+}
+
+/// Transport security. Plaintext unless `cert` and `key` are set; mutual TLS when
+/// `client_ca` is set as well.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TlsSection {
+    /// PEM certificate chain of the server.
+    pub cert: Option<PathBuf>,
+    /// PEM private key of the server.
+    pub key: Option<PathBuf>,
+    /// PEM CA certificate(s) client certificates must be signed by (mTLS).
+    pub client_ca: Option<PathBuf>,
+    /// With `client_ca`: still accept clients presenting no certificate.
+    pub client_auth_optional: bool,
+}
+
+/// Bearer token authentication. Disabled unless `keys_file` is set.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthSection {
+    /// Key file with one `<tenant> <token>` pair per line.
+    pub keys_file: Option<PathBuf>,
+}
+
+/// Resource limits; `None` = unlimited.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Quota {
+    /// Live sessions (recorded ones included).
+    pub max_sessions: Option<u64>,
+    /// Grid cells of a single session.
+    pub max_cells_per_session: Option<u64>,
+    /// Trips of a single session.
+    pub max_trips_per_session: Option<u64>,
+    /// RunAndRecord runs at the same time (each one owns an OS thread).
+    pub max_concurrent_recordings: Option<u64>,
+    /// RunAndRecord horizon, also capping runs without an explicit horizon.
+    pub max_horizon_ticks: Option<u64>,
+}
+
+/// Admission limits: `server` applies to the whole server, `tenant` to every tenant
+/// (authentication enabled) unless `tenants.<name>` overrides some of its fields.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QuotasSection {
+    pub server: Quota,
+    pub tenant: Quota,
+    pub tenants: HashMap<String, Quota>,
+}
+
+/// Prometheus metrics endpoint. Disabled unless `addr` is set.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricsSection {
+    /// Listen address of the plain HTTP endpoint serving `/metrics`.
+    pub addr: Option<SocketAddr>,
+}
+
+/// Graceful shutdown on SIGINT / SIGTERM.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShutdownSection {
+    /// Time given to in-flight RPCs (running recordings included) to finish once the
+    /// shutdown starts; connections still open afterwards are closed.
+    pub drain_timeout_secs: u64,
+    /// Directory the live sessions are saved to on shutdown and restored from on
+    /// start. Disabled unless set.
+    pub snapshot_dir: Option<PathBuf>,
+}
+
+impl Default for ServerSection {
+    fn default() -> Self {
+        Self {
+            addr: SocketAddr::from(([0, 0, 0, 0], 50051)),
+        }
+    }
+}
+
+impl Default for LoggingSection {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            format: LogFormat::Text,
+            otlp_endpoint: None,
+            verbose: 0,
+            service_verbose: 1,
+        }
+    }
+}
+
+impl Default for SessionsSection {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 4 * 60,
+            purge_interval_secs: 30,
+        }
+    }
+}
+
+impl Default for LimitsSection {
+    fn default() -> Self {
+        Self {
+            max_entities_per_message: 10000,
+            default_batch_ticks: 300,
+            horizon_hard_cap: 1_000_000,
+        }
+    }
+}
+
+impl Default for ShutdownSection {
+    fn default() -> Self {
+        Self {
+            drain_timeout_secs: 30,
+            snapshot_dir: None,
+        }
+    }
+}
+
+impl ServerConfig {
+    pub fn session_ttl(&self) -> Duration {
+        Duration::from_secs(self.sessions.ttl_secs)
+    }
+
+    pub fn purge_interval(&self) -> Duration {
+        Duration::from_secs(self.sessions.purge_interval_secs)
+    }
+
+    pub fn drain_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown.drain_timeout_secs)
+    }
+
+    pub fn sim_verbose(&self) -> VerboseLevel {
+        verbose_level(self.logging.verbose)
+    }
+
+    pub fn storage_verbose(&self) -> VerboseLevel {
+        verbose_level(self.logging.service_verbose)
+    }
+
+    /// Server TLS settings read from the configured PEM files (`None` = plaintext).
+    pub fn server_tls(&self) -> Result<Option<ServerTlsConfig>, ConfigError> {
+        let (Some(cert), Some(key)) = (&self.tls.cert, &self.tls.key) else {
+            return Ok(None);
+        };
+        let read =
+            |path: &PathBuf| std::fs::read(path).map_err(|e| ConfigError::Read(path.clone(), e));
+        let mut tls = ServerTlsConfig::new().identity(Identity::from_pem(read(cert)?, read(key)?));
+        if let Some(client_ca) = &self.tls.client_ca {
+            tls = tls
+                .client_ca_root(Certificate::from_pem(read(client_ca)?))
+                .client_auth_optional(self.tls.client_auth_optional);
+        }
+        Ok(Some(tls))
+    }
+
+    /// Checks the values that can not be expressed by the types alone.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |msg: String| Err(ConfigError::Invalid(msg));
+        if let Err(e) = tracing_subscriber::EnvFilter::try_new(&self.logging.level) {
+            return invalid(format!("logging.level: {}", e));
+        }
+        if self.logging.otlp_endpoint.is_some() && !cfg!(feature = "otlp") {
+            return invalid(
+                "logging.otlp_endpoint requires the server built with the `otlp` feature"
+                    .to_string(),
+            );
+        }
+        if self.sessions.ttl_secs == 0 {
+            return invalid("sessions.ttl_secs must be positive".to_string());
+        }
+        if self.sessions.purge_interval_secs == 0 {
+            return invalid("sessions.purge_interval_secs must be positive".to_string());
+        }
+        if self.limits.max_entities_per_message == 0 {
+            return invalid("limits.max_entities_per_message must be positive".to_string());
+        }
+        if self.limits.default_batch_ticks == 0 {
+            return invalid("limits.default_batch_ticks must be positive".to_string());
+        }
+        if self.limits.horizon_hard_cap == 0 {
+            return invalid("limits.horizon_hard_cap must be positive".to_string());
+        }
+        if self.shutdown.drain_timeout_secs == 0 {
+            return invalid("shutdown.drain_timeout_secs must be positive".to_string());
+        }
+        if let Some(dir) = &self.shutdown.snapshot_dir {
+            if dir.exists() && !dir.is_dir() {
+                return invalid(format!(
+                    "shutdown.snapshot_dir '{}' is not a directory",
+                    dir.display()
+                ));
+            }
+        }
+        if self.tls.cert.is_some() != self.tls.key.is_some() {
+            return invalid("tls.cert and tls.key must be set together".to_string());
+        }
+        if self.tls.client_ca.is_some() && self.tls.cert.is_none() {
+            return invalid("tls.client_ca requires tls.cert and tls.key".to_string());
+        }
+        for path in [&self.tls.cert, &self.tls.key, &self.tls.client_ca]
+            .into_iter()
+            .flatten()
+        {
+            if !path.is_file() {
+                return invalid(format!("TLS file '{}' does not exist", path.display()));
+            }
+        }
+        let quotas = std::iter::once(("server", &self.quotas.server))
+            .chain(std::iter::once(("tenant", &self.quotas.tenant)))
+            .chain(self.quotas.tenants.iter().map(|(t, q)| (t.as_str(), q)));
+        for (scope, quota) in quotas {
+            let limits = [
+                ("max_sessions", quota.max_sessions),
+                ("max_cells_per_session", quota.max_cells_per_session),
+                ("max_trips_per_session", quota.max_trips_per_session),
+                ("max_concurrent_recordings", quota.max_concurrent_recordings),
+                ("max_horizon_ticks", quota.max_horizon_ticks),
+            ];
+            if let Some((name, _)) = limits.iter().find(|(_, v)| *v == Some(0)) {
+                return invalid(format!("quotas of '{}': {} must be positive", scope, name));
+            }
+        }
+        if let Some(path) = &self.auth.keys_file {
+            if !path.is_file() {
+                return invalid(format!("auth key file '{}' does not exist", path.display()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads a verbose level flag the way the server always has: "0" and "2" are taken as
+/// is, any other value (`1`, `true`, `yes`, ...) means Main.
+fn lenient_verbose(value: &str) -> Result<u8, std::convert::Infallible> {
+    Ok(match value.trim() {
+        "0" => 0,
+        "2" => 2,
+        _ => 1,
+    })
+}
+
+fn verbose_level(level: u8) -> VerboseLevel {
+    match level {
+        0 => VerboseLevel::None,
+        2 => VerboseLevel::Additional,
+        _ => VerboseLevel::Main,
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The configuration file can not be read.
+    Read(PathBuf, std::io::Error),
+    /// The configuration file is not valid TOML or has unknown / mistyped keys.
+    Parse(PathBuf, toml::de::Error),
+    /// A value is out of its range.
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Read(path, e) => write!(f, "can not read '{}': {}", path.display(), e),
+            ConfigError::Parse(path, e) => write!(f, "can not parse '{}': {}", path.display(), e),
+            ConfigError::Invalid(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Command line flags. Every flag can also be set by its environment variable.
+#[derive(Debug, Default, Parser)]
+#[command(
+    name = "micro_traffic_sim",
+    version,
+    about = "micro_traffic_sim gRPC server"
+)]
+pub struct Cli {
+    /// TOML configuration file
+    #[arg(long, env = "MT_SIM_CONFIG")]
+    pub config: Option<PathBuf>,
+    /// Listen address [default: 0.0.0.0:50051]
+    #[arg(long, env = "MT_SIM_ADDR")]
+    pub addr: Option<SocketAddr>,
+    /// Server log filter, e.g. `debug` or `warn,micro_traffic_sim=debug` [default: info]
+    #[arg(long, env = "MT_SIM_LOG_LEVEL")]
+    pub log_level: Option<String>,
+    /// Server log format [default: text]
+    #[arg(long, env = "MT_SIM_LOG_FORMAT", value_enum)]
+    pub log_format: Option<LogFormat>,
+    /// OTLP/gRPC collector to export the RPC spans to, e.g. `http://localhost:4317`
+    #[arg(long, env = "MT_SIM_OTLP_ENDPOINT")]
+    pub otlp_endpoint: Option<String>,
+    /// Per-session simulation logging: 0 = None, 2 = Additional, anything else = Main [default: 0]
+    #[arg(long, env = "MT_SIM_VERBOSE", value_parser = lenient_verbose)]
+    pub verbose: Option<u8>,
+    /// Storage-level logging: 0 = None, 2 = Additional, anything else = Main [default: 1]
+    #[arg(long, env = "MT_SIM_SERVICE_VERBOSE", value_parser = lenient_verbose)]
+    pub service_verbose: Option<u8>,
+    /// Session time-to-live in seconds [default: 240]
+    #[arg(long, env = "MT_SIM_SESSION_TTL_SECS")]
+    pub session_ttl_secs: Option<u64>,
+    /// Expired sessions purge period in seconds [default: 30]
+    #[arg(long, env = "MT_SIM_PURGE_INTERVAL_SECS")]
+    pub purge_interval_secs: Option<u64>,
+    /// Maximum number of data entities in a single message [default: 10000]
+    #[arg(long, env = "MT_SIM_MAX_ENTITIES_PER_MESSAGE")]
+    pub max_entities_per_message: Option<usize>,
+    /// RunAndRecord ticks per batch when the request leaves it unset [default: 300]
+    #[arg(long, env = "MT_SIM_DEFAULT_BATCH_TICKS")]
+    pub default_batch_ticks: Option<u32>,
+    /// RunAndRecord ticks cap when the request sets no horizon [default: 1000000]
+    #[arg(long, env = "MT_SIM_HORIZON_HARD_CAP")]
+    pub horizon_hard_cap: Option<u64>,
+    /// PEM certificate chain of the server (enables TLS, requires --tls-key)
+    #[arg(long, env = "MT_SIM_TLS_CERT")]
+    pub tls_cert: Option<PathBuf>,
+    /// PEM private key of the server
+    #[arg(long, env = "MT_SIM_TLS_KEY")]
+    pub tls_key: Option<PathBuf>,
+    /// PEM CA certificate client certificates must be signed by (enables mTLS)
+    #[arg(long, env = "MT_SIM_TLS_CLIENT_CA")]
+    pub tls_client_ca: Option<PathBuf>,
+    /// Key file of `<tenant> <token>` lines (enables bearer token authentication)
+    #[arg(long, env = "MT_SIM_AUTH_KEYS_FILE")]
+    pub auth_keys_file: Option<PathBuf>,
+    /// Server-wide maximum number of live sessions [default: unlimited]
+    #[arg(long, env = "MT_SIM_MAX_SESSIONS")]
+    pub max_sessions: Option<u64>,
+    /// Server-wide maximum number of grid cells per session [default: unlimited]
+    #[arg(long, env = "MT_SIM_MAX_CELLS_PER_SESSION")]
+    pub max_cells_per_session: Option<u64>,
+    /// Server-wide maximum number of trips per session [default: unlimited]
+    #[arg(long, env = "MT_SIM_MAX_TRIPS_PER_SESSION")]
+    pub max_trips_per_session: Option<u64>,
+    /// Server-wide maximum number of concurrent RunAndRecord runs [default: unlimited]
+    #[arg(long, env = "MT_SIM_MAX_CONCURRENT_RECORDINGS")]
+    pub max_concurrent_recordings: Option<u64>,
+    /// Server-wide maximum RunAndRecord horizon in ticks [default: unlimited]
+    #[arg(long, env = "MT_SIM_MAX_HORIZON_TICKS")]
+    pub max_horizon_ticks: Option<u64>,
+    /// Listen address of the Prometheus `/metrics` endpoint [default: disabled]
+    #[arg(long, env = "MT_SIM_METRICS_ADDR")]
+    pub metrics_addr: Option<SocketAddr>,
+    /// Seconds given to in-flight RPCs to finish on shutdown [default: 30]
+    #[arg(long, env = "MT_SIM_DRAIN_TIMEOUT_SECS")]
+    pub drain_timeout_secs: Option<u64>,
+    /// Directory to save live sessions to on shutdown and restore them from on start
+    #[arg(long, env = "MT_SIM_SNAPSHOT_DIR")]
+    pub snapshot_dir: Option<PathBuf>,
+}
+
+impl Cli {
+    /// Builds the validated configuration: the file (if any) overridden by the flags.
+    pub fn load(self) -> Result<ServerConfig, ConfigError> {
+        let mut config = match &self.config {
+            Some(path) => {
+                let text = std::fs::read_to_string(path)
+                    .map_err(|e| ConfigError::Read(path.clone(), e))?;
+                toml::from_str(&text).map_err(|e| ConfigError::Parse(path.clone(), e))?
+            }
+            None => ServerConfig::default(),
+        };
+        if let Some(addr) = self.addr {
+            config.server.addr = addr;
+        }
+        if let Some(level) = self.log_level {
+            config.logging.level = level;
+        }
+        if let Some(format) = self.log_format {
+            config.logging.format = format;
+        }
+        if let Some(endpoint) = self.otlp_endpoint {
+            config.logging.otlp_endpoint = Some(endpoint);
+        }
+        if let Some(verbose) = self.verbose {
+            config.logging.verbose = verbose;
+        }
+        if let Some(verbose) = self.service_verbose {
+            config.logging.service_verbose = verbose;
+        }
+        if let Some(secs) = self.session_ttl_secs {
+            config.sessions.ttl_secs = secs;
+        }
+        if let Some(secs) = self.purge_interval_secs {
+            config.sessions.purge_interval_secs = secs;
+        }
+        if let Some(max) = self.max_entities_per_message {
+            config.limits.max_entities_per_message = max;
+        }
+        if let Some(ticks) = self.default_batch_ticks {
+            config.limits.default_batch_ticks = ticks;
+        }
+        if let Some(cap) = self.horizon_hard_cap {
+            config.limits.horizon_hard_cap = cap;
+        }
+        if let Some(path) = self.tls_cert {
+            config.tls.cert = Some(path);
+        }
+        if let Some(path) = self.tls_key {
+            config.tls.key = Some(path);
+        }
+        if let Some(path) = self.tls_client_ca {
+            config.tls.client_ca = Some(path);
+        }
+        if let Some(path) = self.auth_keys_file {
+            config.auth.keys_file = Some(path);
+        }
+        if let Some(addr) = self.metrics_addr {
+            config.metrics.addr = Some(addr);
+        }
+        if let Some(secs) = self.drain_timeout_secs {
+            config.shutdown.drain_timeout_secs = secs;
+        }
+        if let Some(dir) = self.snapshot_dir {
+            config.shutdown.snapshot_dir = Some(dir);
+        }
+        let server = &mut config.quotas.server;
+        server.max_sessions = self.max_sessions.or(server.max_sessions);
+        server.max_cells_per_session = self.max_cells_per_session.or(server.max_cells_per_session);
+        server.max_trips_per_session = self.max_trips_per_session.or(server.max_trips_per_session);
+        server.max_concurrent_recordings = self
+            .max_concurrent_recordings
+            .or(server.max_concurrent_recordings);
+        server.max_horizon_ticks = self.max_horizon_ticks.or(server.max_horizon_ticks);
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+static CONFIG: OnceLock<ServerConfig> = OnceLock::new();
+
+/// Installs the process-wide configuration. Must be called once, before serving.
+pub fn install(config: ServerConfig) {
+    CONFIG
+        .set(config)
+        .expect("server configuration is installed twice");
+}
+
+/// Process-wide configuration (the defaults when none has been installed, e.g. in tests).
+pub fn get() -> &'static ServerConfig {
+    CONFIG.get_or_init(ServerConfig::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{CommandFactory, FromArgMatches};
+
+    /// Parses command line arguments ignoring the `MT_SIM_*` environment of the test
+    /// process.
+    fn parse_args(args: &[&str]) -> Result<Cli, clap::Error> {
+        let matches = Cli::command()
+            .mut_args(|arg| arg.env(None::<&str>))
+            .try_get_matches_from(args)?;
+        Cli::from_arg_matches(&matches)
+    }
+
+    #[test]
+    fn file_then_flags() {
+        let path = std::env::temp_dir().join(format!("mt_sim_config_{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            "[server]\naddr = \"127.0.0.1:6000\"\n[sessions]\nttl_secs = 60\n[limits]\nhorizon_hard_cap = 5000\n",
+        )
+        .unwrap();
+        let cli = Cli {
+            config: Some(path.clone()),
+            session_ttl_secs: Some(90),
+            ..Cli::default()
+        };
+        let config = cli.load().unwrap();
+        assert_eq!(config.server.addr, "127.0.0.1:6000".parse().unwrap());
+        assert_eq!(config.sessions.ttl_secs, 90);
+        assert_eq!(config.sessions.purge_interval_secs, 30);
+        assert_eq!(config.limits.horizon_hard_cap, 5000);
+        assert_eq!(config.limits.max_entities_per_message, 10000);
+        assert_eq!(config.logging.format, LogFormat::Text);
+
+        std::fs::write(&path, "[limits]\nmax_entities = 5\n").unwrap();
+        let cli = Cli {
+            config: Some(path.clone()),
+            ..Cli::default()
+        };
+        assert!(matches!(cli.load(), Err(ConfigError::Parse(..))));
+        std::fs::remove_file(&path).unwrap();
+
+        // Verbose levels stay lenient: anything but 0 and 2 means Main
+        assert_eq!(lenient_verbose("true"), Ok(1));
+        assert_eq!(lenient_verbose("2"), Ok(2));
+        let cli = parse_args(&["micro_traffic_sim", "--verbose", "yes"]).unwrap();
+        assert_eq!(cli.verbose, Some(1));
+        let cli = Cli {
+            service_verbose: Some(3),
+            ..Cli::default()
+        };
+        assert_eq!(cli.load().unwrap().storage_verbose(), VerboseLevel::Main);
+
+        let cli = Cli {
+            log_level: Some("micro_traffic_sim=loud".to_string()),
+            ..Cli::default()
+        };
+        assert!(
+            cli.load()
+                .unwrap_err()
+                .to_string()
+                .starts_with("logging.level")
+        );
+
+        let cli = Cli {
+            drain_timeout_secs: Some(0),
+            ..Cli::default()
+        };
+        let err = cli.load().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "shutdown.drain_timeout_secs must be positive"
+        );
+
+        let cli = Cli {
+            tls_cert: Some(PathBuf::from("server.crt")),
+            ..Cli::default()
+        };
+        let err = cli.load().unwrap_err();
+        assert_eq!(err.to_string(), "tls.cert and tls.key must be set together");
+    }
+}
@@ -1,17 +1,25 @@
-use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Code, Request, Response, Status};
+use tracing::Instrument;
 use uuid::Uuid;
 
 use micro_traffic_sim::pb;
 use micro_traffic_sim_core::conflict_zones::{
     ConflictEdge, ConflictWinnerType, ConflictZone, ConflictZoneType,
 };
-use micro_traffic_sim_core::simulation::sessions_storage::SessionsStorage;
+use micro_traffic_sim_core::geom::Point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::zones::ZoneType;
 
 use super::BoxStream;
+use super::auth;
+use super::catalog::{self, Catalogs};
+use super::storage::SessionStore;
+use super::{blocking, check_entities_count, ensure_session, parse_session_uuid};
 
 /// Converts proto ConflictWinnerType (i32) to computational core ConflictWinnerType
 fn proto_winner_type_to_core(winner_type: i32) -> ConflictWinnerType {
@@ -24,113 +32,662 @@ fn proto_winner_type_to_core(winner_type: i32) -> ConflictWinnerType {
 }
 
 /// Converts proto ConflictZoneType (i32) to computational core ConflictZoneType
-fn proto_zone_type_to_core(_zone_type: i32) -> ConflictZoneType {
-    // Currently only Undefined is implemented in core
-    ConflictZoneType::Undefined
+fn proto_zone_type_to_core(zone_type: i32) -> ConflictZoneType {
+    // Currently only Undefined is implemented in core; other values are rejected by
+    // `validate_conflict_zones`
+    match pb::ConflictZoneType::try_from(zone_type) {
+        Ok(pb::ConflictZoneType::Undefined) | Err(_) => ConflictZoneType::Undefined,
+    }
+}
+
+/// Pair of edges resolved by a conflict zone, regardless of their order.
+fn edges_key(zone: &pb::ConflictZone) -> [(CellID, CellID); 2] {
+    let mut key = [
+        (zone.source_x, zone.target_x),
+        (zone.source_y, zone.target_y),
+    ];
+    key.sort_unstable();
+    key
+}
+
+/// Checks conflict zones before adding them to a session: known enum values, edges
+/// being actual cell connections of the grid and no pair of edges used twice (within
+/// the batch or by another zone of the session). Zones overwrite the session zones
+/// having the same id, as the core does.
+fn validate_conflict_zones(
+    cells: &HashMap<CellID, Cell>,
+    existing: &HashMap<i64, pb::ConflictZone>,
+    zones: &[pb::ConflictZone],
+) -> Result<(), Status> {
+    let batch_ids: HashSet<i64> = zones.iter().map(|z| z.id).collect();
+    let mut used: HashMap<[(CellID, CellID); 2], i64> = existing
+        .values()
+        .filter(|z| !batch_ids.contains(&z.id))
+        .map(|z| (edges_key(z), z.id))
+        .collect();
+    // A cell is the target of one conflict zone at most, as in `generate_conflict_zones`
+    let mut claimed: HashMap<CellID, i64> = existing
+        .values()
+        .filter(|z| !batch_ids.contains(&z.id))
+        .flat_map(|z| [(z.target_x, z.id), (z.target_y, z.id)])
+        .collect();
+    let mut ids = HashSet::new();
+    for zone in zones {
+        if !ids.insert(zone.id) {
+            return Err(Status::invalid_argument(format!(
+                "Duplicate conflict zone ID: '{}'",
+                zone.id
+            )));
+        }
+        if pb::ConflictWinnerType::try_from(zone.conflict_winner).is_err() {
+            return Err(Status::invalid_argument(format!(
+                "Conflict zone '{}': unknown conflict winner type {}",
+                zone.id, zone.conflict_winner
+            )));
+        }
+        if pb::ConflictZoneType::try_from(zone.conflict_type).is_err() {
+            return Err(Status::invalid_argument(format!(
+                "Conflict zone '{}': unknown conflict zone type {}",
+                zone.id, zone.conflict_type
+            )));
+        }
+        for (source, target) in [
+            (zone.source_x, zone.target_x),
+            (zone.source_y, zone.target_y),
+        ] {
+            let source_cell = cells
+                .get(&source)
+                .ok_or_else(|| Status::not_found(format!("Not found cell ID: '{}'", source)))?;
+            if !cells.contains_key(&target) {
+                return Err(Status::not_found(format!(
+                    "Not found cell ID: '{}'",
+                    target
+                )));
+            }
+            if !catalog::outgoing(source_cell).any(|next| next == target) {
+                return Err(Status::invalid_argument(format!(
+                    "Conflict zone '{}': cell {} is not connected to cell {}",
+                    zone.id, source, target
+                )));
+            }
+        }
+        let key = edges_key(zone);
+        if key[0] == key[1] {
+            return Err(Status::invalid_argument(format!(
+                "Conflict zone '{}': both edges are {} -> {}",
+                zone.id, zone.source_x, zone.target_x
+            )));
+        }
+        if let Some(other) = used.insert(key, zone.id) {
+            return Err(Status::invalid_argument(format!(
+                "Conflict zone '{}' duplicates conflict zone '{}'",
+                zone.id, other
+            )));
+        }
+        for target in [zone.target_x, zone.target_y] {
+            if let Some(other) = claimed.insert(target, zone.id).filter(|id| *id != zone.id) {
+                return Err(Status::invalid_argument(format!(
+                    "Conflict zone '{}' shares target cell {} with conflict zone '{}'",
+                    zone.id, target, other
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Heading of the movement `source` -> `target` (zero when a cell is unknown).
+fn heading(cells: &HashMap<CellID, Cell>, source: CellID, target: CellID) -> (f64, f64) {
+    match (cells.get(&source), cells.get(&target)) {
+        (Some(from), Some(to)) => (
+            to.get_point().x() - from.get_point().x(),
+            to.get_point().y() - from.get_point().y(),
+        ),
+        _ => (0.0, 0.0),
+    }
+}
+
+/// Right-hand rule: the movement coming from the right (its heading turned
+/// counterclockwise from the other one) wins; parallel or opposite movements tie.
+fn right_hand_winner(first: (f64, f64), second: (f64, f64)) -> pb::ConflictWinnerType {
+    let cross = first.0 * second.1 - first.1 * second.0;
+    let scale = first.0.hypot(first.1) * second.0.hypot(second.1);
+    if cross.abs() <= 1e-9 * scale {
+        pb::ConflictWinnerType::ConflictWinnerEqual
+    } else if cross > 0.0 {
+        pb::ConflictWinnerType::ConflictWinnerSecond
+    } else {
+        pb::ConflictWinnerType::ConflictWinnerFirst
+    }
+}
+
+/// Expands a priority rule preset into the winner of every conflict zone.
+fn apply_priority(
+    cells: &HashMap<CellID, Cell>,
+    priority: &pb::ConflictPriority,
+    zones: &mut [pb::ConflictZone],
+) -> Result<(), Status> {
+    let preset = pb::ConflictPriorityPreset::try_from(priority.preset).map_err(|_| {
+        Status::invalid_argument(format!(
+            "Unknown conflict priority preset: {}",
+            priority.preset
+        ))
+    })?;
+    let priority_road: HashSet<CellID> = priority.priority_cells.iter().copied().collect();
+    if preset == pb::ConflictPriorityPreset::PriorityRoad && priority_road.is_empty() {
+        return Err(Status::invalid_argument(
+            "Priority road preset requires priority cells",
+        ));
+    }
+    for zone in zones {
+        let right_hand = || {
+            right_hand_winner(
+                heading(cells, zone.source_x, zone.target_x),
+                heading(cells, zone.source_y, zone.target_y),
+            )
+        };
+        let winner = match preset {
+            pb::ConflictPriorityPreset::None => continue,
+            pb::ConflictPriorityPreset::AllWayStop => pb::ConflictWinnerType::ConflictWinnerEqual,
+            pb::ConflictPriorityPreset::RightHand => right_hand(),
+            pb::ConflictPriorityPreset::PriorityRoad => match (
+                priority_road.contains(&zone.source_x),
+                priority_road.contains(&zone.source_y),
+            ) {
+                (true, false) => pb::ConflictWinnerType::ConflictWinnerFirst,
+                (false, true) => pb::ConflictWinnerType::ConflictWinnerSecond,
+                _ => right_hand(),
+            },
+        };
+        zone.conflict_winner = winner as i32;
+    }
+    Ok(())
+}
+
+/// Converts a proto conflict zone to the computational core one
+pub(super) fn core_conflict_zone(data: &pb::ConflictZone) -> ConflictZone {
+    let first_edge = ConflictEdge {
+        source: data.source_x,
+        target: data.target_x,
+    };
+    let second_edge = ConflictEdge {
+        source: data.source_y,
+        target: data.target_y,
+    };
+    ConflictZone::new(data.id as i32, first_edge, second_edge)
+        .with_winner_type(proto_winner_type_to_core(data.conflict_winner))
+        .with_zone_type(proto_zone_type_to_core(data.conflict_type))
+        .build()
 }
 
 pub async fn push_session_conflict_zones(
-    sessions: Arc<Mutex<SessionsStorage>>,
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
     request: Request<tonic::Streaming<pb::SessionConflictZones>>,
 ) -> Result<Response<BoxStream<pb::SessionConflictZonesResponse>>, Status> {
+    let caller = auth::Caller::of(&request);
     let mut stream = request.into_inner();
     let (tx, rx) = mpsc::channel(16);
 
-    tokio::spawn(async move {
-        while let Ok(Some(req)) = stream.message().await {
-            // Validate session_id presence
-            let session_id = match &req.session_id {
-                Some(id) => &id.value,
-                None => {
-                    let _ = tx
-                        .send(Err(Status::invalid_argument(
-                            "No session ID has been provided",
-                        )))
-                        .await;
+    tokio::spawn(
+        async move {
+            while let Ok(Some(req)) = stream.message().await {
+                // Validate session_id presence
+                let session_id = match &req.session_id {
+                    Some(id) => &id.value,
+                    None => {
+                        let _ = tx
+                            .send(Err(Status::invalid_argument(
+                                "No session ID has been provided",
+                            )))
+                            .await;
+                        return;
+                    }
+                };
+
+                // Parse UUID
+                let session_uuid = match Uuid::parse_str(session_id) {
+                    Ok(uuid) => uuid,
+                    Err(_) => {
+                        let _ = tx
+                            .send(Err(Status::invalid_argument(format!(
+                                "Session ID should be of type UUID v4: '{}'",
+                                session_id
+                            ))))
+                            .await;
+                        return;
+                    }
+                };
+
+                // Reject sessions owned by other tenants
+                let authorized = auth::authorize_session(caller.as_ref(), &session_uuid);
+                if let Err(status) = authorized {
+                    let _ = tx.send(Err(status)).await;
                     return;
                 }
-            };
 
-            // Parse UUID
-            let session_uuid = match Uuid::parse_str(session_id) {
-                Ok(uuid) => uuid,
-                Err(_) => {
-                    let _ = tx
-                        .send(Err(Status::invalid_argument(format!(
-                            "Session ID should be of type UUID v4: '{}'",
-                            session_id
-                        ))))
-                        .await;
+                // Validate data size
+                if let Err(status) = check_entities_count(req.data.len()) {
+                    let _ = tx.send(Err(status)).await;
                     return;
                 }
-            };
 
-            // Validate data size
-            if req.data.len() > 10000 {
-                let _ = tx
-                    .send(Err(Status::invalid_argument(format!(
-                        "Max amount on data entities is 10000, but provided is {}",
-                        req.data.len()
-                    ))))
-                    .await;
-                return;
-            }
+                if req.data.is_empty() {
+                    let _ = tx.send(Err(Status::invalid_argument("No data"))).await;
+                    return;
+                }
 
-            if req.data.is_empty() {
-                let _ = tx.send(Err(Status::invalid_argument("No data"))).await;
-                return;
-            }
+                // Get session, validate the conflict zones against its grid and add them
+                // (on the blocking pool, OFF the async workers)
+                let add_result = {
+                    let sessions = sessions.clone();
+                    let catalogs = catalogs.clone();
+                    blocking(move || {
+                        sessions.with_session_mut(&session_uuid, |session| {
+                            catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+                                validate_conflict_zones(&c.cells, &c.conflict_zones, &req.data)?;
+                                let mut zones = req.data;
+                                if let Some(priority) = &req.priority {
+                                    apply_priority(&c.cells, priority, &mut zones)?;
+                                }
+                                for zone in &zones {
+                                    session.add_conflict_zone(core_conflict_zone(zone));
+                                    // Mirror the conflict zone into the session catalog (used to derive events)
+                                    c.conflict_zones.insert(zone.id, *zone);
+                                }
+                                Ok(())
+                            })
+                        })
+                    })
+                    .await
+                };
 
-            // Get session and add conflict zones (use block scope to ensure lock is dropped before await)
-            let add_result = {
-                let mut sessions_guard = sessions.lock().unwrap();
-                sessions_guard.with_session_mut(&session_uuid, |session| {
-                    // Convert proto conflict zones to core conflict zones and add them
-                    for cz_data in &req.data {
-                        let first_edge = ConflictEdge {
-                            source: cz_data.source_x,
-                            target: cz_data.target_x,
-                        };
-                        let second_edge = ConflictEdge {
-                            source: cz_data.source_y,
-                            target: cz_data.target_y,
-                        };
-
-                        let winner_type = proto_winner_type_to_core(cz_data.conflict_winner);
-                        let zone_type = proto_zone_type_to_core(cz_data.conflict_type);
-
-                        let conflict_zone =
-                            ConflictZone::new(cz_data.id as i32, first_edge, second_edge)
-                                .with_winner_type(winner_type)
-                                .with_zone_type(zone_type)
-                                .build();
-
-                        session.add_conflict_zone(conflict_zone);
+                match add_result {
+                    Ok(None) => {
+                        let _ = tx
+                            .send(Err(Status::not_found(format!(
+                                "Not found session ID: '{}'",
+                                session_uuid
+                            ))))
+                            .await;
+                        return;
                     }
-                })
-            };
-
-            if add_result.is_none() {
-                let _ = tx
-                    .send(Err(Status::not_found(format!(
-                        "Not found session ID: '{}'",
-                        session_id
-                    ))))
-                    .await;
-                return;
-            }
+                    Ok(Some(Err(status))) | Err(status) => {
+                        let _ = tx.send(Err(status)).await;
+                        return;
+                    }
+                    Ok(Some(Ok(()))) => {}
+                }
 
-            // Send OK response
-            let resp = pb::SessionConflictZonesResponse {
-                code: Code::Ok as u32,
-                text: "OK".to_string(),
-            };
-            if tx.send(Ok(resp)).await.is_err() {
-                return;
+                // Send OK response
+                let resp = pb::SessionConflictZonesResponse {
+                    code: Code::Ok as u32,
+                    text: "OK".to_string(),
+                };
+                if tx.send(Ok(resp)).await.is_err() {
+                    return;
+                }
             }
         }
-    });
+        .instrument(tracing::Span::current()),
+    );
 
     let out: BoxStream<pb::SessionConflictZonesResponse> = Box::pin(ReceiverStream::new(rx));
     Ok(Response::new(out))
 }
+
+/// Directed move between two neighbouring cells.
+#[derive(Clone, Copy)]
+struct Movement {
+    source: CellID,
+    target: CellID,
+    from: (f64, f64),
+    to: (f64, f64),
+}
+
+/// Movements with at least one end in a coordination zone, ordered by (source, target).
+fn coordination_movements(cells: &HashMap<CellID, Cell>) -> Vec<Movement> {
+    let is_coordination = |id: &CellID| {
+        cells
+            .get(id)
+            .is_some_and(|c| c.get_zone_type() == ZoneType::Coordination)
+    };
+    let xy = |cell: &Cell| (cell.get_point().x(), cell.get_point().y());
+    let mut movements: Vec<Movement> = cells
+        .values()
+        .flat_map(|cell| catalog::outgoing(cell).map(move |target| (cell, target)))
+        .filter(|(cell, target)| is_coordination(&cell.get_id()) || is_coordination(target))
+        .filter_map(|(cell, target)| {
+            cells.get(&target).map(|next| Movement {
+                source: cell.get_id(),
+                target,
+                from: xy(cell),
+                to: xy(next),
+            })
+        })
+        .collect();
+    movements.sort_by_key(|m| (m.source, m.target));
+    movements
+}
+
+/// Whether segments `a` and `b` cross at a single point lying strictly inside both
+/// (touching ends and collinear overlaps do not count).
+fn segments_cross(a: &Movement, b: &Movement) -> bool {
+    let orient = |p: (f64, f64), q: (f64, f64), r: (f64, f64)| {
+        (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+    };
+    let d1 = orient(a.from, a.to, b.from);
+    let d2 = orient(a.from, a.to, b.to);
+    let d3 = orient(b.from, b.to, a.from);
+    let d4 = orient(b.from, b.to, a.to);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+/// Detects merging and crossing movements within coordination zones and builds a
+/// conflict zone for each of them, numbered from `first_id`.
+///
+/// A conflict is skipped (and counted) when one of its target cells is in `claimed` or
+/// already taken by a previously generated zone, since the core resolves every target
+/// cell against a single zone.
+fn generate_conflict_zones(
+    cells: &HashMap<CellID, Cell>,
+    claimed: &HashSet<CellID>,
+    winner: pb::ConflictWinnerType,
+    first_id: i64,
+) -> (Vec<pb::ConflictZone>, u32) {
+    let movements = coordination_movements(cells);
+
+    // Crossing segments have their midpoints closer than the longest movement, so only
+    // movements of neighbouring buckets (of that size) need to be compared.
+    let bucket_size = movements
+        .iter()
+        .map(|m| (m.to.0 - m.from.0).hypot(m.to.1 - m.from.1))
+        .fold(0.0_f64, f64::max)
+        .max(f64::EPSILON);
+    let bucket_of = |m: &Movement| {
+        (
+            ((m.from.0 + m.to.0) / 2.0 / bucket_size).floor() as i64,
+            ((m.from.1 + m.to.1) / 2.0 / bucket_size).floor() as i64,
+        )
+    };
+    let mut buckets: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    for (idx, m) in movements.iter().enumerate() {
+        buckets.entry(bucket_of(m)).or_default().push(idx);
+    }
+
+    let mut claimed = claimed.clone();
+    let mut zones = Vec::new();
+    let mut skipped = 0;
+    for (i, first) in movements.iter().enumerate() {
+        let (bx, by) = bucket_of(first);
+        let mut candidates: Vec<usize> = (-1..=1)
+            .flat_map(|dx| (-1..=1).map(move |dy| (bx + dx, by + dy)))
+            .filter_map(|key| buckets.get(&key))
+            .flatten()
+            .copied()
+            .filter(|j| *j > i)
+            .collect();
+        candidates.sort_unstable();
+        for second in candidates.into_iter().map(|j| &movements[j]) {
+            if first.source == second.source {
+                continue;
+            }
+            let merging = first.target == second.target;
+            if !merging && !segments_cross(first, second) {
+                continue;
+            }
+            if claimed.contains(&first.target) || claimed.contains(&second.target) {
+                skipped += 1;
+                continue;
+            }
+            claimed.insert(first.target);
+            claimed.insert(second.target);
+            zones.push(pb::ConflictZone {
+                id: first_id + zones.len() as i64,
+                source_x: first.source,
+                target_x: first.target,
+                source_y: second.source,
+                target_y: second.target,
+                conflict_winner: winner as i32,
+                conflict_type: pb::ConflictZoneType::Undefined as i32,
+            });
+        }
+    }
+    (zones, skipped)
+}
+
+/// Generates conflict zones for the coordination zones of the session grid, adding them
+/// to the session unless `dry_run` is set.
+pub async fn generate_session_conflict_zones(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    request: Request<pb::GenerateConflictZonesRequest>,
+) -> Result<Response<pb::GenerateConflictZonesResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+    ensure_session(&sessions, &session_uuid).await?;
+
+    let winner = match pb::ConflictWinnerType::try_from(req.default_winner) {
+        Ok(pb::ConflictWinnerType::ConflictWinnerUndefined) => {
+            pb::ConflictWinnerType::ConflictWinnerEqual
+        }
+        Ok(winner) => winner,
+        Err(_) => {
+            return Err(Status::invalid_argument(format!(
+                "Unknown conflict winner type: {}",
+                req.default_winner
+            )));
+        }
+    };
+
+    let (data, skipped) = blocking(move || {
+        sessions.with_session_mut(&session_uuid, |session| {
+            catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+                let claimed: HashSet<CellID> = c
+                    .conflict_zones
+                    .values()
+                    .flat_map(|z| [z.target_x, z.target_y])
+                    .collect();
+                let first_id = if req.first_id > 0 {
+                    req.first_id
+                } else {
+                    c.conflict_zones.keys().max().map_or(1, |id| id + 1)
+                };
+                let (mut zones, skipped) =
+                    generate_conflict_zones(&c.cells, &claimed, winner, first_id);
+                // Generated zones never overwrite the session ones
+                if let Some(taken) = zones.iter().find(|z| c.conflict_zones.contains_key(&z.id)) {
+                    return Err(Status::already_exists(format!(
+                        "Conflict zone ID '{}' already exists",
+                        taken.id
+                    )));
+                }
+                if let Some(priority) = &req.priority {
+                    apply_priority(&c.cells, priority, &mut zones)?;
+                }
+                validate_conflict_zones(&c.cells, &c.conflict_zones, &zones)?;
+                if !req.dry_run {
+                    for zone in &zones {
+                        session.add_conflict_zone(core_conflict_zone(zone));
+                        c.conflict_zones.insert(zone.id, *zone);
+                    }
+                }
+                Ok::<_, Status>((zones, skipped))
+            })
+        })
+    })
+    .await?
+    .ok_or_else(|| Status::not_found(format!("Not found session ID: '{}'", session_uuid)))??;
+
+    Ok(Response::new(pb::GenerateConflictZonesResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        data,
+        skipped,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use micro_traffic_sim_core::geom::new_point;
+
+    fn cell(id: CellID, x: f64, y: f64, forward: CellID, zone: ZoneType) -> Cell {
+        Cell::new(id)
+            .with_point(new_point(x, y, None))
+            .with_zone_type(zone)
+            .with_speed_limit(1)
+            .with_left_node(-1)
+            .with_forward_node(forward)
+            .with_right_node(-1)
+            .build()
+    }
+
+    fn crossing_cells() -> HashMap<CellID, Cell> {
+        // 1 -> 2 heads north-east, 3 -> 4 heads south-east, crossing at (1, 1)
+        [
+            cell(1, 0.0, 0.0, 2, ZoneType::Coordination),
+            cell(2, 2.0, 2.0, -1, ZoneType::Coordination),
+            cell(3, 0.0, 2.0, 4, ZoneType::Coordination),
+            cell(4, 2.0, 0.0, -1, ZoneType::Coordination),
+        ]
+        .into_iter()
+        .map(|c| (c.get_id(), c))
+        .collect()
+    }
+
+    fn zone(id: i64, first: (CellID, CellID), second: (CellID, CellID)) -> pb::ConflictZone {
+        pb::ConflictZone {
+            id,
+            source_x: first.0,
+            target_x: first.1,
+            source_y: second.0,
+            target_y: second.1,
+            conflict_winner: pb::ConflictWinnerType::ConflictWinnerEqual as i32,
+            conflict_type: pb::ConflictZoneType::Undefined as i32,
+        }
+    }
+
+    #[test]
+    fn validation() {
+        let cells = crossing_cells();
+        let existing = HashMap::from([(1, zone(1, (1, 2), (3, 4)))]);
+
+        // Overwriting a zone by id is fine
+        assert!(validate_conflict_zones(&cells, &existing, &[zone(1, (3, 4), (1, 2))]).is_ok());
+
+        let err =
+            validate_conflict_zones(&cells, &existing, &[zone(2, (3, 4), (1, 2))]).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!(err.message().contains("duplicates conflict zone '1'"));
+
+        let err = validate_conflict_zones(&cells, &HashMap::new(), &[zone(2, (1, 4), (3, 4))])
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!(err.message().contains("cell 1 is not connected to cell 4"));
+
+        let err = validate_conflict_zones(&cells, &HashMap::new(), &[zone(2, (1, 2), (3, 42))])
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+
+        let batch = [zone(2, (1, 2), (3, 4)), zone(2, (3, 4), (1, 2))];
+        let err = validate_conflict_zones(&cells, &HashMap::new(), &batch).unwrap_err();
+        assert!(err.message().contains("Duplicate conflict zone ID"));
+
+        // One zone per target cell, against the existing zones and within the batch
+        let mut cells = cells;
+        cells.insert(5, cell(5, 4.0, 2.0, 2, ZoneType::Coordination));
+        let err =
+            validate_conflict_zones(&cells, &existing, &[zone(2, (5, 2), (3, 4))]).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert!(
+            err.message()
+                .contains("shares target cell 2 with conflict zone '1'")
+        );
+        let batch = [zone(2, (1, 2), (3, 4)), zone(3, (5, 2), (3, 4))];
+        let err = validate_conflict_zones(&cells, &HashMap::new(), &batch).unwrap_err();
+        assert!(err.message().contains("with conflict zone '2'"));
+        // A merge: both edges of one zone lead into the same cell
+        assert!(
+            validate_conflict_zones(&cells, &HashMap::new(), &[zone(2, (1, 2), (5, 2))]).is_ok()
+        );
+    }
+
+    #[test]
+    fn priority_presets() {
+        let cells = crossing_cells();
+        let winner = |preset: pb::ConflictPriorityPreset, priority_cells: Vec<CellID>| {
+            let mut zones = [zone(1, (1, 2), (3, 4))];
+            let priority = pb::ConflictPriority {
+                preset: preset as i32,
+                priority_cells,
+            };
+            apply_priority(&cells, &priority, &mut zones).map(|_| zones[0].conflict_winner)
+        };
+        let first = pb::ConflictWinnerType::ConflictWinnerFirst as i32;
+        let second = pb::ConflictWinnerType::ConflictWinnerSecond as i32;
+        let equal = pb::ConflictWinnerType::ConflictWinnerEqual as i32;
+
+        // 3 -> 4 comes from the left of 1 -> 2
+        assert_eq!(
+            winner(pb::ConflictPriorityPreset::RightHand, vec![]).unwrap(),
+            first
+        );
+        assert_eq!(
+            winner(pb::ConflictPriorityPreset::PriorityRoad, vec![3]).unwrap(),
+            second
+        );
+        assert_eq!(
+            winner(pb::ConflictPriorityPreset::PriorityRoad, vec![1, 3]).unwrap(),
+            first
+        );
+        assert_eq!(
+            winner(pb::ConflictPriorityPreset::AllWayStop, vec![]).unwrap(),
+            equal
+        );
+        assert_eq!(
+            winner(pb::ConflictPriorityPreset::None, vec![]).unwrap(),
+            equal
+        );
+        assert!(winner(pb::ConflictPriorityPreset::PriorityRoad, vec![]).is_err());
+    }
+
+    #[test]
+    fn crossing_and_merging_movements() {
+        let cells: HashMap<CellID, Cell> = [
+            // 1 -> 2 and 3 -> 4 cross at (1, 1)
+            cell(1, 0.0, 0.0, 2, ZoneType::Coordination),
+            cell(2, 2.0, 2.0, -1, ZoneType::Coordination),
+            cell(3, 0.0, 2.0, 4, ZoneType::Coordination),
+            cell(4, 2.0, 0.0, -1, ZoneType::Coordination),
+            // 5 -> 6 and 7 -> 6 merge
+            cell(5, 10.0, 0.0, 6, ZoneType::Common),
+            cell(6, 11.0, 1.0, -1, ZoneType::Coordination),
+            cell(7, 12.0, 0.0, 6, ZoneType::Common),
+            // 8 -> 9 and 10 -> 11 cross outside of coordination zones
+            cell(8, 20.0, 0.0, 9, ZoneType::Common),
+            cell(9, 22.0, 2.0, -1, ZoneType::Common),
+            cell(10, 20.0, 2.0, 11, ZoneType::Common),
+            cell(11, 22.0, 0.0, -1, ZoneType::Common),
+        ]
+        .into_iter()
+        .map(|c| (c.get_id(), c))
+        .collect();
+
+        let winner = pb::ConflictWinnerType::ConflictWinnerFirst;
+        let (zones, skipped) = generate_conflict_zones(&cells, &HashSet::new(), winner, 5);
+        assert_eq!(skipped, 0);
+        let edges: Vec<_> = zones
+            .iter()
+            .map(|z| (z.id, z.source_x, z.target_x, z.source_y, z.target_y))
+            .collect();
+        assert_eq!(edges, vec![(5, 1, 2, 3, 4), (6, 5, 6, 7, 6)]);
+        assert!(zones.iter().all(|z| z.conflict_winner == winner as i32));
+        assert!(validate_conflict_zones(&cells, &HashMap::new(), &zones).is_ok());
+
+        // Target cell 6 is already resolved by another zone
+        let (zones, skipped) = generate_conflict_zones(&cells, &HashSet::from([6]), winner, 1);
+        assert_eq!(zones.len(), 1);
+        assert_eq!(skipped, 1);
+    }
+}
@@ -0,0 +1,121 @@
+use tonic::Status;
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::simulation::session::Session;
+
+use super::catalog::SessionCatalog;
+use super::trip::core_trip;
+
+/// Checks the demand profile of a trip: bins sorted by strictly increasing start time,
+/// probabilities within [0, 1] and non-negative generation times.
+pub fn validate_profile(trip: &pb::Trip) -> Result<(), Status> {
+    for (i, bin) in trip.demand_profile.iter().enumerate() {
+        if i > 0 && bin.start_time <= trip.demand_profile[i - 1].start_time {
+            return Err(Status::invalid_argument(format!(
+                "Demand profile of trip '{}' should be sorted by strictly increasing start time",
+                trip.id
+            )));
+        }
+        if !(0.0..=1.0).contains(&bin.probability) {
+            return Err(Status::invalid_argument(format!(
+                "Demand probability should be within [0, 1], but provided is {} (trip '{}')",
+                bin.probability, trip.id
+            )));
+        }
+        if bin.time < 0 {
+            return Err(Status::invalid_argument(format!(
+                "Demand time should be non-negative, but provided is {} (trip '{}')",
+                bin.time, trip.id
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Bin of a (sorted) profile in effect at `tick`. None = the trip's own values.
+fn active_bin(profile: &[pb::DemandBin], tick: i64) -> Option<usize> {
+    profile.iter().rposition(|bin| bin.start_time <= tick)
+}
+
+/// Pushes the trips whose demand bin changes at `tick` to the session. Must be called
+/// right before `Session::step`; paused trips are left alone.
+pub fn apply_profiles(session: &mut Session, catalog: &mut SessionCatalog, tick: i64) {
+    let mut changed = Vec::new();
+    for (id, trip) in &catalog.trips {
+        if trip.demand_profile.is_empty() || catalog.paused_trips.contains(id) {
+            continue;
+        }
+        let bin = active_bin(&trip.demand_profile, tick);
+        // No entry = the trip's own values, as pushed on ingest
+        if catalog.demand_bins.get(id).copied().flatten() == bin {
+            continue;
+        }
+        let mut core = core_trip(trip);
+        if let Some(i) = bin {
+            core.probability = trip.demand_profile[i].probability;
+            core.time = trip.demand_profile[i].time as i32;
+        }
+        session.add_trip(core);
+        changed.push((*id, bin));
+    }
+    catalog.demand_bins.extend(changed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::road;
+
+    /// Trip without base demand, saturated between steps 5 and 10.
+    #[test]
+    fn demand_follows_profile() {
+        let mut session = Session::default(None);
+        session.add_cells(road(5));
+        let bin = |start_time: i64, probability: f64| pb::DemandBin {
+            start_time,
+            probability,
+            time: 0,
+        };
+        let trip = pb::Trip {
+            id: 1,
+            trip_type: pb::TripType::Random as i32,
+            from_node: 1,
+            to_node: 5,
+            initial_speed: 1,
+            probability: 0.0,
+            demand_profile: vec![bin(5, 1.0), bin(10, 0.0)],
+            ..Default::default()
+        };
+        validate_profile(&trip).unwrap();
+        let mut catalog = SessionCatalog::new();
+        session.add_trip(core_trip(&trip));
+        catalog.trips.insert(trip.id, trip);
+
+        let mut spawned_at = Vec::new();
+        for _ in 0..20 {
+            let tick = session.get_steps() as i64;
+            apply_profiles(&mut session, &mut catalog, tick);
+            let before = session.get_last_vehicle_id();
+            session.step().unwrap();
+            if session.get_last_vehicle_id() > before {
+                spawned_at.push(tick);
+            }
+        }
+        assert!(!spawned_at.is_empty());
+        assert!(
+            spawned_at.iter().all(|t| (5..10).contains(t)),
+            "{spawned_at:?}"
+        );
+
+        let unsorted = pb::Trip {
+            demand_profile: vec![bin(5, 0.1), bin(5, 0.2)],
+            ..Default::default()
+        };
+        assert!(validate_profile(&unsorted).is_err());
+        let out_of_range = pb::Trip {
+            demand_profile: vec![bin(0, 1.5)],
+            ..Default::default()
+        };
+        assert!(validate_profile(&out_of_range).is_err());
+    }
+}
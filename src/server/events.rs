@@ -0,0 +1,382 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::agents::VehicleID;
+use micro_traffic_sim_core::grid::cell::{CellID, CellState};
+use micro_traffic_sim_core::grid::zones::ZoneType;
+use micro_traffic_sim_core::maneuver::LaneChangeType;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::states::AutomataState;
+use micro_traffic_sim_core::trips::trip::TripID;
+
+use super::catalog::SessionCatalog;
+
+/// Vehicle data captured right before `Session::step`.
+struct PreVehicle {
+    cell: CellID,
+    trip: TripID,
+    trip_destination: CellID,
+    speed_limit: i32,
+    speed: i32,
+    travel_time: i64,
+}
+
+/// Snapshot of the vehicles storage taken before a step. The core does not report
+/// lifecycle transitions, so events are derived by diffing this snapshot against the
+/// post-step storage and the state dump.
+pub struct PreStep {
+    vehicles: HashMap<VehicleID, PreVehicle>,
+    occupied: HashSet<CellID>,
+    next_vehicle_id: VehicleID,
+}
+
+impl PreStep {
+    /// Travel time of a vehicle removed during the step, as the core counted it on
+    /// its last move (None for vehicles unknown before the step).
+    pub fn final_travel_time(&self, vehicle_id: VehicleID) -> Option<i64> {
+        self.vehicles.get(&vehicle_id).map(|p| p.travel_time + 1)
+    }
+
+    /// Cell and speed of a vehicle before the step (None for vehicles spawned during it).
+    pub fn cell_and_speed(&self, vehicle_id: VehicleID) -> Option<(CellID, i32)> {
+        self.vehicles.get(&vehicle_id).map(|p| (p.cell, p.speed))
+    }
+
+    /// Drops a vehicle from the snapshot, so its disappearance is not reported by
+    /// `collect`. Returns its cell and trip before the step.
+    pub fn forget(&mut self, vehicle_id: VehicleID) -> Option<(CellID, TripID)> {
+        self.vehicles.remove(&vehicle_id).map(|p| (p.cell, p.trip))
+    }
+}
+
+/// Captures the pre-step snapshot. Must be called right before `Session::step`.
+pub fn capture(session: &Session) -> PreStep {
+    let storage = session.get_vehicles();
+    let mut vehicles = HashMap::with_capacity(storage.len());
+    let mut occupied = HashSet::with_capacity(storage.len());
+    for v in storage.values() {
+        vehicles.insert(
+            v.id,
+            PreVehicle {
+                cell: v.cell_id,
+                trip: v.trip,
+                trip_destination: v.trip_destination,
+                speed_limit: v.speed_limit,
+                speed: v.speed,
+                travel_time: v.travel_time,
+            },
+        );
+        occupied.insert(v.cell_id);
+        occupied.extend(v.tail_cells.iter().copied());
+    }
+    PreStep {
+        vehicles,
+        occupied,
+        next_vehicle_id: session.get_last_vehicle_id(),
+    }
+}
+
+pub(super) fn event(
+    vehicle_id: VehicleID,
+    event_type: pb::VehicleEventType,
+    trip_id: TripID,
+    cell: CellID,
+    from_cell: CellID,
+) -> pb::VehicleEvent {
+    pb::VehicleEvent {
+        vehicle_id: vehicle_id as i64,
+        event_type: event_type as i32,
+        trip_id,
+        cell,
+        from_cell,
+    }
+}
+
+/// Whether any outgoing cell of `cell_id` is banned (i.e. under a prohibiting signal).
+fn is_held_by_signal(session: &Session, cell_id: CellID) -> bool {
+    let Some(cell) = session.get_cell(&cell_id) else {
+        return false;
+    };
+    [
+        cell.get_forward_id(),
+        cell.get_left_id(),
+        cell.get_right_id(),
+    ]
+    .iter()
+    .filter(|id| **id >= 0)
+    .filter_map(|id| session.get_cell(id))
+    .any(|c| c.get_state() == CellState::Banned)
+}
+
+/// Classifies a vehicle which has left the simulation during the step. Searches the
+/// cells reachable from its last known position within one step (bounded by its speed
+/// limit): the trip destination being reachable means ARRIVED, otherwise LOST in the
+/// first death cell found (or -1 if none is reachable).
+fn classify_removed(session: &Session, pre: &PreVehicle) -> (pb::VehicleEventType, CellID) {
+    let max_depth = pre.speed_limit.max(1) as usize;
+    let mut visited: HashSet<CellID> = HashSet::from([pre.cell]);
+    let mut queue: VecDeque<(CellID, usize)> = VecDeque::from([(pre.cell, 0)]);
+    let mut death_cell: Option<CellID> = None;
+    while let Some((cell_id, depth)) = queue.pop_front() {
+        if cell_id == pre.trip_destination && depth > 0 {
+            return (pb::VehicleEventType::Arrived, cell_id);
+        }
+        let Some(cell) = session.get_cell(&cell_id) else {
+            continue;
+        };
+        if depth > 0 && death_cell.is_none() && cell.get_zone_type() == ZoneType::Death {
+            death_cell = Some(cell_id);
+        }
+        if depth >= max_depth {
+            continue;
+        }
+        for next in [
+            cell.get_forward_id(),
+            cell.get_left_id(),
+            cell.get_right_id(),
+        ] {
+            if next >= 0 && visited.insert(next) {
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+    (pb::VehicleEventType::Lost, death_cell.unwrap_or(-1))
+}
+
+/// Derives the lifecycle events of one step from the pre-step snapshot, the post-step
+/// session state and its dump. Updates the cumulative counters kept in `catalog`.
+/// Events are ordered by (vehicle_id, event_type).
+pub fn collect(
+    session: &Session,
+    pre: &PreStep,
+    dump: &AutomataState,
+    catalog: &mut SessionCatalog,
+) -> Vec<pb::VehicleEvent> {
+    let mut events: Vec<pb::VehicleEvent> = Vec::new();
+    let storage = session.get_vehicles();
+
+    // Exact number of arrivals / losses of this step, used to keep the per-vehicle
+    // classification consistent with the core counters.
+    let mut arrived_budget = (dump.vehicles_completed - catalog.vehicles_completed).max(0);
+    let mut lost_budget = (dump.vehicles_lost - catalog.vehicles_lost).max(0);
+    catalog.vehicles_completed = dump.vehicles_completed;
+    catalog.vehicles_lost = dump.vehicles_lost;
+
+    // Vehicles still present after the step.
+    let mut present: HashSet<VehicleID> = HashSet::with_capacity(storage.len());
+    for v in storage.values() {
+        present.insert(v.id);
+        match pre.vehicles.get(&v.id) {
+            None => {
+                let birth = catalog
+                    .trips
+                    .get(&v.trip)
+                    .map(|t| t.from_node)
+                    .unwrap_or(-1);
+                events.push(event(
+                    v.id,
+                    pb::VehicleEventType::Spawned,
+                    v.trip,
+                    birth,
+                    -1,
+                ));
+            }
+            Some(p) => {
+                let maneuver = v.intention.intention_maneuver;
+                if (maneuver == LaneChangeType::ChangeLeft
+                    || maneuver == LaneChangeType::ChangeRight)
+                    && v.cell_id != p.cell
+                {
+                    events.push(event(
+                        v.id,
+                        pb::VehicleEventType::LaneChanged,
+                        v.trip,
+                        v.cell_id,
+                        p.cell,
+                    ));
+                }
+            }
+        }
+        if v.speed != 0 {
+            continue;
+        }
+        if is_held_by_signal(session, v.cell_id) {
+            events.push(event(
+                v.id,
+                pb::VehicleEventType::RedLightStop,
+                v.trip,
+                v.cell_id,
+                -1,
+            ));
+            continue;
+        }
+        let yielded = catalog.conflict_zones.values().any(|cz| {
+            (cz.source_x == v.cell_id && !pre.occupied.contains(&cz.target_x))
+                || (cz.source_y == v.cell_id && !pre.occupied.contains(&cz.target_y))
+        });
+        if yielded {
+            events.push(event(
+                v.id,
+                pb::VehicleEventType::ConflictYield,
+                v.trip,
+                v.cell_id,
+                -1,
+            ));
+        }
+    }
+
+    // Vehicles which existed before the step and are gone now.
+    let mut removed: Vec<(&VehicleID, &PreVehicle)> = pre
+        .vehicles
+        .iter()
+        .filter(|(id, _)| !present.contains(id))
+        .collect();
+    removed.sort_unstable_by_key(|(id, _)| **id);
+    for (id, p) in removed {
+        let (kind, cell) = if lost_budget == 0 {
+            (pb::VehicleEventType::Arrived, p.trip_destination)
+        } else if arrived_budget == 0 {
+            let (_, cell) = classify_removed(session, p);
+            (pb::VehicleEventType::Lost, cell)
+        } else {
+            classify_removed(session, p)
+        };
+        match kind {
+            pb::VehicleEventType::Arrived => arrived_budget = (arrived_budget - 1).max(0),
+            _ => lost_budget = (lost_budget - 1).max(0),
+        }
+        events.push(event(*id, kind, p.trip, cell, -1));
+    }
+
+    // Vehicles spawned and removed within the same step: only the remaining budget
+    // tells whether they arrived or were lost, their trip and cells are unknown.
+    for id in pre.next_vehicle_id..session.get_last_vehicle_id() {
+        if present.contains(&id) || pre.vehicles.contains_key(&id) {
+            continue;
+        }
+        events.push(event(id, pb::VehicleEventType::Spawned, -1, -1, -1));
+        let kind = if arrived_budget > 0 {
+            arrived_budget -= 1;
+            pb::VehicleEventType::Arrived
+        } else {
+            lost_budget = (lost_budget - 1).max(0);
+            pb::VehicleEventType::Lost
+        };
+        events.push(event(id, kind, -1, -1, -1));
+    }
+
+    events.sort_by_key(|e| (e.vehicle_id, e.event_type));
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::road;
+    use micro_traffic_sim_core::geom::new_point;
+    use micro_traffic_sim_core::grid::cell::Cell;
+    use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+
+    fn step_with_events(
+        session: &mut Session,
+        catalog: &mut SessionCatalog,
+    ) -> Vec<pb::VehicleEvent> {
+        let pre = capture(session);
+        let dump = session.step().unwrap();
+        collect(session, &pre, &dump, catalog)
+    }
+
+    fn kinds(events: &[pb::VehicleEvent]) -> Vec<pb::VehicleEventType> {
+        events
+            .iter()
+            .map(|e| pb::VehicleEventType::try_from(e.event_type).unwrap())
+            .collect()
+    }
+
+    /// A single vehicle is spawned by a trip, drives down the road and arrives.
+    #[test]
+    fn spawn_then_arrive() {
+        let mut session = Session::default(None);
+        session.add_cells(road(5));
+        let trip = Trip::new(1, 4, TripType::Constant)
+            .with_id(7)
+            .with_initial_speed(1)
+            .with_time(1000)
+            .with_start_time(0)
+            .with_end_time(0)
+            .build();
+        session.add_trip(trip);
+        let mut catalog = SessionCatalog::new();
+        catalog.trips.insert(
+            7,
+            pb::Trip {
+                id: 7,
+                from_node: 1,
+                to_node: 4,
+                ..Default::default()
+            },
+        );
+
+        let first = step_with_events(&mut session, &mut catalog);
+        assert_eq!(kinds(&first), vec![pb::VehicleEventType::Spawned]);
+        assert_eq!(first[0].trip_id, 7);
+        assert_eq!(first[0].cell, 1);
+
+        let mut all = Vec::new();
+        for _ in 0..10 {
+            all.extend(step_with_events(&mut session, &mut catalog));
+        }
+        let arrived: Vec<_> = all
+            .iter()
+            .filter(|e| e.event_type == pb::VehicleEventType::Arrived as i32)
+            .collect();
+        assert_eq!(arrived.len(), 1);
+        assert_eq!(arrived[0].vehicle_id, first[0].vehicle_id);
+        assert_eq!(arrived[0].cell, 4);
+        assert_eq!(catalog.vehicles_completed, 1);
+        assert!(
+            all.iter()
+                .all(|e| e.event_type != pb::VehicleEventType::Lost as i32)
+        );
+    }
+
+    /// A vehicle whose destination is not on its road ends up lost in the death zone.
+    #[test]
+    fn lost_in_death_zone() {
+        let mut session = Session::default(None);
+        let mut cells = road(3);
+        // Unreachable destination off the road
+        cells.push(
+            Cell::new(100)
+                .with_point(new_point(0.0, 10.0, None))
+                .with_zone_type(ZoneType::Common)
+                .with_speed_limit(1)
+                .with_left_node(-1)
+                .with_forward_node(-1)
+                .with_right_node(-1)
+                .build(),
+        );
+        session.add_cells(cells);
+        let trip = Trip::new(1, 100, TripType::Constant)
+            .with_id(1)
+            .with_initial_speed(1)
+            .with_time(1000)
+            .with_start_time(0)
+            .with_end_time(0)
+            .build();
+        session.add_trip(trip);
+        let mut catalog = SessionCatalog::new();
+
+        let mut all = Vec::new();
+        for _ in 0..10 {
+            all.extend(step_with_events(&mut session, &mut catalog));
+        }
+        let lost: Vec<_> = all
+            .iter()
+            .filter(|e| e.event_type == pb::VehicleEventType::Lost as i32)
+            .collect();
+        assert_eq!(lost.len(), catalog.vehicles_lost as usize);
+        assert!(!lost.is_empty());
+        assert_eq!(lost[0].cell, 3);
+    }
+}
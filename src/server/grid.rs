@@ -1,16 +1,22 @@
-use std::sync::{Arc, Mutex};
+use std::collections::HashSet;
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Code, Request, Response, Status};
+use tracing::Instrument;
 use uuid::Uuid;
 
 use micro_traffic_sim::pb;
-use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::geom::{Point, SRID, new_point};
 use micro_traffic_sim_core::grid::cell::Cell;
 use micro_traffic_sim_core::grid::zones::ZoneType;
-use micro_traffic_sim_core::simulation::sessions_storage::SessionsStorage;
 
 use super::BoxStream;
+use super::auth;
+use super::catalog::{self, Catalogs};
+use super::quotas;
+use super::storage::SessionStore;
+use super::{blocking, check_entities_count};
 
 /// Converts proto ZoneType to computational core ZoneType
 fn proto_zone_to_core(zone: i32) -> ZoneType {
@@ -27,123 +33,172 @@ fn proto_zone_to_core(zone: i32) -> ZoneType {
     }
 }
 
+/// Converts computational core ZoneType to proto ZoneType
+fn core_zone_to_proto(zone: ZoneType) -> pb::ZoneType {
+    match zone {
+        ZoneType::Birth => pb::ZoneType::Birth,
+        ZoneType::Death => pb::ZoneType::Death,
+        ZoneType::Coordination => pb::ZoneType::Coordination,
+        ZoneType::Common => pb::ZoneType::Common,
+        ZoneType::Isolated => pb::ZoneType::Isolated,
+        ZoneType::LaneForBus => pb::ZoneType::LaneForBus,
+        ZoneType::Transit => pb::ZoneType::Transit,
+        ZoneType::Crosswalk => pb::ZoneType::Crosswalk,
+        ZoneType::Undefined => pb::ZoneType::Undefined,
+    }
+}
+
+/// Converts a proto cell to a core cell of a session with the given SRID.
+pub(super) fn core_cell(c: &pb::Cell, srid: SRID) -> Cell {
+    let (x, y) = c.geom.as_ref().map_or((0.0, 0.0), |p| (p.x, p.y));
+    Cell::new(c.id)
+        .with_point(new_point(x, y, Some(srid)))
+        .with_zone_type(proto_zone_to_core(c.zone_type))
+        .with_speed_limit(c.speed_limit as i32)
+        .with_left_node(c.left_node)
+        .with_forward_node(c.forward_node)
+        .with_right_node(c.right_node)
+        .with_meso_link(c.meso_link_id)
+        .build()
+}
+
+/// Converts a core cell back to its proto form (the core does not expose the meso link).
+pub(super) fn proto_cell(cell: &Cell, meso_link_id: i64) -> pb::Cell {
+    let point = cell.get_point();
+    pb::Cell {
+        id: cell.get_id(),
+        geom: Some(pb::Point {
+            x: point.x(),
+            y: point.y(),
+        }),
+        zone_type: core_zone_to_proto(cell.get_zone_type()) as i32,
+        speed_limit: cell.get_speed_limit() as i64,
+        left_node: cell.get_left_id(),
+        forward_node: cell.get_forward_id(),
+        right_node: cell.get_right_id(),
+        meso_link_id,
+    }
+}
+
 pub async fn push_session_grid(
-    sessions: Arc<Mutex<SessionsStorage>>,
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
     request: Request<tonic::Streaming<pb::SessionGrid>>,
 ) -> Result<Response<BoxStream<pb::SessionGridResponse>>, Status> {
+    let caller = auth::Caller::of(&request);
     let mut stream = request.into_inner();
     let (tx, rx) = mpsc::channel(16);
 
-    tokio::spawn(async move {
-        while let Ok(Some(req)) = stream.message().await {
-            // Validate session_id
-            let session_id = match req.session_id {
-                Some(id) => id.value,
-                None => {
-                    let _ = tx
-                        .send(Err(Status::invalid_argument("No session ID provided")))
-                        .await;
+    tokio::spawn(
+        async move {
+            while let Ok(Some(req)) = stream.message().await {
+                // Validate session_id
+                let session_id = match req.session_id {
+                    Some(id) => id.value,
+                    None => {
+                        let _ = tx
+                            .send(Err(Status::invalid_argument("No session ID provided")))
+                            .await;
+                        continue;
+                    }
+                };
+
+                let sid = match Uuid::parse_str(&session_id) {
+                    Ok(u) => u,
+                    Err(_) => {
+                        let _ = tx
+                            .send(Err(Status::invalid_argument("Invalid UUID format")))
+                            .await;
+                        continue;
+                    }
+                };
+
+                // Reject sessions owned by other tenants
+                let authorized = auth::authorize_session(caller.as_ref(), &sid);
+                if let Err(status) = authorized {
+                    let _ = tx.send(Err(status)).await;
+                    continue;
+                }
+
+                // Validate data size
+                if let Err(status) = check_entities_count(req.data.len()) {
+                    let _ = tx.send(Err(status)).await;
                     continue;
                 }
-            };
 
-            let sid = match Uuid::parse_str(&session_id) {
-                Ok(u) => u,
-                Err(_) => {
+                if req.data.is_empty() {
                     let _ = tx
-                        .send(Err(Status::invalid_argument("Invalid UUID format")))
+                        .send(Err(Status::invalid_argument("No data provided")))
                         .await;
                     continue;
                 }
-            };
-
-            // Validate data size
-            if req.data.len() > 10000 {
-                let _ = tx
-                    .send(Err(Status::invalid_argument(format!(
-                        "Max amount of data entities is 10000, but provided is {}",
-                        req.data.len()
-                    ))))
-                    .await;
-                continue;
-            }
-
-            if req.data.is_empty() {
-                let _ = tx
-                    .send(Err(Status::invalid_argument("No data provided")))
-                    .await;
-                continue;
-            }
 
-            // Get session and SRID
-            let srid_result = sessions.lock().ok().and_then(|mut guard| {
-                guard.with_session_mut(&sid, |session| session.get_world_srid())
-            });
-
-            let srid = match srid_result {
-                Some(s) => s,
-                None => {
-                    // Either lock poisoned or session not found - try to distinguish
-                    if sessions.lock().is_err() {
-                        let _ = tx.send(Err(Status::internal("Storage poisoned"))).await;
-                    } else {
+                // Add cells to session (a copy is mirrored into the session catalog for
+                // routing). The cells quota is counted, checked and the grid updated in one
+                // catalog section, so concurrent pushes can not overshoot it.
+                let add_result = {
+                    let sessions = sessions.clone();
+                    let catalogs = catalogs.clone();
+                    let caller = caller.clone();
+                    blocking(move || {
+                        sessions.with_session_mut(&sid, |session| {
+                            catalog::with_catalog_mut(&catalogs, &sid, |c| {
+                                // The grid as it would be after this message
+                                let new_ids: HashSet<i64> = req
+                                    .data
+                                    .iter()
+                                    .map(|d| d.id)
+                                    .filter(|id| !c.cells.contains_key(id))
+                                    .collect();
+                                quotas::check_cells(
+                                    caller.as_ref(),
+                                    c.cells.len() + new_ids.len(),
+                                )?;
+
+                                // Convert proto cells to core cells
+                                let srid = session.get_world_srid();
+                                let cells_data: Vec<Cell> =
+                                    req.data.iter().map(|d| core_cell(d, srid)).collect();
+                                session.add_cells(cells_data.clone());
+                                for (cell, cell_data) in cells_data.into_iter().zip(&req.data) {
+                                    c.insert_cell(cell, cell_data.meso_link_id);
+                                }
+                                Ok(())
+                            })
+                        })
+                    })
+                    .await
+                };
+
+                match add_result {
+                    Ok(Some(Ok(()))) => {}
+                    Ok(Some(Err(status))) | Err(status) => {
+                        let _ = tx.send(Err(status)).await;
+                        continue;
+                    }
+                    Ok(None) => {
                         let _ = tx
                             .send(Err(Status::not_found(format!(
                                 "Session not found: {}",
                                 session_id
                             ))))
                             .await;
+                        continue;
                     }
-                    continue;
                 }
-            };
-
-            // Convert proto cells to core cells
-            let cells_data: Vec<Cell> = req
-                .data
-                .iter()
-                .map(|c| {
-                    let (x, y) = c.geom.as_ref().map_or((0.0, 0.0), |p| (p.x, p.y));
-                    Cell::new(c.id)
-                        .with_point(new_point(x, y, Some(srid)))
-                        .with_zone_type(proto_zone_to_core(c.zone_type))
-                        .with_speed_limit(c.speed_limit as i32)
-                        .with_left_node(c.left_node)
-                        .with_forward_node(c.forward_node)
-                        .with_right_node(c.right_node)
-                        .with_meso_link(c.meso_link_id)
-                        .build()
-                })
-                .collect();
-
-            // Add cells to session
-            let add_result = sessions.lock().ok().and_then(|mut guard| {
-                guard.with_session_mut(&sid, |session| {
-                    session.add_cells(cells_data);
-                })
-            });
-
-            if add_result.is_none() {
-                // Session disappeared between SRID fetch and add - rare but possible
-                let _ = tx
-                    .send(Err(Status::not_found(format!(
-                        "Session not found: {}",
-                        session_id
-                    ))))
-                    .await;
-                continue;
-            }
 
-            let resp = pb::SessionGridResponse {
-                code: Code::Ok as u32,
-                text: Code::Ok.to_string(),
-            };
+                let resp = pb::SessionGridResponse {
+                    code: Code::Ok as u32,
+                    text: Code::Ok.to_string(),
+                };
 
-            if tx.send(Ok(resp)).await.is_err() {
-                break;
+                if tx.send(Ok(resp)).await.is_err() {
+                    break;
+                }
             }
         }
-    });
+        .instrument(tracing::Span::current()),
+    );
 
     let out: BoxStream<pb::SessionGridResponse> = Box::pin(ReceiverStream::new(rx));
     Ok(Response::new(out))
@@ -0,0 +1,77 @@
+use tonic_health::ServingStatus;
+use tonic_health::server::HealthReporter;
+use tonic_reflection::server::{Builder, Error, v1, v1alpha};
+
+use micro_traffic_sim::pb;
+
+/// Service name reported by `grpc.health.v1.Health` for the simulation API.
+type SimServer = pb::service_server::ServiceServer<super::SimService>;
+
+/// Creates the `grpc.health.v1.Health` service with both the overall server ("")
+/// and the simulation service reported as SERVING.
+pub async fn health_service() -> (
+    HealthReporter,
+    tonic_health::pb::health_server::HealthServer<impl tonic_health::pb::health_server::Health>,
+) {
+    let (reporter, service) = tonic_health::server::health_reporter();
+    reporter.set_serving::<SimServer>().await;
+    (reporter, service)
+}
+
+/// Flips the overall server and the simulation service to NOT_SERVING, so probes
+/// and watchers see the server going away before connections are drained.
+pub async fn set_not_serving(reporter: &HealthReporter) {
+    reporter.set_not_serving::<SimServer>().await;
+    reporter
+        .set_service_status("", ServingStatus::NotServing)
+        .await;
+}
+
+fn reflection_builder() -> Builder<'static> {
+    Builder::configure()
+        .register_encoded_file_descriptor_set(pb::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+}
+
+/// Server reflection over the simulation and health services, in both the v1 and
+/// the older v1alpha flavours (grpcurl and most tools still ask for the latter).
+pub fn reflection_services() -> Result<
+    (
+        v1::ServerReflectionServer<impl v1::ServerReflection>,
+        v1alpha::ServerReflectionServer<impl v1alpha::ServerReflection>,
+    ),
+    Error,
+> {
+    Ok((
+        reflection_builder().build_v1()?,
+        reflection_builder().build_v1alpha()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic_health::pb::HealthCheckRequest;
+    use tonic_health::pb::health_server::Health;
+
+    #[tokio::test]
+    async fn not_serving_on_shutdown() {
+        assert!(reflection_services().is_ok());
+        let (reporter, _) = health_service().await;
+        let service = tonic_health::server::HealthService::from_health_reporter(reporter.clone());
+        let status = |service_name: &str| {
+            let request = tonic::Request::new(HealthCheckRequest {
+                service: service_name.to_string(),
+            });
+            let service = &service;
+            async move { service.check(request).await.unwrap().into_inner().status }
+        };
+        let name = <SimServer as tonic::server::NamedService>::NAME;
+        assert_eq!(name, "micro_traffic_sim.Service");
+        assert_eq!(status("").await, ServingStatus::Serving as i32);
+        assert_eq!(status(name).await, ServingStatus::Serving as i32);
+        set_not_serving(&reporter).await;
+        assert_eq!(status("").await, ServingStatus::NotServing as i32);
+        assert_eq!(status(name).await, ServingStatus::NotServing as i32);
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use tonic::{Code, Request, Response, Status};
+use uuid::Uuid;
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID, CellState};
+use micro_traffic_sim_core::simulation::session::Session;
+
+use super::catalog::{self, Catalogs};
+use super::recordings::{RecordingHandle, Recordings};
+use super::storage::SessionStore;
+use super::{blocking, check_entities_count, ensure_session, parse_session_uuid};
+
+/// Server-wide incident id sequence (ids are unique across sessions).
+static NEXT_INCIDENT_ID: AtomicI64 = AtomicI64::new(1);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IncidentKind {
+    /// Cells are banned for the whole incident.
+    Block,
+    /// Speed limit of the cells is capped by the given value.
+    SpeedLimit(i32),
+}
+
+#[derive(Clone, Debug)]
+pub struct Incident {
+    pub kind: IncidentKind,
+    pub cells: Vec<CellID>,
+    pub start_tick: i64,
+    /// First tick the incident is no longer in effect (None = until cleared).
+    pub end_tick: Option<i64>,
+    applied: bool,
+    cleared: bool,
+}
+
+impl Incident {
+    pub fn new(
+        kind: IncidentKind,
+        cells: Vec<CellID>,
+        start_tick: i64,
+        end_tick: Option<i64>,
+    ) -> Self {
+        Self {
+            kind,
+            cells,
+            start_tick,
+            end_tick,
+            applied: false,
+            cleared: false,
+        }
+    }
+
+    fn is_over(&self, tick: i64) -> bool {
+        self.cleared || self.end_tick.is_some_and(|end| tick >= end)
+    }
+
+    fn is_active(&self, tick: i64) -> bool {
+        !self.is_over(tick) && tick >= self.start_tick
+    }
+}
+
+/// Scheduled incidents of one session, keyed by incident id.
+///
+/// The core offers no per-cell overrides, so incidents are applied by overwriting the
+/// affected cells (`Session::add_cells`) with modified copies of the cells mirrored in
+/// the session catalog, and restored the same way once no incident covers them.
+#[derive(Clone, Default)]
+pub struct Incidents {
+    items: BTreeMap<i64, Incident>,
+}
+
+impl Incidents {
+    pub fn insert(&mut self, id: i64, incident: Incident) {
+        self.items.insert(id, incident);
+    }
+
+    /// Marks the incident as cleared; its cells are restored on the next `apply`.
+    /// Returns false for an unknown (or already expired) incident.
+    pub fn clear(&mut self, id: i64) -> bool {
+        match self.items.get_mut(&id) {
+            Some(incident) if !incident.cleared => {
+                incident.cleared = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Brings the session cells in line with the incidents in effect at `tick` (the
+    /// timestamp of the upcoming step). Must be called right before `Session::step`.
+    /// Cells missing from `cells` are skipped.
+    pub fn apply(
+        &mut self,
+        session: &mut Session,
+        cells: &HashMap<CellID, Cell>,
+        meso_links: &HashMap<CellID, i64>,
+        tick: i64,
+    ) {
+        let mut dirty: BTreeSet<CellID> = BTreeSet::new();
+        self.items.retain(|_, incident| {
+            let active = incident.is_active(tick);
+            if active != incident.applied {
+                incident.applied = active;
+                dirty.extend(incident.cells.iter().copied());
+            }
+            !incident.is_over(tick)
+        });
+        if dirty.is_empty() {
+            return;
+        }
+
+        let updated: Vec<Cell> = dirty
+            .into_iter()
+            .filter_map(|cell_id| {
+                let base = cells.get(&cell_id)?;
+                let meso_link_id = meso_links.get(&cell_id).copied().unwrap_or(-1);
+                Some(self.effective_cell(base, meso_link_id))
+            })
+            .collect();
+        session.add_cells(updated);
+    }
+
+    /// Copy of `base` with the overrides of the incidents currently applied to it.
+    pub fn effective_cell(&self, base: &Cell, meso_link_id: i64) -> Cell {
+        let cell_id = base.get_id();
+        let mut blocked = false;
+        let mut speed_limit: Option<i32> = None;
+        for incident in self.items.values() {
+            if !incident.applied || !incident.cells.contains(&cell_id) {
+                continue;
+            }
+            match incident.kind {
+                IncidentKind::Block => blocked = true,
+                IncidentKind::SpeedLimit(v) => {
+                    speed_limit = Some(speed_limit.map_or(v, |s| s.min(v)))
+                }
+            }
+        }
+        let mut cell = match speed_limit {
+            Some(v) if v < base.get_speed_limit() => cell_with_speed_limit(base, meso_link_id, v),
+            _ => base.clone(),
+        };
+        cell.set_state(if blocked {
+            CellState::Banned
+        } else {
+            CellState::Free
+        });
+        cell
+    }
+}
+
+/// Copy of `base` with another speed limit (the core has no speed limit setter).
+pub fn cell_with_speed_limit(base: &Cell, meso_link_id: i64, speed_limit: i32) -> Cell {
+    Cell::new(base.get_id())
+        .with_point(*base.get_point())
+        .with_zone_type(base.get_zone_type())
+        .with_speed_limit(speed_limit)
+        .with_left_node(base.get_left_id())
+        .with_forward_node(base.get_forward_id())
+        .with_right_node(base.get_right_id())
+        .with_meso_link(meso_link_id)
+        .build()
+}
+
+/// Running recording of the session, if any (such a session lives outside the storage).
+pub(super) fn running_recording(
+    recordings: &Recordings,
+    id: &Uuid,
+) -> Result<Option<Arc<RecordingHandle>>, Status> {
+    let reg = recordings
+        .lock()
+        .map_err(|_| Status::internal("recordings registry lock poisoned"))?;
+    Ok(reg.get(id).cloned())
+}
+
+/// Tick argument as a signed tick, rejecting values the ticks (i64) can not hold.
+fn tick_arg(name: &str, value: u64) -> Result<i64, Status> {
+    i64::try_from(value).map_err(|_| {
+        Status::invalid_argument(format!(
+            "{} should not exceed {}, but provided is {}",
+            name,
+            i64::MAX,
+            value
+        ))
+    })
+}
+
+/// Rejects the first cell unknown to the session grid.
+fn check_cells_known(cells: &[CellID], known: impl Fn(&CellID) -> bool) -> Result<(), Status> {
+    match cells.iter().find(|id| !known(id)) {
+        Some(unknown) => Err(Status::not_found(format!(
+            "Not found cell ID: '{}'",
+            unknown
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Schedules an incident on an interactive session or on a running recording.
+pub async fn inject_incident(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::InjectIncidentRequest>,
+) -> Result<Response<pb::InjectIncidentResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+
+    let kind = match pb::IncidentType::try_from(req.r#type) {
+        Ok(pb::IncidentType::Block) => IncidentKind::Block,
+        Ok(pb::IncidentType::SpeedLimit) => {
+            if req.speed_limit < 0 {
+                return Err(Status::invalid_argument(format!(
+                    "Speed limit should be non-negative, but provided is {}",
+                    req.speed_limit
+                )));
+            }
+            IncidentKind::SpeedLimit(req.speed_limit)
+        }
+        _ => {
+            return Err(Status::invalid_argument(format!(
+                "Incident type not supported: {}",
+                req.r#type
+            )));
+        }
+    };
+    if req.cells.is_empty() {
+        return Err(Status::invalid_argument("No cells provided"));
+    }
+    check_entities_count(req.cells.len())?;
+    let start_tick = tick_arg("Start tick", req.start_tick)?;
+    let end_tick = match tick_arg("Duration", req.duration_ticks)? {
+        0 => None,
+        duration => Some(start_tick.checked_add(duration).ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "Incident end tick overflows: start tick {} + duration {}",
+                start_tick, duration
+            ))
+        })?),
+    };
+    let incident = Incident::new(kind, req.cells, start_tick, end_tick);
+    let incident_id = NEXT_INCIDENT_ID.fetch_add(1, Ordering::Relaxed);
+
+    if let Some(handle) = running_recording(&recordings, &session_uuid)? {
+        check_cells_known(&incident.cells, |id| handle.cells.contains(id))?;
+        // Picked up by the recording thread before its next step
+        handle
+            .incidents
+            .lock()
+            .map_err(|_| Status::internal("recording incidents lock poisoned"))?
+            .insert(incident_id, incident);
+    } else {
+        ensure_session(&sessions, &session_uuid).await?;
+        blocking(move || {
+            catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+                check_cells_known(&incident.cells, |id| c.cells.contains_key(id))?;
+                c.incidents.insert(incident_id, incident);
+                Ok::<_, Status>(())
+            })
+        })
+        .await??;
+    }
+
+    Ok(Response::new(pb::InjectIncidentResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        incident_id,
+    }))
+}
+
+/// Lifts an incident of an interactive session or of a running recording.
+pub async fn clear_incident(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::ClearIncidentRequest>,
+) -> Result<Response<pb::ClearIncidentResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+
+    let cleared = if let Some(handle) = running_recording(&recordings, &session_uuid)? {
+        handle
+            .incidents
+            .lock()
+            .map_err(|_| Status::internal("recording incidents lock poisoned"))?
+            .clear(req.incident_id)
+    } else {
+        ensure_session(&sessions, &session_uuid).await?;
+        let incident_id = req.incident_id;
+        blocking(move || {
+            catalog::with_catalog_mut(&catalogs, &session_uuid, |c| c.incidents.clear(incident_id))
+        })
+        .await?
+    };
+    if !cleared {
+        return Err(Status::not_found(format!(
+            "Not found incident ID: '{}'",
+            req.incident_id
+        )));
+    }
+
+    Ok(Response::new(pb::ClearIncidentResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::{by_id, road, road_cell};
+    use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+
+    /// A closure of cell 5 on a single-lane road holds the traffic back until cleared.
+    #[test]
+    fn block_then_clear() {
+        let cells = by_id(road(8));
+        let meso_links = HashMap::new();
+        let mut session = Session::default(None);
+        session.add_cells(cells.values().cloned().collect());
+        session.add_trip(
+            Trip::new(1, 7, TripType::Constant)
+                .with_id(1)
+                .with_initial_speed(1)
+                .with_time(1)
+                .with_start_time(0)
+                .with_end_time(0)
+                .build(),
+        );
+
+        let mut incidents = Incidents::default();
+        incidents.insert(1, Incident::new(IncidentKind::Block, vec![5], 0, None));
+        let step = |incidents: &mut Incidents, session: &mut Session| {
+            incidents.apply(session, &cells, &meso_links, session.get_steps() as i64);
+            session.step().unwrap()
+        };
+
+        for _ in 0..10 {
+            let dump = step(&mut incidents, &mut session);
+            assert!(dump.vehicles.iter().all(|v| v.last_cell < 5));
+        }
+        assert_eq!(session.get_cell(&5).unwrap().get_state(), CellState::Banned);
+
+        assert!(incidents.clear(1));
+        assert!(!incidents.clear(1));
+        let mut passed = false;
+        for _ in 0..10 {
+            let dump = step(&mut incidents, &mut session);
+            passed |= dump.vehicles.iter().any(|v| v.last_cell >= 5);
+        }
+        assert!(passed, "traffic flows again once the incident is cleared");
+        assert_eq!(session.get_cell(&5).unwrap().get_state(), CellState::Free);
+    }
+
+    #[test]
+    fn ticks_and_cells_are_checked() {
+        assert_eq!(tick_arg("Start tick", 7).unwrap(), 7);
+        let err = tick_arg("Start tick", i64::MAX as u64 + 1).unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+        let known = |id: &CellID| *id < 10;
+        assert!(check_cells_known(&[1, 9], known).is_ok());
+        let err = check_cells_known(&[1, 42], known).unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+    }
+
+    #[test]
+    fn speed_limit_window() {
+        let base = road_cell(3, 0.0, -1).with_speed_limit(4).build();
+        let cells = HashMap::from([(3, base)]);
+        let meso_links = HashMap::from([(3, 11)]);
+        let mut session = Session::default(None);
+        session.add_cells(cells.values().cloned().collect());
+
+        let mut incidents = Incidents::default();
+        incidents.insert(
+            7,
+            Incident::new(IncidentKind::SpeedLimit(1), vec![3], 2, Some(4)),
+        );
+        let limits: Vec<i32> = (0..6)
+            .map(|tick| {
+                incidents.apply(&mut session, &cells, &meso_links, tick);
+                session.get_cell(&3).unwrap().get_speed_limit()
+            })
+            .collect();
+        assert_eq!(limits, vec![4, 4, 1, 1, 4, 4]);
+        assert!(!incidents.clear(7), "expired incidents are dropped");
+    }
+}
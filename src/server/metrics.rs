@@ -0,0 +1,371 @@
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, LazyLock};
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use axum::routing;
+use http_body::{Body, Frame, SizeHint};
+use prometheus::{
+    Encoder, GaugeVec, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge,
+    Opts, Registry, TextEncoder, exponential_buckets,
+};
+use tonic::codegen::http;
+use tower::{Layer, Service};
+
+use super::catalog::{self, Catalogs};
+use super::recordings::Recordings;
+use super::storage::SessionStore;
+
+/// Process-wide Prometheus metrics. They are always collected (updates are cheap);
+/// the HTTP endpoint exposing them is optional (`[metrics] addr`).
+pub struct Metrics {
+    registry: Registry,
+    /// Sessions in the storage plus sessions being recorded (refreshed on scrape).
+    pub live_sessions: IntGauge,
+    /// Sessions dropped by the TTL purge.
+    pub sessions_purged: IntCounter,
+    /// Finished RPCs by method (`/package.Service/Method`) and gRPC status code.
+    pub rpc_requests: IntCounterVec,
+    /// RPC latency by method, until the end of the response (whole stream for streaming RPCs).
+    pub rpc_duration: HistogramVec,
+    /// Duration of a single simulation step, `mode` = `interactive` or `recording`.
+    pub step_duration: HistogramVec,
+    /// Simulated ticks per wall-clock second of every running recording, by session id.
+    pub recording_ticks_per_second: GaugeVec,
+    /// Encoded size of the RunAndRecord responses streamed to clients.
+    pub recording_bytes: IntCounter,
+    /// Vehicles on the network as of the last step of every session (refreshed on scrape).
+    pub active_vehicles: IntGauge,
+    /// Time spent waiting for the lock of a session (held by another RPC on that session).
+    pub session_lock_wait: Histogram,
+}
+
+impl Metrics {
+    fn new() -> prometheus::Result<Self> {
+        let registry = Registry::new_custom(Some("mt_sim".to_string()), None)?;
+        let live_sessions =
+            IntGauge::new("live_sessions", "Live sessions (recorded ones included)")?;
+        let sessions_purged =
+            IntCounter::new("sessions_purged_total", "Sessions dropped by the TTL purge")?;
+        let rpc_requests = IntCounterVec::new(
+            Opts::new(
+                "rpc_requests_total",
+                "Finished RPCs by method and status code",
+            ),
+            &["method", "code"],
+        )?;
+        let rpc_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "rpc_duration_seconds",
+                "RPC latency until the end of the response",
+            )
+            .buckets(exponential_buckets(0.0005, 4.0, 10)?),
+            &["method"],
+        )?;
+        let step_duration = HistogramVec::new(
+            HistogramOpts::new("step_duration_seconds", "Duration of a simulation step")
+                .buckets(exponential_buckets(0.0001, 2.0, 16)?),
+            &["mode"],
+        )?;
+        let recording_ticks_per_second = GaugeVec::new(
+            Opts::new(
+                "recording_ticks_per_second",
+                "Simulated ticks per second of running recordings",
+            ),
+            &["session_id"],
+        )?;
+        let recording_bytes = IntCounter::new(
+            "recording_bytes_total",
+            "Encoded size of the streamed RunAndRecord responses",
+        )?;
+        let active_vehicles = IntGauge::new(
+            "active_vehicles",
+            "Vehicles on the network as of the last step of every session",
+        )?;
+        let session_lock_wait = Histogram::with_opts(
+            HistogramOpts::new(
+                "session_lock_wait_seconds",
+                "Time spent waiting for the lock of a session",
+            )
+            .buckets(exponential_buckets(0.00001, 4.0, 10)?),
+        )?;
+        registry.register(Box::new(live_sessions.clone()))?;
+        registry.register(Box::new(sessions_purged.clone()))?;
+        registry.register(Box::new(rpc_requests.clone()))?;
+        registry.register(Box::new(rpc_duration.clone()))?;
+        registry.register(Box::new(step_duration.clone()))?;
+        registry.register(Box::new(recording_ticks_per_second.clone()))?;
+        registry.register(Box::new(recording_bytes.clone()))?;
+        registry.register(Box::new(active_vehicles.clone()))?;
+        registry.register(Box::new(session_lock_wait.clone()))?;
+        Ok(Self {
+            registry,
+            live_sessions,
+            sessions_purged,
+            rpc_requests,
+            rpc_duration,
+            step_duration,
+            recording_ticks_per_second,
+            recording_bytes,
+            active_vehicles,
+            session_lock_wait,
+        })
+    }
+
+    /// Records the duration of a simulation step started at `started`.
+    pub fn observe_step(&self, mode: &str, started: Instant) {
+        self.step_duration
+            .with_label_values(&[mode])
+            .observe(started.elapsed().as_secs_f64());
+    }
+}
+
+static METRICS: LazyLock<Metrics> =
+    LazyLock::new(|| Metrics::new().expect("metrics are registered once"));
+
+/// Process-wide metrics.
+pub fn get() -> &'static Metrics {
+    &METRICS
+}
+
+/// State read on scrape to refresh the gauges that are not updated in place.
+#[derive(Clone)]
+pub struct Sources {
+    pub sessions: Arc<SessionStore>,
+    pub catalogs: Catalogs,
+    pub recordings: Recordings,
+}
+
+impl Sources {
+    fn refresh(&self) {
+        let stored = self.sessions.sessions_num();
+        let (recorded, recorded_vehicles) = match self.recordings.lock() {
+            Ok(reg) => (
+                reg.len(),
+                reg.values()
+                    .map(|h| h.vehicles.load(Ordering::Relaxed))
+                    .sum::<u64>(),
+            ),
+            Err(_) => (0, 0),
+        };
+        let vehicles = catalog::vehicles_active(&self.catalogs);
+        get().live_sessions.set((stored + recorded) as i64);
+        get()
+            .active_vehicles
+            .set((vehicles + recorded_vehicles) as i64);
+    }
+}
+
+/// Renders all the metrics in the Prometheus text format.
+fn render(sources: &Sources) -> String {
+    sources.refresh();
+    let mut buf = Vec::new();
+    if let Err(e) = TextEncoder::new().encode(&get().registry.gather(), &mut buf) {
+        return format!("# failed to encode metrics: {}\n", e);
+    }
+    String::from_utf8(buf).unwrap_or_default()
+}
+
+/// Binds the plain HTTP metrics endpoint and serves `GET /metrics` in the background.
+pub async fn serve(addr: SocketAddr, sources: Sources) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let app = axum::Router::new().route(
+        "/metrics",
+        routing::get(move || {
+            let sources = sources.clone();
+            // Catalog locks may be held by a step: wait for them on the blocking pool
+            async move {
+                tokio::task::spawn_blocking(move || render(&sources))
+                    .await
+                    .unwrap_or_else(|e| format!("# failed to render metrics: {}\n", e))
+            }
+        }),
+    );
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            tracing::error!("Metrics endpoint failed: {}", e);
+        }
+    });
+    Ok(())
+}
+
+/// Tower layer counting RPCs and measuring their latency per method.
+#[derive(Clone, Copy, Default)]
+pub struct RpcMetricsLayer;
+
+impl<S> Layer<S> for RpcMetricsLayer {
+    type Service = RpcMetrics<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMetrics { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct RpcMetrics<S> {
+    inner: S,
+}
+
+impl<S, B, ResBody> Service<http::Request<B>> for RpcMetrics<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<RpcBody<ResBody>>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let method = request.uri().path().to_string();
+        let started = Instant::now();
+        let response = self.inner.call(request);
+        Box::pin(async move {
+            // Polled within the RPC span (see `telemetry::rpc_span`), unlike `call`
+            let span = tracing::Span::current();
+            let response = response.await?;
+            // Errors returned before any message come as a trailers-only response
+            let status = grpc_status(response.headers());
+            Ok(response.map(|inner| RpcBody {
+                inner,
+                method,
+                started,
+                span,
+                status,
+                finished: false,
+            }))
+        })
+    }
+}
+
+/// Status code and message of a headers or trailers map carrying `grpc-status`.
+fn grpc_status(map: &http::HeaderMap) -> Option<(tonic::Code, String)> {
+    let code = tonic::Code::from_bytes(map.get("grpc-status")?.as_bytes());
+    let message = map
+        .get("grpc-message")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    Some((code, message))
+}
+
+/// Response body recording the RPC once its status is known (trailers) or the
+/// body is dropped. RPCs ending without a status were cancelled by the client.
+pub struct RpcBody<B> {
+    inner: B,
+    method: String,
+    started: Instant,
+    span: tracing::Span,
+    status: Option<(tonic::Code, String)>,
+    finished: bool,
+}
+
+impl<B> RpcBody<B> {
+    fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        let (code, message) = self
+            .status
+            .take()
+            .unwrap_or((tonic::Code::Cancelled, String::new()));
+        let elapsed = self.started.elapsed();
+        let code_label = format!("{:?}", code);
+        let metrics = get();
+        metrics
+            .rpc_requests
+            .with_label_values(&[self.method.as_str(), code_label.as_str()])
+            .inc();
+        metrics
+            .rpc_duration
+            .with_label_values(&[self.method.as_str()])
+            .observe(elapsed.as_secs_f64());
+        let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+        self.span.in_scope(|| {
+            if code == tonic::Code::Ok {
+                tracing::debug!(code = %code_label, elapsed_ms, "RPC finished");
+            } else {
+                tracing::warn!(code = %code_label, message, elapsed_ms, "RPC failed");
+            }
+        });
+    }
+}
+
+impl<B: Body + Unpin> Body for RpcBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = &mut *self;
+        let polled = Pin::new(&mut this.inner).poll_frame(cx);
+        match &polled {
+            Poll::Ready(Some(Ok(frame))) => {
+                if let Some(status) = frame.trailers_ref().and_then(grpc_status) {
+                    this.status = Some(status);
+                    this.finish();
+                }
+            }
+            Poll::Ready(Some(Err(_))) => {
+                this.status
+                    .get_or_insert((tonic::Code::Unknown, "response body error".to_string()));
+                this.finish();
+            }
+            Poll::Ready(None) => this.finish(),
+            Poll::Pending => {}
+        }
+        polled
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B> Drop for RpcBody<B> {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exposition() {
+        let sources = Sources {
+            sessions: Arc::new(SessionStore::new(
+                std::time::Duration::from_secs(60),
+                micro_traffic_sim_core::verbose::VerboseLevel::None,
+            )),
+            catalogs: super::super::catalog::new_registry(),
+            recordings: super::super::recordings::new_registry(),
+        };
+        get().sessions_purged.inc();
+        get().observe_step("interactive", Instant::now());
+        let text = render(&sources);
+        for name in [
+            "mt_sim_live_sessions 0",
+            "mt_sim_active_vehicles 0",
+            "mt_sim_sessions_purged_total",
+            "mt_sim_step_duration_seconds_count{mode=\"interactive\"}",
+            "mt_sim_session_lock_wait_seconds_count",
+        ] {
+            assert!(text.contains(name), "{} missing in:\n{}", name, text);
+        }
+    }
+}
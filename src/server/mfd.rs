@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::simulation::states::AutomataState;
+
+use super::catalog::SessionCatalog;
+use super::events::PreStep;
+
+/// Raw totals of one sampling window.
+#[derive(Clone, Copy, Default)]
+struct Window {
+    /// Distance travelled by all vehicles, in grid distance units (meters for WGS84).
+    distance: f64,
+    /// Vehicles present, summed over the window's steps.
+    vehicle_ticks: u64,
+}
+
+/// Accumulates macroscopic fundamental diagram samples, one every `interval_ticks`
+/// steps, network-wide and per meso link.
+pub struct MfdAcc {
+    interval_ticks: u32,
+    tick_seconds: f64,
+    tick_start: u64,
+    tick_count: u32,
+    network: Window,
+    links: BTreeMap<i64, Window>,
+    samples: Vec<pb::MfdSample>,
+}
+
+impl MfdAcc {
+    pub fn new(interval_ticks: u32, tick_seconds: f64) -> Self {
+        Self {
+            interval_ticks: interval_ticks.max(1),
+            tick_seconds,
+            tick_start: 0,
+            tick_count: 0,
+            network: Window::default(),
+            links: BTreeMap::new(),
+            samples: Vec::new(),
+        }
+    }
+
+    /// Adds one step. Distances are measured along the cells a vehicle crossed since
+    /// `pre` and are attributed to the meso link of the entered cell; time spent is
+    /// attributed to the meso link of the vehicle's head cell. Vehicles spawned or
+    /// removed during the step contribute no distance.
+    pub fn push_tick(&mut self, catalog: &SessionCatalog, pre: &PreStep, dump: &AutomataState) {
+        if self.tick_count == 0 {
+            self.tick_start = dump.timestamp as u64;
+        }
+        for v in &dump.vehicles {
+            self.network.vehicle_ticks += 1;
+            if let Some(link) = catalog.meso_links.get(&v.last_cell) {
+                self.links.entry(*link).or_default().vehicle_ticks += 1;
+            }
+
+            let Some((from, _)) = pre.cell_and_speed(v.id) else {
+                continue;
+            };
+            let path: Vec<i64> = std::iter::once(from)
+                .chain(v.last_intermediate_cells.iter().copied())
+                .chain(std::iter::once(v.last_cell))
+                .collect();
+            for pair in path.windows(2) {
+                if pair[0] == pair[1] {
+                    continue;
+                }
+                let (a, b) = (catalog.cells.get(&pair[0]), catalog.cells.get(&pair[1]));
+                let (Some(a), Some(b)) = (a, b) else {
+                    continue;
+                };
+                let d = a.distance_to(b);
+                self.network.distance += d;
+                if let Some(link) = catalog.meso_links.get(&pair[1]) {
+                    self.links.entry(*link).or_default().distance += d;
+                }
+            }
+        }
+        self.tick_count += 1;
+        if self.tick_count >= self.interval_ticks {
+            self.flush();
+        }
+    }
+
+    /// Takes the samples of the windows closed so far, in tick order.
+    pub fn take_samples(&mut self) -> Vec<pb::MfdSample> {
+        std::mem::take(&mut self.samples)
+    }
+
+    /// Closes the trailing partial window and returns the samples not taken yet.
+    pub fn finish(mut self) -> Vec<pb::MfdSample> {
+        if self.tick_count > 0 {
+            self.flush();
+        }
+        self.samples
+    }
+
+    fn flush(&mut self) {
+        let (tick_count, tick_seconds) = (self.tick_count, self.tick_seconds);
+        let links = std::mem::take(&mut self.links)
+            .into_iter()
+            .map(|(meso_link_id, w)| pb::MfdLinkMeasure {
+                meso_link_id,
+                measure: Some(measure(&w, tick_count, tick_seconds)),
+            })
+            .collect();
+        self.samples.push(pb::MfdSample {
+            tick_start: self.tick_start,
+            tick_count,
+            network: Some(measure(&self.network, tick_count, tick_seconds)),
+            links,
+        });
+        self.network = Window::default();
+        self.tick_count = 0;
+    }
+}
+
+fn measure(w: &Window, tick_count: u32, tick_seconds: f64) -> pb::MfdMeasure {
+    let vkt = w.distance / 1000.0;
+    let total_time_spent = w.vehicle_ticks as f64 * tick_seconds / 3600.0;
+    let hours = tick_count as f64 * tick_seconds / 3600.0;
+    pb::MfdMeasure {
+        vkt,
+        total_time_spent,
+        production: if hours > 0.0 { vkt / hours } else { 0.0 },
+        accumulation: if tick_count > 0 {
+            w.vehicle_ticks as f64 / tick_count as f64
+        } else {
+            0.0
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::{road_cell, road_zone};
+    use micro_traffic_sim_core::grid::cell::Cell;
+    use micro_traffic_sim_core::simulation::session::Session;
+    use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+
+    /// Road of 100 m cells, cells 1..=5 on meso link 1 and 6..=10 on meso link 2.
+    #[test]
+    fn samples_per_window_and_link() {
+        let cells: Vec<Cell> = (1..=10)
+            .map(|id| {
+                road_cell(id, id as f64 * 100.0, if id == 10 { -1 } else { id + 1 })
+                    .with_zone_type(road_zone(id, 10))
+                    .build()
+            })
+            .collect();
+        let mut session = Session::default(None);
+        let mut catalog = SessionCatalog::new();
+        for cell in &cells {
+            catalog.insert_cell(cell.clone(), if cell.get_id() <= 5 { 1 } else { 2 });
+        }
+        session.add_cells(cells);
+        session.add_trip(
+            Trip::new(1, 9, TripType::Constant)
+                .with_id(1)
+                .with_initial_speed(1)
+                .with_time(1000)
+                .with_start_time(0)
+                .with_end_time(0)
+                .build(),
+        );
+
+        let mut mfd = MfdAcc::new(4, 1.0);
+        let mut vehicle_ticks = 0;
+        for _ in 0..10 {
+            let pre = crate::server::events::capture(&session);
+            let dump = session.step().unwrap();
+            vehicle_ticks += dump.vehicles.len();
+            mfd.push_tick(&catalog, &pre, &dump);
+        }
+        // Closed windows are taken as they go, the trailing one on finish
+        let mut samples = mfd.take_samples();
+        assert_eq!(samples.len(), 2);
+        assert!(mfd.take_samples().is_empty());
+        samples.extend(mfd.finish());
+        assert_eq!(
+            samples.iter().map(|s| s.tick_count).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+
+        let network: Vec<_> = samples.iter().map(|s| s.network.unwrap()).collect();
+        let vkt: f64 = network.iter().map(|m| m.vkt).sum();
+        assert!(vkt > 0.0);
+        // Distances are whole cells of 100 m
+        assert!(((vkt * 10.0).round() - vkt * 10.0).abs() < 1e-9);
+        let tts: f64 = network.iter().map(|m| m.total_time_spent).sum();
+        assert!((tts - vehicle_ticks as f64 / 3600.0).abs() < 1e-12);
+        let first = &network[0];
+        assert!((first.production - first.vkt * 3600.0 / 4.0).abs() < 1e-9);
+
+        // Per-link measures add up to the network ones
+        let links_vkt: f64 = samples
+            .iter()
+            .flat_map(|s| &s.links)
+            .map(|l| l.measure.unwrap().vkt)
+            .sum();
+        assert!((links_vkt - vkt).abs() < 1e-9);
+        assert!(
+            samples
+                .iter()
+                .flat_map(|s| &s.links)
+                .any(|l| l.meso_link_id == 2)
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tonic::{Code, Request, Response, Status};
+use uuid::Uuid;
+
+use micro_traffic_sim::od::{OdError, count_od_trips, expand_od_matrix};
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::grid::cell::CellID;
+
+use super::auth;
+use super::catalog::{self, Catalogs, SessionCatalog};
+use super::incidents;
+use super::quotas;
+use super::recordings::Recordings;
+use super::storage::SessionStore;
+use super::trip::{core_trip, trip_reachability};
+use super::{blocking, check_entities_count, ensure_session, parse_session_uuid};
+
+fn to_status(err: OdError) -> Status {
+    match err {
+        OdError::UnknownZone(id) => Status::not_found(format!("Not found zone ID: '{}'", id)),
+        other => Status::invalid_argument(other.to_string()),
+    }
+}
+
+/// Expands an OD matrix into trips and adds them to an interactive session.
+pub async fn push_session_od_matrix(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::PushSessionOdMatrixRequest>,
+) -> Result<Response<pb::PushSessionOdMatrixResponse>, Status> {
+    let caller = auth::Caller::of(&request);
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+    let Some(matrix) = req.matrix else {
+        return Err(Status::invalid_argument("No data"));
+    };
+    if incidents::running_recording(&recordings, &session_uuid)?.is_some() {
+        return Err(Status::failed_precondition(format!(
+            "Session '{}' is being recorded",
+            session_uuid
+        )));
+    }
+
+    ensure_session(&sessions, &session_uuid).await?;
+
+    // Expansion and route checks are CPU-heavy: all of it runs on the blocking pool
+    let response = blocking(move || {
+        push_matrix(
+            &sessions,
+            &catalogs,
+            caller,
+            session_uuid,
+            matrix,
+            req.first_trip_id,
+            req.reject_unreachable,
+        )
+    })
+    .await??;
+    Ok(Response::new(response))
+}
+
+/// Expands the matrix and adds its trips to the session. Limits are checked on the trip
+/// count, before anything is expanded; the expansion and the route checks run outside
+/// the session and catalog locks.
+fn push_matrix(
+    sessions: &SessionStore,
+    catalogs: &Catalogs,
+    caller: Option<auth::Caller>,
+    session_uuid: Uuid,
+    matrix: pb::OdMatrix,
+    first_trip_id: i64,
+    reject_unreachable: bool,
+) -> Result<pb::PushSessionOdMatrixResponse, Status> {
+    let trips_count = count_od_trips(&matrix);
+    check_entities_count(trips_count)?;
+    let (first_trip_id, net) = catalog::with_catalog_mut(catalogs, &session_uuid, |c| {
+        let cells = matrix
+            .zones
+            .iter()
+            .flat_map(|z| z.birth_cells.iter().chain(&z.death_cells));
+        for cell_id in cells {
+            if !c.cells.contains_key(cell_id) {
+                return Err(Status::not_found(format!(
+                    "Not found cell ID: '{}'",
+                    cell_id
+                )));
+            }
+        }
+        let first_trip_id = if first_trip_id == 0 {
+            c.next_trip_id()
+        } else {
+            first_trip_id
+        };
+        check_free_ids(c, first_trip_id, trips_count)?;
+        quotas::check_trips(caller.as_ref(), c.trips.len() + trips_count)?;
+        Ok((first_trip_id, c.network()))
+    })?;
+
+    let trips = expand_od_matrix(&matrix, first_trip_id).map_err(to_status)?;
+    let mut routes: HashMap<(CellID, CellID), pb::TripReachability> = HashMap::new();
+    let reachability: Vec<pb::TripReachability> = trips
+        .iter()
+        .map(|t| pb::TripReachability {
+            trip_id: t.id,
+            ..routes
+                .entry((t.from_node, t.to_node))
+                .or_insert_with(|| trip_reachability(&net, t))
+                .clone()
+        })
+        .collect();
+    if reject_unreachable {
+        if let Some(r) = reachability.iter().find(|r| !r.reachable) {
+            return Err(Status::failed_precondition(format!(
+                "Trip '{}' is unreachable: {}",
+                r.trip_id, r.reason
+            )));
+        }
+    }
+
+    let result = {
+        sessions.with_session_mut(&session_uuid, |session| {
+            catalog::with_catalog_mut(catalogs, &session_uuid, |c| {
+                // Trips may have been pushed while the matrix was expanded
+                check_free_ids(c, first_trip_id, trips.len())?;
+                quotas::check_trips(caller.as_ref(), c.trips.len() + trips.len())?;
+                for trip in &trips {
+                    session.add_trip(core_trip(trip));
+                    c.removed_trips.remove(&trip.id);
+                }
+                let count = trips.len() as u32;
+                c.trips.extend(trips.into_iter().map(|t| (t.id, t)));
+                Ok::<_, Status>(count)
+            })
+        })
+    };
+    let trips_count = match result {
+        None => {
+            return Err(Status::not_found(format!(
+                "Not found session ID: '{}'",
+                session_uuid
+            )));
+        }
+        Some(r) => r?,
+    };
+
+    Ok(pb::PushSessionOdMatrixResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        first_trip_id,
+        trips_count,
+        reachability,
+    })
+}
+
+/// Fails when one of the `count` trip ids starting at `first_trip_id` is taken by a
+/// trip of the session.
+fn check_free_ids(
+    catalog: &SessionCatalog,
+    first_trip_id: i64,
+    count: usize,
+) -> Result<(), Status> {
+    let ids = first_trip_id..first_trip_id.saturating_add(count as i64);
+    match catalog.trips.keys().filter(|id| ids.contains(id)).min() {
+        Some(id) => Err(Status::already_exists(format!(
+            "Trip ID '{}' already exists",
+            id
+        ))),
+        None => Ok(()),
+    }
+}
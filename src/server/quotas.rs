@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use tonic::{Code, Request, Response, Status};
+use uuid::Uuid;
+
+use micro_traffic_sim::pb;
+
+use super::auth::Caller;
+use super::config::{self, Quota};
+use super::recordings::Recordings;
+use super::storage::SessionStore;
+
+/// Tightest of two limits (`None` = unlimited).
+fn tightest(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Quota of a tenant: its own overrides on top of the default tenant quota.
+pub fn tenant_quota(tenant: &str) -> Quota {
+    let quotas = &config::get().quotas;
+    let base = quotas.tenant;
+    let Some(own) = quotas.tenants.get(tenant) else {
+        return base;
+    };
+    Quota {
+        max_sessions: own.max_sessions.or(base.max_sessions),
+        max_cells_per_session: own.max_cells_per_session.or(base.max_cells_per_session),
+        max_trips_per_session: own.max_trips_per_session.or(base.max_trips_per_session),
+        max_concurrent_recordings: own
+            .max_concurrent_recordings
+            .or(base.max_concurrent_recordings),
+        max_horizon_ticks: own.max_horizon_ticks.or(base.max_horizon_ticks),
+    }
+}
+
+/// Per-session limits applying to the caller: the server quota tightened by the
+/// caller's tenant quota (authentication enabled).
+pub fn session_quota(caller: Option<&Caller>) -> Quota {
+    let server = config::get().quotas.server;
+    let Some(caller) = caller else {
+        return server;
+    };
+    let tenant = tenant_quota(caller.tenant());
+    Quota {
+        max_sessions: tightest(server.max_sessions, tenant.max_sessions),
+        max_cells_per_session: tightest(server.max_cells_per_session, tenant.max_cells_per_session),
+        max_trips_per_session: tightest(server.max_trips_per_session, tenant.max_trips_per_session),
+        max_concurrent_recordings: tightest(
+            server.max_concurrent_recordings,
+            tenant.max_concurrent_recordings,
+        ),
+        max_horizon_ticks: tightest(server.max_horizon_ticks, tenant.max_horizon_ticks),
+    }
+}
+
+fn exhausted(what: &str, limit: u64, scope: &str) -> Status {
+    Status::resource_exhausted(format!("Max amount of {} {} is {}", what, scope, limit))
+}
+
+/// Checks a session of the caller can hold `count` entities (`what`: "cells", "trips")
+/// against `limit` of its quota.
+fn check_per_session(limit: Option<u64>, what: &str, count: usize) -> Result<(), Status> {
+    match limit {
+        Some(max) if count as u64 > max => Err(Status::resource_exhausted(format!(
+            "Max amount of {} per session is {}, but the session would have {}",
+            what, max, count
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Checks a session of the caller can hold `count` grid cells.
+pub fn check_cells(caller: Option<&Caller>, count: usize) -> Result<(), Status> {
+    check_per_session(session_quota(caller).max_cells_per_session, "cells", count)
+}
+
+/// Checks a session of the caller can hold `count` trips.
+pub fn check_trips(caller: Option<&Caller>, count: usize) -> Result<(), Status> {
+    check_per_session(session_quota(caller).max_trips_per_session, "trips", count)
+}
+
+/// Running recordings: total and the caller's tenant ones.
+fn running_recordings(recordings: &Recordings, caller: Option<&Caller>) -> (u64, u64) {
+    let running: Vec<Uuid> = recordings.lock().unwrap().keys().copied().collect();
+    let mine = caller.map_or(0, |c| c.owned_sessions(Some(&running)));
+    (running.len() as u64, mine)
+}
+
+/// Checks a new session is admitted, given the number of live sessions in the store
+/// (expired ones purged). Called by the store under its registry lock.
+pub fn check_new_session(
+    stored: usize,
+    recordings: &Recordings,
+    caller: Option<&Caller>,
+) -> Result<(), Status> {
+    if let Some(max) = config::get().quotas.server.max_sessions {
+        if stored as u64 + running_recordings(recordings, None).0 >= max {
+            return Err(exhausted("sessions", max, "on the server"));
+        }
+    }
+    if let Some(caller) = caller {
+        if let Some(max) = tenant_quota(caller.tenant()).max_sessions {
+            if caller.owned_sessions(None) >= max {
+                return Err(exhausted("sessions", max, "per tenant"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks a new recording is admitted, given the running ones (the registry being
+/// locked by the caller, so that admission and registration are atomic).
+pub fn check_new_recording(running: &[Uuid], caller: Option<&Caller>) -> Result<(), Status> {
+    let total = running.len() as u64;
+    let mine = caller.map_or(0, |c| c.owned_sessions(Some(running)));
+    if let Some(max) = config::get().quotas.server.max_concurrent_recordings {
+        if total >= max {
+            return Err(exhausted("concurrent recordings", max, "on the server"));
+        }
+    }
+    if let Some(caller) = caller {
+        if let Some(max) = tenant_quota(caller.tenant()).max_concurrent_recordings {
+            if mine >= max {
+                return Err(exhausted("concurrent recordings", max, "per tenant"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Horizon of a RunAndRecord run: the requested one (0 = run until drained, capped by
+/// `limits.horizon_hard_cap`), checked against the quota.
+pub fn horizon_ticks(caller: Option<&Caller>, requested: u64) -> Result<u64, Status> {
+    let cap = session_quota(caller).max_horizon_ticks;
+    if requested == 0 {
+        let hard_cap = config::get().limits.horizon_hard_cap;
+        return Ok(cap.map_or(hard_cap, |cap| cap.min(hard_cap)));
+    }
+    match cap {
+        Some(max) if requested > max => Err(Status::resource_exhausted(format!(
+            "Max horizon is {} ticks, but requested is {}",
+            max, requested
+        ))),
+        _ => Ok(requested),
+    }
+}
+
+fn proto_limits(quota: &Quota) -> pb::ResourceLimits {
+    pb::ResourceLimits {
+        max_sessions: quota.max_sessions.unwrap_or(0),
+        max_cells_per_session: quota.max_cells_per_session.unwrap_or(0),
+        max_trips_per_session: quota.max_trips_per_session.unwrap_or(0),
+        max_concurrent_recordings: quota.max_concurrent_recordings.unwrap_or(0),
+        max_horizon_ticks: quota.max_horizon_ticks.unwrap_or(0),
+    }
+}
+
+/// Reports the limits applying to the caller and the current usage.
+pub async fn get_server_limits(
+    sessions: Arc<SessionStore>,
+    recordings: Recordings,
+    request: Request<pb::GetServerLimitsRequest>,
+) -> Result<Response<pb::GetServerLimitsResponse>, Status> {
+    let caller = Caller::of(&request);
+    let cfg = config::get();
+    let (running, mine) = running_recordings(&recordings, caller.as_ref());
+    let sessions_num = sessions.sessions_num() as u64;
+
+    Ok(Response::new(pb::GetServerLimitsResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        server: Some(proto_limits(&cfg.quotas.server)),
+        server_usage: Some(pb::ResourceUsage {
+            sessions: sessions_num + running,
+            running_recordings: running,
+        }),
+        tenant: caller
+            .as_ref()
+            .map(|c| c.tenant().to_string())
+            .unwrap_or_default(),
+        tenant_limits: caller
+            .as_ref()
+            .map(|c| proto_limits(&tenant_quota(c.tenant()))),
+        tenant_usage: caller.as_ref().map(|c| pb::ResourceUsage {
+            sessions: c.owned_sessions(None),
+            running_recordings: mine,
+        }),
+        max_entities_per_message: cfg.limits.max_entities_per_message as u64,
+        horizon_hard_cap: cfg.limits.horizon_hard_cap,
+        default_batch_ticks: cfg.limits.default_batch_ticks,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tightest_limit() {
+        assert_eq!(tightest(None, None), None);
+        assert_eq!(tightest(Some(5), None), Some(5));
+        assert_eq!(tightest(None, Some(7)), Some(7));
+        assert_eq!(tightest(Some(5), Some(3)), Some(3));
+
+        // Default configuration: unlimited
+        assert!(check_cells(None, usize::MAX).is_ok());
+        assert_eq!(horizon_ticks(None, 0).unwrap(), 1_000_000);
+        assert_eq!(horizon_ticks(None, 42).unwrap(), 42);
+        let err = check_per_session(Some(10), "trips", 11).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::ResourceExhausted);
+    }
+}
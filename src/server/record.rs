@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use prost::Message;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
@@ -9,21 +11,30 @@ use uuid::Uuid;
 
 use micro_traffic_sim::pb;
 use micro_traffic_sim_core::agents_types::AgentType;
-use micro_traffic_sim_core::simulation::sessions_storage::SessionsStorage;
 use micro_traffic_sim_core::simulation::states::{TrafficLightGroupState, VehicleState};
 use micro_traffic_sim_core::traffic_lights::lights::TrafficLightID;
 use micro_traffic_sim_core::traffic_lights::signals::SignalType;
 
 use super::BoxStream;
+use super::auth;
+use super::blocking;
+use super::catalog::{self, Catalogs};
+use super::config;
+use super::demand;
+use super::events;
+use super::metrics;
+use super::mfd::MfdAcc;
+use super::quotas;
 use super::recordings::{RecordingGuard, RecordingHandle, Recordings};
+use super::storage::SessionStore;
+use super::vehicles;
 
 /// Layout version of the RecordBatch.columns blob. See `protos/record.proto`
 /// RECORD BLOB LAYOUT. Bump on ANY change to the blob layout.
 const RECORD_BATCH_VERSION: u8 = 1;
-/// Ticks per RecordBatch when the request leaves `batch_ticks = 0`.
-const DEFAULT_BATCH_TICKS: u32 = 300;
-/// Safety cap on the number of ticks when `horizon_ticks = 0` (run until drained).
-const HORIZON_HARD_CAP: u64 = 1_000_000;
+/// Layout version of blobs carrying the trailing vehicle events section
+/// (RunAndRecordRequest.include_events).
+const RECORD_BATCH_VERSION_EVENTS: u8 = 2;
 /// Mirrors the (private) core SPAWN_SEED used to seed vehicle spawning, recorded
 /// into RunMetadata for reproducibility.
 const SPAWN_SEED: u64 = 0x00C0_FFEE;
@@ -52,6 +63,16 @@ struct BatchAcc {
     tl_keys: Vec<(u32, u32)>,
     // per-tick signal codes in tl_keys order, K*G bytes total
     tl_signals: Vec<u8>,
+    // emit the vehicle events section (blob version 2)
+    with_events: bool,
+    events_per_tick: Vec<u32>,
+    ev_vehicle_id: Vec<u32>,
+    ev_type: Vec<u8>,
+    ev_trip: Vec<u32>,
+    ev_cell: Vec<u32>,
+    ev_from_cell: Vec<u32>,
+    // speed limit changes applied during the batch (proto envelope only, not in the blob)
+    speed_limit_changes: Vec<pb::SpeedLimitChange>,
 }
 
 impl BatchAcc {
@@ -82,6 +103,34 @@ impl BatchAcc {
         self.tail_vals.clear();
         self.tl_keys.clear();
         self.tl_signals.clear();
+        self.events_per_tick.clear();
+        self.ev_vehicle_id.clear();
+        self.ev_type.clear();
+        self.ev_trip.clear();
+        self.ev_cell.clear();
+        self.ev_from_cell.clear();
+        self.speed_limit_changes.clear();
+    }
+
+    fn version(&self) -> u8 {
+        if self.with_events {
+            RECORD_BATCH_VERSION_EVENTS
+        } else {
+            RECORD_BATCH_VERSION
+        }
+    }
+
+    /// Appends the events of the tick last pushed with `push_tick`. -1 ids are
+    /// stored as u32::MAX.
+    fn push_events(&mut self, events: &[pb::VehicleEvent]) {
+        self.events_per_tick.push(events.len() as u32);
+        for e in events {
+            self.ev_vehicle_id.push(e.vehicle_id as u32);
+            self.ev_type.push(e.event_type as u8);
+            self.ev_trip.push(e.trip_id as u32);
+            self.ev_cell.push(e.cell as u32);
+            self.ev_from_cell.push(e.from_cell as u32);
+        }
     }
 
     fn push_tick(
@@ -142,7 +191,7 @@ impl BatchAcc {
                 + total_rows * 25
                 + (self.ic_vals.len() + self.tail_vals.len()) * 4,
         );
-        buf.push(RECORD_BATCH_VERSION);
+        buf.push(self.version());
         buf.extend_from_slice(&self.tick_start.to_le_bytes());
         buf.extend_from_slice(&(self.rows_per_tick.len() as u32).to_le_bytes());
         buf.extend_from_slice(&(total_rows as u32).to_le_bytes());
@@ -184,15 +233,32 @@ impl BatchAcc {
             buf.extend_from_slice(&gr.to_le_bytes());
         }
         buf.extend_from_slice(&self.tl_signals);
+        // Events section (version 2 only).
+        if self.with_events {
+            for &x in &self.events_per_tick {
+                buf.extend_from_slice(&x.to_le_bytes());
+            }
+            for &x in &self.ev_vehicle_id {
+                buf.extend_from_slice(&x.to_le_bytes());
+            }
+            buf.extend_from_slice(&self.ev_type);
+            for col in [&self.ev_trip, &self.ev_cell, &self.ev_from_cell] {
+                for &x in col {
+                    buf.extend_from_slice(&x.to_le_bytes());
+                }
+            }
+        }
         buf
     }
 
-    fn into_proto(&self) -> pb::RecordBatch {
+    fn to_proto(&self, mfd: Vec<pb::MfdSample>) -> pb::RecordBatch {
         pb::RecordBatch {
             tick_start: self.tick_start as u64,
             tick_count: self.ticks(),
             total_rows: self.rows(),
             columns: self.to_blob(),
+            speed_limit_changes: self.speed_limit_changes.clone(),
+            mfd,
         }
     }
 }
@@ -260,17 +326,80 @@ fn tl_column_schema() -> pb::ColumnSchema {
     }
 }
 
+/// Self-describing layout of the optional vehicle events section (blob version 2).
+fn events_column_schema() -> pb::ColumnSchema {
+    let col = |name: &str, ty: &str| pb::ColumnDef {
+        name: name.to_string(),
+        r#type: ty.to_string(),
+    };
+    pb::ColumnSchema {
+        columns: vec![
+            col("events_per_tick", "u32[tick_count]"),
+            col("event_vehicle_id", "u32[event_count]"),
+            col("event_type", "u8[event_count]"),
+            col("event_trip_id", "u32[event_count]"),
+            col("event_cell", "u32[event_count]"),
+            col("event_from_cell", "u32[event_count]"),
+        ],
+    }
+}
+
 fn wrap(payload: pb::run_and_record_response::Payload) -> pb::RunAndRecordResponse {
     pb::RunAndRecordResponse {
         payload: Some(payload),
     }
 }
 
+/// Sends a response of the recording thread, accounting its encoded size in the
+/// metrics. Returns `false` once the client stream is gone.
+fn send(
+    tx: &mpsc::Sender<Result<pb::RunAndRecordResponse, Status>>,
+    payload: pb::run_and_record_response::Payload,
+) -> bool {
+    let response = wrap(payload);
+    let bytes = response.encoded_len() as u64;
+    let sent = tx.blocking_send(Ok(response)).is_ok();
+    if sent {
+        metrics::get().recording_bytes.inc_by(bytes);
+    }
+    sent
+}
+
+/// Ticks per wall-clock second of a recording, published about once a second.
+struct TickRate {
+    label: String,
+    since: Instant,
+    ticks: u64,
+}
+
+impl TickRate {
+    fn new(session_id: Uuid) -> Self {
+        Self {
+            label: session_id.to_string(),
+            since: Instant::now(),
+            ticks: 0,
+        }
+    }
+
+    fn tick(&mut self) {
+        self.ticks += 1;
+        let elapsed = self.since.elapsed();
+        if elapsed >= Duration::from_secs(1) {
+            metrics::get()
+                .recording_ticks_per_second
+                .with_label_values(&[self.label.as_str()])
+                .set(self.ticks as f64 / elapsed.as_secs_f64());
+            self.since = Instant::now();
+            self.ticks = 0;
+        }
+    }
+}
+
 /// Headless run + columnar trajectory recording. See `protos/record.proto` for the
 /// wire contract and the RECORD BLOB LAYOUT.
 ///
-/// Takes the session OUT of `SessionsStorage` for the duration of the run (so a long
-/// batch run neither holds the global storage lock per tick nor races the TTL purge),
+/// Takes the session OUT of the `SessionStore` for the duration of the run (so a long
+/// batch run neither takes the session lock per tick nor races the TTL purge),
 /// owns it on a dedicated OS thread (off the async runtime), and drops it on
 /// completion - the recording is the canonical artifact, the spent session is freed.
 /// The run is registered in `recordings` so callers can observe progress and request a
@@ -279,10 +408,12 @@ fn wrap(payload: pb::run_and_record_response::Payload) -> pb::RunAndRecordRespon
 /// Streams RunMetadata first, then one RecordBatch every `batch_ticks` (K) ticks, then
 /// RunSummary last, through a bounded mpsc channel for natural backpressure.
 pub async fn run_and_record(
-    sessions: Arc<Mutex<SessionsStorage>>,
+    sessions: Arc<SessionStore>,
     recordings: Recordings,
+    catalogs: Catalogs,
     request: Request<pb::RunAndRecordRequest>,
 ) -> Result<Response<BoxStream<pb::RunAndRecordResponse>>, Status> {
+    let caller = auth::Caller::of(&request);
     let req = request.into_inner();
 
     let session_id = req
@@ -295,49 +426,69 @@ pub async fn run_and_record(
     })?;
 
     let batch_ticks = (if req.batch_ticks == 0 {
-        DEFAULT_BATCH_TICKS
+        config::get().limits.default_batch_ticks
     } else {
         req.batch_ticks
     }) as usize;
     let explicit_horizon = req.horizon_ticks > 0;
-    let max_ticks = if explicit_horizon {
-        req.horizon_ticks
-    } else {
-        HORIZON_HARD_CAP
-    };
+    let max_ticks = quotas::horizon_ticks(caller.as_ref(), req.horizon_ticks)?;
     // Best-effort: the per-tick stochastic seed (MTSC_SEED) is entropy-seeded in the
     // core when unset, in which case the exact seed used is not recoverable here.
     let stochastic_seed = std::env::var("MTSC_SEED")
         .ok()
         .and_then(|s| s.parse::<u64>().ok())
         .unwrap_or(0);
-
-    // Take ownership of the session: out of the storage's TTL/lock machinery for the
-    // whole run. Stepping an owned session holds no global storage lock (concurrent
-    // interactive sessions are unaffected) and the TTL purge cannot reap it mid-run.
-    let mut session = {
-        let mut guard = sessions
-            .lock()
-            .map_err(|_| Status::internal("sessions storage lock poisoned"))?;
-        match guard.remove_session(&session_uuid) {
-            Some(s) => s,
-            None => {
-                return Err(Status::not_found(format!(
-                    "Not found session ID: '{}'",
-                    session_id
-                )));
-            }
+    let include_events = req.include_events;
+    let mfd_interval_ticks = req.mfd_interval_ticks;
+
+    // Take ownership of the session: out of the store's TTL/lock machinery for the
+    // whole run. Stepping an owned session holds no lock at all and the TTL purge
+    // cannot reap it mid-run. The session catalog follows the session: owned by the
+    // run, dropped with it. Waiting for both locks happens on the blocking pool.
+    let removed = {
+        let sessions = sessions.clone();
+        let catalogs = catalogs.clone();
+        blocking(move || {
+            sessions
+                .remove_session(&session_uuid)
+                .map(|s| (s, catalog::take_catalog(&catalogs, &session_uuid)))
+        })
+        .await?
+    };
+    let (mut session, mut session_catalog) = match removed {
+        Some(taken) => taken,
+        None => {
+            return Err(Status::not_found(format!(
+                "Not found session ID: '{}'",
+                session_id
+            )));
         }
     };
 
     // Register the control handle so StopRecording / RecordingStatus can reach this run.
-    let handle = Arc::new(RecordingHandle::default());
-    {
+    // Incidents scheduled on the interactive session carry over to the run.
+    // Admission is checked under the registry lock so that concurrent runs can not
+    // overshoot the quota; a rejected session is handed back untouched.
+    let handle = {
         let mut reg = recordings
             .lock()
             .map_err(|_| Status::internal("recordings registry lock poisoned"))?;
+        let running: Vec<Uuid> = reg.keys().copied().collect();
+        if let Err(status) = quotas::check_new_recording(&running, caller.as_ref()) {
+            drop(reg);
+            let _ = sessions.register_session(session_uuid, session);
+            catalog::restore_catalog(&catalogs, session_uuid, session_catalog);
+            return Err(status);
+        }
+        let handle = Arc::new(RecordingHandle {
+            cells: session_catalog.cells.keys().copied().collect(),
+            meso_link_ids: session_catalog.meso_links.values().copied().collect(),
+            incidents: Mutex::new(std::mem::take(&mut session_catalog.incidents)),
+            ..Default::default()
+        });
         reg.insert(session_uuid, handle.clone());
-    }
+        handle
+    };
 
     let (tx, rx) = mpsc::channel::<Result<pb::RunAndRecordResponse, Status>>(16);
 
@@ -345,14 +496,21 @@ pub async fn run_and_record(
     // long (30+ min) run must not hog a tokio worker and starve other clients. The
     // thread feeds the gRPC stream through the bounded channel; `blocking_send` blocks
     // the thread (not a worker) when the consumer is slow, giving natural backpressure.
+    let span = tracing::Span::current();
     std::thread::spawn(move || {
+        let _span = span.enter();
         // Deregisters the recording on ANY exit (completion / stop / error / panic);
         // the owned `session` is dropped together with this thread = immediate cleanup.
         let _guard = RecordingGuard::new(recordings, session_uuid);
+        tracing::info!(max_ticks, batch_ticks, "Recording started");
 
         // Metadata, sent exactly once before any batch.
+        let mut batch = BatchAcc {
+            with_events: include_events,
+            ..Default::default()
+        };
         let meta = pb::RunMetadata {
-            format_version: RECORD_BATCH_VERSION as u32,
+            format_version: batch.version() as u32,
             tick_seconds: TICK_SECONDS,
             spawn_seed: SPAWN_SEED,
             stochastic_seed,
@@ -364,24 +522,22 @@ pub async fn run_and_record(
             config_hash: String::new(),
             schema: Some(column_schema()),
             tl_schema: Some(tl_column_schema()),
+            events_schema: include_events.then(events_column_schema),
         };
-        if tx
-            .blocking_send(Ok(wrap(pb::run_and_record_response::Payload::Metadata(
-                meta,
-            ))))
-            .is_err()
-        {
+        if !send(&tx, pb::run_and_record_response::Payload::Metadata(meta)) {
             return;
         }
 
         // Step the engine, packing batches.
-        let mut batch = BatchAcc::default();
         let mut total_ticks: u64 = 0;
         let mut total_rows: u64 = 0;
         let mut total_bytes: u64 = 0;
         let mut completed: i32 = 0;
         let mut lost: i32 = 0;
         let mut seen_any = false;
+        let mut mfd =
+            (mfd_interval_ticks > 0).then(|| MfdAcc::new(mfd_interval_ticks, TICK_SECONDS));
+        let mut rate = TickRate::new(session_uuid);
 
         for _ in 0..max_ticks {
             // Stop promptly on an out-of-band StopRecording (cancel flag) or a
@@ -390,18 +546,80 @@ pub async fn run_and_record(
                 break;
             }
 
-            let dump = match session.step() {
+            if let Ok(mut incidents) = handle.incidents.lock() {
+                let tick = session.get_steps() as i64;
+                incidents.apply(
+                    &mut session,
+                    &session_catalog.cells,
+                    &session_catalog.meso_links,
+                    tick,
+                );
+                let changes = handle
+                    .speed_limits
+                    .lock()
+                    .map(|mut pending| std::mem::take(&mut *pending))
+                    .unwrap_or_default();
+                for change in changes {
+                    // Targets were checked by SetSpeedLimit against the fixed grid
+                    if let Ok(cells) = change.apply(
+                        &mut session,
+                        &mut session_catalog.cells,
+                        &session_catalog.meso_links,
+                        &incidents,
+                    ) {
+                        session_catalog.invalidate_network();
+                        batch.speed_limit_changes.push(pb::SpeedLimitChange {
+                            tick: tick as u64,
+                            cells,
+                            speed_limit: change.speed_limit,
+                        });
+                    }
+                }
+            }
+
+            let trip_changes = handle
+                .trips
+                .lock()
+                .map(|mut pending| std::mem::take(&mut *pending))
+                .unwrap_or_default();
+            for change in trip_changes {
+                // Changes targeting unknown trips are dropped
+                let _ = change.apply(&mut session, &mut session_catalog);
+            }
+            let tick = session.get_steps() as i64;
+            demand::apply_profiles(&mut session, &mut session_catalog, tick);
+
+            let mut pre = (include_events || mfd.is_some()).then(|| events::capture(&session));
+            let started = Instant::now();
+            let stepped = session.step();
+            metrics::get().observe_step("recording", started);
+            let mut dump = match stepped {
                 Ok(d) => d,
                 Err(e) => {
+                    tracing::warn!(tick = total_ticks, error = %e, "Recording aborted");
                     let _ = tx.blocking_send(Err(Status::aborted(e.to_string())));
                     return;
                 }
             };
 
+            // Vehicles spawned / removed on the session before the run
+            let mut tick_events =
+                vehicles::settle_manual(&session, &mut session_catalog, pre.as_mut(), &mut dump);
+
             if batch.is_empty() {
                 batch.tick_start = dump.timestamp as u32;
             }
             batch.push_tick(&dump.vehicles, &dump.tls);
+            if let Some(pre) = &pre {
+                if include_events {
+                    tick_events.extend(events::collect(&session, pre, &dump, &mut session_catalog));
+                    tick_events.sort_by_key(|e| (e.vehicle_id, e.event_type));
+                    batch.push_events(&tick_events);
+                }
+                if let Some(mfd) = mfd.as_mut() {
+                    mfd.push_tick(&session_catalog, pre, &dump);
+                }
+            }
 
             let n = dump.vehicles.len();
             if n > 0 {
@@ -415,15 +633,14 @@ pub async fn run_and_record(
                 .progress_tick
                 .store(dump.timestamp as u64, Ordering::Relaxed);
             handle.rows.store(total_rows, Ordering::Relaxed);
+            handle.vehicles.store(n as u64, Ordering::Relaxed);
+            rate.tick();
 
             // Flush a full batch.
             if batch.ticks() as usize >= batch_ticks {
-                let rb = batch.into_proto();
+                let rb = batch.to_proto(mfd.as_mut().map(MfdAcc::take_samples).unwrap_or_default());
                 total_bytes += rb.columns.len() as u64;
-                if tx
-                    .blocking_send(Ok(wrap(pb::run_and_record_response::Payload::Batch(rb))))
-                    .is_err()
-                {
+                if !send(&tx, pb::run_and_record_response::Payload::Batch(rb)) {
                     return;
                 }
                 batch.clear();
@@ -437,16 +654,20 @@ pub async fn run_and_record(
 
         // Flush the trailing partial batch.
         if !batch.is_empty() {
-            let rb = batch.into_proto();
+            let rb = batch.to_proto(mfd.as_mut().map(MfdAcc::take_samples).unwrap_or_default());
             total_bytes += rb.columns.len() as u64;
-            if tx
-                .blocking_send(Ok(wrap(pb::run_and_record_response::Payload::Batch(rb))))
-                .is_err()
-            {
+            if !send(&tx, pb::run_and_record_response::Payload::Batch(rb)) {
                 return;
             }
         }
 
+        tracing::info!(
+            total_ticks,
+            total_rows,
+            total_bytes,
+            stopped = handle.cancel.load(Ordering::Relaxed),
+            "Recording finished"
+        );
         // Summary, sent exactly once after the last batch.
         let summary = pb::RunSummary {
             total_ticks,
@@ -454,10 +675,9 @@ pub async fn run_and_record(
             total_bytes,
             vehicles_completed: completed,
             vehicles_lost: lost,
+            mfd: mfd.map(MfdAcc::finish).unwrap_or_default(),
         };
-        let _ = tx.blocking_send(Ok(wrap(pb::run_and_record_response::Payload::Summary(
-            summary,
-        ))));
+        send(&tx, pb::run_and_record_response::Payload::Summary(summary));
     });
 
     let out: BoxStream<pb::RunAndRecordResponse> = Box::pin(ReceiverStream::new(rx));
@@ -468,6 +688,7 @@ pub async fn run_and_record(
 mod tests {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     fn veh(
         id: u64,
         cell: i64,
@@ -512,8 +733,10 @@ mod tests {
             v
         };
 
-        let mut acc = BatchAcc::default();
-        acc.tick_start = 7;
+        let mut acc = BatchAcc {
+            tick_start: 7,
+            ..Default::default()
+        };
         // one traffic light, two groups, constant across the batch
         let tls: HashMap<TrafficLightID, Vec<TrafficLightGroupState>> = HashMap::from([(
             1i64,
@@ -642,4 +865,52 @@ mod tests {
         // blob is exactly consumed
         assert_eq!(o, blob.len());
     }
+
+    /// With events enabled the blob is version 2 and decodes back (through the
+    /// public decoder) into the same per-tick events, -1 sentinels included.
+    #[test]
+    fn blob_with_events_roundtrip() {
+        let mut acc = BatchAcc {
+            tick_start: 3,
+            with_events: true,
+            ..Default::default()
+        };
+        let tls = HashMap::new();
+        acc.push_tick(
+            &[veh(1, 100, 1, 0.0, 5, vec![], vec![], AgentType::Car)],
+            &tls,
+        );
+        acc.push_events(&[pb::VehicleEvent {
+            vehicle_id: 1,
+            event_type: pb::VehicleEventType::Spawned as i32,
+            trip_id: 5,
+            cell: 100,
+            from_cell: -1,
+        }]);
+        acc.push_tick(&[], &tls);
+        acc.push_events(&[pb::VehicleEvent {
+            vehicle_id: 1,
+            event_type: pb::VehicleEventType::Arrived as i32,
+            trip_id: 5,
+            cell: 101,
+            from_cell: -1,
+        }]);
+
+        let blob = acc.to_blob();
+        assert_eq!(blob[0], RECORD_BATCH_VERSION_EVENTS);
+        let decoded = micro_traffic_sim::record::decode_record_batch(&blob).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].events.len(), 1);
+        assert_eq!(decoded[0].events[0].cell, 100);
+        assert_eq!(decoded[0].events[0].from_cell, -1);
+        assert_eq!(
+            decoded[1].events[0].event_type,
+            pb::VehicleEventType::Arrived as i32
+        );
+
+        // Clearing keeps the mode but drops the accumulated events.
+        acc.clear();
+        assert!(acc.with_events);
+        assert!(acc.events_per_tick.is_empty());
+    }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
@@ -6,11 +6,18 @@ use tonic::{Request, Response, Status};
 use uuid::Uuid;
 
 use micro_traffic_sim::pb;
+use micro_traffic_sim_core::grid::cell::CellID;
+
+use super::incidents::Incidents;
+use super::metrics;
+use super::parse_session_uuid;
+use super::speed_limits::SpeedLimitChange;
+use super::trip::TripChange;
 
 /// Shared control + observation handle for one active batch recording.
 ///
 /// Lives in the gRPC layer, NOT the core: a recording's session is removed from
-/// `SessionsStorage` and owned by its handler task, so this registry is the only
+/// the `SessionStore` and owned by its handler task, so this registry is the only
 /// way out-of-band callers (StopRecording / RecordingStatus) can reach a running
 /// recording by `session_id`.
 #[derive(Default)]
@@ -19,8 +26,22 @@ pub struct RecordingHandle {
     pub progress_tick: AtomicU64,
     /// Total vehicle-ticks (rows) recorded so far.
     pub rows: AtomicU64,
+    /// Vehicles on the network as of the last tick.
+    pub vehicles: AtomicU64,
     /// Set by StopRecording; the handler checks it every tick and stops.
     pub cancel: AtomicBool,
+    /// Cell ids of the recorded grid (fixed for the whole run), to validate requests.
+    pub cells: HashSet<CellID>,
+    /// Meso link ids of the recorded grid (fixed for the whole run), to validate requests.
+    pub meso_link_ids: HashSet<i64>,
+    /// Incidents of the recorded session; InjectIncident / ClearIncident edit them and
+    /// the handler applies them before every tick.
+    pub incidents: Mutex<Incidents>,
+    /// Speed limit changes requested by SetSpeedLimit, applied before the next tick.
+    pub speed_limits: Mutex<Vec<SpeedLimitChange>>,
+    /// Trip changes requested by UpdateSessionTrip / RemoveSessionTrip /
+    /// SetSessionTripPaused, applied before the next tick.
+    pub trips: Mutex<Vec<TripChange>>,
 }
 
 /// Registry of active batch recordings, keyed by session id. An entry exists
@@ -52,19 +73,12 @@ impl Drop for RecordingGuard {
         if let Ok(mut reg) = self.recordings.lock() {
             reg.remove(&self.id);
         }
+        let _ = metrics::get()
+            .recording_ticks_per_second
+            .remove_label_values(&[&self.id.to_string()]);
     }
 }
 
-fn parse_session_uuid(id: &Option<pb::UuiDv4>) -> Result<Uuid, Status> {
-    let value = id
-        .as_ref()
-        .map(|u| u.value.as_str())
-        .ok_or_else(|| Status::invalid_argument("No session ID has been provided"))?;
-    Uuid::parse_str(value).map_err(|_| {
-        Status::invalid_argument(format!("Session ID should be a UUID v4: '{}'", value))
-    })
-}
-
 /// Out-of-band: poll the progress/state of a recording by session id.
 pub async fn recording_status(
     recordings: Recordings,
@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use tonic::{Code, Request, Response, Status};
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::shortest_path::router::shortest_path;
+
+use super::catalog::{self, Catalogs};
+use super::storage::SessionStore;
+use super::{blocking, ensure_session, parse_session_uuid};
+
+/// Route between two cells of the session grid, as the core router sees it.
+pub struct Route {
+    /// Cells from the origin to the destination (both included).
+    pub cells: Vec<CellID>,
+    /// Geometric length: sum of distances between consecutive cells.
+    pub length: f64,
+    /// Free-flow travel time in simulation steps: sum of 1 / speed_limit over the
+    /// traversed cells (the origin excluded).
+    pub free_flow_ticks: f64,
+}
+
+#[derive(Debug)]
+pub enum RouteError {
+    /// The given cell is not a part of the grid.
+    CellNotFound(CellID),
+    /// The destination can not be reached from the origin.
+    NoPath { from: CellID, to: CellID },
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteError::CellNotFound(id) => write!(f, "Cell not found: {}", id),
+            RouteError::NoPath { from, to } => {
+                write!(f, "No path from cell {} to cell {}", from, to)
+            }
+        }
+    }
+}
+
+/// Builds a road network for routing from the cells mirrored in the session catalog.
+pub fn build_network(cells: &HashMap<CellID, Cell>) -> GridRoads {
+    let mut net = GridRoads::new();
+    for cell in cells.values() {
+        net.add_cell(cell.clone());
+    }
+    net
+}
+
+/// Finds the shortest route `from` -> `transits...` -> `to` with lane changes allowed.
+pub fn find_route(
+    net: &GridRoads,
+    from: CellID,
+    to: CellID,
+    transits: &[CellID],
+) -> Result<Route, RouteError> {
+    let mut waypoints = Vec::with_capacity(transits.len() + 2);
+    waypoints.push(from);
+    waypoints.extend_from_slice(transits);
+    waypoints.push(to);
+
+    let mut route = Route {
+        cells: vec![from],
+        length: 0.0,
+        free_flow_ticks: 0.0,
+    };
+    for leg in waypoints.windows(2) {
+        let start = net
+            .get_cell(&leg[0])
+            .ok_or(RouteError::CellNotFound(leg[0]))?;
+        let goal = net
+            .get_cell(&leg[1])
+            .ok_or(RouteError::CellNotFound(leg[1]))?;
+        if start.get_id() == goal.get_id() {
+            continue;
+        }
+        let path = shortest_path(start, goal, net, true, None).map_err(|_| RouteError::NoPath {
+            from: leg[0],
+            to: leg[1],
+        })?;
+        let vertices = path.vertices();
+        if vertices.last().map(|c| c.get_id()) != Some(goal.get_id()) {
+            return Err(RouteError::NoPath {
+                from: leg[0],
+                to: leg[1],
+            });
+        }
+        for pair in vertices.windows(2) {
+            route.length += pair[0].distance_to(pair[1]);
+            route.free_flow_ticks += 1.0 / pair[1].get_speed_limit().max(1) as f64;
+            route.cells.push(pair[1].get_id());
+        }
+    }
+    Ok(route)
+}
+
+/// Meso links traversed by a route, in order: consecutive repeats collapsed, cells
+/// without a meso link skipped.
+pub fn route_meso_links(cells: &[CellID], meso_links: &HashMap<CellID, i64>) -> Vec<i64> {
+    let mut links: Vec<i64> = Vec::new();
+    for link in cells.iter().filter_map(|c| meso_links.get(c)) {
+        if links.last() != Some(link) {
+            links.push(*link);
+        }
+    }
+    links
+}
+
+/// Computes the shortest route between two cells of the session grid.
+pub async fn compute_route(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    request: Request<pb::ComputeRouteRequest>,
+) -> Result<Response<pb::ComputeRouteResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+    ensure_session(&sessions, &session_uuid).await?;
+
+    // Routed on the blocking pool, outside the catalog lock
+    let (route, meso_links) = blocking(move || {
+        let net = catalog::with_catalog_mut(&catalogs, &session_uuid, |c| c.network());
+        let route =
+            find_route(&net, req.from_cell, req.to_cell, &req.transits).map_err(|e| match e {
+                RouteError::CellNotFound(id) => {
+                    Status::not_found(format!("Not found cell ID: '{}'", id))
+                }
+                RouteError::NoPath { .. } => Status::failed_precondition(e.to_string()),
+            })?;
+        let meso_links = catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+            route_meso_links(&route.cells, &c.meso_links)
+        });
+        Ok::<_, Status>((route, meso_links))
+    })
+    .await??;
+
+    Ok(Response::new(pb::ComputeRouteResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        length_cells: (route.cells.len() - 1) as u32,
+        cells: route.cells,
+        length: route.length,
+        free_flow_time: route.free_flow_ticks,
+        meso_links,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::{by_id, road_cell};
+
+    fn cell(id: CellID, x: f64, forward: CellID, speed_limit: i32) -> Cell {
+        road_cell(id, x, forward)
+            .with_speed_limit(speed_limit)
+            .build()
+    }
+
+    #[test]
+    fn route_along_road() {
+        let cells = by_id(vec![
+            cell(1, 0.0, 2, 1),
+            cell(2, 1.0, 3, 2),
+            cell(3, 2.0, 4, 2),
+            cell(4, 3.0, -1, 1),
+            cell(9, 9.0, -1, 1),
+        ]);
+        let net = build_network(&cells);
+
+        let route = find_route(&net, 1, 4, &[3]).unwrap();
+        assert_eq!(route.cells, vec![1, 2, 3, 4]);
+        assert!((route.length - 3.0).abs() < 1e-9);
+        // 1/2 + 1/2 + 1/1
+        assert!((route.free_flow_ticks - 2.0).abs() < 1e-9);
+
+        assert!(matches!(
+            find_route(&net, 1, 9, &[]),
+            Err(RouteError::NoPath { from: 1, to: 9 })
+        ));
+        assert!(matches!(
+            find_route(&net, 1, 42, &[]),
+            Err(RouteError::CellNotFound(42))
+        ));
+
+        let meso_links: HashMap<CellID, i64> = HashMap::from([(1, 5), (2, 5), (3, 6), (4, 5)]);
+        assert_eq!(route_meso_links(&route.cells, &meso_links), vec![5, 6, 5]);
+    }
+}
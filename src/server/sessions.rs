@@ -1,19 +1,27 @@
-use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::sync::Arc;
 use tonic::{Code, Request, Response, Status};
 use uuid::Uuid;
 
 use micro_traffic_sim::pb;
 use micro_traffic_sim_core::geom::SRID;
 use micro_traffic_sim_core::simulation::session::Session;
-use micro_traffic_sim_core::simulation::sessions_storage::SessionsStorage;
 use micro_traffic_sim_core::verbose::VerboseLevel;
 
+use super::auth;
+use super::blocking;
+use super::catalog::{self, Catalogs};
+use super::quotas;
+use super::recordings::Recordings;
+use super::storage::SessionStore;
+use super::telemetry;
+
 pub async fn new_session(
-    sessions: Arc<Mutex<SessionsStorage>>,
+    sessions: Arc<SessionStore>,
+    recordings: Recordings,
     session_verbose: VerboseLevel,
     request: Request<pb::SessionReq>,
 ) -> Result<Response<pb::NewSessionResponse>, Status> {
+    let caller = auth::Caller::of(&request);
     let srid = match request.into_inner().srid {
         4326 => Some(SRID::WGS84),
         0 => Some(SRID::Euclidean),
@@ -24,12 +32,15 @@ pub async fn new_session(
     session.set_verbose_level(session_verbose);
     let sid = session.get_id();
 
-    let ttl = Some(Duration::from_secs(4 * 60));
-    let mut guard = sessions
-        .lock()
-        .map_err(|_| Status::internal("storage poisoned"))?;
-    let _ = guard.register_session(sid, session, ttl);
-    drop(guard);
+    sessions.register_admitted(sid, session, |stored| {
+        quotas::check_new_session(stored, &recordings, caller.as_ref())
+    })?;
+    // Tag the session with its owner tenant (authentication enabled)
+    telemetry::record_session(&sid);
+    if let Some(caller) = caller {
+        telemetry::record_tenant(caller.tenant());
+        caller.claim(sid);
+    }
 
     let resp = pb::NewSessionResponse {
         code: Code::Ok as u32,
@@ -42,18 +53,25 @@ pub async fn new_session(
 }
 
 pub async fn info_session(
-    sessions: Arc<Mutex<SessionsStorage>>,
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
     request: Request<pb::UuiDv4>,
 ) -> Result<Response<pb::InfoSessionResponse>, Status> {
     let id = request.into_inner().value;
     let sid = Uuid::parse_str(&id).map_err(|_| Status::invalid_argument("invalid UUID"))?;
 
-    let mut guard = sessions
-        .lock()
-        .map_err(|_| Status::internal("storage poisoned"))?;
     // with_session_mut extends TTL; we just check presence
-    let found = guard.with_session_mut(&sid, |sess| sess.get_id()).is_some();
-    drop(guard);
+    let found = blocking(move || {
+        let found = sessions
+            .with_session_mut(&sid, |sess| sess.get_id())
+            .is_some();
+        if found {
+            // Keep the session catalog alive together with the session
+            catalog::with_catalog_mut(&catalogs, &sid, |_| ());
+        }
+        found
+    })
+    .await?;
 
     if !found {
         let resp = pb::InfoSessionResponse {
@@ -63,7 +81,6 @@ pub async fn info_session(
         };
         return Ok(Response::new(resp));
     }
-
     let resp = pb::InfoSessionResponse {
         code: Code::Ok as u32,
         text: Code::Ok.to_string(),
@@ -0,0 +1,73 @@
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time::{Instant, sleep, sleep_until};
+
+use super::recordings::Recordings;
+
+/// Resolves on the first SIGINT (Ctrl-C) or SIGTERM (what orchestrators send).
+pub async fn signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "SIGTERM handler not installed");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+    let signal = tokio::select! {
+        _ = ctrl_c => "SIGINT",
+        _ = terminate => "SIGTERM",
+    };
+    tracing::info!(signal, "Shutting down gRPC server");
+}
+
+/// Resolves once the drain deadline, set when the shutdown starts, has passed. Never
+/// resolves when the server stops without a shutdown signal.
+pub async fn drain_deadline(mut deadline: watch::Receiver<Option<Instant>>) {
+    let deadline = match deadline.wait_for(Option::is_some).await {
+        Ok(deadline) => *deadline,
+        Err(_) => None,
+    };
+    match deadline {
+        Some(deadline) => sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Asks every running recording to stop, as StopRecording does: each one finishes
+/// its current tick, flushes its last batch and sends its RunSummary.
+pub fn cancel_recordings(recordings: &Recordings) -> usize {
+    match recordings.lock() {
+        Ok(reg) => {
+            for handle in reg.values() {
+                handle.cancel.store(true, Ordering::Relaxed);
+            }
+            reg.len()
+        }
+        Err(_) => 0,
+    }
+}
+
+/// Waits for the recording threads to deregister, until `deadline` at the latest.
+/// Returns the number of recordings still running.
+pub async fn wait_recordings(recordings: &Recordings, deadline: Instant) -> usize {
+    loop {
+        let running = recordings.lock().map_or(0, |reg| reg.len());
+        if running == 0 || Instant::now() >= deadline {
+            return running;
+        }
+        sleep(Duration::from_millis(50)).await;
+    }
+}
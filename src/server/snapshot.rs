@@ -0,0 +1,299 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use prost::Message;
+use uuid::Uuid;
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::geom::SRID;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+use super::auth::{self, Owners};
+use super::catalog::{self, Catalogs, SessionCatalog};
+use super::conflict_zones::core_conflict_zone;
+use super::grid::{core_cell, proto_cell};
+use super::storage::SessionStore;
+use super::tls::{core_traffic_light, parse_signals};
+use super::trip::{core_trip, dormant};
+
+/// Layout version of the snapshot files (`SessionSnapshot.format_version`).
+const FORMAT_VERSION: u32 = 1;
+
+/// Extension of the snapshot files: one `<session id>.pb` file per session.
+const EXTENSION: &str = "pb";
+
+/// Saves the definition of every live session to `dir`, one file per session, and
+/// returns the number of saved sessions. Sessions consumed by a recording are not
+/// in the store any more and are not saved.
+pub fn save_all(
+    dir: &Path,
+    sessions: &Arc<SessionStore>,
+    catalogs: &Catalogs,
+    owners: &Owners,
+) -> std::io::Result<usize> {
+    std::fs::create_dir_all(dir)?;
+    let snapshots: Vec<pb::SessionSnapshot> = sessions
+        .ids()
+        .into_iter()
+        .filter_map(|id| {
+            // Lock order: session first, then its catalog
+            sessions.with_session_mut(&id, |s| {
+                let tenant = auth::owner_of(owners, &id);
+                catalog::with_catalog_mut(catalogs, &id, |c| {
+                    snapshot_of(id, s.get_world_srid(), c, tenant)
+                })
+            })
+        })
+        .collect();
+    for snapshot in &snapshots {
+        let id = snapshot
+            .session_id
+            .as_ref()
+            .map_or("", |u| u.value.as_str());
+        let path = dir.join(format!("{}.{}", id, EXTENSION));
+        // Written aside first: a crash mid-write never leaves a truncated snapshot
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, snapshot.encode_to_vec())?;
+        std::fs::rename(&tmp, &path)?;
+    }
+    Ok(snapshots.len())
+}
+
+/// Restores the sessions saved to `dir` by [`save_all`] under their former ids, and
+/// removes their files. Files which can not be restored are left in place and logged.
+/// Returns the number of restored sessions.
+pub fn restore_all(
+    dir: &Path,
+    sessions: &Arc<SessionStore>,
+    catalogs: &Catalogs,
+    owners: &Owners,
+    verbose: VerboseLevel,
+) -> std::io::Result<usize> {
+    let mut paths: Vec<PathBuf> = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == EXTENSION))
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+    paths.sort();
+    let mut restored = 0;
+    for path in paths {
+        let result = std::fs::read(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| {
+                pb::SessionSnapshot::decode(bytes.as_slice()).map_err(|e| e.to_string())
+            })
+            .and_then(|snapshot| restore(&snapshot, verbose).map(|r| (r, snapshot.tenant)));
+        let ((id, session, session_catalog), tenant) = match result {
+            Ok(r) => r,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Session snapshot not restored");
+                continue;
+            }
+        };
+        if !sessions.register_session(id, session) {
+            tracing::warn!(path = %path.display(), session_id = %id, "Session snapshot not restored: session exists");
+            continue;
+        }
+        catalog::restore_catalog(catalogs, id, session_catalog);
+        if !tenant.is_empty() {
+            auth::restore_owner(owners, id, tenant);
+        }
+        std::fs::remove_file(&path)?;
+        restored += 1;
+    }
+    Ok(restored)
+}
+
+/// Definition of a session, as mirrored by its catalog.
+fn snapshot_of(
+    id: Uuid,
+    srid: SRID,
+    c: &SessionCatalog,
+    tenant: Option<String>,
+) -> pb::SessionSnapshot {
+    let mut cells: Vec<pb::Cell> = c
+        .cells
+        .values()
+        .map(|cell| {
+            proto_cell(
+                cell,
+                c.meso_links.get(&cell.get_id()).copied().unwrap_or(-1),
+            )
+        })
+        .collect();
+    cells.sort_by_key(|cell| cell.id);
+    let mut trips: Vec<pb::Trip> = c.trips.values().cloned().collect();
+    trips.sort_by_key(|trip| trip.id);
+    let mut paused_trips: Vec<i64> = c.paused_trips.iter().copied().collect();
+    paused_trips.sort_unstable();
+    let mut traffic_lights: Vec<pb::TrafficLight> = c.traffic_lights.values().cloned().collect();
+    traffic_lights.sort_by_key(|tl| tl.id);
+    let mut conflict_zones: Vec<pb::ConflictZone> = c.conflict_zones.values().cloned().collect();
+    conflict_zones.sort_by_key(|zone| zone.id);
+    pb::SessionSnapshot {
+        format_version: FORMAT_VERSION,
+        session_id: Some(pb::UuiDv4 {
+            value: id.to_string(),
+        }),
+        srid: srid as i32,
+        tenant: tenant.unwrap_or_default(),
+        cells,
+        trips,
+        paused_trips,
+        traffic_lights,
+        conflict_zones,
+    }
+}
+
+/// Rebuilds a session and its catalog from a snapshot: the grid, the trips (paused
+/// ones stay paused), the traffic lights and the conflict zones. The session starts
+/// over from step 0, without vehicles.
+fn restore(
+    snapshot: &pb::SessionSnapshot,
+    verbose: VerboseLevel,
+) -> Result<(Uuid, Session, SessionCatalog), String> {
+    if snapshot.format_version != FORMAT_VERSION {
+        return Err(format!(
+            "unsupported format version {}",
+            snapshot.format_version
+        ));
+    }
+    let id = snapshot
+        .session_id
+        .as_ref()
+        .and_then(|u| Uuid::parse_str(&u.value).ok())
+        .ok_or_else(|| "invalid session ID".to_string())?;
+    let srid = match snapshot.srid {
+        4326 => SRID::WGS84,
+        0 => SRID::Euclidean,
+        other => return Err(format!("unsupported SRID {}", other)),
+    };
+    let mut session = Session::default(Some(srid));
+    session.set_verbose_level(verbose);
+    let mut c = SessionCatalog::new();
+
+    let cells: Vec<_> = snapshot.cells.iter().map(|d| core_cell(d, srid)).collect();
+    for (cell, cell_data) in cells.iter().zip(&snapshot.cells) {
+        c.insert_cell(cell.clone(), cell_data.meso_link_id);
+    }
+    session.add_cells(cells);
+
+    c.paused_trips = snapshot.paused_trips.iter().copied().collect();
+    for trip_data in &snapshot.trips {
+        let trip = core_trip(trip_data);
+        if c.paused_trips.contains(&trip_data.id) {
+            session.add_trip(dormant(trip));
+        } else {
+            session.add_trip(trip);
+        }
+        c.trips.insert(trip_data.id, trip_data.clone());
+    }
+
+    for tl_data in &snapshot.traffic_lights {
+        let signals = parse_signals(tl_data).map_err(|s| s.message().to_string())?;
+        session.add_traffic_light(core_traffic_light(tl_data, &signals, srid));
+        c.traffic_lights.insert(tl_data.id, tl_data.clone());
+    }
+
+    for zone in &snapshot.conflict_zones {
+        session.add_conflict_zone(core_conflict_zone(zone));
+        c.conflict_zones.insert(zone.id, *zone);
+    }
+    Ok((id, session, c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(id: i64, forward: i64, meso_link_id: i64) -> pb::Cell {
+        pb::Cell {
+            id,
+            geom: Some(pb::Point {
+                x: id as f64,
+                y: 0.5,
+            }),
+            zone_type: pb::ZoneType::Common as i32,
+            speed_limit: 2,
+            left_node: -1,
+            forward_node: forward,
+            right_node: -1,
+            meso_link_id,
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let id = Uuid::new_v4();
+        let mut c = SessionCatalog::new();
+        for data in [cell(1, 2, 10), cell(2, 3, 10), cell(3, -1, -1)] {
+            c.insert_cell(core_cell(&data, SRID::Euclidean), data.meso_link_id);
+        }
+        for trip_id in [7, 8] {
+            let trip = pb::Trip {
+                id: trip_id,
+                trip_type: pb::TripType::Random as i32,
+                from_node: 1,
+                to_node: 3,
+                probability: 0.5,
+                ..Default::default()
+            };
+            c.trips.insert(trip_id, trip);
+        }
+        c.paused_trips.insert(8);
+        let tl = pb::TrafficLight {
+            id: 4,
+            groups: vec![pb::Group {
+                id: 1,
+                cells: vec![2],
+                signals: vec!["g".to_string(), "r".to_string()],
+                ..Default::default()
+            }],
+            times: vec![10, 10],
+            ..Default::default()
+        };
+        c.traffic_lights.insert(tl.id, tl);
+        let zone = pb::ConflictZone {
+            id: 5,
+            source_x: 1,
+            target_x: 2,
+            source_y: 3,
+            target_y: 2,
+            ..Default::default()
+        };
+        c.conflict_zones.insert(zone.id, zone);
+
+        let snapshot = snapshot_of(id, SRID::Euclidean, &c, Some("team-a".to_string()));
+        assert_eq!(
+            snapshot.cells,
+            vec![cell(1, 2, 10), cell(2, 3, 10), cell(3, -1, -1)]
+        );
+        assert_eq!(snapshot.paused_trips, vec![8]);
+        let bytes = snapshot.encode_to_vec();
+        let decoded = pb::SessionSnapshot::decode(bytes.as_slice()).unwrap();
+
+        let (restored_id, session, restored) = restore(&decoded, VerboseLevel::None).unwrap();
+        assert_eq!(restored_id, id);
+        assert!(session.get_cell(&2).is_some());
+        assert_eq!(session.get_tls_ref().len(), 1);
+        assert_eq!(restored.meso_links, c.meso_links);
+        assert_eq!(restored.upstream_of(2), &[1]);
+        assert_eq!(restored.trips, c.trips);
+        assert_eq!(restored.paused_trips, c.paused_trips);
+        assert_eq!(restored.traffic_lights, c.traffic_lights);
+        assert_eq!(restored.conflict_zones, c.conflict_zones);
+        // Everything else starts over
+        assert_eq!(session.get_steps(), 0);
+
+        let mut bad = decoded.clone();
+        bad.format_version = FORMAT_VERSION + 1;
+        assert!(restore(&bad, VerboseLevel::None).is_err());
+        bad.format_version = FORMAT_VERSION;
+        bad.srid = 3857;
+        assert!(restore(&bad, VerboseLevel::None).is_err());
+    }
+}
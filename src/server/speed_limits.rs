@@ -0,0 +1,245 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use tonic::{Code, Request, Response, Status};
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::grid::cell::{Cell, CellID};
+use micro_traffic_sim_core::simulation::session::Session;
+
+use super::catalog::{self, Catalogs};
+use super::incidents::{self, Incidents};
+use super::recordings::Recordings;
+use super::storage::SessionStore;
+use super::{blocking, check_entities_count, parse_session_uuid};
+
+/// Target of a speed limit change.
+#[derive(Clone, Debug)]
+pub enum SpeedLimitTarget {
+    Cells(Vec<CellID>),
+    MesoLink(i64),
+}
+
+/// Runtime speed limit change, as requested by SetSpeedLimit.
+#[derive(Clone, Debug)]
+pub struct SpeedLimitChange {
+    pub target: SpeedLimitTarget,
+    pub speed_limit: i32,
+}
+
+#[derive(Debug)]
+pub enum SpeedLimitError {
+    CellNotFound(CellID),
+    MesoLinkNotFound(i64),
+}
+
+impl SpeedLimitChange {
+    /// Checks the targets of the change against the cell and meso link ids of a grid.
+    pub fn check(
+        &self,
+        cells: &HashSet<CellID>,
+        meso_link_ids: &HashSet<i64>,
+    ) -> Result<(), SpeedLimitError> {
+        match &self.target {
+            SpeedLimitTarget::Cells(ids) => match ids.iter().find(|id| !cells.contains(id)) {
+                Some(unknown) => Err(SpeedLimitError::CellNotFound(*unknown)),
+                None => Ok(()),
+            },
+            SpeedLimitTarget::MesoLink(link) if !meso_link_ids.contains(link) => {
+                Err(SpeedLimitError::MesoLinkNotFound(*link))
+            }
+            SpeedLimitTarget::MesoLink(_) => Ok(()),
+        }
+    }
+
+    /// Cells affected by the change, sorted.
+    fn resolve(
+        &self,
+        cells: &HashMap<CellID, Cell>,
+        meso_links: &HashMap<CellID, i64>,
+    ) -> Result<Vec<CellID>, SpeedLimitError> {
+        let mut resolved: Vec<CellID> = match &self.target {
+            SpeedLimitTarget::Cells(ids) => {
+                if let Some(unknown) = ids.iter().find(|id| !cells.contains_key(id)) {
+                    return Err(SpeedLimitError::CellNotFound(*unknown));
+                }
+                ids.clone()
+            }
+            SpeedLimitTarget::MesoLink(link) => {
+                let ids: Vec<CellID> = meso_links
+                    .iter()
+                    .filter(|(_, l)| *l == link)
+                    .map(|(id, _)| *id)
+                    .collect();
+                if ids.is_empty() {
+                    return Err(SpeedLimitError::MesoLinkNotFound(*link));
+                }
+                ids
+            }
+        };
+        resolved.sort_unstable();
+        resolved.dedup();
+        Ok(resolved)
+    }
+
+    /// Replaces the speed limit of the mirrored cells and pushes them to the session
+    /// (with the overrides of the incidents in effect). Returns the updated cells.
+    pub fn apply(
+        &self,
+        session: &mut Session,
+        cells: &mut HashMap<CellID, Cell>,
+        meso_links: &HashMap<CellID, i64>,
+        incidents: &Incidents,
+    ) -> Result<Vec<CellID>, SpeedLimitError> {
+        let ids = self.resolve(cells, meso_links)?;
+        let mut updated = Vec::with_capacity(ids.len());
+        for id in &ids {
+            let meso_link_id = meso_links.get(id).copied().unwrap_or(-1);
+            let base = incidents::cell_with_speed_limit(&cells[id], meso_link_id, self.speed_limit);
+            updated.push(incidents.effective_cell(&base, meso_link_id));
+            cells.insert(*id, base);
+        }
+        session.add_cells(updated);
+        Ok(ids)
+    }
+}
+
+fn to_status(err: SpeedLimitError) -> Status {
+    match err {
+        SpeedLimitError::CellNotFound(id) => {
+            Status::not_found(format!("Not found cell ID: '{}'", id))
+        }
+        SpeedLimitError::MesoLinkNotFound(id) => {
+            Status::not_found(format!("Not found meso link ID: '{}'", id))
+        }
+    }
+}
+
+/// Changes the speed limit of cells (or of a meso link) of an interactive session right
+/// away, or queues the change for the next tick of a running recording.
+pub async fn set_speed_limit(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::SetSpeedLimitRequest>,
+) -> Result<Response<pb::SetSpeedLimitResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+
+    if req.speed_limit <= 0 {
+        return Err(Status::invalid_argument(format!(
+            "Speed limit should be positive, but provided is {}",
+            req.speed_limit
+        )));
+    }
+    check_entities_count(req.cells.len())?;
+    let change = SpeedLimitChange {
+        target: if req.cells.is_empty() {
+            SpeedLimitTarget::MesoLink(req.meso_link_id)
+        } else {
+            SpeedLimitTarget::Cells(req.cells)
+        },
+        speed_limit: req.speed_limit,
+    };
+
+    if let Some(handle) = incidents::running_recording(&recordings, &session_uuid)? {
+        change
+            .check(&handle.cells, &handle.meso_link_ids)
+            .map_err(to_status)?;
+        // Applied (and reported) by the recording thread before its next step
+        handle
+            .speed_limits
+            .lock()
+            .map_err(|_| Status::internal("recording speed limits lock poisoned"))?
+            .push(change);
+    } else {
+        let result = blocking(move || {
+            sessions.with_session_mut(&session_uuid, |session| {
+                catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+                    let applied = change.apply(session, &mut c.cells, &c.meso_links, &c.incidents);
+                    c.invalidate_network();
+                    applied
+                })
+            })
+        })
+        .await?;
+        match result {
+            None => {
+                return Err(Status::not_found(format!(
+                    "Not found session ID: '{}'",
+                    session_uuid
+                )));
+            }
+            Some(Err(e)) => return Err(to_status(e)),
+            Some(Ok(_)) => {}
+        }
+    }
+
+    Ok(Response::new(pb::SetSpeedLimitResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::{by_id, road_cell};
+    use micro_traffic_sim_core::simulation::session::Session;
+
+    #[test]
+    fn change_by_meso_link() {
+        let mut cells = by_id(
+            (1..=4)
+                .map(|id| {
+                    road_cell(id, id as f64, if id == 4 { -1 } else { id + 1 })
+                        .with_speed_limit(3)
+                        .build()
+                })
+                .collect(),
+        );
+        let meso_links: HashMap<CellID, i64> = HashMap::from([(1, 7), (2, 7), (3, 8), (4, 8)]);
+        let mut session = Session::default(None);
+        session.add_cells(cells.values().cloned().collect());
+
+        let change = SpeedLimitChange {
+            target: SpeedLimitTarget::MesoLink(8),
+            speed_limit: 1,
+        };
+        let updated = change
+            .apply(&mut session, &mut cells, &meso_links, &Incidents::default())
+            .unwrap();
+        assert_eq!(updated, vec![3, 4]);
+        let limits: Vec<i32> = (1..=4)
+            .map(|id| session.get_cell(&id).unwrap().get_speed_limit())
+            .collect();
+        assert_eq!(limits, vec![3, 3, 1, 1]);
+        assert_eq!(cells[&4].get_speed_limit(), 1, "mirror follows the change");
+
+        let missing = SpeedLimitChange {
+            target: SpeedLimitTarget::MesoLink(9),
+            speed_limit: 1,
+        };
+        assert!(matches!(
+            missing.apply(&mut session, &mut cells, &meso_links, &Incidents::default()),
+            Err(SpeedLimitError::MesoLinkNotFound(9))
+        ));
+
+        // Recordings check the change up front against the ids of the fixed grid
+        let cell_ids: HashSet<CellID> = cells.keys().copied().collect();
+        let link_ids: HashSet<i64> = meso_links.values().copied().collect();
+        assert!(change.check(&cell_ids, &link_ids).is_ok());
+        assert!(matches!(
+            missing.check(&cell_ids, &link_ids),
+            Err(SpeedLimitError::MesoLinkNotFound(9))
+        ));
+        let unknown_cell = SpeedLimitChange {
+            target: SpeedLimitTarget::Cells(vec![2, 5]),
+            speed_limit: 1,
+        };
+        assert!(matches!(
+            unknown_cell.check(&cell_ids, &link_ids),
+            Err(SpeedLimitError::CellNotFound(5))
+        ));
+    }
+}
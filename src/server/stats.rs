@@ -0,0 +1,338 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use tonic::{Code, Request, Response, Status};
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::simulation::states::AutomataState;
+
+use super::catalog::{self, Catalogs, SessionCatalog};
+use super::events::PreStep;
+use super::routing;
+use super::storage::SessionStore;
+use super::{blocking, ensure_session, parse_session_uuid};
+
+/// Per-trip counters accumulated from the step events.
+#[derive(Clone, Default)]
+pub struct TripAcc {
+    pub generated: u64,
+    pub arrived: u64,
+    pub lost: u64,
+    /// Travel times (simulation steps) of arrived vehicles.
+    pub travel_times: TravelTimes,
+}
+
+/// Travel times below this are counted exactly.
+const EXACT_TRAVEL_TIME: i64 = 1024;
+/// Buckets per power of two above [`EXACT_TRAVEL_TIME`] (relative width < 1/64).
+const BUCKETS_PER_OCTAVE_LOG2: u32 = 6;
+
+/// Bounded histogram of travel times: exact below [`EXACT_TRAVEL_TIME`], log-linear
+/// buckets above it, so a trip keeps a few thousand counters at most however many
+/// vehicles arrive. The mean is exact.
+#[derive(Clone, Default)]
+pub struct TravelTimes {
+    /// Vehicles per bucket, keyed by the lower bound of the bucket.
+    buckets: BTreeMap<i64, u64>,
+    count: u64,
+    sum: i64,
+}
+
+impl TravelTimes {
+    /// Width of the bucket holding `value` (1 in the exact range).
+    fn bucket_width(value: i64) -> i64 {
+        if value < EXACT_TRAVEL_TIME {
+            return 1;
+        }
+        let octave = 63 - value.leading_zeros();
+        1 << (octave - BUCKETS_PER_OCTAVE_LOG2)
+    }
+
+    pub fn push(&mut self, travel_time: i64) {
+        let value = travel_time.max(0);
+        let width = Self::bucket_width(value);
+        *self.buckets.entry(value / width * width).or_default() += 1;
+        self.count += 1;
+        self.sum += value;
+    }
+
+    /// Mean travel time (0 when empty).
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.sum as f64 / self.count as f64
+    }
+
+    /// Nearest-rank percentile (0 when empty). Above [`EXACT_TRAVEL_TIME`] the upper
+    /// bound of the bucket holding the ranked value is returned.
+    pub fn percentile(&self, p: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        let rank = ((p / 100.0) * self.count as f64).ceil() as u64;
+        let rank = rank.clamp(1, self.count);
+        let mut seen = 0;
+        for (&lower, &n) in &self.buckets {
+            seen += n;
+            if seen >= rank {
+                return (lower + Self::bucket_width(lower) - 1) as f64;
+            }
+        }
+        0.0
+    }
+}
+
+/// Aggregates the KPIs of one step from its state dump and the events derived for it
+/// (see `events::collect`), so spawn/arrival/loss counts match the events exactly.
+pub fn tick_stats(dump: &AutomataState, events: &[pb::VehicleEvent]) -> pb::TickStats {
+    let count = |kind: pb::VehicleEventType| {
+        events
+            .iter()
+            .filter(|e| e.event_type == kind as i32)
+            .count() as u32
+    };
+
+    let mut speed_sum: i64 = 0;
+    let mut stopped_vehicles: u32 = 0;
+    let mut queue_length: u32 = 0;
+    for v in &dump.vehicles {
+        speed_sum += v.last_speed as i64;
+        if v.last_speed == 0 {
+            stopped_vehicles += 1;
+            queue_length += 1 + v.tail_cells.len() as u32;
+        }
+    }
+    let active_vehicles = dump.vehicles.len() as u32;
+    let mean_speed = if active_vehicles > 0 {
+        speed_sum as f64 / active_vehicles as f64
+    } else {
+        0.0
+    };
+
+    pb::TickStats {
+        active_vehicles,
+        spawned: count(pb::VehicleEventType::Spawned),
+        arrived: count(pb::VehicleEventType::Arrived),
+        lost: count(pb::VehicleEventType::Lost),
+        mean_speed,
+        stopped_vehicles,
+        queue_length,
+        vehicles_completed: dump.vehicles_completed,
+        vehicles_lost: dump.vehicles_lost,
+    }
+}
+
+/// Accumulates the per-trip counters from the events of one step. Vehicles spawned
+/// and removed within the same step carry no trip id and are not attributed.
+pub fn accumulate_trips(catalog: &mut SessionCatalog, pre: &PreStep, events: &[pb::VehicleEvent]) {
+    for e in events.iter().filter(|e| e.trip_id >= 0) {
+        let acc = catalog.trip_stats.entry(e.trip_id).or_default();
+        match pb::VehicleEventType::try_from(e.event_type) {
+            Ok(pb::VehicleEventType::Spawned) => acc.generated += 1,
+            Ok(pb::VehicleEventType::Arrived) => {
+                acc.arrived += 1;
+                if let Some(t) = pre.final_travel_time(e.vehicle_id as u64) {
+                    acc.travel_times.push(t);
+                }
+            }
+            Ok(pb::VehicleEventType::Lost) => acc.lost += 1,
+            _ => {}
+        }
+    }
+}
+
+/// Builds the statistics of a single trip. `free_flow` is the free-flow travel time
+/// of the trip's route (0 when the route is unknown).
+fn trip_stats(trip_id: i64, acc: Option<&TripAcc>, free_flow: f64) -> pb::TripStats {
+    let empty = TripAcc::default();
+    let acc = acc.unwrap_or(&empty);
+    let mean_travel_time = acc.travel_times.mean();
+    let delay_index = if mean_travel_time > 0.0 && free_flow > 0.0 {
+        mean_travel_time / free_flow
+    } else {
+        0.0
+    };
+    pb::TripStats {
+        trip_id,
+        generated: acc.generated,
+        arrived: acc.arrived,
+        lost: acc.lost,
+        mean_travel_time,
+        p50_travel_time: acc.travel_times.percentile(50.0),
+        p85_travel_time: acc.travel_times.percentile(85.0),
+        p95_travel_time: acc.travel_times.percentile(95.0),
+        free_flow_travel_time: free_flow,
+        delay_index,
+    }
+}
+
+/// Returns per-trip counters, travel time percentiles and the delay index against the
+/// free-flow travel time along the trip's shortest route.
+pub async fn get_trip_stats(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    request: Request<pb::TripStatsRequest>,
+) -> Result<Response<pb::TripStatsResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+    ensure_session(&sessions, &session_uuid).await?;
+
+    // Only the snapshot is taken under the catalog lock; routing runs outside it.
+    let data = blocking(move || {
+        let (net, wanted) = catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+            let trip_ids: BTreeSet<i64> = if req.trip_ids.is_empty() {
+                c.trips.keys().chain(c.trip_stats.keys()).copied().collect()
+            } else {
+                req.trip_ids.iter().copied().collect()
+            };
+            if let Some(unknown) = trip_ids
+                .iter()
+                .find(|id| !c.trips.contains_key(id) && !c.trip_stats.contains_key(id))
+            {
+                return Err(Status::not_found(format!(
+                    "Not found trip ID: '{}'",
+                    unknown
+                )));
+            }
+            let wanted: Vec<_> = trip_ids
+                .into_iter()
+                .map(|trip_id| {
+                    let route = c
+                        .trips
+                        .get(&trip_id)
+                        .map(|t| (t.from_node, t.to_node, t.transits.clone()));
+                    (trip_id, route, c.trip_stats.get(&trip_id).cloned())
+                })
+                .collect();
+            Ok::<_, Status>((c.network(), wanted))
+        })?;
+        Ok::<_, Status>(
+            wanted
+                .into_iter()
+                .map(|(trip_id, route, acc)| {
+                    let free_flow = route
+                        .and_then(|(from, to, transits)| {
+                            routing::find_route(&net, from, to, &transits).ok()
+                        })
+                        .map(|r| r.free_flow_ticks)
+                        .unwrap_or(0.0);
+                    trip_stats(trip_id, acc.as_ref(), free_flow)
+                })
+                .collect(),
+        )
+    })
+    .await??;
+
+    Ok(Response::new(pb::TripStatsResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        data,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use micro_traffic_sim_core::agents_types::AgentType;
+    use micro_traffic_sim_core::simulation::states::VehicleState;
+    use std::collections::HashMap;
+
+    fn veh(id: u64, speed: i32, tail: Vec<i64>) -> VehicleState {
+        VehicleState {
+            occupied_points: vec![],
+            last_cell: id as i64,
+            tail_cells: tail,
+            last_intermediate_cells: vec![],
+            last_speed: speed,
+            last_angle: 0.0,
+            vehicle_type: AgentType::Car,
+            travel_time: 0,
+            id,
+            trip_id: 1,
+        }
+    }
+
+    #[test]
+    fn aggregates_dump_and_events() {
+        let dump = AutomataState {
+            timestamp: 10,
+            vehicles: vec![
+                veh(1, 2, vec![]),
+                veh(2, 0, vec![]),
+                veh(3, 0, vec![30, 31]),
+            ],
+            tls: HashMap::new(),
+            vehicles_completed: 4,
+            vehicles_lost: 1,
+        };
+        let ev = |id: i64, kind: pb::VehicleEventType| pb::VehicleEvent {
+            vehicle_id: id,
+            event_type: kind as i32,
+            trip_id: 1,
+            cell: -1,
+            from_cell: -1,
+        };
+        let events = vec![
+            ev(3, pb::VehicleEventType::Spawned),
+            ev(5, pb::VehicleEventType::Arrived),
+            ev(6, pb::VehicleEventType::Arrived),
+            ev(2, pb::VehicleEventType::RedLightStop),
+        ];
+
+        let s = tick_stats(&dump, &events);
+        assert_eq!(s.active_vehicles, 3);
+        assert_eq!(s.spawned, 1);
+        assert_eq!(s.arrived, 2);
+        assert_eq!(s.lost, 0);
+        assert!((s.mean_speed - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(s.stopped_vehicles, 2);
+        // vehicle 2 (head only) + vehicle 3 (head + 2 tail cells)
+        assert_eq!(s.queue_length, 4);
+        assert_eq!(s.vehicles_completed, 4);
+        assert_eq!(s.vehicles_lost, 1);
+    }
+
+    #[test]
+    fn trip_percentiles_and_delay() {
+        let mut acc = TripAcc {
+            generated: 12,
+            arrived: 10,
+            lost: 1,
+            ..Default::default()
+        };
+        for t in [10, 1, 9, 2, 8, 3, 7, 4, 6, 5] {
+            acc.travel_times.push(t);
+        }
+        let s = trip_stats(3, Some(&acc), 2.5);
+        assert_eq!(s.trip_id, 3);
+        assert_eq!((s.generated, s.arrived, s.lost), (12, 10, 1));
+        assert!((s.mean_travel_time - 5.5).abs() < 1e-9);
+        assert_eq!(s.p50_travel_time, 5.0);
+        assert_eq!(s.p85_travel_time, 9.0);
+        assert_eq!(s.p95_travel_time, 10.0);
+        assert!((s.delay_index - 2.2).abs() < 1e-9);
+
+        // No arrivals and no route: everything derived stays 0.
+        let s = trip_stats(4, None, 0.0);
+        assert_eq!(s.mean_travel_time, 0.0);
+        assert_eq!(s.p95_travel_time, 0.0);
+        assert_eq!(s.delay_index, 0.0);
+    }
+
+    #[test]
+    fn travel_times_are_bounded() {
+        let mut times = TravelTimes::default();
+        for t in 0..100_000 {
+            times.push(t);
+        }
+        // Exact range plus 64 buckets per power of two up to 2^17
+        assert!(times.buckets.len() <= 1024 + 64 * 7);
+        assert!((times.mean() - 49_999.5).abs() < 1e-9);
+        for (p, exact) in [(50.0, 49_999.0), (85.0, 84_999.0), (95.0, 94_999.0)] {
+            let approx = times.percentile(p);
+            assert!(approx >= exact && approx - exact <= exact / 64.0);
+        }
+        assert_eq!(TravelTimes::default().percentile(50.0), 0.0);
+    }
+}
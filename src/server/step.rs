@@ -1,18 +1,28 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+use std::time::Instant;
 
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Code, Request, Response, Status};
+use tracing::Instrument;
 use uuid::Uuid;
 
 use micro_traffic_sim::pb;
 use micro_traffic_sim_core::agents_types::AgentType;
-use micro_traffic_sim_core::simulation::sessions_storage::SessionsStorage;
 
 use super::BoxStream;
+use super::auth;
+use super::catalog::{self, Catalogs};
+use super::demand;
+use super::events;
+use super::metrics;
+use super::stats;
+use super::storage::SessionStore;
+use super::tl_stats;
+use super::vehicles;
 
 /// Converts core AgentType to proto AgentType (i32)
-fn core_agent_type_to_proto(agent_type: AgentType) -> i32 {
+pub(super) fn core_agent_type_to_proto(agent_type: AgentType) -> i32 {
     match agent_type {
         AgentType::Undefined => 0,
         AgentType::Car => 1,
@@ -25,120 +35,183 @@ fn core_agent_type_to_proto(agent_type: AgentType) -> i32 {
 }
 
 pub async fn simulation_step_session(
-    sessions: Arc<Mutex<SessionsStorage>>,
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
     request: Request<tonic::Streaming<pb::SessionStep>>,
 ) -> Result<Response<BoxStream<pb::SessionStepResponse>>, Status> {
+    let caller = auth::Caller::of(&request);
     let mut stream = request.into_inner();
     let (tx, rx) = mpsc::channel(16);
 
-    tokio::spawn(async move {
-        while let Ok(Some(req)) = stream.message().await {
-            // Validate session_id presence
-            let session_id = match &req.session_id {
-                Some(id) => &id.value,
-                None => {
-                    let _ = tx
-                        .send(Err(Status::invalid_argument(
-                            "No session ID has been provided",
-                        )))
-                        .await;
-                    return;
-                }
-            };
-
-            // Parse UUID
-            let session_uuid = match Uuid::parse_str(session_id) {
-                Ok(uuid) => uuid,
-                Err(_) => {
-                    let _ = tx
-                        .send(Err(Status::invalid_argument(format!(
-                            "Session ID should be of type UUID v4: '{}'",
-                            session_id
-                        ))))
-                        .await;
-                    return;
-                }
-            };
-
-            // Get session and run step (use block scope to ensure lock is dropped before await)
-            let step_result = {
-                let mut sessions_guard = sessions.lock().unwrap();
-                sessions_guard.with_session_mut(&session_uuid, |session| session.step())
-            };
-
-            let dump = match step_result {
-                None => {
-                    let _ = tx
-                        .send(Err(Status::not_found(format!(
-                            "Not found session ID: '{}'",
-                            session_id
-                        ))))
-                        .await;
-                    return;
-                }
-                Some(Err(e)) => {
-                    let _ = tx.send(Err(Status::aborted(e.to_string()))).await;
+    tokio::spawn(
+        async move {
+            while let Ok(Some(req)) = stream.message().await {
+                // Validate session_id presence
+                let session_id = match &req.session_id {
+                    Some(id) => &id.value,
+                    None => {
+                        let _ = tx
+                            .send(Err(Status::invalid_argument(
+                                "No session ID has been provided",
+                            )))
+                            .await;
+                        return;
+                    }
+                };
+
+                // Parse UUID
+                let session_uuid = match Uuid::parse_str(session_id) {
+                    Ok(uuid) => uuid,
+                    Err(_) => {
+                        let _ = tx
+                            .send(Err(Status::invalid_argument(format!(
+                                "Session ID should be of type UUID v4: '{}'",
+                                session_id
+                            ))))
+                            .await;
+                        return;
+                    }
+                };
+
+                // Reject sessions owned by other tenants
+                let authorized = auth::authorize_session(caller.as_ref(), &session_uuid);
+                if let Err(status) = authorized {
+                    let _ = tx.send(Err(status)).await;
                     return;
                 }
-                Some(Ok(state)) => state,
-            };
-
-            // Convert vehicle states
-            let vehicle_data: Vec<pb::VehicleState> = dump
-                .vehicles
-                .iter()
-                .map(|v| {
-                    let intermediate_cells: Vec<i64> = v.last_intermediate_cells.clone();
-                    let tail_cells: Vec<i64> = v.tail_cells.clone();
-
-                    pb::VehicleState {
-                        vehicle_id: v.id as i64,
-                        vehicle_type: core_agent_type_to_proto(v.vehicle_type),
-                        speed: v.last_speed as i64,
-                        bearing: v.last_angle,
-                        cell: v.last_cell,
-                        intermediate_cells,
-                        travel_time: v.travel_time,
-                        trip_id: v.trip_id,
-                        tail_cells,
-                    }
-                })
-                .collect();
-
-            // Convert TLS states
-            let tls_data: Vec<pb::TlsState> = dump
-                .tls
-                .iter()
-                .map(|(tl_id, groups)| {
-                    let groups_proto: Vec<pb::TlGroup> = groups
-                        .iter()
-                        .map(|g| pb::TlGroup {
-                            id: g.group_id,
-                            signal: g.last_signal.to_string(),
-                        })
-                        .collect();
 
-                    pb::TlsState {
-                        id: *tl_id,
-                        groups: groups_proto,
+                // Run the step on the blocking pool, OFF the async workers: a heavy session
+                // must not stall other clients' RPCs. Only this session's lock is held.
+                let step_result = tokio::task::spawn_blocking({
+                    let sessions = sessions.clone();
+                    let catalogs = catalogs.clone();
+                    let span = tracing::Span::current();
+                    move || {
+                        let _span = span.enter();
+                        sessions.with_session_mut(&session_uuid, |session| {
+                            catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+                                let tick = session.get_steps() as i64;
+                                c.incidents.apply(session, &c.cells, &c.meso_links, tick);
+                                demand::apply_profiles(session, c, tick);
+                            });
+                            let mut pre = events::capture(session);
+                            let started = Instant::now();
+                            let stepped = session.step();
+                            metrics::get().observe_step("interactive", started);
+                            stepped.map(|mut dump| {
+                                let events =
+                                    catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+                                        let mut events = vehicles::settle_manual(
+                                            session,
+                                            c,
+                                            Some(&mut pre),
+                                            &mut dump,
+                                        );
+                                        c.vehicles_active = dump.vehicles.len();
+                                        events.extend(events::collect(session, &pre, &dump, c));
+                                        events.sort_by_key(|e| (e.vehicle_id, e.event_type));
+                                        stats::accumulate_trips(c, &pre, &events);
+                                        tl_stats::accumulate_tls(c, &pre, &dump);
+                                        events
+                                    });
+                                (dump, events)
+                            })
+                        })
                     }
                 })
-                .collect();
-
-            // Send response
-            let resp = pb::SessionStepResponse {
-                code: Code::Ok as u32,
-                text: "OK".to_string(),
-                timestamp: dump.timestamp as i64,
-                vehicle_data,
-                tls_data,
-            };
-
-            if tx.send(Ok(resp)).await.is_err() {
-                return;
+                .await;
+
+                let (dump, events) = match step_result {
+                    Err(e) => {
+                        let _ = tx
+                            .send(Err(Status::internal(format!("Step failed: {}", e))))
+                            .await;
+                        return;
+                    }
+                    Ok(None) => {
+                        let _ = tx
+                            .send(Err(Status::not_found(format!(
+                                "Not found session ID: '{}'",
+                                session_id
+                            ))))
+                            .await;
+                        return;
+                    }
+                    Ok(Some(Err(e))) => {
+                        let _ = tx.send(Err(Status::aborted(e.to_string()))).await;
+                        return;
+                    }
+                    Ok(Some(Ok(result))) => result,
+                };
+
+                // Convert vehicle states
+                let vehicle_data: Vec<pb::VehicleState> = dump
+                    .vehicles
+                    .iter()
+                    .map(|v| {
+                        let intermediate_cells: Vec<i64> = v.last_intermediate_cells.clone();
+                        let tail_cells: Vec<i64> = v.tail_cells.clone();
+
+                        pb::VehicleState {
+                            vehicle_id: v.id as i64,
+                            vehicle_type: core_agent_type_to_proto(v.vehicle_type),
+                            speed: v.last_speed as i64,
+                            bearing: v.last_angle,
+                            cell: v.last_cell,
+                            intermediate_cells,
+                            travel_time: v.travel_time,
+                            trip_id: v.trip_id,
+                            tail_cells,
+                        }
+                    })
+                    .collect();
+
+                // Convert TLS states
+                let tls_data: Vec<pb::TlsState> = dump
+                    .tls
+                    .iter()
+                    .map(|(tl_id, groups)| {
+                        let groups_proto: Vec<pb::TlGroup> = groups
+                            .iter()
+                            .map(|g| pb::TlGroup {
+                                id: g.group_id,
+                                signal: g.last_signal.to_string(),
+                            })
+                            .collect();
+
+                        pb::TlsState {
+                            id: *tl_id,
+                            groups: groups_proto,
+                        }
+                    })
+                    .collect();
+
+                let stats = stats::tick_stats(&dump, &events);
+                tracing::debug!(
+                    tick = dump.timestamp,
+                    vehicles = dump.vehicles.len(),
+                    events = events.len(),
+                    "Step"
+                );
+
+                // Send response
+                let resp = pb::SessionStepResponse {
+                    code: Code::Ok as u32,
+                    text: "OK".to_string(),
+                    timestamp: dump.timestamp as i64,
+                    vehicle_data,
+                    tls_data,
+                    events,
+                    stats: Some(stats),
+                };
+
+                if tx.send(Ok(resp)).await.is_err() {
+                    return;
+                }
             }
         }
-    });
+        .instrument(tracing::Span::current()),
+    );
 
     let out: BoxStream<pb::SessionStepResponse> = Box::pin(ReceiverStream::new(rx));
     Ok(Response::new(out))
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::verbose::VerboseLevel;
+
+use super::metrics;
+
+/// Live interactive sessions, each one behind its own lock.
+///
+/// Takes the place of the core `SessionsStorage`, which can only be shared behind a
+/// single mutex: stepping or ingesting into one session would then block every other
+/// client. Here the registry lock is held only to look a session up, add or remove
+/// one; the work on a session holds that session's lock alone, so concurrent sessions
+/// scale with the cores. Same TTL semantics: every access extends the expiration,
+/// expired sessions are not accessible any more and are dropped by [`purge_expired`].
+///
+/// Lock order: registry, then session (never the other way around); the session
+/// catalog lock, if needed, is taken after the session one.
+///
+/// [`purge_expired`]: SessionStore::purge_expired
+pub struct SessionStore {
+    slots: RwLock<HashMap<Uuid, Arc<Slot>>>,
+    ttl: Duration,
+    verbose: VerboseLevel,
+}
+
+struct Slot {
+    /// `None` once the session has been removed while a caller was waiting for it.
+    session: Mutex<Option<Session>>,
+    touched_at: Mutex<Instant>,
+}
+
+impl Slot {
+    fn expired(&self, ttl: Duration) -> bool {
+        self.touched_at
+            .lock()
+            .map_or(true, |touched| touched.elapsed() > ttl)
+    }
+}
+
+impl SessionStore {
+    /// Creates an empty store; sessions expire when not accessed for `ttl`. Session
+    /// creation and expiration are logged (through `tracing`) unless `verbose` is None.
+    pub fn new(ttl: Duration, verbose: VerboseLevel) -> Self {
+        Self {
+            slots: RwLock::new(HashMap::new()),
+            ttl,
+            verbose,
+        }
+    }
+
+    /// Number of sessions in the store (expired ones included until purged).
+    pub fn sessions_num(&self) -> usize {
+        self.slots.read().map_or(0, |slots| slots.len())
+    }
+
+    /// Ids of the live sessions.
+    pub fn ids(&self) -> Vec<Uuid> {
+        match self.slots.read() {
+            Ok(slots) => slots
+                .iter()
+                .filter(|(_, slot)| !slot.expired(self.ttl))
+                .map(|(id, _)| *id)
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Whether a live session exists under `session_id`. Neither waits for the session
+    /// nor extends its expiration.
+    pub fn contains(&self, session_id: &Uuid) -> bool {
+        self.slots.read().is_ok_and(|slots| {
+            slots
+                .get(session_id)
+                .is_some_and(|slot| !slot.expired(self.ttl))
+        })
+    }
+
+    /// Registers a session under `session_id`. Returns `false` (and drops the session)
+    /// when a live session already exists under that id.
+    pub fn register_session(&self, session_id: Uuid, session: Session) -> bool {
+        self.register_admitted(session_id, session, |_| Ok::<(), ()>(()))
+            .unwrap_or(false)
+    }
+
+    /// Registers a session if `admit`, given the number of live sessions, lets it in.
+    /// Expired sessions are purged first, and admission and registration happen under
+    /// the registry lock, so that concurrent calls can not overshoot a limit.
+    pub fn register_admitted<E>(
+        &self,
+        session_id: Uuid,
+        session: Session,
+        admit: impl FnOnce(usize) -> Result<(), E>,
+    ) -> Result<bool, E> {
+        let Ok(mut slots) = self.slots.write() else {
+            return Ok(false);
+        };
+        self.retain_live(&mut slots);
+        admit(slots.len())?;
+        if slots.contains_key(&session_id) {
+            return Ok(false);
+        }
+        if self.verbose.is_at_least(VerboseLevel::Main) {
+            tracing::info!(session_id = %session_id, "Session has been created");
+        }
+        slots.insert(
+            session_id,
+            Arc::new(Slot {
+                session: Mutex::new(Some(session)),
+                touched_at: Mutex::new(Instant::now()),
+            }),
+        );
+        Ok(true)
+    }
+
+    /// Runs `f` against a live session, extending its expiration. Waits for the other
+    /// callers working on the same session only. Returns `None` if the session does not
+    /// exist or has expired.
+    pub fn with_session_mut<R, F: FnOnce(&mut Session) -> R>(
+        &self,
+        session_id: &Uuid,
+        f: F,
+    ) -> Option<R> {
+        let slot = self.slots.read().ok()?.get(session_id).cloned()?;
+        {
+            let mut touched = slot.touched_at.lock().ok()?;
+            if touched.elapsed() > self.ttl {
+                return None;
+            }
+            *touched = Instant::now();
+        }
+        let started = Instant::now();
+        let mut session = slot.session.lock().ok()?;
+        metrics::get()
+            .session_lock_wait
+            .observe(started.elapsed().as_secs_f64());
+        session.as_mut().map(f)
+    }
+
+    /// Removes a session and hands it over to the caller (e.g. to run a recording
+    /// outside of the store), once the callers working on it are done. Expired but
+    /// not yet purged sessions are returned as well.
+    pub fn remove_session(&self, session_id: &Uuid) -> Option<Session> {
+        let slot = self.slots.write().ok()?.remove(session_id)?;
+        slot.session.lock().ok()?.take()
+    }
+
+    /// Drops the sessions not accessed within the TTL. Returns the number of dropped ones.
+    pub fn purge_expired(&self) -> usize {
+        match self.slots.write() {
+            Ok(mut slots) => self.retain_live(&mut slots),
+            Err(_) => 0,
+        }
+    }
+
+    fn retain_live(&self, slots: &mut HashMap<Uuid, Arc<Slot>>) -> usize {
+        let before = slots.len();
+        slots.retain(|id, slot| {
+            let alive = !slot.expired(self.ttl);
+            if !alive && self.verbose.is_at_least(VerboseLevel::Main) {
+                tracing::info!(session_id = %id, "Session has been expired");
+            }
+            alive
+        });
+        let purged = before - slots.len();
+        metrics::get().sessions_purged.inc_by(purged as u64);
+        purged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn sessions_are_locked_one_by_one() {
+        let store = Arc::new(SessionStore::new(
+            Duration::from_millis(200),
+            VerboseLevel::None,
+        ));
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        assert!(store.register_session(a, Session::default(None)));
+        assert!(store.register_session(b, Session::default(None)));
+        assert!(!store.register_session(a, Session::default(None)));
+        assert_eq!(
+            store.register_admitted(Uuid::new_v4(), Session::default(None), |n| {
+                if n >= 2 { Err(n) } else { Ok(()) }
+            }),
+            Err(2)
+        );
+
+        // A session busy on another thread does not block the other one
+        let (locked_tx, locked_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let busy = {
+            let store = store.clone();
+            std::thread::spawn(move || {
+                store.with_session_mut(&a, |_| {
+                    locked_tx.send(()).unwrap();
+                    release_rx.recv().unwrap();
+                })
+            })
+        };
+        locked_rx.recv().unwrap();
+        assert_eq!(store.with_session_mut(&b, |s| s.get_steps()), Some(0));
+        release_tx.send(()).unwrap();
+        assert!(busy.join().unwrap().is_some());
+
+        // Removal hands the session over
+        assert!(store.remove_session(&a).is_some());
+        assert!(store.with_session_mut(&a, |_| ()).is_none());
+        assert_eq!(store.ids(), vec![b]);
+
+        // Not accessed within the TTL: expired, then purged
+        std::thread::sleep(Duration::from_millis(250));
+        assert!(store.with_session_mut(&b, |_| ()).is_none());
+        assert_eq!(store.sessions_num(), 1);
+        assert_eq!(store.purge_expired(), 1);
+        assert_eq!(store.sessions_num(), 0);
+    }
+}
@@ -0,0 +1,109 @@
+use std::io::IsTerminal;
+
+use tonic::codegen::http;
+use tracing::Span;
+use tracing::field::{Empty, display};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::prelude::*;
+use uuid::Uuid;
+
+use super::config::{ConfigError, LogFormat, LoggingSection};
+
+/// Keeps the span exporter (if any) alive; pending spans are flushed on drop.
+pub struct Telemetry {
+    #[cfg(feature = "otlp")]
+    provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        #[cfg(feature = "otlp")]
+        if let Some(provider) = self.provider.take() {
+            let _ = provider.shutdown();
+        }
+    }
+}
+
+/// Installs the global `tracing` subscriber: `logging.level` filter, text or JSON
+/// output on stdout and, with the `otlp` feature, span export to `logging.otlp_endpoint`.
+/// Must be called within the tokio runtime (the OTLP exporter is a tonic client).
+pub fn init(logging: &LoggingSection) -> Result<Telemetry, ConfigError> {
+    let filter = EnvFilter::try_new(&logging.level)
+        .map_err(|e| ConfigError::Invalid(format!("logging.level: {}", e)))?;
+    let output = match logging.format {
+        LogFormat::Text => tracing_subscriber::fmt::layer()
+            .with_ansi(std::io::stdout().is_terminal())
+            .boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .boxed(),
+    };
+    let registry = tracing_subscriber::registry().with(filter).with(output);
+
+    #[cfg(feature = "otlp")]
+    {
+        let provider = match &logging.otlp_endpoint {
+            Some(endpoint) => Some(otlp_provider(endpoint)?),
+            None => None,
+        };
+        let layer = provider.as_ref().map(|p| {
+            use opentelemetry::trace::TracerProvider;
+            tracing_opentelemetry::layer().with_tracer(p.tracer("micro_traffic_sim"))
+        });
+        registry.with(layer).init();
+        Ok(Telemetry { provider })
+    }
+    #[cfg(not(feature = "otlp"))]
+    {
+        registry.init();
+        Ok(Telemetry {})
+    }
+}
+
+#[cfg(feature = "otlp")]
+fn otlp_provider(
+    endpoint: &str,
+) -> Result<opentelemetry_sdk::trace::SdkTracerProvider, ConfigError> {
+    use opentelemetry_otlp::WithExportConfig;
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()
+        .map_err(|e| ConfigError::Invalid(format!("logging.otlp_endpoint: {}", e)))?;
+    let resource = opentelemetry_sdk::Resource::builder()
+        .with_service_name("micro_traffic_sim")
+        .build();
+    Ok(opentelemetry_sdk::trace::SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(resource)
+        .build())
+}
+
+/// Span of a single RPC. `session_id` and `tenant` are filled in by the handlers;
+/// `request_id` is the client's `x-request-id` metadata, if sent.
+pub fn rpc_span(request: &http::Request<()>) -> Span {
+    let request_id = request
+        .headers()
+        .get("x-request-id")
+        .and_then(|v| v.to_str().ok());
+    tracing::info_span!(
+        "rpc",
+        method = %request.uri().path(),
+        request_id,
+        session_id = Empty,
+        tenant = Empty,
+    )
+}
+
+/// Tags the current RPC span with the session it addresses.
+pub fn record_session(session_id: &Uuid) {
+    Span::current().record("session_id", display(session_id));
+}
+
+/// Tags the current RPC span with the caller's tenant.
+pub fn record_tenant(tenant: &str) {
+    Span::current().record("tenant", tenant);
+}
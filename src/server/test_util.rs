@@ -0,0 +1,52 @@
+//! Grid fixtures shared by the unit tests of the server modules.
+
+use std::collections::HashMap;
+
+use micro_traffic_sim_core::geom::new_point;
+use micro_traffic_sim_core::grid::cell::{Cell, CellBuilder, CellID};
+use micro_traffic_sim_core::grid::zones::ZoneType;
+
+/// Single-lane cell at (x, 0) leading to `forward` (-1 for none): common zone, speed
+/// limit 1, no side lanes.
+pub fn road_cell(id: CellID, x: f64, forward: CellID) -> CellBuilder {
+    Cell::new(id)
+        .with_point(new_point(x, 0.0, None))
+        .with_zone_type(ZoneType::Common)
+        .with_speed_limit(1)
+        .with_left_node(-1)
+        .with_forward_node(forward)
+        .with_right_node(-1)
+}
+
+/// Zone of cell `id` on a road of `n` cells: birth on the first one, death on the last.
+pub fn road_zone(id: CellID, n: CellID) -> ZoneType {
+    match id {
+        1 => ZoneType::Birth,
+        _ if id == n => ZoneType::Death,
+        _ => ZoneType::Common,
+    }
+}
+
+/// Straight single-lane road 1 -> 2 -> ... -> n of unit-spaced [`road_cell`]s, all of
+/// them common cells.
+pub fn plain_road(n: CellID) -> Vec<Cell> {
+    (1..=n)
+        .map(|id| road_cell(id, id as f64, if id == n { -1 } else { id + 1 }).build())
+        .collect()
+}
+
+/// [`plain_road`] with a birth zone on cell 1 and a death zone on cell n.
+pub fn road(n: CellID) -> Vec<Cell> {
+    (1..=n)
+        .map(|id| {
+            road_cell(id, id as f64, if id == n { -1 } else { id + 1 })
+                .with_zone_type(road_zone(id, n))
+                .build()
+        })
+        .collect()
+}
+
+/// Cells keyed by their id.
+pub fn by_id(cells: Vec<Cell>) -> HashMap<CellID, Cell> {
+    cells.into_iter().map(|c| (c.get_id(), c)).collect()
+}
@@ -0,0 +1,329 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use tonic::{Code, Request, Response, Status};
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::grid::cell::CellID;
+use micro_traffic_sim_core::simulation::states::AutomataState;
+use micro_traffic_sim_core::traffic_lights::signals::SignalType;
+
+use super::catalog::{self, Catalogs, SessionCatalog};
+use super::events::PreStep;
+use super::storage::SessionStore;
+use super::{blocking, ensure_session, parse_session_uuid};
+
+/// Per-group signal performance counters accumulated on every step.
+#[derive(Clone, Default)]
+pub struct TlGroupAcc {
+    /// Vehicles which entered one of the group's cells.
+    pub served: u64,
+    /// Number of green intervals started.
+    pub green_phases: u64,
+    /// Steps with a green signal.
+    pub green_ticks: u64,
+    /// Green steps during which at least one vehicle has been served.
+    pub green_ticks_used: u64,
+    /// Vehicle-steps spent stopped in the group's queue.
+    pub delay_ticks: u64,
+    /// Vehicles which came to a stop in the group's queue.
+    pub stops: u64,
+    /// Longest queue observed, in cells.
+    pub max_queue: u32,
+    was_green: bool,
+}
+
+/// Whether the signal lets vehicles into the group's cells (mirrors the core, which
+/// bans the cells on red, yellow, red-yellow and blinking).
+fn is_green(signal: SignalType) -> bool {
+    !matches!(
+        signal,
+        SignalType::Red | SignalType::Yellow | SignalType::RedYellow | SignalType::Blinking
+    )
+}
+
+/// Queue of a group: cells occupied by stopped vehicles, walked upstream from the
+/// group's cells for as long as the stopped vehicles are adjacent.
+fn queue_cells(
+    catalog: &SessionCatalog,
+    group_cells: &[CellID],
+    stopped: &HashSet<CellID>,
+) -> HashSet<CellID> {
+    let mut queue = HashSet::new();
+    let mut visited: HashSet<CellID> = group_cells.iter().copied().collect();
+    let mut frontier: VecDeque<CellID> = group_cells.iter().copied().collect();
+    for cell_id in group_cells {
+        if stopped.contains(cell_id) {
+            queue.insert(*cell_id);
+        }
+    }
+    while let Some(cell_id) = frontier.pop_front() {
+        for prev in catalog.upstream_of(cell_id) {
+            if stopped.contains(prev) && visited.insert(*prev) {
+                queue.insert(*prev);
+                frontier.push_back(*prev);
+            }
+        }
+    }
+    queue
+}
+
+/// Accumulates the per-group signal counters from one step.
+///
+/// A vehicle is served by a group when it enters one of the group's cells (these are
+/// banned unless the signal is green). Control delay is approximated by the stopped
+/// time spent in the group's queue.
+pub fn accumulate_tls(catalog: &mut SessionCatalog, pre: &PreStep, dump: &AutomataState) {
+    if catalog.traffic_lights.is_empty() {
+        return;
+    }
+    // Vehicles indexed by cell once, so that every group only looks at its own cells:
+    // by the cells reached on the step (head and intermediate cells) and, for the
+    // stopped ones, by their head cell.
+    let mut reached: HashMap<CellID, Vec<usize>> = HashMap::new();
+    let mut stopped_at: HashMap<CellID, usize> = HashMap::new();
+    let mut stopped: HashSet<CellID> = HashSet::new();
+    for (idx, v) in dump.vehicles.iter().enumerate() {
+        for cell in std::iter::once(&v.last_cell).chain(&v.last_intermediate_cells) {
+            reached.entry(*cell).or_default().push(idx);
+        }
+        if v.last_speed == 0 {
+            stopped_at.insert(v.last_cell, idx);
+            stopped.insert(v.last_cell);
+            stopped.extend(v.tail_cells.iter().copied());
+        }
+    }
+
+    let mut updates: Vec<((i64, i64), TlGroupAcc)> = Vec::new();
+    for (tl_id, groups) in &dump.tls {
+        let Some(tl) = catalog.traffic_lights.get(tl_id) else {
+            continue;
+        };
+        for state in groups {
+            let Some(group) = tl.groups.iter().find(|g| g.id == state.group_id) else {
+                continue;
+            };
+            let key = (*tl_id, state.group_id);
+            let mut acc = catalog.tl_stats.get(&key).cloned().unwrap_or_default();
+            let group_cells: HashSet<CellID> = group.cells.iter().copied().collect();
+            let queue = queue_cells(catalog, &group.cells, &stopped);
+
+            let entered: HashSet<usize> = group
+                .cells
+                .iter()
+                .filter_map(|cell| reached.get(cell))
+                .flatten()
+                .copied()
+                .filter(|idx| {
+                    pre.cell_and_speed(dump.vehicles[*idx].id)
+                        .is_some_and(|(cell, _)| !group_cells.contains(&cell))
+                })
+                .collect();
+            let served = entered.len() as u64;
+            for idx in queue.iter().filter_map(|cell| stopped_at.get(cell)) {
+                acc.delay_ticks += 1;
+                let before = pre.cell_and_speed(dump.vehicles[*idx].id);
+                if before.is_none_or(|(_, speed)| speed != 0) {
+                    acc.stops += 1;
+                }
+            }
+            acc.served += served;
+
+            let green = is_green(state.last_signal);
+            if green {
+                acc.green_ticks += 1;
+                if served > 0 {
+                    acc.green_ticks_used += 1;
+                }
+                if !acc.was_green {
+                    acc.green_phases += 1;
+                }
+            }
+            acc.was_green = green;
+            acc.max_queue = acc.max_queue.max(queue.len() as u32);
+            updates.push((key, acc));
+        }
+    }
+    catalog.tl_stats.extend(updates);
+}
+
+fn ratio(num: u64, den: u64) -> f64 {
+    if den == 0 {
+        0.0
+    } else {
+        num as f64 / den as f64
+    }
+}
+
+/// Derived figures of one or several (summed) groups.
+fn figures(acc: &TlGroupAcc) -> (f64, f64, f64, f64) {
+    (
+        ratio(acc.served, acc.green_phases),
+        ratio(acc.delay_ticks, acc.served),
+        ratio(acc.stops, acc.served),
+        ratio(acc.green_ticks_used, acc.green_ticks),
+    )
+}
+
+fn group_stats(group_id: i64, acc: &TlGroupAcc) -> pb::TrafficLightGroupStats {
+    let (vehicles_per_green, mean_control_delay, stops_per_vehicle, green_utilization) =
+        figures(acc);
+    pb::TrafficLightGroupStats {
+        group_id,
+        vehicles_served: acc.served,
+        green_phases: acc.green_phases,
+        vehicles_per_green,
+        mean_control_delay,
+        stops_per_vehicle,
+        max_queue: acc.max_queue,
+        green_utilization,
+    }
+}
+
+/// Statistics of a traffic light: its groups plus the totals over them.
+fn traffic_light_stats(catalog: &SessionCatalog, tl: &pb::TrafficLight) -> pb::TrafficLightStats {
+    let mut total = TlGroupAcc::default();
+    let mut groups = Vec::with_capacity(tl.groups.len());
+    for group in &tl.groups {
+        let acc = catalog
+            .tl_stats
+            .get(&(tl.id, group.id))
+            .cloned()
+            .unwrap_or_default();
+        total.served += acc.served;
+        total.green_phases += acc.green_phases;
+        total.green_ticks += acc.green_ticks;
+        total.green_ticks_used += acc.green_ticks_used;
+        total.delay_ticks += acc.delay_ticks;
+        total.stops += acc.stops;
+        total.max_queue = total.max_queue.max(acc.max_queue);
+        groups.push(group_stats(group.id, &acc));
+    }
+    let (vehicles_per_green, mean_control_delay, stops_per_vehicle, green_utilization) =
+        figures(&total);
+    pb::TrafficLightStats {
+        traffic_light_id: tl.id,
+        vehicles_served: total.served,
+        vehicles_per_green,
+        mean_control_delay,
+        stops_per_vehicle,
+        max_queue: total.max_queue,
+        green_utilization,
+        groups,
+    }
+}
+
+/// Returns signal performance statistics per traffic light and per group.
+pub async fn get_traffic_light_stats(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    request: Request<pb::TrafficLightStatsRequest>,
+) -> Result<Response<pb::TrafficLightStatsResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+    ensure_session(&sessions, &session_uuid).await?;
+
+    let data = blocking(move || {
+        catalog::with_catalog_mut(&catalogs, &session_uuid, |c| {
+            let tl_ids: BTreeSet<i64> = if req.traffic_light_ids.is_empty() {
+                c.traffic_lights.keys().copied().collect()
+            } else {
+                req.traffic_light_ids.iter().copied().collect()
+            };
+            tl_ids
+                .into_iter()
+                .map(|tl_id| match c.traffic_lights.get(&tl_id) {
+                    Some(tl) => Ok(traffic_light_stats(c, tl)),
+                    None => Err(Status::not_found(format!(
+                        "Not found traffic light ID: '{}'",
+                        tl_id
+                    ))),
+                })
+                .collect::<Result<Vec<_>, Status>>()
+        })
+    })
+    .await??;
+
+    Ok(Response::new(pb::TrafficLightStatsResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        data,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::road;
+    use micro_traffic_sim_core::simulation::session::Session;
+    use micro_traffic_sim_core::traffic_lights::groups::TrafficLightGroup;
+    use micro_traffic_sim_core::traffic_lights::lights::TrafficLight;
+    use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+
+    /// Single-lane road 1 -> ... -> 10 with a signal on cell 6: 5 steps red, 5 green.
+    #[test]
+    fn queue_and_service_at_signal() {
+        let cells = road(10);
+        let mut session = Session::default(None);
+        let mut catalog = SessionCatalog::new();
+        for cell in &cells {
+            catalog.insert_cell(cell.clone(), -1);
+        }
+        session.add_cells(cells);
+        session.add_trip(
+            Trip::new(1, 9, TripType::Constant)
+                .with_id(1)
+                .with_initial_speed(1)
+                .with_time(1)
+                .with_start_time(0)
+                .with_end_time(0)
+                .build(),
+        );
+        session.add_traffic_light(
+            TrafficLight::new(1)
+                .with_groups(vec![
+                    TrafficLightGroup::new(10)
+                        .with_cells_ids(vec![6])
+                        .with_signal(vec![SignalType::Red, SignalType::Green])
+                        .build(),
+                ])
+                .with_phases_times(vec![5, 5])
+                .build(),
+        );
+        catalog.traffic_lights.insert(
+            1,
+            pb::TrafficLight {
+                id: 1,
+                groups: vec![pb::Group {
+                    id: 10,
+                    cells: vec![6],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        );
+
+        for _ in 0..20 {
+            let pre = crate::server::events::capture(&session);
+            let dump = session.step().unwrap();
+            accumulate_tls(&mut catalog, &pre, &dump);
+        }
+
+        let stats = traffic_light_stats(&catalog, &catalog.traffic_lights[&1]);
+        let group = &stats.groups[0];
+        assert_eq!(group.group_id, 10);
+        // Two red / green cycles over 20 steps
+        assert_eq!(group.green_phases, 2);
+        assert_eq!(group.vehicles_served, 6);
+        assert_eq!(group.vehicles_per_green, 3.0);
+        // The road up to the signal (cells 1..=5) fills up during red
+        assert_eq!(group.max_queue, 5, "vehicles queue up during red");
+        let acc = &catalog.tl_stats[&(1, 10)];
+        assert_eq!((acc.delay_ticks, acc.stops), (11, 5));
+        assert!((group.mean_control_delay - 11.0 / 6.0).abs() < 1e-12);
+        assert!((group.stops_per_vehicle - 5.0 / 6.0).abs() < 1e-12);
+        // 6 of the 10 green steps served a vehicle
+        assert!((group.green_utilization - 0.6).abs() < 1e-12);
+        assert_eq!(stats.vehicles_served, group.vehicles_served);
+    }
+}
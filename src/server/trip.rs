@@ -1,17 +1,29 @@
-use std::sync::{Arc, Mutex};
+use std::collections::HashSet;
+use std::sync::Arc;
 
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Code, Request, Response, Status};
+use tracing::Instrument;
 use uuid::Uuid;
 
 use micro_traffic_sim::pb;
 use micro_traffic_sim_core::agents_types::AgentType;
 use micro_traffic_sim_core::behaviour::BehaviourType;
-use micro_traffic_sim_core::simulation::sessions_storage::SessionsStorage;
-use micro_traffic_sim_core::trips::trip::{Trip, TripType};
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::trips::trip::{Trip, TripID, TripType};
 
 use super::BoxStream;
+use super::auth;
+use super::catalog::{self, Catalogs, SessionCatalog};
+use super::demand;
+use super::incidents;
+use super::quotas;
+use super::recordings::Recordings;
+use super::routing;
+use super::storage::SessionStore;
+use super::{blocking, check_entities_count, parse_session_uuid};
 
 /// Converts proto TripType (i32) to computational core TripType
 fn proto_trip_type_to_core(trip_type: i32) -> TripType {
@@ -23,7 +35,7 @@ fn proto_trip_type_to_core(trip_type: i32) -> TripType {
 }
 
 /// Converts proto BehaviourType (i32) to computational core BehaviourType
-fn proto_behaviour_type_to_core(behaviour_type: i32) -> BehaviourType {
+pub(super) fn proto_behaviour_type_to_core(behaviour_type: i32) -> BehaviourType {
     match behaviour_type {
         1 => BehaviourType::Block,
         2 => BehaviourType::Aggressive,
@@ -34,7 +46,7 @@ fn proto_behaviour_type_to_core(behaviour_type: i32) -> BehaviourType {
 }
 
 /// Converts proto AgentType (i32) to computational core AgentType
-fn proto_agent_type_to_core(agent_type: i32) -> AgentType {
+pub(super) fn proto_agent_type_to_core(agent_type: i32) -> AgentType {
     match agent_type {
         1 => AgentType::Car,
         2 => AgentType::Bus,
@@ -46,116 +58,490 @@ fn proto_agent_type_to_core(agent_type: i32) -> AgentType {
     }
 }
 
+/// Converts a proto trip to the computational core one
+pub(super) fn core_trip(trip_data: &pb::Trip) -> Trip {
+    let trip_type = proto_trip_type_to_core(trip_data.trip_type);
+    let behaviour_type = proto_behaviour_type_to_core(trip_data.behaviour_type);
+    let agent_type = proto_agent_type_to_core(trip_data.agent_type);
+
+    // Convert transits vector
+    let transits: Vec<i64> = trip_data.transits.clone();
+
+    // Build trip using the builder pattern
+    let mut trip_builder = Trip::new(trip_data.from_node, trip_data.to_node, trip_type)
+        .with_id(trip_data.id)
+        .with_initial_speed(trip_data.initial_speed as i32)
+        .with_probability(trip_data.probability)
+        .with_allowed_agent_type(agent_type)
+        .with_allowed_behaviour_type(behaviour_type)
+        .with_time(trip_data.time as i32)
+        .with_start_time(trip_data.start_time as i32)
+        .with_end_time(trip_data.end_time as i32);
+
+    // Set transits if any
+    if !transits.is_empty() {
+        trip_builder = trip_builder.with_transits_cells(transits, trip_data.relax_time as i32);
+    }
+
+    trip_builder.build()
+}
+
+/// Copy of a trip which never generates vehicles. The core has no trip removal API, so
+/// paused and removed trips are overwritten with such a copy.
+pub(super) fn dormant(mut trip: Trip) -> Trip {
+    trip.trip_type = TripType::Undefined;
+    trip
+}
+
+/// Checks that the trip's destination (and its transits, in order) can be reached
+/// from its origin over the given road network.
+pub(super) fn trip_reachability(net: &GridRoads, trip: &pb::Trip) -> pb::TripReachability {
+    match routing::find_route(net, trip.from_node, trip.to_node, &trip.transits) {
+        Ok(route) => pb::TripReachability {
+            trip_id: trip.id,
+            reachable: true,
+            path_cells: (route.cells.len() - 1) as u32,
+            path_length: route.length,
+            reason: String::new(),
+        },
+        Err(e) => pb::TripReachability {
+            trip_id: trip.id,
+            reachable: false,
+            path_cells: 0,
+            path_length: 0.0,
+            reason: e.to_string(),
+        },
+    }
+}
+
+/// Adds the trips of one PushSessionTrip message to the session and mirrors them into
+/// its catalog. None when the session does not exist.
+fn add_trips(
+    sessions: &SessionStore,
+    catalogs: &Catalogs,
+    caller: Option<auth::Caller>,
+    session_uuid: Uuid,
+    req: pb::SessionTrip,
+) -> Option<Result<Vec<pb::TripReachability>, Status>> {
+    // Route check over the grid pushed so far, outside the session and catalog locks
+    let net = catalog::with_catalog_mut(catalogs, &session_uuid, |c| c.network());
+    let reachability: Vec<pb::TripReachability> = req
+        .data
+        .iter()
+        .map(|t| trip_reachability(&net, t))
+        .collect();
+
+    // Quota count, check and insert in one catalog section: concurrent pushes can not
+    // overshoot the quota
+    let added = sessions.with_session_mut(&session_uuid, |session| {
+        catalog::with_catalog_mut(catalogs, &session_uuid, |c| {
+            let new_ids: HashSet<i64> = req
+                .data
+                .iter()
+                .map(|t| t.id)
+                .filter(|id| !c.trips.contains_key(id))
+                .collect();
+            quotas::check_trips(caller.as_ref(), c.trips.len() + new_ids.len())?;
+            if req.reject_unreachable {
+                if let Some(r) = reachability.iter().find(|r| !r.reachable) {
+                    return Err(Status::failed_precondition(format!(
+                        "Trip '{}' is unreachable: {}",
+                        r.trip_id, r.reason
+                    )));
+                }
+            }
+            // Convert proto trips to core trips and add them; re-pushed paused trips
+            // stay paused
+            for trip_data in &req.data {
+                let trip = core_trip(trip_data);
+                if c.paused_trips.contains(&trip_data.id) {
+                    session.add_trip(dormant(trip));
+                } else {
+                    session.add_trip(trip);
+                }
+            }
+            // Mirror the trips into the session catalog (used to derive events and stats)
+            for trip_data in req.data {
+                // Same acceptance rule as Session::add_trip
+                if trip_data.end_time == 0 || trip_data.end_time >= trip_data.start_time {
+                    c.demand_bins.remove(&trip_data.id);
+                    c.removed_trips.remove(&trip_data.id);
+                    c.trips.insert(trip_data.id, trip_data);
+                }
+            }
+            Ok(())
+        })
+    });
+    if let Err(e) = added? {
+        return Some(Err(e));
+    }
+    Some(Ok(reachability))
+}
+
 pub async fn push_session_trip(
-    sessions: Arc<Mutex<SessionsStorage>>,
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
     request: Request<tonic::Streaming<pb::SessionTrip>>,
 ) -> Result<Response<BoxStream<pb::SessionTripResponse>>, Status> {
+    let caller = auth::Caller::of(&request);
     let mut stream = request.into_inner();
     let (tx, rx) = mpsc::channel(16);
 
-    tokio::spawn(async move {
-        while let Ok(Some(req)) = stream.message().await {
-            // Validate session_id presence
-            let session_id = match &req.session_id {
-                Some(id) => &id.value,
-                None => {
-                    let _ = tx
-                        .send(Err(Status::invalid_argument(
-                            "No session ID has been provided",
-                        )))
-                        .await;
+    tokio::spawn(
+        async move {
+            while let Ok(Some(req)) = stream.message().await {
+                // Validate session_id presence
+                let session_id = match &req.session_id {
+                    Some(id) => &id.value,
+                    None => {
+                        let _ = tx
+                            .send(Err(Status::invalid_argument(
+                                "No session ID has been provided",
+                            )))
+                            .await;
+                        return;
+                    }
+                };
+
+                // Parse UUID
+                let session_uuid = match Uuid::parse_str(session_id) {
+                    Ok(uuid) => uuid,
+                    Err(_) => {
+                        let _ = tx
+                            .send(Err(Status::invalid_argument(format!(
+                                "Session ID should be of type UUID v4: '{}'",
+                                session_id
+                            ))))
+                            .await;
+                        return;
+                    }
+                };
+
+                // Reject sessions owned by other tenants
+                let authorized = auth::authorize_session(caller.as_ref(), &session_uuid);
+                if let Err(status) = authorized {
+                    let _ = tx.send(Err(status)).await;
                     return;
                 }
-            };
-
-            // Parse UUID
-            let session_uuid = match Uuid::parse_str(session_id) {
-                Ok(uuid) => uuid,
-                Err(_) => {
-                    let _ = tx
-                        .send(Err(Status::invalid_argument(format!(
-                            "Session ID should be of type UUID v4: '{}'",
-                            session_id
-                        ))))
-                        .await;
+
+                // Validate data size
+                if let Err(status) = check_entities_count(req.data.len()) {
+                    let _ = tx.send(Err(status)).await;
                     return;
                 }
-            };
-
-            // Validate data size
-            if req.data.len() > 10000 {
-                let _ = tx
-                    .send(Err(Status::invalid_argument(format!(
-                        "Max amount on data entities is 10000, but provided is {}",
-                        req.data.len()
-                    ))))
-                    .await;
-                return;
-            }
 
-            if req.data.is_empty() {
-                let _ = tx.send(Err(Status::invalid_argument("No data"))).await;
-                return;
-            }
+                if req.data.is_empty() {
+                    let _ = tx.send(Err(Status::invalid_argument("No data"))).await;
+                    return;
+                }
 
-            // Get session and add trips (use block scope to ensure lock is dropped before await)
-            let add_result = {
-                let mut sessions_guard = sessions.lock().unwrap();
-                sessions_guard.with_session_mut(&session_uuid, |session| {
-                    // Convert proto trips to core trips and add them
-                    for trip_data in &req.data {
-                        let trip_type = proto_trip_type_to_core(trip_data.trip_type);
-                        let behaviour_type = proto_behaviour_type_to_core(trip_data.behaviour_type);
-                        let agent_type = proto_agent_type_to_core(trip_data.agent_type);
-
-                        // Convert transits vector
-                        let transits: Vec<i64> = trip_data.transits.clone();
-
-                        // Build trip using the builder pattern
-                        let mut trip_builder =
-                            Trip::new(trip_data.from_node, trip_data.to_node, trip_type)
-                                .with_id(trip_data.id)
-                                .with_initial_speed(trip_data.initial_speed as i32)
-                                .with_probability(trip_data.probability)
-                                .with_allowed_agent_type(agent_type)
-                                .with_allowed_behaviour_type(behaviour_type)
-                                .with_time(trip_data.time as i32)
-                                .with_start_time(trip_data.start_time as i32)
-                                .with_end_time(trip_data.end_time as i32);
-
-                        // Set transits if any
-                        if !transits.is_empty() {
-                            trip_builder = trip_builder
-                                .with_transits_cells(transits, trip_data.relax_time as i32);
-                        }
-
-                        let trip = trip_builder.build();
-                        session.add_trip(trip);
+                if let Err(e) = req.data.iter().try_for_each(demand::validate_profile) {
+                    let _ = tx.send(Err(e)).await;
+                    return;
+                }
+
+                // Locks are taken on the blocking pool, OFF the async workers
+                let add_result = {
+                    let sessions = sessions.clone();
+                    let catalogs = catalogs.clone();
+                    let caller = caller.clone();
+                    blocking(move || add_trips(&sessions, &catalogs, caller, session_uuid, req))
+                        .await
+                };
+
+                let reachability = match add_result {
+                    Ok(None) => {
+                        let _ = tx
+                            .send(Err(Status::not_found(format!(
+                                "Not found session ID: '{}'",
+                                session_uuid
+                            ))))
+                            .await;
+                        return;
                     }
-                })
-            };
-
-            if add_result.is_none() {
-                let _ = tx
-                    .send(Err(Status::not_found(format!(
-                        "Not found session ID: '{}'",
-                        session_id
-                    ))))
-                    .await;
-                return;
-            }
+                    Ok(Some(Err(e))) | Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                    Ok(Some(Ok(reachability))) => reachability,
+                };
 
-            // Send OK response
-            let resp = pb::SessionTripResponse {
-                code: Code::Ok as u32,
-                text: "OK".to_string(),
-            };
-            if tx.send(Ok(resp)).await.is_err() {
-                return;
+                // Send OK response
+                let resp = pb::SessionTripResponse {
+                    code: Code::Ok as u32,
+                    text: "OK".to_string(),
+                    reachability,
+                };
+                if tx.send(Ok(resp)).await.is_err() {
+                    return;
+                }
             }
         }
-    });
+        .instrument(tracing::Span::current()),
+    );
 
     let out: BoxStream<pb::SessionTripResponse> = Box::pin(ReceiverStream::new(rx));
     Ok(Response::new(out))
 }
+
+/// Runtime change of a trip, as requested by UpdateSessionTrip, RemoveSessionTrip or
+/// SetSessionTripPaused.
+#[derive(Clone, Debug)]
+pub enum TripChange {
+    Update(pb::Trip),
+    Remove(TripID),
+    SetPaused(TripID, bool),
+}
+
+impl TripChange {
+    fn trip_id(&self) -> TripID {
+        match self {
+            TripChange::Update(trip) => trip.id,
+            TripChange::Remove(id) | TripChange::SetPaused(id, _) => *id,
+        }
+    }
+
+    /// Applies the change to the session and to the mirrored trips.
+    pub fn apply(&self, session: &mut Session, catalog: &mut SessionCatalog) -> Result<(), Status> {
+        let trip_id = self.trip_id();
+        let Some(current) = catalog.trips.get(&trip_id) else {
+            return Err(Status::not_found(format!(
+                "Not found trip ID: '{}'",
+                trip_id
+            )));
+        };
+        // The core gets the trip's own values (or a dormant copy) again
+        catalog.demand_bins.remove(&trip_id);
+        match self {
+            TripChange::Update(trip) => {
+                if catalog.paused_trips.contains(&trip_id) {
+                    session.add_trip(dormant(core_trip(trip)));
+                } else {
+                    session.add_trip(core_trip(trip));
+                }
+                catalog.trips.insert(trip_id, trip.clone());
+            }
+            TripChange::Remove(_) => {
+                session.add_trip(dormant(core_trip(current)));
+                catalog.trips.remove(&trip_id);
+                catalog.paused_trips.remove(&trip_id);
+                catalog.removed_trips.insert(trip_id);
+            }
+            TripChange::SetPaused(_, true) => {
+                session.add_trip(dormant(core_trip(current)));
+                catalog.paused_trips.insert(trip_id);
+            }
+            TripChange::SetPaused(_, false) => {
+                session.add_trip(core_trip(current));
+                catalog.paused_trips.remove(&trip_id);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Applies a trip change to an interactive session right away, or queues it for the
+/// next tick of a running recording.
+async fn change_trip(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    session_uuid: Uuid,
+    change: TripChange,
+) -> Result<(), Status> {
+    if let Some(handle) = incidents::running_recording(&recordings, &session_uuid)? {
+        // Applied by the recording thread before its next step
+        handle
+            .trips
+            .lock()
+            .map_err(|_| Status::internal("recording trips lock poisoned"))?
+            .push(change);
+        return Ok(());
+    }
+    let result = blocking(move || {
+        sessions.with_session_mut(&session_uuid, |session| {
+            catalog::with_catalog_mut(&catalogs, &session_uuid, |c| change.apply(session, c))
+        })
+    })
+    .await?;
+    match result {
+        None => Err(Status::not_found(format!(
+            "Not found session ID: '{}'",
+            session_uuid
+        ))),
+        Some(r) => r,
+    }
+}
+
+/// Replaces an existing trip of a session.
+pub async fn update_session_trip(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::UpdateSessionTripRequest>,
+) -> Result<Response<pb::UpdateSessionTripResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+    let Some(trip) = req.trip else {
+        return Err(Status::invalid_argument("No data"));
+    };
+    // Same acceptance rule as Session::add_trip
+    if trip.end_time != 0 && trip.end_time < trip.start_time {
+        return Err(Status::invalid_argument(format!(
+            "Trip end time {} is before its start time {}",
+            trip.end_time, trip.start_time
+        )));
+    }
+    demand::validate_profile(&trip)?;
+    change_trip(
+        sessions,
+        catalogs,
+        recordings,
+        session_uuid,
+        TripChange::Update(trip),
+    )
+    .await?;
+
+    Ok(Response::new(pb::UpdateSessionTripResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+    }))
+}
+
+/// Removes a trip from a session.
+pub async fn remove_session_trip(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::RemoveSessionTripRequest>,
+) -> Result<Response<pb::RemoveSessionTripResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+    change_trip(
+        sessions,
+        catalogs,
+        recordings,
+        session_uuid,
+        TripChange::Remove(req.trip_id),
+    )
+    .await?;
+
+    Ok(Response::new(pb::RemoveSessionTripResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+    }))
+}
+
+/// Pauses or resumes vehicle generation of a trip.
+pub async fn set_session_trip_paused(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::SetSessionTripPausedRequest>,
+) -> Result<Response<pb::SetSessionTripPausedResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+    change_trip(
+        sessions,
+        catalogs,
+        recordings,
+        session_uuid,
+        TripChange::SetPaused(req.trip_id, req.paused),
+    )
+    .await?;
+
+    Ok(Response::new(pb::SetSessionTripPausedResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::{by_id, plain_road, road};
+
+    /// Vehicles generated over `steps` steps on a road 1 -> ... -> 5.
+    fn generated(session: &mut Session, steps: usize) -> u64 {
+        let before = session.get_last_vehicle_id();
+        for _ in 0..steps {
+            session.step().unwrap();
+        }
+        session.get_last_vehicle_id() - before
+    }
+
+    #[test]
+    fn reachability_of_trips() {
+        let cells = by_id(plain_road(5));
+        let net = routing::build_network(&cells);
+        let trip = |from_node: i64, to_node: i64, transits: Vec<i64>| pb::Trip {
+            id: 1,
+            from_node,
+            to_node,
+            transits,
+            ..Default::default()
+        };
+
+        let r = trip_reachability(&net, &trip(1, 5, vec![3]));
+        assert!(r.reachable);
+        assert_eq!(r.path_cells, 4);
+        assert!((r.path_length - 4.0).abs() < 1e-9);
+
+        // Backwards along a one-way road, through a transit behind the origin
+        assert!(!trip_reachability(&net, &trip(5, 1, vec![])).reachable);
+        let r = trip_reachability(&net, &trip(3, 5, vec![2]));
+        assert!(!r.reachable);
+        assert!(!r.reason.is_empty());
+        assert!(!trip_reachability(&net, &trip(1, 42, vec![])).reachable);
+    }
+
+    #[test]
+    fn pause_update_remove() {
+        let mut session = Session::default(None);
+        session.add_cells(road(5));
+        let trip = pb::Trip {
+            id: 3,
+            trip_type: pb::TripType::Constant as i32,
+            from_node: 1,
+            to_node: 5,
+            initial_speed: 1,
+            time: 2,
+            ..Default::default()
+        };
+        let mut catalog = SessionCatalog::new();
+        session.add_trip(core_trip(&trip));
+        catalog.trips.insert(trip.id, trip.clone());
+        assert!(generated(&mut session, 10) > 0);
+
+        TripChange::SetPaused(3, true)
+            .apply(&mut session, &mut catalog)
+            .unwrap();
+        assert_eq!(generated(&mut session, 10), 0);
+
+        // Updating a paused trip keeps it paused
+        let faster = pb::Trip { time: 1, ..trip };
+        TripChange::Update(faster)
+            .apply(&mut session, &mut catalog)
+            .unwrap();
+        assert_eq!(generated(&mut session, 10), 0);
+        assert_eq!(catalog.trips[&3].time, 1);
+
+        TripChange::SetPaused(3, false)
+            .apply(&mut session, &mut catalog)
+            .unwrap();
+        assert!(generated(&mut session, 10) > 0);
+
+        TripChange::Remove(3)
+            .apply(&mut session, &mut catalog)
+            .unwrap();
+        assert!(!catalog.trips.contains_key(&3));
+        assert_eq!(generated(&mut session, 10), 0);
+        // The id of the dormant copy is not reused
+        assert_eq!(catalog.next_trip_id(), 4);
+        assert!(
+            TripChange::SetPaused(3, false)
+                .apply(&mut session, &mut catalog)
+                .is_err()
+        );
+    }
+}
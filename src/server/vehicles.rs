@@ -0,0 +1,497 @@
+use std::sync::Arc;
+
+use tonic::{Code, Request, Response, Status};
+
+use micro_traffic_sim::pb;
+use micro_traffic_sim_core::agents::{Vehicle, VehicleID};
+use micro_traffic_sim_core::agents_types::AgentType;
+use micro_traffic_sim_core::behaviour::{BehaviourParameters, BehaviourType};
+use micro_traffic_sim_core::grid::cell::CellID;
+use micro_traffic_sim_core::grid::road_network::GridRoads;
+use micro_traffic_sim_core::simulation::session::Session;
+use micro_traffic_sim_core::simulation::states::AutomataState;
+
+use super::catalog::{self, Catalogs, SessionCatalog};
+use super::events::{self, PreStep};
+use super::incidents;
+use super::recordings::Recordings;
+use super::routing;
+use super::step::core_agent_type_to_proto;
+use super::storage::SessionStore;
+use super::trip::{proto_agent_type_to_core, proto_behaviour_type_to_core};
+use super::{blocking, parse_session_uuid};
+
+/// Builds a vehicle the same way the core does for trip-generated ones.
+fn build_vehicle(id: VehicleID, req: &pb::SpawnVehicleRequest) -> Vehicle {
+    let agent_type = proto_agent_type_to_core(req.agent_type);
+    let behaviour_type = proto_behaviour_type_to_core(req.behaviour_type);
+    let bp = BehaviourParameters::from_behaviour_type(behaviour_type);
+    // Buses head to their first stop, as for trip-generated ones
+    let target = if agent_type == AgentType::Bus && !req.transits.is_empty() {
+        req.transits[0]
+    } else {
+        req.destination_cell
+    };
+    Vehicle::new(id)
+        .with_type(agent_type)
+        .with_behaviour(behaviour_type)
+        .with_cell(req.origin_cell)
+        .with_speed(req.initial_speed)
+        .with_speed_limit(bp.speed_limit())
+        .with_slowdown(bp.slowdown_factor())
+        .with_min_safe_distance(bp.min_safe_distance())
+        .with_aggressive_level(bp.aggressive_level())
+        .with_destination(target)
+        .with_trip(-1)
+        .with_transit_cells(req.transits.clone())
+        .build()
+}
+
+/// Puts a vehicle on the origin cell of an interactive session. Returns its id.
+fn spawn(
+    session: &mut Session,
+    catalog: &mut SessionCatalog,
+    req: &pb::SpawnVehicleRequest,
+) -> Result<VehicleID, Status> {
+    let cells = std::iter::once(&req.origin_cell)
+        .chain(std::iter::once(&req.destination_cell))
+        .chain(&req.transits);
+    for cell_id in cells {
+        if !catalog.cells.contains_key(cell_id) {
+            return Err(Status::not_found(format!(
+                "Not found cell ID: '{}'",
+                cell_id
+            )));
+        }
+    }
+    let occupied = session
+        .get_vehicles()
+        .values()
+        .any(|v| v.cell_id == req.origin_cell || v.tail_cells.contains(&req.origin_cell));
+    if occupied {
+        return Err(Status::failed_precondition(format!(
+            "Cell '{}' is occupied by another vehicle",
+            req.origin_cell
+        )));
+    }
+    let id = session.get_last_vehicle_id();
+    session.add_vehicles(vec![build_vehicle(id, req)]);
+    catalog.pending_spawns.push((id, req.origin_cell));
+    Ok(id)
+}
+
+/// Takes a vehicle out of an interactive session. The core has no removal API, so the
+/// vehicle is replaced by a blocked one whose destination is its current cell: the core
+/// drops it (as completed) on the next step, see `settle_manual`.
+fn remove(
+    session: &mut Session,
+    catalog: &mut SessionCatalog,
+    vehicle_id: VehicleID,
+) -> Result<(), Status> {
+    let Some(v) = session.get_vehicles().get(&vehicle_id) else {
+        return Err(Status::not_found(format!(
+            "Not found vehicle ID: '{}'",
+            vehicle_id
+        )));
+    };
+    let ghost = Vehicle::new(vehicle_id)
+        .with_type(v.vehicle_type)
+        .with_behaviour(BehaviourType::Block)
+        .with_cell(v.cell_id)
+        .with_speed(0)
+        .with_destination(v.cell_id)
+        .with_trip(v.trip)
+        .build();
+    catalog
+        .pending_removals
+        .insert(vehicle_id, (v.cell_id, v.trip));
+    session.add_vehicles(vec![ghost]);
+    Ok(())
+}
+
+/// Settles the vehicles spawned and removed by the client since the previous step.
+/// Must be called right after `Session::step` and before `events::collect`.
+///
+/// Reports SPAWNED for the manual spawns and REMOVED for the removed vehicles gone
+/// during the step (these are dropped from `pre`, so `collect` ignores them), and
+/// takes the removals out of the core's `vehicles_completed` counter in `dump`.
+pub fn settle_manual(
+    session: &Session,
+    catalog: &mut SessionCatalog,
+    mut pre: Option<&mut PreStep>,
+    dump: &mut AutomataState,
+) -> Vec<pb::VehicleEvent> {
+    let mut events = Vec::new();
+    for (id, origin) in catalog.pending_spawns.drain(..) {
+        events.push(events::event(
+            id,
+            pb::VehicleEventType::Spawned,
+            -1,
+            origin,
+            -1,
+        ));
+    }
+
+    let storage = session.get_vehicles();
+    let mut removed: Vec<VehicleID> = catalog
+        .pending_removals
+        .keys()
+        .filter(|id| !storage.contains_key(*id))
+        .copied()
+        .collect();
+    removed.sort_unstable();
+    for id in removed {
+        let Some((cell, trip)) = catalog.pending_removals.remove(&id) else {
+            continue;
+        };
+        catalog.vehicles_removed += 1;
+        if let Some(pre) = pre.as_deref_mut() {
+            pre.forget(id);
+        }
+        events.push(events::event(
+            id,
+            pb::VehicleEventType::Removed,
+            trip,
+            cell,
+            -1,
+        ));
+    }
+
+    dump.vehicles_completed -= catalog.vehicles_removed;
+    events
+}
+
+/// Spawns a single vehicle on an interactive session.
+pub async fn spawn_vehicle(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::SpawnVehicleRequest>,
+) -> Result<Response<pb::SpawnVehicleResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+
+    if req.initial_speed < 0 {
+        return Err(Status::invalid_argument(format!(
+            "Initial speed should be non-negative, but provided is {}",
+            req.initial_speed
+        )));
+    }
+    if incidents::running_recording(&recordings, &session_uuid)?.is_some() {
+        return Err(Status::failed_precondition(format!(
+            "Session '{}' is being recorded",
+            session_uuid
+        )));
+    }
+
+    let result = blocking(move || {
+        sessions.with_session_mut(&session_uuid, |session| {
+            catalog::with_catalog_mut(&catalogs, &session_uuid, |c| spawn(session, c, &req))
+        })
+    })
+    .await?;
+    let vehicle_id = match result {
+        None => {
+            return Err(Status::not_found(format!(
+                "Not found session ID: '{}'",
+                session_uuid
+            )));
+        }
+        Some(r) => r?,
+    };
+
+    Ok(Response::new(pb::SpawnVehicleResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        vehicle_id: vehicle_id as i64,
+    }))
+}
+
+/// Removes a vehicle from an interactive session.
+pub async fn remove_vehicle(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    recordings: Recordings,
+    request: Request<pb::RemoveVehicleRequest>,
+) -> Result<Response<pb::RemoveVehicleResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+
+    if req.vehicle_id < 0 {
+        return Err(Status::not_found(format!(
+            "Not found vehicle ID: '{}'",
+            req.vehicle_id
+        )));
+    }
+    if incidents::running_recording(&recordings, &session_uuid)?.is_some() {
+        return Err(Status::failed_precondition(format!(
+            "Session '{}' is being recorded",
+            session_uuid
+        )));
+    }
+
+    let vehicle_id = req.vehicle_id as VehicleID;
+    let result = blocking(move || {
+        sessions.with_session_mut(&session_uuid, |session| {
+            catalog::with_catalog_mut(&catalogs, &session_uuid, |c| remove(session, c, vehicle_id))
+        })
+    })
+    .await?;
+    match result {
+        None => {
+            return Err(Status::not_found(format!(
+                "Not found session ID: '{}'",
+                session_uuid
+            )));
+        }
+        Some(r) => r?,
+    }
+
+    Ok(Response::new(pb::RemoveVehicleResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+    }))
+}
+
+/// Converts core BehaviourType to proto BehaviourType (i32)
+fn core_behaviour_type_to_proto(behaviour_type: BehaviourType) -> i32 {
+    match behaviour_type {
+        BehaviourType::Undefined => 0,
+        BehaviourType::Block => 1,
+        BehaviourType::Aggressive => 2,
+        BehaviourType::Cooperative => 3,
+        BehaviourType::LimitSpeedByTrip => 4,
+    }
+}
+
+/// Builds the inspection view of a vehicle, without its remaining path: see
+/// [`route_remaining`].
+fn vehicle_info(v: &Vehicle) -> pb::VehicleInfo {
+    let remaining_transits: Vec<CellID> = if v.vehicle_type == AgentType::Bus {
+        v.transit_cells
+            .iter()
+            .skip(v.get_transits_made() as usize)
+            .copied()
+            .collect()
+    } else {
+        Vec::new()
+    };
+    pb::VehicleInfo {
+        state: Some(pb::VehicleState {
+            vehicle_id: v.id as i64,
+            vehicle_type: core_agent_type_to_proto(v.vehicle_type),
+            bearing: v.bearing,
+            speed: v.speed as i64,
+            cell: v.cell_id,
+            intermediate_cells: v.intention.intermediate_cells.clone(),
+            travel_time: v.travel_time,
+            trip_id: v.trip,
+            tail_cells: v.tail_cells.clone(),
+        }),
+        behaviour_type: core_behaviour_type_to_proto(v.strategy_type),
+        speed_limit: v.speed_limit,
+        destination: v.destination,
+        trip_destination: v.trip_destination,
+        remaining_transits,
+        destination_reachable: false,
+        remaining_path: Vec::new(),
+        // The core counts the lifetime of every vehicle on each step
+        steps_since_spawn: v.travel_time,
+        intention_cell: v.intention.intention_cell_id,
+        conflict_participant: v.is_conflict_participant,
+    }
+}
+
+/// Fills the remaining path of a vehicle, routed over the mirrored grid the way the
+/// core routes the vehicle: through the transits still to visit for buses, straight to
+/// the destination otherwise.
+fn route_remaining(info: &mut pb::VehicleInfo, net: &GridRoads) {
+    let cell = info.state.as_ref().map_or(-1, |s| s.cell);
+    info.remaining_path =
+        routing::find_route(net, cell, info.trip_destination, &info.remaining_transits)
+            .map(|r| r.cells)
+            .unwrap_or_default();
+    info.destination_reachable = !info.remaining_path.is_empty();
+}
+
+/// Returns the state, remaining route and intent of a vehicle of an interactive session.
+pub async fn get_vehicle(
+    sessions: Arc<SessionStore>,
+    catalogs: Catalogs,
+    request: Request<pb::GetVehicleRequest>,
+) -> Result<Response<pb::GetVehicleResponse>, Status> {
+    let req = request.into_inner();
+    let session_uuid = parse_session_uuid(&req.session_id)?;
+
+    let vehicle = blocking(move || {
+        let result = sessions.with_session_mut(&session_uuid, |session| {
+            let vehicle = u64::try_from(req.vehicle_id)
+                .ok()
+                .and_then(|id| session.get_vehicles().get(&id));
+            match vehicle {
+                Some(v) => Ok(vehicle_info(v)),
+                None => Err(Status::not_found(format!(
+                    "Not found vehicle ID: '{}'",
+                    req.vehicle_id
+                ))),
+            }
+        });
+        let mut vehicle = match result {
+            None => {
+                return Err(Status::not_found(format!(
+                    "Not found session ID: '{}'",
+                    session_uuid
+                )));
+            }
+            Some(r) => r?,
+        };
+        // Routed outside the session and catalog locks
+        let net = catalog::with_catalog_mut(&catalogs, &session_uuid, |c| c.network());
+        route_remaining(&mut vehicle, &net);
+        Ok(vehicle)
+    })
+    .await??;
+
+    Ok(Response::new(pb::GetVehicleResponse {
+        code: Code::Ok as u32,
+        text: "OK".to_string(),
+        vehicle: Some(vehicle),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::test_util::{plain_road, road};
+    use crate::server::trip::core_trip;
+
+    fn step(session: &mut Session, catalog: &mut SessionCatalog) -> Vec<pb::VehicleEvent> {
+        let mut pre = events::capture(session);
+        let mut dump = session.step().unwrap();
+        let mut all = settle_manual(session, catalog, Some(&mut pre), &mut dump);
+        all.extend(events::collect(session, &pre, &dump, catalog));
+        all
+    }
+
+    /// Two vehicles spawned on a road; one is removed, the other arrives.
+    #[test]
+    fn spawn_then_remove() {
+        let cells = plain_road(10);
+        let mut session = Session::default(None);
+        let mut catalog = SessionCatalog::new();
+        for cell in &cells {
+            catalog.insert_cell(cell.clone(), -1);
+        }
+        session.add_cells(cells);
+
+        let req = |origin: CellID| pb::SpawnVehicleRequest {
+            agent_type: pb::AgentType::Car as i32,
+            behaviour_type: pb::BehaviourType::Cooperative as i32,
+            origin_cell: origin,
+            destination_cell: 9,
+            initial_speed: 1,
+            ..Default::default()
+        };
+        let first = spawn(&mut session, &mut catalog, &req(1)).unwrap();
+        let second = spawn(&mut session, &mut catalog, &req(5)).unwrap();
+        assert_ne!(first, second);
+        assert!(
+            spawn(&mut session, &mut catalog, &req(5)).is_err(),
+            "occupied"
+        );
+        assert!(
+            spawn(&mut session, &mut catalog, &req(100)).is_err(),
+            "unknown"
+        );
+
+        let events = step(&mut session, &mut catalog);
+        let spawned: Vec<_> = events
+            .iter()
+            .filter(|e| e.event_type == pb::VehicleEventType::Spawned as i32)
+            .map(|e| (e.vehicle_id as VehicleID, e.cell))
+            .collect();
+        assert_eq!(spawned, vec![(first, 1), (second, 5)]);
+
+        let mut info = vehicle_info(&session.get_vehicles()[&second]);
+        route_remaining(&mut info, &catalog.network());
+        assert_eq!(info.trip_destination, 9);
+        assert_eq!(info.steps_since_spawn, 1);
+        assert!(info.destination_reachable);
+        let cell = info.state.unwrap().cell;
+        assert_eq!(info.remaining_path, (cell..=9).collect::<Vec<_>>());
+
+        remove(&mut session, &mut catalog, first).unwrap();
+        assert!(remove(&mut session, &mut catalog, 999).is_err());
+        let events = step(&mut session, &mut catalog);
+        let removed: Vec<_> = events
+            .iter()
+            .filter(|e| e.event_type == pb::VehicleEventType::Removed as i32)
+            .collect();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].vehicle_id as VehicleID, first);
+        assert!(!session.get_vehicles().contains_key(&first));
+        assert_eq!(catalog.vehicles_removed, 1);
+        assert_eq!(catalog.vehicles_completed, 0);
+
+        let mut all = Vec::new();
+        for _ in 0..10 {
+            all.extend(step(&mut session, &mut catalog));
+        }
+        let arrived: Vec<_> = all
+            .iter()
+            .filter(|e| e.event_type == pb::VehicleEventType::Arrived as i32)
+            .collect();
+        assert_eq!(arrived.len(), 1);
+        assert_eq!(arrived[0].vehicle_id as VehicleID, second);
+        assert_eq!(catalog.vehicles_completed, 1);
+    }
+
+    /// A trip vehicle removed mid-way: reported with its own trip, counted neither as
+    /// completed nor as lost, with or without the pre-step snapshot.
+    #[test]
+    fn removal_counters() {
+        for with_pre in [true, false] {
+            let mut session = Session::default(None);
+            let mut catalog = SessionCatalog::new();
+            session.add_cells(road(10));
+            let trip = pb::Trip {
+                id: 7,
+                trip_type: pb::TripType::Constant as i32,
+                from_node: 1,
+                to_node: 10,
+                initial_speed: 1,
+                time: 4,
+                ..Default::default()
+            };
+            session.add_trip(core_trip(&trip));
+
+            let step = |session: &mut Session, catalog: &mut SessionCatalog| {
+                let mut pre = events::capture(session);
+                let mut dump = session.step().unwrap();
+                let events =
+                    settle_manual(session, catalog, with_pre.then_some(&mut pre), &mut dump);
+                (events, dump)
+            };
+            let mut first = None;
+            while first.is_none() {
+                step(&mut session, &mut catalog);
+                first = session.get_vehicles().keys().min().copied();
+            }
+            let first = first.unwrap();
+            remove(&mut session, &mut catalog, first).unwrap();
+            let (events, dump) = step(&mut session, &mut catalog);
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].event_type, pb::VehicleEventType::Removed as i32);
+            assert_eq!(events[0].trip_id, 7);
+            assert_eq!((dump.vehicles_completed, dump.vehicles_lost), (0, 0));
+
+            // The next vehicle of the trip arrives and is the only one counted
+            let mut dump = dump;
+            for _ in 0..12 {
+                dump = step(&mut session, &mut catalog).1;
+            }
+            assert_eq!((dump.vehicles_completed, dump.vehicles_lost), (1, 0));
+            assert_eq!(catalog.vehicles_removed, 1);
+        }
+    }
+}